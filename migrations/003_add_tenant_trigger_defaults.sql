@@ -0,0 +1,5 @@
+-- Tenant-level defaults merged into trigger configurations at delivery time
+-- Keys present in a trigger's own configuration always take precedence
+
+ALTER TABLE tenants
+ADD COLUMN trigger_defaults JSONB DEFAULT '{}'::jsonb;
@@ -0,0 +1,15 @@
+-- Optional per-blockchain sub-quotas, applied on top of the tenant-wide limits
+-- A NULL limit means the blockchain is only bound by the tenant-wide quota
+
+CREATE TABLE IF NOT EXISTS tenant_blockchain_quotas (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    blockchain VARCHAR(50) NOT NULL, -- 'stellar', 'evm'
+    max_monitors INTEGER,
+    max_networks INTEGER,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, blockchain)
+);
+
+CREATE TRIGGER update_tenant_blockchain_quotas_updated_at BEFORE UPDATE ON tenant_blockchain_quotas
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,40 @@
+-- Ordered stream of monitor/network/trigger changes used for cache invalidation
+-- Rows are written by table triggers so every mutation path is captured
+
+CREATE TABLE IF NOT EXISTS resource_change_events (
+    seq BIGSERIAL PRIMARY KEY, -- Feed cursor
+    tenant_id UUID NOT NULL,
+    kind VARCHAR(50) NOT NULL, -- 'monitor', 'network', 'trigger'
+    resource_id UUID NOT NULL,
+    action VARCHAR(50) NOT NULL, -- 'created', 'updated', 'deleted'
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_resource_change_events_tenant_seq ON resource_change_events(tenant_id, seq);
+
+CREATE OR REPLACE FUNCTION record_resource_change()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO resource_change_events (tenant_id, kind, resource_id, action)
+        VALUES (OLD.tenant_id, TG_ARGV[0], OLD.id, 'deleted');
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO resource_change_events (tenant_id, kind, resource_id, action)
+    VALUES (
+        NEW.tenant_id,
+        TG_ARGV[0],
+        NEW.id,
+        CASE WHEN TG_OP = 'INSERT' THEN 'created' ELSE 'updated' END
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_tenant_networks_change AFTER INSERT OR UPDATE OR DELETE ON tenant_networks
+    FOR EACH ROW EXECUTE FUNCTION record_resource_change('network');
+CREATE TRIGGER record_tenant_monitors_change AFTER INSERT OR UPDATE OR DELETE ON tenant_monitors
+    FOR EACH ROW EXECUTE FUNCTION record_resource_change('monitor');
+CREATE TRIGGER record_tenant_triggers_change AFTER INSERT OR UPDATE OR DELETE ON tenant_triggers
+    FOR EACH ROW EXECUTE FUNCTION record_resource_change('trigger');
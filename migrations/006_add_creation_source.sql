@@ -0,0 +1,18 @@
+-- Track how monitors, networks and triggers were created
+-- Existing rows were all created through the API
+
+ALTER TABLE tenant_monitors
+ADD COLUMN creation_source VARCHAR(20) NOT NULL DEFAULT 'api'
+    CHECK (creation_source IN ('api', 'import', 'template'));
+
+ALTER TABLE tenant_networks
+ADD COLUMN creation_source VARCHAR(20) NOT NULL DEFAULT 'api'
+    CHECK (creation_source IN ('api', 'import', 'template'));
+
+ALTER TABLE tenant_triggers
+ADD COLUMN creation_source VARCHAR(20) NOT NULL DEFAULT 'api'
+    CHECK (creation_source IN ('api', 'import', 'template'));
+
+CREATE INDEX idx_tenant_monitors_creation_source ON tenant_monitors(tenant_id, creation_source);
+CREATE INDEX idx_tenant_networks_creation_source ON tenant_networks(tenant_id, creation_source);
+CREATE INDEX idx_tenant_triggers_creation_source ON tenant_triggers(tenant_id, creation_source);
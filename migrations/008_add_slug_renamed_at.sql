@@ -0,0 +1,3 @@
+-- Last slug rename per tenant, used to enforce the rename cooldown
+
+ALTER TABLE tenants ADD COLUMN IF NOT EXISTS slug_renamed_at TIMESTAMPTZ;
@@ -0,0 +1,42 @@
+-- Soft-delete for monitors: deleted rows are hidden and purged after a retention window
+
+ALTER TABLE tenant_monitors ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_tenant_monitors_deleted_at ON tenant_monitors(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- Report soft-deletes and restores in the change feed, and skip purges of rows already reported deleted
+CREATE OR REPLACE FUNCTION record_resource_change()
+RETURNS TRIGGER AS $$
+DECLARE
+    was_deleted BOOLEAN;
+    is_deleted BOOLEAN;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        IF to_jsonb(OLD) ->> 'deleted_at' IS NOT NULL THEN
+            RETURN OLD;
+        END IF;
+        INSERT INTO resource_change_events (tenant_id, kind, resource_id, action)
+        VALUES (OLD.tenant_id, TG_ARGV[0], OLD.id, 'deleted');
+        RETURN OLD;
+    END IF;
+
+    IF TG_OP = 'UPDATE' THEN
+        was_deleted := to_jsonb(OLD) ->> 'deleted_at' IS NOT NULL;
+        is_deleted := to_jsonb(NEW) ->> 'deleted_at' IS NOT NULL;
+    END IF;
+
+    INSERT INTO resource_change_events (tenant_id, kind, resource_id, action)
+    VALUES (
+        NEW.tenant_id,
+        TG_ARGV[0],
+        NEW.id,
+        CASE
+            WHEN TG_OP = 'INSERT' THEN 'created'
+            WHEN is_deleted AND NOT was_deleted THEN 'deleted'
+            WHEN was_deleted AND NOT is_deleted THEN 'created'
+            ELSE 'updated'
+        END
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
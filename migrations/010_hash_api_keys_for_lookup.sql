@@ -0,0 +1,22 @@
+-- API keys are now looked up by a clear-text prefix and verified against a SHA-256 hash
+-- of the key body (everything after the configured "smt_" prefix)
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS key_prefix VARCHAR(16);
+
+-- Keys stored unhashed: keep them working by hashing them in place
+UPDATE api_keys
+SET key_prefix = LEFT(key_hash, 8),
+    key_hash = encode(sha256(convert_to(key_hash, 'UTF8')), 'hex'),
+    updated_at = NOW()
+WHERE key_prefix IS NULL
+AND key_hash NOT LIKE '$argon2%'
+AND LENGTH(key_hash) > 8;
+
+-- Keys stored as salted password hashes can't be looked up and never authenticated;
+-- disable them so they show up as revoked and can be reissued
+UPDATE api_keys
+SET is_active = false,
+    updated_at = NOW()
+WHERE key_prefix IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_key_prefix ON api_keys(key_prefix);
@@ -0,0 +1,8 @@
+-- Rotating an API key keeps its id; the replaced key can stay valid for a grace period
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS previous_key_prefix VARCHAR(16);
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS previous_key_hash VARCHAR(255);
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS previous_key_expires_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_previous_key_prefix ON api_keys(previous_key_prefix)
+WHERE previous_key_prefix IS NOT NULL;
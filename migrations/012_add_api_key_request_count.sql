@@ -0,0 +1,3 @@
+-- Requests authenticated by each API key, flushed together with last_used_at
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS request_count BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,6 @@
+-- Limited-use API keys: remaining_uses counts down on every authentication
+-- and the key is deactivated when it reaches zero. Both stay NULL for
+-- unlimited keys.
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS max_uses INTEGER;
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS remaining_uses INTEGER;
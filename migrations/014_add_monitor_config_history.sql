@@ -0,0 +1,14 @@
+-- Prior monitor configurations, recorded whenever an update replaces one.
+-- Versions count up per monitor; the live configuration is one past the latest.
+
+CREATE TABLE IF NOT EXISTS monitor_config_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    monitor_id UUID NOT NULL REFERENCES tenant_monitors(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    configuration JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(monitor_id, version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_monitor_config_history_tenant_id ON monitor_config_history(tenant_id);
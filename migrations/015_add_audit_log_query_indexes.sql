@@ -0,0 +1,5 @@
+-- Indexes backing the filtered audit log query, which always filters by tenant
+-- and pages newest first.
+
+CREATE INDEX IF NOT EXISTS idx_audit_logs_tenant_created_at ON audit_logs(tenant_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_logs_tenant_resource ON audit_logs(tenant_id, resource_type, resource_id);
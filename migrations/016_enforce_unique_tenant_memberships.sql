@@ -0,0 +1,18 @@
+-- Enforce one membership per (tenant, user) on databases whose memberships
+-- table predates the UNIQUE constraint in the initial schema. If duplicates
+-- already exist the index is skipped so startup is not blocked; list them via
+-- GET /api/v1/admin/memberships/duplicates, remove the extras and re-run.
+
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM tenant_memberships
+        GROUP BY tenant_id, user_id
+        HAVING COUNT(*) > 1
+    ) THEN
+        RAISE WARNING 'tenant_memberships has duplicate (tenant_id, user_id) rows; unique index not created';
+    ELSE
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_tenant_memberships_tenant_user
+            ON tenant_memberships(tenant_id, user_id);
+    END IF;
+END $$;
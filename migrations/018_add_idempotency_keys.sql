@@ -0,0 +1,14 @@
+-- Responses to create requests sent with an Idempotency-Key header, so a
+-- retried request replays the stored response instead of creating the
+-- resource again. Keys are scoped per tenant; a row is only committed once
+-- the first request has finished, and concurrent requests with the same key
+-- wait on its primary key until then.
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    key VARCHAR(255) NOT NULL,
+    response_status INTEGER,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, key)
+);
@@ -0,0 +1,6 @@
+-- Users confirm their email address before they can log in, when
+-- SMT__AUTH__REQUIRE_EMAIL_VERIFICATION is set. Existing users are treated as
+-- verified; users registered from now on start out unverified.
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email_verified BOOLEAN NOT NULL DEFAULT true;
+ALTER TABLE users ALTER COLUMN email_verified SET DEFAULT false;
@@ -0,0 +1,6 @@
+-- Slugs only need to be unique among live tenants, so a soft-deleted tenant's
+-- slug can be claimed again; SMT__TENANTS__DELETED_SLUG_RETENTION_DAYS holds it back
+
+ALTER TABLE tenants DROP CONSTRAINT IF EXISTS tenants_slug_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tenants_slug_live ON tenants(slug) WHERE deleted_at IS NULL;
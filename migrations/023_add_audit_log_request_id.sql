@@ -0,0 +1,8 @@
+-- Id of the API request that wrote the entry, as returned in its X-Request-Id
+-- header, so request logs can be joined to audit entries. NULL for entries
+-- written outside a request, e.g. by background cleanup.
+
+ALTER TABLE audit_logs ADD COLUMN IF NOT EXISTS request_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_audit_logs_request_id ON audit_logs(request_id)
+	WHERE request_id IS NOT NULL;
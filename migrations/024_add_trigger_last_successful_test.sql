@@ -0,0 +1,6 @@
+-- When a test delivery from POST /triggers/{id}/test last reached the
+-- trigger's channel. Cleared when the trigger's configuration changes, and
+-- checked before a monitor is activated when SMT__MONITORS__REQUIRE_TESTED_TRIGGERS
+-- is set.
+
+ALTER TABLE tenant_triggers ADD COLUMN IF NOT EXISTS last_successful_test_at TIMESTAMPTZ;
@@ -0,0 +1,10 @@
+-- Fingerprint (method, path and body hash) of the request that claimed an
+-- idempotency key, so reusing the key for a different request is rejected
+-- instead of replaying an unrelated response. Rows written before this have
+-- no fingerprint and match any request.
+ALTER TABLE idempotency_keys ADD COLUMN IF NOT EXISTS request_fingerprint VARCHAR(64);
+
+-- Renewed while the claiming request runs; a pending claim is only treated as
+-- abandoned once its lease has not been renewed for the claim timeout
+ALTER TABLE idempotency_keys
+    ADD COLUMN IF NOT EXISTS lease_renewed_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
@@ -1,30 +1,49 @@
 use axum::{
-	extract::{Path, State},
-	http::StatusCode,
+	extract::{ConnectInfo, Path, State},
+	http::{HeaderMap, StatusCode},
 	response::IntoResponse,
 	Json,
 };
+use axum_extra::{
+	extract::TypedHeader,
+	headers::{authorization::Bearer, Authorization},
+};
 use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
 use uuid::Uuid;
 
-use super::handlers::{ApiError, ApiResponse};
+use super::handlers::{request_metadata, ApiError, ApiResponse};
 use crate::models::*;
+use crate::repositories::{
+	accept_pending_invitations, InvitationRepository, TenantRepositoryError,
+};
 use crate::services::ServiceError;
+use crate::utils::AuthService;
 
 #[derive(Debug, Deserialize)]
 pub struct RegisterRequest {
 	pub email: String,
 	pub password: String,
 	pub tenant_name: String,
-	pub tenant_slug: String,
+	/// Derived from `tenant_name` when omitted
+	#[serde(default)]
+	pub tenant_slug: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct RegisterResponse {
 	pub user: User,
 	pub tenant: Tenant,
-	pub access_token: String,
-	pub refresh_token: String,
+	/// Tenants joined by accepting invitations sent to the email before
+	/// registering; when verification is required they are joined on verification
+	pub joined_tenant_ids: Vec<Uuid>,
+	/// Confirms the email address through `/auth/verify-email`
+	pub verification_token: String,
+	/// Left out when the user has to verify their email before logging in
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub access_token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub refresh_token: Option<String>,
 }
 
 pub async fn register<M, N, T, TR, A>(
@@ -43,6 +62,21 @@ where
 		return Err(ApiError::BadRequest("Invalid email address".to_string()));
 	}
 
+	// Reject malformed or reserved tenant slugs
+	let tenant_slug = request
+		.tenant_slug
+		.filter(|slug| !slug.is_empty())
+		.unwrap_or_else(|| slugify(&request.tenant_name));
+	state
+		.tenant_repo
+		.validate_slug(&tenant_slug)
+		.map_err(ServiceError::from)?;
+	state
+		.tenant_repo
+		.check_deleted_slug(&tenant_slug)
+		.await
+		.map_err(ServiceError::from)?;
+
 	// Hash password
 	let password_hash = state
 		.auth_service
@@ -68,7 +102,7 @@ where
 		          created_at, updated_at
 		"#,
 		request.tenant_name,
-		request.tenant_slug
+		tenant_slug
 	)
 	.fetch_one(&mut *tx)
 	.await
@@ -83,11 +117,11 @@ where
 	let user = sqlx::query_as!(
 		User,
 		r#"
-		INSERT INTO users (email, password_hash)
-		VALUES ($1, $2)
+		INSERT INTO users (email, password_hash, email_verified)
+		VALUES ($1, $2, false)
 		RETURNING id, email, password_hash,
 		          COALESCE(is_active, true) as "is_active!",
-		          created_at, updated_at
+		          email_verified, created_at, updated_at
 		"#,
 		request.email,
 		password_hash
@@ -114,10 +148,292 @@ where
 	.await
 	.map_err(|_| ApiError::Internal)?;
 
+	// Join the tenants the email was invited to before registering, unless
+	// the email has to be verified first
+	let verification_required = state.auth_service.requires_email_verification();
+	let accepted = if verification_required {
+		Vec::new()
+	} else {
+		accept_pending_invitations(&mut tx, user.id, &user.email)
+			.await
+			.map_err(|_| ApiError::Internal)?
+	};
+
 	// Commit transaction
 	tx.commit().await.map_err(|_| ApiError::Internal)?;
 
+	for invitation in &accepted {
+		log_invitation_accepted(&state.audit_service, invitation, None).await?;
+	}
+
 	// Generate tokens
+	let verification_token = state
+		.auth_service
+		.generate_verification_token(&user)
+		.map_err(|_| ApiError::Internal)?;
+	let (access_token, refresh_token) = if verification_required {
+		(None, None)
+	} else {
+		(
+			Some(
+				state
+					.auth_service
+					.generate_jwt(&user)
+					.map_err(|_| ApiError::Internal)?,
+			),
+			Some(
+				state
+					.auth_service
+					.generate_refresh_token(&user)
+					.map_err(|_| ApiError::Internal)?,
+			),
+		)
+	};
+
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: RegisterResponse {
+				user,
+				tenant,
+				joined_tenant_ids: accepted
+					.iter()
+					.map(|invitation| invitation.tenant_id)
+					.collect(),
+				verification_token,
+				access_token,
+				refresh_token,
+			},
+			meta: None,
+		}),
+	))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailRequest {
+	pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyEmailResponse {
+	pub user_id: Uuid,
+	pub email: String,
+	pub email_verified: bool,
+	/// Tenants joined by accepting invitations held back until verification
+	pub joined_tenant_ids: Vec<Uuid>,
+}
+
+/// Mark the user a verification token was issued for as verified. The token
+/// no longer works once the user's email has changed.
+pub async fn verify_email<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: crate::services::MonitorServiceTrait,
+	N: crate::services::NetworkServiceTrait,
+	T: crate::services::TriggerServiceTrait,
+	TR: crate::repositories::TenantRepositoryTrait,
+	A: crate::services::AuditServiceTrait,
+{
+	let claims = state
+		.auth_service
+		.verify_verification_token(&request.token)
+		.map_err(|_| ApiError::BadRequest("Invalid or expired verification token".to_string()))?;
+
+	let mut tx = state.pool.begin().await.map_err(|_| ApiError::Internal)?;
+
+	let user_id = sqlx::query_scalar!(
+		r#"
+		UPDATE users
+		SET email_verified = true, updated_at = NOW()
+		WHERE id = $1 AND email = $2 AND COALESCE(is_active, true) = true
+		RETURNING id
+		"#,
+		claims.sub,
+		claims.email
+	)
+	.fetch_optional(&mut *tx)
+	.await
+	.map_err(|_| ApiError::Internal)?
+	.ok_or_else(|| ApiError::BadRequest("Invalid or expired verification token".to_string()))?;
+
+	// Invitations are only accepted on registration when verification is off
+	let accepted = accept_pending_invitations(&mut tx, user_id, &claims.email)
+		.await
+		.map_err(|_| ApiError::Internal)?;
+
+	tx.commit().await.map_err(|_| ApiError::Internal)?;
+
+	for invitation in &accepted {
+		log_invitation_accepted(&state.audit_service, invitation, None).await?;
+	}
+
+	Ok(Json(ApiResponse {
+		data: VerifyEmailResponse {
+			user_id,
+			email: claims.email,
+			email_verified: true,
+			joined_tenant_ids: accepted
+				.iter()
+				.map(|invitation| invitation.tenant_id)
+				.collect(),
+		},
+		meta: None,
+	}))
+}
+
+/// Join a tenant with an invitation token; the caller must be signed in as
+/// the user the invitation was sent to
+pub async fn accept_invitation<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path(token): Path<String>,
+	auth_header: Option<TypedHeader<Authorization<Bearer>>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: crate::services::MonitorServiceTrait,
+	N: crate::services::NetworkServiceTrait,
+	T: crate::services::TriggerServiceTrait,
+	TR: crate::repositories::TenantRepositoryTrait,
+	A: crate::services::AuditServiceTrait,
+{
+	let claims = auth_header
+		.and_then(|TypedHeader(auth_header)| {
+			state.auth_service.verify_jwt(auth_header.token()).ok()
+		})
+		.ok_or(ApiError::Unauthorized)?;
+
+	let invitation = InvitationRepository::new(state.pool.clone())
+		.accept(&token, claims.sub, &claims.email)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::AccessDenied(message) => {
+				ApiError::Service(ServiceError::AccessDenied(message))
+			}
+			e => ApiError::Service(ServiceError::from(e)),
+		})?;
+
+	log_invitation_accepted(
+		&state.audit_service,
+		&invitation,
+		Some(request_metadata(addr, &headers)),
+	)
+	.await?;
+
+	Ok(Json(ApiResponse {
+		data: invitation,
+		meta: None,
+	}))
+}
+
+async fn log_invitation_accepted<A>(
+	audit_service: &A,
+	invitation: &TenantInvitation,
+	metadata: Option<RequestMetadata>,
+) -> Result<(), ApiError>
+where
+	A: crate::services::AuditServiceTrait,
+{
+	let metadata = metadata.unwrap_or_default();
+	audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: invitation.tenant_id,
+			user_id: invitation.accepted_by,
+			api_key_id: None,
+			action: AuditAction::InvitationAccepted,
+			resource_type: Some(ResourceType::Invitation),
+			resource_id: Some(invitation.id),
+			changes: Some(serde_json::json!({
+				"email": invitation.email,
+				"role": invitation.role,
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok(())
+}
+
+#[derive(Debug, Serialize)]
+pub struct SsoProvisionResponse {
+	pub user: User,
+	pub tenants: Vec<UserTenant>,
+	/// Whether a tenant was provisioned for the user by this request
+	pub tenant_created: bool,
+	pub access_token: String,
+	pub refresh_token: String,
+}
+
+/// Sign in with an ID token from the configured identity provider, creating or
+/// linking the local user and, for users without a tenant, provisioning one
+pub async fn sso_provision<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<SsoProvisionRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: crate::services::MonitorServiceTrait,
+	N: crate::services::NetworkServiceTrait,
+	T: crate::services::TriggerServiceTrait,
+	TR: crate::repositories::TenantRepositoryTrait,
+	A: crate::services::AuditServiceTrait,
+{
+	let sso = state.auth_service.sso().ok_or(ApiError::NotFound)?;
+	let identity = sso
+		.verify(&request.id_token)
+		.map_err(|_| ApiError::Unauthorized)?;
+
+	let user = match state
+		.tenant_repo
+		.find_user_by_identity(&identity.issuer, &identity.subject)
+		.await
+		.map_err(ServiceError::from)?
+	{
+		Some(user) => user,
+		None => state
+			.tenant_repo
+			.link_identity(&identity)
+			.await
+			.map_err(ServiceError::from)?,
+	};
+	if !user.is_active {
+		return Err(ApiError::Unauthorized);
+	}
+
+	let mut tenants = state
+		.tenant_repo
+		.get_user_tenants(user.id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let tenant_created = tenants.is_empty() && sso.provisions_tenants();
+	if tenant_created {
+		let name = request
+			.tenant_name
+			.or(identity.name)
+			.unwrap_or_else(|| identity.email.clone());
+		let tenant = state
+			.tenant_repo
+			.create_with_owner(
+				CreateTenantRequest {
+					slug: request.tenant_slug.unwrap_or_default(),
+					name,
+					max_monitors: None,
+					max_networks: None,
+					max_triggers_per_monitor: None,
+					max_rpc_requests_per_minute: None,
+					max_storage_mb: None,
+				},
+				user.id,
+			)
+			.await
+			.map_err(ServiceError::from)?;
+		tenants.push((tenant, TenantRole::Owner));
+	}
+
 	let access_token = state
 		.auth_service
 		.generate_jwt(&user)
@@ -127,12 +443,27 @@ where
 		.generate_refresh_token(&user)
 		.map_err(|_| ApiError::Internal)?;
 
+	let status = if tenant_created {
+		StatusCode::CREATED
+	} else {
+		StatusCode::OK
+	};
+
 	Ok((
-		StatusCode::CREATED,
+		status,
 		Json(ApiResponse {
-			data: RegisterResponse {
+			data: SsoProvisionResponse {
 				user,
-				tenant,
+				tenants: tenants
+					.into_iter()
+					.map(|(tenant, role)| UserTenant {
+						tenant_id: tenant.id,
+						tenant_name: tenant.name,
+						tenant_slug: tenant.slug,
+						role,
+					})
+					.collect(),
+				tenant_created,
 				access_token,
 				refresh_token,
 			},
@@ -158,7 +489,7 @@ where
 		r#"
 		SELECT id, email, password_hash,
 		       COALESCE(is_active, true) as "is_active!",
-		       created_at, updated_at
+		       email_verified, created_at, updated_at
 		FROM users 
 		WHERE email = $1 AND COALESCE(is_active, true) = true
 		"#,
@@ -169,6 +500,11 @@ where
 	.map_err(|_| ApiError::Internal)?
 	.ok_or(ApiError::Unauthorized)?;
 
+	// Users provisioned through SSO have no password
+	if user.password_hash.is_empty() {
+		return Err(ApiError::Unauthorized);
+	}
+
 	// Verify password
 	let password_valid = state
 		.auth_service
@@ -179,6 +515,10 @@ where
 		return Err(ApiError::Unauthorized);
 	}
 
+	if state.auth_service.requires_email_verification() && !user.email_verified {
+		return Err(ApiError::EmailNotVerified);
+	}
+
 	// Get user's tenants
 	let user_tenants = sqlx::query!(
 		r#"
@@ -235,6 +575,81 @@ where
 	}))
 }
 
+/// A tenant the caller can operate on
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessibleTenant {
+	pub id: Uuid,
+	pub name: String,
+	pub slug: String,
+	pub is_active: bool,
+	/// The user's role; left out for an API key, whose permissions are its own
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<TenantRole>,
+}
+
+impl AccessibleTenant {
+	fn new(tenant: Tenant, role: Option<TenantRole>) -> Self {
+		Self {
+			id: tenant.id,
+			name: tenant.name,
+			slug: tenant.slug,
+			is_active: tenant.is_active,
+			role,
+		}
+	}
+}
+
+/// Tenants the bearer token can operate on: an API key's own tenant, or every
+/// tenant the signed-in user is a member of
+pub async fn list_accessible_tenants<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	auth_header: Option<TypedHeader<Authorization<Bearer>>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: crate::services::MonitorServiceTrait,
+	N: crate::services::NetworkServiceTrait,
+	T: crate::services::TriggerServiceTrait,
+	TR: crate::repositories::TenantRepositoryTrait,
+	A: crate::services::AuditServiceTrait,
+{
+	let TypedHeader(auth_header) = auth_header.ok_or(ApiError::Unauthorized)?;
+	let token = auth_header.token();
+
+	let tenants = if token.starts_with(&crate::config::Config::default().auth.api_key_prefix) {
+		let context = super::middleware::authenticate_api_key(
+			&state.pool,
+			None,
+			token,
+			state.auth_service.default_full_access(),
+		)
+		.await?;
+		let tenant = state
+			.tenant_repo
+			.get(context.tenant_id)
+			.await
+			.map_err(ServiceError::from)?;
+		vec![AccessibleTenant::new(tenant, None)]
+	} else {
+		let claims = state
+			.auth_service
+			.verify_jwt(token)
+			.map_err(|_| ApiError::Unauthorized)?;
+		state
+			.tenant_repo
+			.get_user_tenants(claims.sub)
+			.await
+			.map_err(ServiceError::from)?
+			.into_iter()
+			.map(|(tenant, role)| AccessibleTenant::new(tenant, Some(role)))
+			.collect()
+	};
+
+	Ok(Json(ApiResponse {
+		data: tenants,
+		meta: None,
+	}))
+}
+
 pub async fn create_api_key<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	Path(tenant_slug): Path<String>,
@@ -256,7 +671,8 @@ where
 		)));
 	}
 
-	// Get tenant
+	// Get tenant; under `/tenants/current` the slug comes from the context
+	let tenant_slug = context.tenant_slug.clone().unwrap_or(tenant_slug);
 	let tenant = state
 		.tenant_repo
 		.get_by_slug(&tenant_slug)
@@ -270,25 +686,34 @@ where
 		)));
 	}
 
-	// Generate API key
+	if request.max_uses.is_some_and(|max_uses| max_uses < 1) {
+		return Err(ApiError::BadRequest(
+			"max_uses must be at least 1".to_string(),
+		));
+	}
+
+	// Generate API key; only its lookup id and hash are stored
 	let api_key = state.auth_service.generate_api_key();
-	let key_hash = state
-		.auth_service
-		.hash_password(&api_key)
-		.map_err(|_| ApiError::Internal)?;
+	let key_prefix = AuthService::api_key_lookup(&api_key).ok_or(ApiError::Internal)?;
+	let key_hash = AuthService::hash_api_key(&api_key);
 
 	// Store API key
 	let stored_key = sqlx::query!(
 		r#"
-		INSERT INTO api_keys (tenant_id, name, key_hash, permissions, expires_at)
-		VALUES ($1, $2, $3, $4, $5)
+		INSERT INTO api_keys (
+			tenant_id, name, key_prefix, key_hash, permissions, expires_at,
+			max_uses, remaining_uses
+		)
+		VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
 		RETURNING id, created_at
 		"#,
 		tenant.id,
 		request.name,
+		key_prefix,
 		key_hash,
 		serde_json::to_value(&request.permissions).unwrap(),
-		request.expires_at
+		request.expires_at,
+		request.max_uses
 	)
 	.fetch_one(&state.pool)
 	.await
@@ -301,12 +726,13 @@ where
 				id: stored_key.id,
 				name: request.name.clone(),
 				key: format!(
-					"{}_{}",
+					"{}{}",
 					crate::config::Config::default().auth.api_key_prefix,
 					api_key
 				),
 				permissions: request.permissions.clone(),
 				expires_at: request.expires_at,
+				max_uses: request.max_uses,
 				created_at: stored_key.created_at.unwrap_or_else(chrono::Utc::now),
 			},
 			meta: None,
@@ -327,10 +753,18 @@ where
 {
 	let context = crate::utils::current_tenant_context();
 
+	// Verify user can manage tenant
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to list API keys".to_string(),
+		)));
+	}
+
 	// List API keys (without the actual key values)
 	let keys = sqlx::query!(
 		r#"
-		SELECT id, name, permissions, last_used_at, expires_at, is_active, created_at, updated_at
+		SELECT id, name, permissions, last_used_at, request_count, max_uses, remaining_uses,
+		       expires_at, is_active, created_at, updated_at
 		FROM api_keys
 		WHERE tenant_id = $1
 		ORDER BY created_at DESC
@@ -349,6 +783,9 @@ where
 				"name": row.name,
 				"permissions": row.permissions,
 				"last_used_at": row.last_used_at,
+				"request_count": row.request_count,
+				"max_uses": row.max_uses,
+				"remaining_uses": row.remaining_uses,
 				"expires_at": row.expires_at,
 				"is_active": row.is_active,
 				"created_at": row.created_at,
@@ -399,3 +836,115 @@ where
 
 	Ok(StatusCode::NO_CONTENT)
 }
+
+pub async fn rotate_api_key<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, key_id)): Path<(String, Uuid)>,
+	request: Option<Json<RotateApiKeyRequest>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: crate::services::MonitorServiceTrait,
+	N: crate::services::NetworkServiceTrait,
+	T: crate::services::TriggerServiceTrait,
+	TR: crate::repositories::TenantRepositoryTrait,
+	A: crate::services::AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	// Verify user can manage tenant
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to rotate API keys".to_string(),
+		)));
+	}
+
+	// Work out until when the replaced key keeps authenticating
+	let grace_period_seconds = request
+		.map(|Json(request)| request.grace_period_seconds)
+		.unwrap_or_default()
+		.unwrap_or(0);
+	if grace_period_seconds < 0 {
+		return Err(ApiError::BadRequest(
+			"grace_period_seconds must not be negative".to_string(),
+		));
+	}
+	let previous_key_expires_at = if grace_period_seconds > 0 {
+		let grace_period = chrono::Duration::try_seconds(grace_period_seconds)
+			.and_then(|grace_period| chrono::Utc::now().checked_add_signed(grace_period))
+			.ok_or_else(|| ApiError::BadRequest("grace_period_seconds is too large".to_string()))?;
+		Some(grace_period)
+	} else {
+		None
+	};
+
+	// Generate the replacement key; name, permissions and expiry are kept
+	let api_key = state.auth_service.generate_api_key();
+	let key_prefix = AuthService::api_key_lookup(&api_key).ok_or(ApiError::Internal)?;
+	let key_hash = AuthService::hash_api_key(&api_key);
+
+	// The right-hand sides see the row before the update, so the old key moves
+	// into the previous_* columns when a grace period was requested
+	let rotated_key = sqlx::query!(
+		r#"
+		UPDATE api_keys
+		SET previous_key_prefix = CASE WHEN $5::timestamptz IS NULL THEN NULL ELSE key_prefix END,
+		    previous_key_hash = CASE WHEN $5::timestamptz IS NULL THEN NULL ELSE key_hash END,
+		    previous_key_expires_at = $5,
+		    key_prefix = $3,
+		    key_hash = $4,
+		    updated_at = NOW()
+		WHERE tenant_id = $1 AND id = $2 AND is_active = true
+		RETURNING id, name, expires_at
+		"#,
+		context.tenant_id,
+		key_id,
+		key_prefix,
+		key_hash,
+		previous_key_expires_at
+	)
+	.fetch_optional(&state.pool)
+	.await
+	.map_err(|_| ApiError::Internal)?
+	.ok_or(ApiError::NotFound)?;
+
+	// Audit log
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|u| u.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::ApiKeyRotated,
+			resource_type: Some(ResourceType::ApiKey),
+			resource_id: Some(rotated_key.id),
+			changes: Some(serde_json::json!({
+				"grace_period_seconds": grace_period_seconds,
+				"previous_key_expires_at": previous_key_expires_at,
+			})),
+			ip_address: Some(addr.ip()),
+			user_agent,
+			request_id: crate::utils::current_request_id(),
+		})
+		.await?;
+
+	Ok(Json(ApiResponse {
+		data: RotateApiKeyResponse {
+			id: rotated_key.id,
+			name: rotated_key.name,
+			key: format!(
+				"{}{}",
+				crate::config::Config::default().auth.api_key_prefix,
+				api_key
+			),
+			expires_at: rotated_key.expires_at,
+			previous_key_expires_at,
+		},
+		meta: None,
+	}))
+}
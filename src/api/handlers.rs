@@ -1,11 +1,16 @@
 use axum::{
 	extract::{ConnectInfo, Path, Query, State},
-	http::{HeaderMap, StatusCode},
-	response::IntoResponse,
+	http::{header, HeaderMap, StatusCode},
+	response::{
+		sse::{Event, KeepAlive, Sse},
+		IntoResponse,
+	},
 	Json,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::models::*;
@@ -16,6 +21,10 @@ use crate::services::*;
 pub struct PaginationQuery {
 	pub limit: Option<i64>,
 	pub offset: Option<i64>,
+	pub source: Option<CreationSource>,
+	pub include_deleted: Option<bool>,
+	/// Opaque `next_cursor` from a previous page; takes precedence over `offset`
+	pub cursor: Option<String>,
 }
 
 impl Default for PaginationQuery {
@@ -23,27 +32,269 @@ impl Default for PaginationQuery {
 		Self {
 			limit: Some(20),
 			offset: Some(0),
+			source: None,
+			include_deleted: None,
+			cursor: None,
 		}
 	}
 }
 
+impl PaginationQuery {
+	/// Decoded `cursor`; cursors only page the unfiltered listing
+	fn list_cursor(&self) -> Result<Option<ListCursor>, ApiError> {
+		let Some(cursor) = self.cursor.as_deref() else {
+			return Ok(None);
+		};
+		if self.source.is_some() || self.include_deleted.unwrap_or(false) {
+			return Err(ApiError::BadRequest(
+				"cursor cannot be combined with source or include_deleted".to_string(),
+			));
+		}
+		ListCursor::decode(cursor)
+			.map(Some)
+			.ok_or_else(|| ApiError::BadRequest("Invalid cursor".to_string()))
+	}
+}
+
+/// Filters of `GET /monitors`; a filtered listing reports its filtered total
+/// but no next cursor
+#[derive(Debug, Default, Deserialize)]
+pub struct MonitorListQuery {
+	pub is_active: Option<bool>,
+	/// Internal id or external `network_id`
+	pub network_id: Option<String>,
+	/// Case-insensitive part of the name or `monitor_id`
+	pub q: Option<String>,
+	pub sort: Option<MonitorSort>,
+	/// Comma-separated subset of `MONITOR_FIELDS` to return for each monitor
+	pub fields: Option<String>,
+}
+
+impl MonitorListQuery {
+	/// The filter to apply, if any parameter is set
+	fn filter(&self) -> Option<MonitorFilter> {
+		if self.is_active.is_none()
+			&& self.network_id.is_none()
+			&& self.q.is_none()
+			&& self.sort.is_none()
+		{
+			return None;
+		}
+		Some(MonitorFilter {
+			is_active: self.is_active,
+			network: self.network_id.clone(),
+			search: self.q.clone(),
+			sort: self.sort.unwrap_or_default(),
+		})
+	}
+}
+
+/// Filters of `GET /triggers`; a filtered listing reports its filtered total
+/// but no next cursor
+#[derive(Debug, Default, Deserialize)]
+pub struct TriggerListQuery {
+	pub trigger_type: Option<String>,
+	/// Internal id or external `monitor_id`
+	pub monitor_id: Option<String>,
+	pub is_active: Option<bool>,
+	/// Comma-separated subset of `TRIGGER_FIELDS` to return for each trigger
+	pub fields: Option<String>,
+}
+
+impl TriggerListQuery {
+	/// The filter to apply, if any parameter is set
+	fn filter(&self) -> Option<TriggerFilter> {
+		if self.trigger_type.is_none() && self.monitor_id.is_none() && self.is_active.is_none() {
+			return None;
+		}
+		Some(TriggerFilter {
+			trigger_type: self.trigger_type.clone(),
+			monitor: self.monitor_id.clone(),
+			is_active: self.is_active,
+		})
+	}
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TriggerQuery {
+	pub effective: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NetworkWriteQuery {
+	/// Probe the network's `rpc_url` before creating or updating it
+	#[serde(alias = "validate")]
+	pub validate_connectivity: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ExportQuery {
+	#[serde(default)]
+	pub format: ExportFormat,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteQuery {
+	/// Delete dependent resources too instead of refusing with `409`
+	#[serde(default)]
+	pub force: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportQuery {
+	#[serde(default)]
+	pub mode: ImportMode,
+	/// Report what the import would do and write nothing
+	#[serde(default)]
+	pub dry_run: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FeedQuery {
+	pub since: Option<i64>,
+	pub limit: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct UsageQuery {
+	pub from: Option<chrono::NaiveDate>,
+	pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TenantListQuery {
+	/// Case-insensitive part of the tenant name
+	pub search: Option<String>,
+	pub active: Option<bool>,
+	pub slug_prefix: Option<String>,
+	pub limit: Option<i64>,
+	pub offset: Option<i64>,
+}
+
+impl TenantListQuery {
+	fn filter(&self) -> TenantFilter {
+		TenantFilter {
+			name_contains: self.search.clone(),
+			is_active: self.active,
+			slug_prefix: self.slug_prefix.clone(),
+		}
+	}
+}
+
+/// Longest range, in days, accepted by usage queries
+const MAX_USAGE_RANGE_DAYS: i64 = 366;
+
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T> {
 	pub data: T,
 	pub meta: Option<MetaData>,
 }
 
+/// Fields a monitor listing may be projected to
+pub const MONITOR_FIELDS: &[&str] = &[
+	"id",
+	"tenant_id",
+	"monitor_id",
+	"name",
+	"network_id",
+	"configuration",
+	"is_active",
+	"creation_source",
+	"created_at",
+	"updated_at",
+	"deleted_at",
+];
+
+/// Fields a trigger listing may be projected to
+pub const TRIGGER_FIELDS: &[&str] = &[
+	"id",
+	"tenant_id",
+	"trigger_id",
+	"monitor_id",
+	"name",
+	"trigger_type",
+	"configuration",
+	"is_active",
+	"creation_source",
+	"created_at",
+	"updated_at",
+];
+
+/// Whether the caller holds the `reveal_secrets` permission on triggers
+fn may_reveal_secrets() -> bool {
+	crate::utils::current_tenant_context().has_permission("triggers", REVEAL_SECRETS_ACTION)
+}
+
+/// Triggers as the caller may see them: secrets stay masked unless the caller
+/// may reveal them
+fn redact_for_caller(triggers: Vec<TenantTrigger>) -> Vec<TenantTrigger> {
+	if may_reveal_secrets() {
+		return triggers;
+	}
+	triggers.into_iter().map(TenantTrigger::redacted).collect()
+}
+
+/// `response` with each item cut down to `fields`, when a projection was requested
+fn project_list<T: Serialize>(
+	response: ApiResponse<Vec<T>>,
+	fields: Option<&[String]>,
+) -> axum::response::Response {
+	let Some(fields) = fields else {
+		return Json(response).into_response();
+	};
+
+	let data: Vec<serde_json::Value> = response
+		.data
+		.iter()
+		.map(|item| match serde_json::to_value(item) {
+			Ok(serde_json::Value::Object(mut object)) => {
+				object.retain(|key, _| fields.contains(key));
+				serde_json::Value::Object(object)
+			}
+			_ => serde_json::Value::Null,
+		})
+		.collect();
+	Json(ApiResponse {
+		data,
+		meta: response.meta,
+	})
+	.into_response()
+}
+
 #[derive(Debug, Serialize)]
 pub struct MetaData {
 	pub total: Option<i64>,
 	pub limit: i64,
 	pub offset: i64,
+	/// Pass as `cursor` to fetch the next page; absent on the last page
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
 	pub error: String,
 	pub code: String,
+	/// Whether repeating the same request later may succeed
+	pub retryable: bool,
+	/// The request's `X-Request-Id`, to quote when reporting a problem
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub request_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub details: Option<serde_json::Value>,
+}
+
+/// `Retry-After` sent with `503 SERVICE_UNAVAILABLE`, when the database
+/// cannot be reached or has no free connection
+pub const UNAVAILABLE_RETRY_AFTER_SECS: u64 = 5;
+
+/// Database errors that say nothing about the request itself and are expected
+/// to clear up on their own
+fn is_database_unavailable(error: &sqlx::Error) -> bool {
+	matches!(
+		error,
+		sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed | sqlx::Error::Io(_)
+	)
 }
 
 // Monitor handlers
@@ -60,6 +311,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -68,12 +321,20 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	let monitor = state
+	let created = state
 		.monitor_service
-		.create_monitor(request, metadata)
-		.await?;
+		.create_monitor(request, metadata.clone())
+		.await;
+	let monitor = audit_quota_exceeded(
+		&state.audit_service,
+		crate::models::audit::ResourceType::Monitor,
+		&metadata,
+		created,
+	)
+	.await?;
 	Ok((
 		StatusCode::CREATED,
 		Json(ApiResponse {
@@ -85,7 +346,7 @@ where
 
 pub async fn get_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Path(monitor_id): Path<String>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -94,6 +355,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "read")?;
+
 	let monitor = state.monitor_service.get_monitor(&monitor_id).await?;
 	Ok(Json(ApiResponse {
 		data: monitor,
@@ -105,7 +368,7 @@ pub async fn update_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(monitor_id): Path<String>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
 	Json(request): Json<UpdateMonitorRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
@@ -115,6 +378,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -123,7 +388,8 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
 	let monitor = state
 		.monitor_service
@@ -139,7 +405,8 @@ pub async fn delete_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(monitor_id): Path<String>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
+	Query(query): Query<DeleteQuery>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -148,6 +415,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "delete")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -156,18 +425,29 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	state
-		.monitor_service
-		.delete_monitor(&monitor_id, metadata)
-		.await?;
+	if query.force {
+		state
+			.monitor_service
+			.delete_monitor_with_triggers(&monitor_id, metadata)
+			.await?;
+	} else {
+		state
+			.monitor_service
+			.delete_monitor(&monitor_id, metadata)
+			.await?;
+	}
 	Ok(StatusCode::NO_CONTENT)
 }
 
-pub async fn list_monitors<M, N, T, TR, A>(
+pub async fn clone_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Query(pagination): Query<PaginationQuery>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
+	Json(request): Json<CloneMonitorRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -176,28 +456,31 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	let limit = pagination.limit.unwrap_or(20);
-	let offset = pagination.offset.unwrap_or(0);
+	crate::utils::require_permission("monitors", "write")?;
 
-	let monitors = state.monitor_service.list_monitors(limit, offset).await?;
-	let total = state.monitor_service.get_monitor_count().await?;
-
-	Ok(Json(ApiResponse {
-		data: monitors,
-		meta: Some(MetaData {
-			total: Some(total),
-			limit,
-			offset,
+	let monitor = state
+		.monitor_service
+		.clone_monitor(
+			&monitor_id,
+			request.new_monitor_id,
+			request.target_network_id,
+			request_metadata(addr, &headers),
+		)
+		.await?;
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: monitor,
+			meta: None,
 		}),
-	}))
+	))
 }
 
-// Network handlers
-pub async fn create_network<M, N, T, TR, A>(
+pub async fn restore_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Json(request): Json<CreateNetworkRequest>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -206,6 +489,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -214,24 +499,24 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	let network = state
-		.network_service
-		.create_network(request, metadata)
+	let monitor = state
+		.monitor_service
+		.restore_monitor(&monitor_id, metadata)
 		.await?;
-	Ok((
-		StatusCode::CREATED,
-		Json(ApiResponse {
-			data: network,
-			meta: None,
-		}),
-	))
+	Ok(Json(ApiResponse {
+		data: monitor,
+		meta: None,
+	}))
 }
 
-pub async fn get_network<M, N, T, TR, A>(
+pub async fn rollback_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Path(network_id): Path<String>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, monitor_id, version)): Path<(String, String, i32)>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -240,19 +525,34 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	let network = state.network_service.get_network(&network_id).await?;
+	crate::utils::require_permission("monitors", "write")?;
+
+	// Extract request metadata
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	let metadata = RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
+
+	let rollback = state
+		.monitor_service
+		.rollback_monitor(&monitor_id, version, metadata)
+		.await?;
 	Ok(Json(ApiResponse {
-		data: network,
+		data: rollback,
 		meta: None,
 	}))
 }
 
-pub async fn update_network<M, N, T, TR, A>(
+pub async fn deactivate_monitor<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(network_id): Path<String>,
-	Json(request): Json<UpdateNetworkRequest>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -261,6 +561,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -269,23 +571,129 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	let network = state
-		.network_service
-		.update_network(&network_id, request, metadata)
+	let deactivation = state
+		.monitor_service
+		.deactivate_with_triggers(&monitor_id, metadata)
 		.await?;
 	Ok(Json(ApiResponse {
-		data: network,
+		data: deactivation,
 		meta: None,
 	}))
 }
 
-pub async fn delete_network<M, N, T, TR, A>(
+pub async fn list_monitors<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(pagination): Query<PaginationQuery>,
+	Query(filters): Query<MonitorListQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("monitors", "read")?;
+
+	let projection = filters
+		.fields
+		.as_deref()
+		.map(|fields| state.api_config.parse_projection(fields, MONITOR_FIELDS))
+		.transpose()
+		.map_err(ApiError::BadRequest)?;
+	let limit = pagination.limit.unwrap_or(20);
+	let cursor = pagination.list_cursor()?;
+	let offset = if cursor.is_some() {
+		0
+	} else {
+		pagination.offset.unwrap_or(0)
+	};
+
+	if let Some(filter) = filters.filter() {
+		if cursor.is_some() || pagination.source.is_some() || pagination.include_deleted.is_some() {
+			return Err(ApiError::BadRequest(
+				"is_active, network_id, q and sort cannot be combined with cursor, source or include_deleted"
+					.to_string(),
+			));
+		}
+		let total = state
+			.monitor_service
+			.count_monitors_filtered(filter.clone())
+			.await?;
+		let monitors = state
+			.monitor_service
+			.list_monitors_filtered(filter, limit, offset)
+			.await?;
+		return Ok(project_list(
+			ApiResponse {
+				data: monitors,
+				meta: Some(MetaData {
+					total: Some(total),
+					limit,
+					offset,
+					next_cursor: None,
+				}),
+			},
+			projection.as_deref(),
+		));
+	}
+
+	// Filtered listings don't report a total or a next cursor
+	let (monitors, total) = match pagination.source {
+		_ if cursor.is_some() => (
+			state
+				.monitor_service
+				.list_monitors_after(cursor, limit)
+				.await?,
+			None,
+		),
+		_ if pagination.include_deleted.unwrap_or(false) => (
+			state
+				.monitor_service
+				.list_monitors_including_deleted(limit, offset)
+				.await?,
+			None,
+		),
+		Some(source) => (
+			state
+				.monitor_service
+				.list_monitors_by_source(source, limit, offset)
+				.await?,
+			None,
+		),
+		None => (
+			state.monitor_service.list_monitors(limit, offset).await?,
+			Some(state.monitor_service.get_monitor_count().await?),
+		),
+	};
+	let next = if cursor.is_some() || total.is_some() {
+		next_cursor(&monitors, limit)
+	} else {
+		None
+	};
+
+	Ok(project_list(
+		ApiResponse {
+			data: monitors,
+			meta: Some(MetaData {
+				total,
+				limit,
+				offset,
+				next_cursor: next,
+			}),
+		},
+		projection.as_deref(),
+	))
+}
+
+pub async fn import_monitors<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(network_id): Path<String>,
+	Json(requests): Json<Vec<CreateMonitorRequest>>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -294,6 +702,13 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("monitors", "write")?;
+
+	state
+		.api_config
+		.check_batch_size(requests.len())
+		.map_err(ApiError::BadRequest)?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -302,18 +717,28 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	state
-		.network_service
-		.delete_network(&network_id, metadata)
+	let monitors = state
+		.monitor_service
+		.import_monitors(requests, metadata)
 		.await?;
-	Ok(StatusCode::NO_CONTENT)
+
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: monitors,
+			meta: None,
+		}),
+	))
 }
 
-pub async fn list_networks<M, N, T, TR, A>(
+/// Which networks a monitor batch refers to already exist and which would
+/// need creating first; nothing is written
+pub async fn plan_monitor_batch<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Query(pagination): Query<PaginationQuery>,
+	Json(request): Json<MonitorBatchPlanRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -322,28 +747,56 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	let limit = pagination.limit.unwrap_or(20);
-	let offset = pagination.offset.unwrap_or(0);
+	crate::utils::require_permission("networks", "read")?;
+
+	state
+		.api_config
+		.check_batch_size(request.monitors.len())
+		.map_err(ApiError::BadRequest)?;
+
+	// Monitors using each referenced network, in network id order
+	let mut networks: BTreeMap<String, Vec<String>> = BTreeMap::new();
+	for monitor in request.monitors {
+		for network_id in monitor.networks {
+			let monitor_ids = networks.entry(network_id).or_default();
+			if !monitor_ids.contains(&monitor.monitor_id) {
+				monitor_ids.push(monitor.monitor_id.clone());
+			}
+		}
+	}
 
-	let networks = state.network_service.list_networks(limit, offset).await?;
-	let total = state.network_service.get_network_count().await?;
+	let mut plan = MonitorBatchPlan::default();
+	for (network_id, monitor_ids) in networks {
+		match state.network_service.get_network(&network_id).await {
+			Ok(network) => plan.existing_networks.push(PlannedNetwork {
+				network_id,
+				id: Some(network.id),
+				monitor_ids,
+			}),
+			Err(ServiceError::Repository(TenantRepositoryError::ResourceNotFound { .. })) => {
+				plan.missing_networks.push(PlannedNetwork {
+					network_id,
+					id: None,
+					monitor_ids,
+				})
+			}
+			Err(e) => return Err(e.into()),
+		}
+	}
 
 	Ok(Json(ApiResponse {
-		data: networks,
-		meta: Some(MetaData {
-			total: Some(total),
-			limit,
-			offset,
-		}),
+		data: plan,
+		meta: None,
 	}))
 }
 
-// Trigger handlers
-pub async fn create_trigger<M, N, T, TR, A>(
+// Network handlers
+pub async fn create_network<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Json(request): Json<CreateTriggerRequest>,
+	Query(query): Query<NetworkWriteQuery>,
+	Json(request): Json<CreateNetworkRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -352,6 +805,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("networks", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -360,24 +815,40 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	let trigger = state
-		.trigger_service
-		.create_trigger(request, metadata)
-		.await?;
+	// Opt-in probe of the RPC endpoint before anything is stored
+	if query.validate_connectivity.unwrap_or(false) {
+		state
+			.network_service
+			.validate_connectivity(&request.configuration, &request.blockchain)
+			.await?;
+	}
+
+	let created = state
+		.network_service
+		.create_network(request, metadata.clone())
+		.await;
+	let network = audit_quota_exceeded(
+		&state.audit_service,
+		crate::models::audit::ResourceType::Network,
+		&metadata,
+		created,
+	)
+	.await?;
 	Ok((
 		StatusCode::CREATED,
 		Json(ApiResponse {
-			data: trigger,
+			data: network,
 			meta: None,
 		}),
 	))
 }
 
-pub async fn get_trigger<M, N, T, TR, A>(
+pub async fn get_network<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Path(trigger_id): Path<String>,
+	Path((_tenant_slug, network_id)): Path<(String, String)>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -386,19 +857,22 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	let trigger = state.trigger_service.get_trigger(&trigger_id).await?;
+	crate::utils::require_permission("networks", "read")?;
+
+	let network = state.network_service.get_network(&network_id).await?;
 	Ok(Json(ApiResponse {
-		data: trigger,
+		data: network,
 		meta: None,
 	}))
 }
 
-pub async fn update_trigger<M, N, T, TR, A>(
+pub async fn update_network<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(trigger_id): Path<String>,
-	Json(request): Json<UpdateTriggerRequest>,
+	Path((_tenant_slug, network_id)): Path<(String, String)>,
+	Query(query): Query<NetworkWriteQuery>,
+	Json(request): Json<UpdateNetworkRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -407,6 +881,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("networks", "write")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -415,23 +891,40 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	let trigger = state
-		.trigger_service
-		.update_trigger(&trigger_id, request, metadata)
+	// Opt-in probe of the configuration the network will have after the update
+	if query.validate_connectivity.unwrap_or(false) {
+		let existing = state.network_service.get_network(&network_id).await?;
+		state
+			.network_service
+			.validate_connectivity(
+				request
+					.configuration
+					.as_ref()
+					.unwrap_or(&existing.configuration),
+				&existing.blockchain,
+			)
+			.await?;
+	}
+
+	let network = state
+		.network_service
+		.update_network(&network_id, request, metadata)
 		.await?;
 	Ok(Json(ApiResponse {
-		data: trigger,
+		data: network,
 		meta: None,
 	}))
 }
 
-pub async fn delete_trigger<M, N, T, TR, A>(
+pub async fn delete_network<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	ConnectInfo(addr): ConnectInfo<SocketAddr>,
 	headers: HeaderMap,
-	Path(trigger_id): Path<String>,
+	Path((_tenant_slug, network_id)): Path<(String, String)>,
+	Query(query): Query<DeleteQuery>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -440,6 +933,8 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("networks", "delete")?;
+
 	// Extract request metadata
 	let user_agent = headers
 		.get("user-agent")
@@ -448,16 +943,24 @@ where
 
 	let metadata = RequestMetadata::new()
 		.with_ip(Some(addr.ip()))
-		.with_user_agent(user_agent);
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
 
-	state
-		.trigger_service
-		.delete_trigger(&trigger_id, metadata)
-		.await?;
+	if query.force {
+		state
+			.network_service
+			.delete_network_with_monitors(&network_id, metadata)
+			.await?;
+	} else {
+		state
+			.network_service
+			.delete_network(&network_id, metadata)
+			.await?;
+	}
 	Ok(StatusCode::NO_CONTENT)
 }
 
-pub async fn list_triggers<M, N, T, TR, A>(
+pub async fn list_networks<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
 	Query(pagination): Query<PaginationQuery>,
 ) -> Result<impl IntoResponse, ApiError>
@@ -468,25 +971,60 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
+	crate::utils::require_permission("networks", "read")?;
+
 	let limit = pagination.limit.unwrap_or(20);
-	let offset = pagination.offset.unwrap_or(0);
+	let cursor = pagination.list_cursor()?;
+	let offset = if cursor.is_some() {
+		0
+	} else {
+		pagination.offset.unwrap_or(0)
+	};
 
-	let triggers = state.trigger_service.list_triggers(limit, offset).await?;
-	let total = state.trigger_service.get_trigger_count().await?;
+	// Filtered listings don't report a total or a next cursor
+	let (networks, total) = match pagination.source {
+		_ if cursor.is_some() => (
+			state
+				.network_service
+				.list_networks_after(cursor, limit)
+				.await?,
+			None,
+		),
+		Some(source) => (
+			state
+				.network_service
+				.list_networks_by_source(source, limit, offset)
+				.await?,
+			None,
+		),
+		None => (
+			state.network_service.list_networks(limit, offset).await?,
+			Some(state.network_service.get_network_count().await?),
+		),
+	};
+	let next = if cursor.is_some() || total.is_some() {
+		next_cursor(&networks, limit)
+	} else {
+		None
+	};
 
 	Ok(Json(ApiResponse {
-		data: triggers,
+		data: networks,
 		meta: Some(MetaData {
-			total: Some(total),
+			total,
 			limit,
 			offset,
+			next_cursor: next,
 		}),
 	}))
 }
 
-pub async fn list_triggers_by_monitor<M, N, T, TR, A>(
+// Trigger handlers
+pub async fn create_trigger<M, N, T, TR, A>(
 	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
-	Path(monitor_id): Path<Uuid>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(request): Json<CreateTriggerRequest>,
 ) -> Result<impl IntoResponse, ApiError>
 where
 	M: MonitorServiceTrait,
@@ -495,23 +1033,1965 @@ where
 	TR: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	let triggers = state
-		.trigger_service
-		.list_triggers_by_monitor(monitor_id)
-		.await?;
-	Ok(Json(ApiResponse {
-		data: triggers,
-		meta: None,
-	}))
-}
+	crate::utils::require_permission("triggers", "write")?;
 
-// Health check
-pub async fn health_check() -> impl IntoResponse {
-	Json(serde_json::json!({
-		"status": "healthy",
-		"timestamp": chrono::Utc::now(),
-	}))
-}
+	// Extract request metadata
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	let metadata = RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
+
+	let created = state
+		.trigger_service
+		.create_trigger(request, metadata.clone())
+		.await;
+	let trigger = audit_quota_exceeded(
+		&state.audit_service,
+		crate::models::audit::ResourceType::Trigger,
+		&metadata,
+		created,
+	)
+	.await?;
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: trigger,
+			meta: None,
+		}),
+	))
+}
+
+pub async fn create_triggers_batch<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(requests): Json<Vec<CreateTriggerRequest>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "write")?;
+
+	state
+		.api_config
+		.check_batch_size(requests.len())
+		.map_err(ApiError::BadRequest)?;
+
+	// Extract request metadata
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	let metadata = RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
+
+	let triggers = state
+		.trigger_service
+		.create_triggers_batch(requests, metadata)
+		.await?;
+
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: triggers,
+			meta: None,
+		}),
+	))
+}
+
+pub async fn get_trigger<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path((_tenant_slug, trigger_id)): Path<(String, String)>,
+	Query(query): Query<TriggerQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "read")?;
+
+	let trigger = if query.effective.unwrap_or(false) {
+		state
+			.trigger_service
+			.get_effective_trigger(&trigger_id)
+			.await?
+	} else {
+		state.trigger_service.get_trigger(&trigger_id).await?
+	};
+	let trigger = if may_reveal_secrets() {
+		trigger
+	} else {
+		trigger.redacted()
+	};
+	Ok(Json(ApiResponse {
+		data: trigger,
+		meta: None,
+	}))
+}
+
+/// Send a sample event to the trigger's channel and report how it answered
+pub async fn test_trigger<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path((_tenant_slug, trigger_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "write")?;
+
+	let result = state
+		.trigger_service
+		.send_test_notification(&trigger_id)
+		.await?;
+	Ok(Json(ApiResponse {
+		data: result,
+		meta: None,
+	}))
+}
+
+pub async fn update_trigger<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, trigger_id)): Path<(String, String)>,
+	Json(request): Json<UpdateTriggerRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "write")?;
+
+	// Extract request metadata
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	let metadata = RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
+
+	let trigger = state
+		.trigger_service
+		.update_trigger(&trigger_id, request, metadata)
+		.await?;
+	let trigger = if may_reveal_secrets() {
+		trigger
+	} else {
+		trigger.redacted()
+	};
+	Ok(Json(ApiResponse {
+		data: trigger,
+		meta: None,
+	}))
+}
+
+pub async fn delete_trigger<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, trigger_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "delete")?;
+
+	// Extract request metadata
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	let metadata = RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id());
+
+	state
+		.trigger_service
+		.delete_trigger(&trigger_id, metadata)
+		.await?;
+	Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_triggers<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(pagination): Query<PaginationQuery>,
+	Query(filters): Query<TriggerListQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "read")?;
+
+	let projection = filters
+		.fields
+		.as_deref()
+		.map(|fields| state.api_config.parse_projection(fields, TRIGGER_FIELDS))
+		.transpose()
+		.map_err(ApiError::BadRequest)?;
+	let limit = pagination.limit.unwrap_or(20);
+	let cursor = pagination.list_cursor()?;
+	let offset = if cursor.is_some() {
+		0
+	} else {
+		pagination.offset.unwrap_or(0)
+	};
+
+	if let Some(filter) = filters.filter() {
+		if cursor.is_some() || pagination.source.is_some() {
+			return Err(ApiError::BadRequest(
+				"trigger_type, monitor_id and is_active cannot be combined with cursor or source"
+					.to_string(),
+			));
+		}
+		let total = state
+			.trigger_service
+			.count_triggers_filtered(filter.clone())
+			.await?;
+		let triggers = redact_for_caller(
+			state
+				.trigger_service
+				.list_triggers_filtered(filter, limit, offset)
+				.await?,
+		);
+		return Ok(project_list(
+			ApiResponse {
+				data: triggers,
+				meta: Some(MetaData {
+					total: Some(total),
+					limit,
+					offset,
+					next_cursor: None,
+				}),
+			},
+			projection.as_deref(),
+		));
+	}
+
+	// Filtered listings don't report a total or a next cursor
+	let (triggers, total) = match pagination.source {
+		_ if cursor.is_some() => (
+			state
+				.trigger_service
+				.list_triggers_after(cursor, limit)
+				.await?,
+			None,
+		),
+		Some(source) => (
+			state
+				.trigger_service
+				.list_triggers_by_source(source, limit, offset)
+				.await?,
+			None,
+		),
+		None => (
+			state.trigger_service.list_triggers(limit, offset).await?,
+			Some(state.trigger_service.get_trigger_count().await?),
+		),
+	};
+	let triggers = redact_for_caller(triggers);
+	let next = if cursor.is_some() || total.is_some() {
+		next_cursor(&triggers, limit)
+	} else {
+		None
+	};
+
+	Ok(project_list(
+		ApiResponse {
+			data: triggers,
+			meta: Some(MetaData {
+				total,
+				limit,
+				offset,
+				next_cursor: next,
+			}),
+		},
+		projection.as_deref(),
+	))
+}
+
+pub async fn list_triggers_using_old_secret<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "read")?;
+
+	let triggers = redact_for_caller(
+		state
+			.trigger_service
+			.list_triggers_using_old_secret()
+			.await?,
+	);
+	Ok(Json(ApiResponse {
+		data: triggers,
+		meta: None,
+	}))
+}
+
+pub async fn get_trigger_type_stats<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "read")?;
+
+	let stats = state.trigger_service.get_trigger_type_stats().await?;
+	Ok(Json(ApiResponse {
+		data: stats,
+		meta: None,
+	}))
+}
+
+pub async fn list_triggers_by_monitor<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path((_tenant_slug, monitor_id)): Path<(String, Uuid)>,
+	Query(pagination): Query<PaginationQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	crate::utils::require_permission("triggers", "read")?;
+
+	let limit = pagination.limit.unwrap_or(20);
+	let offset = pagination.offset.unwrap_or(0);
+	let triggers = redact_for_caller(
+		state
+			.trigger_service
+			.list_triggers_by_monitor(monitor_id, limit, offset)
+			.await?,
+	);
+	let total = state.trigger_service.count_by_monitor(monitor_id).await?;
+	Ok(Json(ApiResponse {
+		data: triggers,
+		meta: Some(MetaData {
+			total: Some(total),
+			limit,
+			offset,
+			next_cursor: None,
+		}),
+	}))
+}
+
+// Tenant handlers
+pub async fn get_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let tenant = state
+		.tenant_repo
+		.get(crate::utils::current_tenant_id())
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: tenant,
+		meta: None,
+	}))
+}
+
+pub async fn rename_tenant_slug<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<RenameTenantSlugRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to rename tenant".to_string(),
+		)));
+	}
+
+	let tenant = state
+		.tenant_repo
+		.rename_slug(context.tenant_id, &request.slug)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: tenant,
+		meta: None,
+	}))
+}
+
+pub async fn transfer_ownership<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<TransferOwnershipRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	let owner = context
+		.user
+		.as_ref()
+		.filter(|user| user.role == TenantRole::Owner)
+		.ok_or_else(|| {
+			ApiError::Service(ServiceError::AccessDenied(
+				"Only an owner can transfer ownership".to_string(),
+			))
+		})?;
+
+	state
+		.tenant_repo
+		.transfer_ownership(context.tenant_id, owner.id, request.to_user_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(StatusCode::NO_CONTENT)
+}
+
+/// Add several existing users to the tenant at once; see
+/// `TenantRepositoryTrait::add_members_batch` for how entries are handled
+pub async fn add_members_batch<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(entries): Json<Vec<BatchMemberEntry>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to add members".to_string(),
+		)));
+	}
+	if entries.iter().any(|entry| entry.role == TenantRole::Owner)
+		&& !context
+			.user
+			.as_ref()
+			.is_some_and(|user| user.role == TenantRole::Owner)
+	{
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Only an owner can add another owner".to_string(),
+		)));
+	}
+	if entries.is_empty() {
+		return Err(ApiError::BadRequest(
+			"At least one member is required".to_string(),
+		));
+	}
+	state
+		.api_config
+		.check_batch_size(entries.len())
+		.map_err(ApiError::BadRequest)?;
+
+	let results = state
+		.tenant_repo
+		.add_members_batch(context.tenant_id, entries)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	for result in results
+		.iter()
+		.filter(|result| result.status == BatchMemberStatus::Added)
+	{
+		state
+			.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|user| user.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::MemberAdded,
+				resource_type: Some(crate::models::audit::ResourceType::User),
+				resource_id: result.user_id,
+				changes: Some(serde_json::json!({ "role": result.role })),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+	}
+
+	Ok(Json(ApiResponse {
+		data: results,
+		meta: None,
+	}))
+}
+
+/// Reconcile the tenant's members with an externally managed list, e.g. from
+/// an identity provider; only owners may change who the owners are
+pub async fn sync_members<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(request): Json<SyncMembersRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to sync members".to_string(),
+		)));
+	}
+	state
+		.api_config
+		.check_batch_size(request.members.len())
+		.map_err(ApiError::BadRequest)?;
+
+	let change_owners = context
+		.user
+		.as_ref()
+		.is_some_and(|user| user.role == TenantRole::Owner);
+	let result = state
+		.tenant_repo
+		.sync_members(context.tenant_id, request.members, change_owners)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::AccessDenied(message) => ServiceError::AccessDenied(message),
+			e => ServiceError::from(e),
+		})?;
+
+	let metadata = request_metadata(addr, &headers);
+	for change in &result.changes {
+		let (action, changes) = match change.action {
+			MemberSyncAction::Added => (
+				AuditAction::MemberAdded,
+				serde_json::json!({ "role": change.role }),
+			),
+			MemberSyncAction::RoleChanged => (
+				AuditAction::UserRoleChanged,
+				serde_json::json!({ "from": change.previous_role, "to": change.role }),
+			),
+			MemberSyncAction::Removed => (
+				AuditAction::UserRemoved,
+				serde_json::json!({ "role": change.previous_role }),
+			),
+		};
+		state
+			.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|user| user.id),
+				api_key_id: context.api_key_id,
+				action,
+				resource_type: Some(crate::models::audit::ResourceType::User),
+				resource_id: Some(change.user_id),
+				changes: Some(changes),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+	}
+
+	Ok(Json(ApiResponse {
+		data: result,
+		meta: None,
+	}))
+}
+
+// Invitation handlers
+pub async fn create_invitation<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(request): Json<CreateInvitationRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to invite members".to_string(),
+		)));
+	}
+	if request.role == TenantRole::Owner
+		&& !context
+			.user
+			.as_ref()
+			.is_some_and(|user| user.role == TenantRole::Owner)
+	{
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Only an owner can invite another owner".to_string(),
+		)));
+	}
+	if !email_address::EmailAddress::is_valid(request.email.trim()) {
+		return Err(ApiError::BadRequest("Invalid email address".to_string()));
+	}
+
+	let expires_at =
+		chrono::Utc::now() + chrono::Duration::hours(state.api_config.invitation_ttl_hours);
+	let invitation = InvitationRepository::new(state.pool.clone())
+		.create(
+			context.tenant_id,
+			&request.email,
+			request.role,
+			context.user.as_ref().map(|user| user.id),
+			&state.auth_service.generate_invitation_token(),
+			expires_at,
+		)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::InvitationCreated,
+			resource_type: Some(crate::models::audit::ResourceType::Invitation),
+			resource_id: Some(invitation.id),
+			changes: Some(serde_json::json!({
+				"email": invitation.email,
+				"role": invitation.role,
+				"expires_at": invitation.expires_at,
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: invitation,
+			meta: None,
+		}),
+	))
+}
+
+pub async fn list_invitations<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to list invitations".to_string(),
+		)));
+	}
+
+	let invitations = InvitationRepository::new(state.pool.clone())
+		.list_open(context.tenant_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: invitations,
+		meta: None,
+	}))
+}
+
+pub async fn revoke_invitation<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path((_tenant_slug, invitation_id)): Path<(String, Uuid)>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = crate::utils::current_tenant_context();
+
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to revoke invitations".to_string(),
+		)));
+	}
+
+	let invitation = InvitationRepository::new(state.pool.clone())
+		.revoke(context.tenant_id, invitation_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::InvitationRevoked,
+			resource_type: Some(crate::models::audit::ResourceType::Invitation),
+			resource_id: Some(invitation.id),
+			changes: Some(serde_json::json!({ "email": invitation.email })),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok(StatusCode::NO_CONTENT)
+}
+
+// Webhook handlers
+fn require_webhook_management() -> Result<Arc<crate::utils::TenantContext>, ApiError> {
+	let context = crate::utils::current_tenant_context();
+	if !context.can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to manage webhooks".to_string(),
+		)));
+	}
+
+	Ok(context)
+}
+
+pub async fn list_webhooks<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+
+	let webhooks = WebhookRepository::new(state.pool.clone())
+		.list(context.tenant_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: webhooks,
+		meta: None,
+	}))
+}
+
+/// Subscribe an endpoint to the tenant's audit events; the response is the
+/// only time the signing secret is returned
+pub async fn create_webhook<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Json(request): Json<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+	validate_webhook(Some(request.url.trim()), Some(&request.events))
+		.map_err(ApiError::BadRequest)?;
+
+	let secret = request
+		.secret
+		.clone()
+		.filter(|secret| !secret.is_empty())
+		.unwrap_or_else(generate_webhook_secret);
+	let webhook = WebhookRepository::new(state.pool.clone())
+		.create(
+			context.tenant_id,
+			&request,
+			&secret,
+			context.user.as_ref().map(|user| user.id),
+		)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::WebhookCreated,
+			resource_type: Some(crate::models::audit::ResourceType::Webhook),
+			resource_id: Some(webhook.id),
+			changes: Some(serde_json::json!({
+				"url": webhook.url,
+				"events": webhook.events,
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok((
+		StatusCode::CREATED,
+		Json(ApiResponse {
+			data: CreatedWebhook { webhook, secret },
+			meta: None,
+		}),
+	))
+}
+
+pub async fn get_webhook<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(webhook_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+
+	let webhook = WebhookRepository::new(state.pool.clone())
+		.get(context.tenant_id, webhook_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: webhook,
+		meta: None,
+	}))
+}
+
+pub async fn update_webhook<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path(webhook_id): Path<Uuid>,
+	Json(request): Json<UpdateWebhookRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+	validate_webhook(
+		request.url.as_deref().map(str::trim),
+		request.events.as_deref(),
+	)
+	.map_err(ApiError::BadRequest)?;
+	if request.secret.as_deref() == Some("") {
+		return Err(ApiError::BadRequest(
+			"Webhook secret cannot be empty".to_string(),
+		));
+	}
+
+	let webhook = WebhookRepository::new(state.pool.clone())
+		.update(context.tenant_id, webhook_id, &request)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::WebhookUpdated,
+			resource_type: Some(crate::models::audit::ResourceType::Webhook),
+			resource_id: Some(webhook.id),
+			changes: Some(serde_json::json!({
+				"url": request.url,
+				"events": request.events,
+				"is_active": request.is_active,
+				"secret_rotated": request.secret.is_some(),
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok(Json(ApiResponse {
+		data: webhook,
+		meta: None,
+	}))
+}
+
+pub async fn delete_webhook<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+	Path(webhook_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+
+	WebhookRepository::new(state.pool.clone())
+		.delete(context.tenant_id, webhook_id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let metadata = request_metadata(addr, &headers);
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::WebhookDeleted,
+			resource_type: Some(crate::models::audit::ResourceType::Webhook),
+			resource_id: Some(webhook_id),
+			changes: None,
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok(StatusCode::NO_CONTENT)
+}
+
+/// The webhook's most recent delivery attempts, newest first
+pub async fn list_webhook_deliveries<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(webhook_id): Path<Uuid>,
+	Query(query): Query<WebhookDeliveryQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let context = require_webhook_management()?;
+
+	let deliveries = WebhookRepository::new(state.pool.clone())
+		.list_deliveries(context.tenant_id, webhook_id, query.page_limit())
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: deliveries,
+		meta: None,
+	}))
+}
+
+// Stats handler
+pub async fn get_resource_stats<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let counts = state
+		.tenant_repo
+		.get_resource_counts(crate::utils::current_tenant_id())
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: counts,
+		meta: None,
+	}))
+}
+
+/// Bytes buffered between the archive writer and the response body
+const EXPORT_STREAM_BUFFER: usize = 64 * 1024;
+
+/// Write the export as a zip archive, one entry at a time
+async fn write_export_archive<W>(export: TenantExport, writer: W) -> Result<(), String>
+where
+	W: tokio::io::AsyncWrite + Unpin,
+{
+	let mut zip = async_zip::tokio::write::ZipFileWriter::with_tokio(writer);
+	for (path, document) in export.entries() {
+		let data = serde_json::to_vec_pretty(&document).map_err(|e| e.to_string())?;
+		let entry = async_zip::ZipEntryBuilder::new(path.into(), async_zip::Compression::Deflate);
+		zip.write_entry_whole(entry, &data)
+			.await
+			.map_err(|e| e.to_string())?;
+	}
+	zip.close().await.map_err(|e| e.to_string())?;
+
+	Ok(())
+}
+
+/// Write the export as one JSON document, an entry at a time
+async fn write_export_document<W>(export: TenantExport, mut writer: W) -> Result<(), String>
+where
+	W: tokio::io::AsyncWrite + Unpin,
+{
+	use tokio::io::AsyncWriteExt;
+
+	let TenantExportDocument {
+		version,
+		networks,
+		monitors,
+		triggers,
+	} = export.document();
+	writer
+		.write_all(format!("{{\"version\":{}", version).as_bytes())
+		.await
+		.map_err(|e| e.to_string())?;
+	write_json_array(&mut writer, "networks", &networks).await?;
+	write_json_array(&mut writer, "monitors", &monitors).await?;
+	write_json_array(&mut writer, "triggers", &triggers).await?;
+	writer.write_all(b"}").await.map_err(|e| e.to_string())?;
+
+	Ok(())
+}
+
+/// Write `,"<field>":[...]`, serializing one item at a time
+async fn write_json_array<W, I>(writer: &mut W, field: &str, items: &[I]) -> Result<(), String>
+where
+	W: tokio::io::AsyncWrite + Unpin,
+	I: Serialize,
+{
+	use tokio::io::AsyncWriteExt;
+
+	writer
+		.write_all(format!(",\"{}\":[", field).as_bytes())
+		.await
+		.map_err(|e| e.to_string())?;
+	for (index, item) in items.iter().enumerate() {
+		if index > 0 {
+			writer.write_all(b",").await.map_err(|e| e.to_string())?;
+		}
+		let data = serde_json::to_vec(item).map_err(|e| e.to_string())?;
+		writer.write_all(&data).await.map_err(|e| e.to_string())?;
+	}
+	writer.write_all(b"]").await.map_err(|e| e.to_string())?;
+
+	Ok(())
+}
+
+pub async fn export_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(query): Query<ExportQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	for resource in ["networks", "monitors", "triggers"] {
+		crate::utils::require_permission(resource, "read")?;
+	}
+
+	// Read everything first so a failed read is still reported as an error
+	let mut export = TenantExportService::new(
+		&state.monitor_service,
+		&state.network_service,
+		&state.trigger_service,
+	)
+	.export(crate::utils::current_tenant_id())
+	.await?;
+	export.triggers = redact_for_caller(export.triggers);
+
+	let slug = crate::utils::current_tenant_context()
+		.tenant_slug
+		.clone()
+		.unwrap_or_else(|| "tenant".to_string());
+
+	let (content_type, extension) = match query.format {
+		ExportFormat::Json => ("application/json", "json"),
+		ExportFormat::Zip => ("application/zip", "zip"),
+	};
+
+	// Either format is streamed to the client as it is written
+	let (writer, reader) = tokio::io::duplex(EXPORT_STREAM_BUFFER);
+	let tenant_slug = slug.clone();
+	let format = query.format;
+	tokio::spawn(async move {
+		let written = match format {
+			ExportFormat::Json => write_export_document(export, writer).await,
+			ExportFormat::Zip => write_export_archive(export, writer).await,
+		};
+		if let Err(e) = written {
+			tracing::error!(tenant_slug = %tenant_slug, error = %e, "Failed to write tenant export");
+		}
+	});
+
+	Ok((
+		[
+			(header::CONTENT_TYPE, content_type.to_string()),
+			(
+				header::CONTENT_DISPOSITION,
+				format!("attachment; filename=\"{}-config.{}\"", slug, extension),
+			),
+		],
+		axum::body::Body::from_stream(tokio_util::io::ReaderStream::new(reader)),
+	))
+}
+
+/// The current tenant's configuration as one JSON document
+pub async fn export_current_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	export_tenant(
+		State(state),
+		Query(ExportQuery {
+			format: ExportFormat::Json,
+		}),
+	)
+	.await
+}
+
+/// Recreate an exported configuration in the current tenant
+pub async fn import_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	Query(query): Query<ImportQuery>,
+	headers: HeaderMap,
+	Json(document): Json<TenantExportDocument>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	for resource in ["networks", "monitors", "triggers"] {
+		crate::utils::require_permission(resource, "write")?;
+	}
+
+	if document.version != EXPORT_FORMAT_VERSION {
+		return Err(ApiError::BadRequest(format!(
+			"Unsupported export version {}; expected {}",
+			document.version, EXPORT_FORMAT_VERSION
+		)));
+	}
+
+	state
+		.api_config
+		.check_batch_size(
+			document.networks.len() + document.monitors.len() + document.triggers.len(),
+		)
+		.map_err(ApiError::BadRequest)?;
+
+	// Reject bad trigger configurations before anything is written
+	for trigger in &document.triggers {
+		validate_trigger_config(&trigger.trigger_type, &trigger.configuration)?;
+	}
+
+	let context = crate::utils::current_tenant_context();
+	let import =
+		TenantImportRepository::new(state.pool.clone()).with_quota_cache(state.quota_cache.clone());
+	if query.dry_run {
+		let summary = import
+			.dry_run(&document, query.mode)
+			.await
+			.map_err(ServiceError::from)?;
+		return Ok(Json(ApiResponse {
+			data: summary,
+			meta: None,
+		}));
+	}
+	let summary = import
+		.import(&document, query.mode)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|u| u.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::ConfigurationImported,
+			resource_type: Some(crate::models::audit::ResourceType::Tenant),
+			resource_id: Some(context.tenant_id),
+			changes: Some(serde_json::to_value(&summary).unwrap_or(serde_json::Value::Null)),
+			ip_address: Some(addr.ip()),
+			user_agent,
+			request_id: crate::utils::current_request_id(),
+		})
+		.await?;
+
+	Ok(Json(ApiResponse {
+		data: summary,
+		meta: None,
+	}))
+}
+
+pub async fn get_quota_status<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let status = state
+		.tenant_repo
+		.get_quota_status(crate::utils::current_tenant_id())
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: status,
+		meta: None,
+	}))
+}
+
+/// Dry-run check of one resource amount against the current tenant's quota;
+/// nothing is reserved or counted
+pub async fn check_quota<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(query): Query<QuotaCheckQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let tenant_id = crate::utils::current_tenant_id();
+	let allowed = state
+		.tenant_repo
+		.check_quota(tenant_id, &query.resource, query.amount)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::InvalidConfiguration(message) => ApiError::BadRequest(message),
+			e => ApiError::Service(ServiceError::from(e)),
+		})?;
+
+	let status = state
+		.tenant_repo
+		.get_quota_status(tenant_id)
+		.await
+		.map_err(ServiceError::from)?;
+	// check_quota has already rejected unknown resources
+	let available = status.available_for(&query.resource).unwrap_or_default();
+	let limit = status.limit_for(&query.resource).unwrap_or_default();
+
+	Ok(Json(ApiResponse {
+		data: QuotaCheckResponse {
+			allowed,
+			available,
+			limit,
+		},
+		meta: None,
+	}))
+}
+
+pub async fn check_quota_batch<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<BatchQuotaCheckRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	state
+		.api_config
+		.check_batch_size(request.checks.len())
+		.map_err(ApiError::BadRequest)?;
+
+	let results = state
+		.tenant_repo
+		.check_quota_batch(crate::utils::current_tenant_id(), &request.checks)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: results,
+		meta: None,
+	}))
+}
+
+// Audit log handlers
+pub async fn list_audit_logs<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(mut filter): Query<AuditLogFilter>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	// The audit trail covers the whole tenant, so only admins and owners may read it
+	if !crate::utils::can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to read audit logs".to_string(),
+		)));
+	}
+
+	state
+		.api_config
+		.limit_audit_query(&mut filter, chrono::Utc::now())
+		.map_err(ApiError::BadRequest)?;
+
+	let total = state.audit_service.count_logs(filter.clone()).await?;
+	let limit = filter.page_limit();
+	let offset = filter.page_offset();
+	let logs = state.audit_service.query_logs(filter).await?;
+
+	Ok(Json(ApiResponse {
+		data: logs,
+		meta: Some(MetaData {
+			total: Some(total),
+			limit,
+			offset,
+			next_cursor: None,
+		}),
+	}))
+}
+
+/// Live audit entries for the tenant as Server-Sent Events. Each entry is an
+/// `audit` event carrying the entry as JSON; a `lagged` event reports how many
+/// entries a slow subscriber missed.
+pub async fn stream_audit_logs<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	if !crate::utils::can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to read audit logs".to_string(),
+		)));
+	}
+
+	let tenant_id = crate::utils::current_tenant_id();
+	let receiver = state.audit_events.subscribe();
+	let stream = futures::stream::unfold(receiver, move |mut receiver| async move {
+		loop {
+			let event = match receiver.recv().await {
+				Ok(log) if log.tenant_id != tenant_id => continue,
+				Ok(log) => Event::default()
+					.event("audit")
+					.id(log.id.to_string())
+					.json_data(&log)
+					.unwrap_or_else(|_| Event::default().event("error")),
+				Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+					Event::default().event("lagged").data(missed.to_string())
+				}
+				Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+			};
+			return Some((Ok::<_, std::convert::Infallible>(event), receiver));
+		}
+	});
+
+	Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+pub async fn get_monitor_audit<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path((_tenant_slug, monitor_id)): Path<(String, String)>,
+	Query(query): Query<ResourceAuditQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	// Entries carry who made each change and from where, as in the tenant-wide log
+	if !crate::utils::can_manage() {
+		return Err(ApiError::Service(ServiceError::AccessDenied(
+			"Insufficient permissions to read audit logs".to_string(),
+		)));
+	}
+
+	let monitor = state.monitor_service.get_monitor(&monitor_id).await?;
+
+	let mut filter = AuditLogFilter::for_resource(
+		crate::models::audit::ResourceType::Monitor,
+		monitor.id,
+		query.limit,
+	);
+	state
+		.api_config
+		.limit_audit_query(&mut filter, chrono::Utc::now())
+		.map_err(ApiError::BadRequest)?;
+
+	let limit = filter.page_limit();
+	let logs = state.audit_service.query_logs(filter).await?;
+
+	Ok(Json(ApiResponse {
+		data: logs,
+		meta: Some(MetaData {
+			total: None,
+			limit,
+			offset: 0,
+			next_cursor: None,
+		}),
+	}))
+}
+
+// Admin handlers
+pub async fn list_tenants<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(query): Query<TenantListQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let limit = query.limit.unwrap_or(20).clamp(1, 100);
+	let offset = query.offset.unwrap_or(0).max(0);
+	let filter = query.filter();
+
+	let total = state
+		.tenant_repo
+		.count_filtered(filter.clone())
+		.await
+		.map_err(ServiceError::from)?;
+	let tenants = state
+		.tenant_repo
+		.list_filtered(filter, limit, offset)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: tenants,
+		meta: Some(MetaData {
+			total: Some(total),
+			limit,
+			offset,
+			next_cursor: None,
+		}),
+	}))
+}
+
+pub async fn batch_quota_status<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<BatchQuotaStatusRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	state
+		.api_config
+		.check_batch_size(request.tenant_ids.len())
+		.map_err(ApiError::BadRequest)?;
+
+	let statuses = state
+		.tenant_repo
+		.get_quota_statuses(&request.tenant_ids)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: statuses,
+		meta: None,
+	}))
+}
+
+/// Quota status of any tenant, by id
+pub async fn tenant_quota_status<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let status = state
+		.tenant_repo
+		.get_quota_status(tenant_id)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::TenantNotFound(_) => ApiError::NotFound,
+			e => ApiError::Service(ServiceError::from(e)),
+		})?;
+
+	Ok(Json(ApiResponse {
+		data: status,
+		meta: None,
+	}))
+}
+
+pub async fn suspend_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(tenant_slug): Path<String>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	set_tenant_active(
+		&state,
+		&tenant_slug,
+		false,
+		request_metadata(addr, &headers),
+	)
+	.await
+}
+
+pub async fn reactivate_tenant<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(tenant_slug): Path<String>,
+	ConnectInfo(addr): ConnectInfo<SocketAddr>,
+	headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	set_tenant_active(&state, &tenant_slug, true, request_metadata(addr, &headers)).await
+}
+
+/// Record a create the tenant's quota refused, so webhook subscribers hear
+/// about it; failing to record it never replaces the quota error
+async fn audit_quota_exceeded<A, R>(
+	audit_service: &A,
+	resource_type: crate::models::audit::ResourceType,
+	metadata: &RequestMetadata,
+	result: Result<R, ServiceError>,
+) -> Result<R, ServiceError>
+where
+	A: AuditServiceTrait,
+{
+	let message = match &result {
+		Err(ServiceError::QuotaExceeded(message))
+		| Err(ServiceError::Repository(TenantRepositoryError::QuotaExceeded(message))) => message,
+		_ => return result,
+	};
+
+	let context = crate::utils::current_tenant_context();
+	if let Err(e) = audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|user| user.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::QuotaExceeded,
+			resource_type: Some(resource_type),
+			resource_id: None,
+			changes: Some(serde_json::json!({ "message": message })),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent.clone(),
+			request_id: metadata.request_id.clone(),
+		})
+		.await
+	{
+		tracing::warn!(tenant_id = %context.tenant_id, error = %e, "Failed to audit quota rejection");
+	}
+
+	result
+}
+
+pub(crate) fn request_metadata(addr: SocketAddr, headers: &HeaderMap) -> RequestMetadata {
+	let user_agent = headers
+		.get("user-agent")
+		.and_then(|h| h.to_str().ok())
+		.map(|s| s.to_string());
+
+	RequestMetadata::new()
+		.with_ip(Some(addr.ip()))
+		.with_user_agent(user_agent)
+		.with_request_id(crate::utils::current_request_id())
+}
+
+/// Suspend or reactivate a tenant; only an actual change is audited
+async fn set_tenant_active<M, N, T, TR, A>(
+	state: &super::routes::AppState<M, N, T, TR, A>,
+	tenant_slug: &str,
+	active: bool,
+	metadata: RequestMetadata,
+) -> Result<Json<ApiResponse<Tenant>>, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let tenant = state
+		.tenant_repo
+		.get_by_slug(tenant_slug)
+		.await
+		.map_err(ServiceError::from)?;
+	if tenant.is_active == active {
+		return Ok(Json(ApiResponse {
+			data: tenant,
+			meta: None,
+		}));
+	}
+
+	let tenant = state
+		.tenant_repo
+		.update(
+			tenant.id,
+			UpdateTenantRequest {
+				is_active: Some(active),
+				..Default::default()
+			},
+		)
+		.await
+		.map_err(ServiceError::from)?;
+
+	state
+		.audit_service
+		.log(CreateAuditLogRequest {
+			tenant_id: tenant.id,
+			user_id: None,
+			api_key_id: None,
+			action: if active {
+				AuditAction::TenantReactivated
+			} else {
+				AuditAction::TenantSuspended
+			},
+			resource_type: Some(crate::models::audit::ResourceType::Tenant),
+			resource_id: Some(tenant.id),
+			changes: Some(serde_json::json!({ "is_active": active })),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent,
+			request_id: metadata.request_id,
+		})
+		.await?;
+
+	Ok(Json(ApiResponse {
+		data: tenant,
+		meta: None,
+	}))
+}
+
+pub async fn list_duplicate_memberships<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let duplicates = state
+		.tenant_repo
+		.find_duplicate_memberships()
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: duplicates,
+		meta: None,
+	}))
+}
+
+/// Check a tenant's cross-references and compare its resource gauges with the stored rows
+pub async fn tenant_integrity_check<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let tenant = state
+		.tenant_repo
+		.get(tenant_id)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::TenantNotFound(_) => ApiError::NotFound,
+			e => ApiError::Service(ServiceError::from(e)),
+		})?;
+
+	let mut violations = state
+		.tenant_repo
+		.find_integrity_violations(tenant.id)
+		.await
+		.map_err(ServiceError::from)?;
+
+	let counts = state
+		.tenant_repo
+		.get_resource_counts(tenant.id)
+		.await
+		.map_err(ServiceError::from)?;
+	let (tracked_monitors, tracked_triggers) =
+		crate::utils::metrics::tenant_resource_counts(&tenant.slug);
+	violations.extend(IntegrityViolation::count_mismatches(
+		&[("monitor", counts.monitors), ("trigger", counts.triggers)],
+		&[("monitor", tracked_monitors), ("trigger", tracked_triggers)],
+	));
+
+	Ok(Json(ApiResponse {
+		data: IntegrityReport::new(tenant.id, violations),
+		meta: None,
+	}))
+}
+
+// Internal handlers
+pub async fn report_usage<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Json(request): Json<ReportUsageRequest>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	// Unknown tenants are a 404 rather than a foreign key violation
+	state
+		.tenant_repo
+		.get(request.tenant_id)
+		.await
+		.map_err(|e| match e {
+			TenantRepositoryError::TenantNotFound(_) => ApiError::NotFound,
+			e => ApiError::Service(ServiceError::from(e)),
+		})?;
+
+	UsageService::new(UsageRepository::new(state.pool.clone()))
+		.report(request)
+		.await?;
+
+	Ok(StatusCode::NO_CONTENT)
+}
+
+// Change feed handler
+pub async fn get_feed<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(query): Query<FeedQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let since = query.since.unwrap_or(0);
+	if since < 0 {
+		return Err(ApiError::BadRequest(
+			"since must be a non-negative cursor".to_string(),
+		));
+	}
+	let limit = query.limit.unwrap_or(100).clamp(1, 500);
+
+	let events = ResourceFeedRepository::new(state.pool.clone())
+		.list_since(since, limit)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: FeedPage::new(events, since),
+		meta: None,
+	}))
+}
+
+// Usage handlers
+pub async fn get_api_request_usage<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+	Query(query): Query<UsageQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let to = query.to.unwrap_or_else(|| chrono::Utc::now().date_naive());
+	let from = query.from.unwrap_or(to - chrono::Duration::days(29));
+	if from > to {
+		return Err(ApiError::BadRequest(
+			"from must not be after to".to_string(),
+		));
+	}
+	if (to - from).num_days() >= MAX_USAGE_RANGE_DAYS {
+		return Err(ApiError::BadRequest(format!(
+			"Usage range cannot exceed {} days",
+			MAX_USAGE_RANGE_DAYS
+		)));
+	}
+
+	let tenant_id = crate::utils::current_tenant_id();
+	let points = UsageRepository::new(state.pool.clone())
+		.api_request_counts(tenant_id, from, to)
+		.await
+		.map_err(ServiceError::from)?;
+
+	Ok(Json(ApiResponse {
+		data: daily_series(from, to, &points),
+		meta: None,
+	}))
+}
+
+// Health check
+/// Liveness probe: `200` whenever the process can serve a request
+pub async fn liveness_check() -> impl IntoResponse {
+	Json(serde_json::json!({
+		"status": "healthy",
+		"timestamp": chrono::Utc::now(),
+	}))
+}
+
+/// Readiness probe: `200` while every critical check in `AppState::health_checks`
+/// passes, `503` otherwise, reporting each check's status and latency
+pub async fn readiness_check<M, N, T, TR, A>(
+	State(state): State<super::routes::AppState<M, N, T, TR, A>>,
+) -> axum::response::Response
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+	TR: TenantRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	let checks = run_health_checks(&state.health_checks, HEALTH_CHECK_TIMEOUT).await;
+	let (status, label) = if checks.iter().any(HealthCheckResult::is_failing) {
+		(StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+	} else {
+		(StatusCode::OK, "ready")
+	};
+
+	(
+		status,
+		Json(serde_json::json!({
+			"status": label,
+			"timestamp": chrono::Utc::now(),
+			"checks": checks,
+			"pool": {
+				"size": state.pool.size(),
+				"idle": state.pool.num_idle(),
+			},
+		})),
+	)
+		.into_response()
+}
 
 // Error handling
 #[derive(Debug, thiserror::Error)]
@@ -525,15 +3005,61 @@ pub enum ApiError {
 	#[error("Unauthorized")]
 	Unauthorized,
 
+	#[error("API key has expired")]
+	ApiKeyExpired,
+
+	#[error("API key has no uses left")]
+	ApiKeyExhausted,
+
 	#[error("Not found")]
 	NotFound,
 
+	#[error("Rate limit exceeded, retry in {0} seconds")]
+	RateLimited(u64),
+
+	#[error("Tenant is suspended")]
+	TenantSuspended,
+
+	#[error("Email address has not been verified")]
+	EmailNotVerified,
+
+	#[error("Idempotency-Key was already used for a different request")]
+	IdempotencyKeyReused,
+
+	#[error(transparent)]
+	InsufficientPermissions(#[from] crate::utils::InsufficientPermissions),
+
 	#[error("Internal server error")]
 	Internal,
 }
 
 impl IntoResponse for ApiError {
 	fn into_response(self) -> axum::response::Response {
+		let retry_after = match &self {
+			ApiError::RateLimited(seconds) => Some(*seconds),
+			ApiError::Service(ServiceError::Repository(
+				crate::repositories::TenantRepositoryError::Database(e),
+			)) if is_database_unavailable(e) => Some(UNAVAILABLE_RETRY_AFTER_SECS),
+			_ => None,
+		};
+		// Let the caller show which resources block the delete or import
+		let details = match &self {
+			ApiError::Service(ServiceError::Repository(
+				crate::repositories::TenantRepositoryError::MonitorHasTriggers {
+					trigger_ids, ..
+				},
+			)) => Some(serde_json::json!({
+				"trigger_count": trigger_ids.len(),
+				"trigger_ids": trigger_ids,
+			})),
+			ApiError::Service(ServiceError::Repository(
+				crate::repositories::TenantRepositoryError::NetworkHasMonitors { monitors, .. },
+			)) => Some(serde_json::json!({ "monitors": monitors })),
+			ApiError::Service(ServiceError::Repository(
+				crate::repositories::TenantRepositoryError::ImportConflicts(conflicts),
+			)) => Some(serde_json::json!({ "conflicts": conflicts })),
+			_ => None,
+		};
 		let (status, code, message) = match self {
 			ApiError::Service(ref err) => match err {
 				ServiceError::AccessDenied(_) => {
@@ -547,6 +3073,11 @@ impl IntoResponse for ApiError {
 				ServiceError::ValidationError(_) => {
 					(StatusCode::BAD_REQUEST, "VALIDATION_ERROR", err.to_string())
 				}
+				ServiceError::NetworkUnreachable(_) => (
+					StatusCode::UNPROCESSABLE_ENTITY,
+					"NETWORK_UNREACHABLE",
+					err.to_string(),
+				),
 				ServiceError::Repository(ref repo_err) => match repo_err {
 					crate::repositories::TenantRepositoryError::ResourceNotFound { .. } => (
 						StatusCode::NOT_FOUND,
@@ -558,6 +3089,59 @@ impl IntoResponse for ApiError {
 						"ALREADY_EXISTS",
 						"Resource already exists".to_string(),
 					),
+					crate::repositories::TenantRepositoryError::ImportConflicts(_) => (
+						StatusCode::CONFLICT,
+						"ALREADY_EXISTS",
+						format!(
+							"{}; pass mode=skip_existing or mode=replace to import anyway",
+							repo_err
+						),
+					),
+					crate::repositories::TenantRepositoryError::ValidationError(_) => (
+						StatusCode::BAD_REQUEST,
+						"VALIDATION_ERROR",
+						repo_err.to_string(),
+					),
+					crate::repositories::TenantRepositoryError::CannotRemoveLastOwner(_) => (
+						StatusCode::CONFLICT,
+						"LAST_OWNER",
+						"A tenant must keep at least one owner; promote another member to owner first"
+							.to_string(),
+					),
+					crate::repositories::TenantRepositoryError::MonitorHasTriggers { .. } => (
+						StatusCode::CONFLICT,
+						"MONITOR_HAS_TRIGGERS",
+						format!("{}; pass force=true to delete them as well", repo_err),
+					),
+					crate::repositories::TenantRepositoryError::NetworkHasMonitors { .. } => (
+						StatusCode::CONFLICT,
+						"CONFLICT",
+						format!("{}; pass force=true to delete them as well", repo_err),
+					),
+					crate::repositories::TenantRepositoryError::DeletedTenantSlug(_) => (
+						StatusCode::CONFLICT,
+						"DELETED_TENANT_SLUG",
+						repo_err.to_string(),
+					),
+					crate::repositories::TenantRepositoryError::QuotaExceeded(_) => (
+						StatusCode::TOO_MANY_REQUESTS,
+						"QUOTA_EXCEEDED",
+						repo_err.to_string(),
+					),
+					crate::repositories::TenantRepositoryError::RateLimited(_) => (
+						StatusCode::TOO_MANY_REQUESTS,
+						"RATE_LIMITED",
+						repo_err.to_string(),
+					),
+					crate::repositories::TenantRepositoryError::Database(e)
+						if is_database_unavailable(e) =>
+					{
+						(
+							StatusCode::SERVICE_UNAVAILABLE,
+							"SERVICE_UNAVAILABLE",
+							"Service temporarily unavailable".to_string(),
+						)
+					}
 					_ => (
 						StatusCode::INTERNAL_SERVER_ERROR,
 						"INTERNAL_ERROR",
@@ -576,7 +3160,40 @@ impl IntoResponse for ApiError {
 				"UNAUTHORIZED",
 				"Unauthorized".to_string(),
 			),
+			ApiError::ApiKeyExpired => (
+				StatusCode::UNAUTHORIZED,
+				"API_KEY_EXPIRED",
+				self.to_string(),
+			),
+			ApiError::ApiKeyExhausted => (
+				StatusCode::UNAUTHORIZED,
+				"API_KEY_EXHAUSTED",
+				self.to_string(),
+			),
 			ApiError::NotFound => (StatusCode::NOT_FOUND, "NOT_FOUND", "Not found".to_string()),
+			ApiError::RateLimited(_) => (
+				StatusCode::TOO_MANY_REQUESTS,
+				"RATE_LIMITED",
+				self.to_string(),
+			),
+			ApiError::InsufficientPermissions(_) => (
+				StatusCode::FORBIDDEN,
+				"INSUFFICIENT_PERMISSIONS",
+				self.to_string(),
+			),
+			ApiError::TenantSuspended => {
+				(StatusCode::FORBIDDEN, "TENANT_SUSPENDED", self.to_string())
+			}
+			ApiError::EmailNotVerified => (
+				StatusCode::FORBIDDEN,
+				"EMAIL_NOT_VERIFIED",
+				self.to_string(),
+			),
+			ApiError::IdempotencyKeyReused => (
+				StatusCode::UNPROCESSABLE_ENTITY,
+				"IDEMPOTENCY_KEY_REUSED",
+				self.to_string(),
+			),
 			ApiError::Internal => (
 				StatusCode::INTERNAL_SERVER_ERROR,
 				"INTERNAL_ERROR",
@@ -584,11 +3201,23 @@ impl IntoResponse for ApiError {
 			),
 		};
 
+		// Throttling and outages pass; a spent quota, a bad request or an
+		// unexpected failure will not change by being repeated
+		let retryable = matches!(code, "RATE_LIMITED" | "SERVICE_UNAVAILABLE");
 		let body = Json(ErrorResponse {
 			error: message,
 			code: code.to_string(),
+			retryable,
+			request_id: crate::utils::current_request_id(),
+			details,
 		});
 
-		(status, body).into_response()
+		let mut response = (status, body).into_response();
+		if let Some(seconds) = retry_after {
+			response
+				.headers_mut()
+				.insert(axum::http::header::RETRY_AFTER, seconds.into());
+		}
+		response
 	}
 }
@@ -31,9 +31,22 @@ where
 	pub audit_service: A,
 	pub pool: sqlx::PgPool,
 	pub auth_service: crate::utils::AuthService,
+	pub api_config: crate::config::ApiConfig,
+	pub rate_limiter: api_middleware::TenantRateLimiter,
+	/// Shared with the repositories, for writes made outside of them such as imports
+	pub quota_cache: crate::utils::QuotaStatusCache,
+	/// Audit entries as they are written, for the live audit stream
+	pub audit_events: crate::services::AuditEvents,
+	/// Dependencies checked by `/health/ready`
+	pub health_checks: Vec<std::sync::Arc<dyn crate::services::HealthCheck>>,
 }
 
-pub fn create_router<M, N, T, TR, A>(state: AppState<M, N, T, TR, A>) -> Router
+/// Monitor, network, trigger and tenant routes served under
+/// `/api/v1/tenants/:tenant_slug`, without the authentication, quota and rate
+/// limit layers `create_router` adds around them
+pub fn tenant_resource_routes<M, N, T, TR, A>(
+	state: &AppState<M, N, T, TR, A>,
+) -> Router<AppState<M, N, T, TR, A>>
 where
 	M: MonitorServiceTrait + Clone + Send + Sync + 'static,
 	N: NetworkServiceTrait + Clone + Send + Sync + 'static,
@@ -41,52 +54,269 @@ where
 	TR: TenantRepositoryTrait + Clone + Send + Sync + 'static,
 	A: AuditServiceTrait + Clone + Send + Sync + 'static,
 {
-	// Public routes (no auth required)
-	let public_routes = Router::new()
-		.route("/health", get(handlers::health_check))
+	Router::new()
+		// Monitor routes
 		.route(
-			"/api/v1/auth/register",
-			post(auth::register::<M, N, T, TR, A>),
+			"/monitors",
+			post(handlers::create_monitor).route_layer(middleware::from_fn_with_state(
+				state.clone(),
+				api_middleware::idempotency_middleware,
+			)),
 		)
-		.route("/api/v1/auth/login", post(auth::login::<M, N, T, TR, A>));
-
-	// Tenant-scoped routes (require auth and tenant context)
-	let tenant_routes = Router::new()
-		// Monitor routes
-		.route("/monitors", post(handlers::create_monitor))
 		.route("/monitors", get(handlers::list_monitors))
+		.route("/monitors/import", post(handlers::import_monitors))
+		.route("/monitors/batch/plan", post(handlers::plan_monitor_batch))
 		.route("/monitors/:monitor_id", get(handlers::get_monitor))
 		.route("/monitors/:monitor_id", put(handlers::update_monitor))
 		.route("/monitors/:monitor_id", delete(handlers::delete_monitor))
+		.route(
+			"/monitors/:monitor_id/deactivate",
+			post(handlers::deactivate_monitor),
+		)
+		.route("/monitors/:monitor_id/clone", post(handlers::clone_monitor))
+		.route(
+			"/monitors/:monitor_id/restore",
+			post(handlers::restore_monitor),
+		)
+		.route(
+			"/monitors/:monitor_id/rollback/:version",
+			post(handlers::rollback_monitor),
+		)
+		.route("/monitors/:monitor_id/audit", get(handlers::get_monitor_audit))
 		// Network routes
-		.route("/networks", post(handlers::create_network))
+		.route(
+			"/networks",
+			post(handlers::create_network).route_layer(middleware::from_fn_with_state(
+				state.clone(),
+				api_middleware::idempotency_middleware,
+			)),
+		)
 		.route("/networks", get(handlers::list_networks))
 		.route("/networks/:network_id", get(handlers::get_network))
 		.route("/networks/:network_id", put(handlers::update_network))
 		.route("/networks/:network_id", delete(handlers::delete_network))
 		// Trigger routes
-		.route("/triggers", post(handlers::create_trigger))
+		.route(
+			"/triggers",
+			post(handlers::create_trigger).route_layer(middleware::from_fn_with_state(
+				state.clone(),
+				api_middleware::idempotency_middleware,
+			)),
+		)
 		.route("/triggers", get(handlers::list_triggers))
+		.route("/triggers/batch", post(handlers::create_triggers_batch))
+		.route(
+			"/triggers/using-old-secret",
+			get(handlers::list_triggers_using_old_secret),
+		)
+		.route("/triggers/type-stats", get(handlers::get_trigger_type_stats))
 		.route("/triggers/:trigger_id", get(handlers::get_trigger))
 		.route("/triggers/:trigger_id", put(handlers::update_trigger))
 		.route("/triggers/:trigger_id", delete(handlers::delete_trigger))
+		.route("/triggers/:trigger_id/test", post(handlers::test_trigger))
 		.route("/monitors/:monitor_id/triggers", get(handlers::list_triggers_by_monitor))
+		// Tenant routes
+		.route("/", get(handlers::get_tenant))
+		.route("/slug", put(handlers::rename_tenant_slug))
+		.route("/transfer-ownership", post(handlers::transfer_ownership))
+		.route("/members/batch", post(handlers::add_members_batch))
+		.route("/members/sync", post(handlers::sync_members))
+		// Change feed
+		.route("/feed", get(handlers::get_feed))
+		// Usage
+		.route("/usage/api-requests", get(handlers::get_api_request_usage))
+		.route("/stats", get(handlers::get_resource_stats))
+		.route("/quota", get(handlers::get_quota_status))
+		.route("/quota/check", get(handlers::check_quota))
+		.route("/quota/check-batch", post(handlers::check_quota_batch))
+		// Configuration export
+		.route(
+			"/export",
+			get(handlers::export_tenant)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		// Audit log
+		.route("/audit-logs", get(handlers::list_audit_logs))
+		.route(
+			"/audit-logs/stream",
+			get(handlers::stream_audit_logs)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+}
+
+pub fn create_router<M, N, T, TR, A>(state: AppState<M, N, T, TR, A>) -> Router
+where
+	M: MonitorServiceTrait + Clone + Send + Sync + 'static,
+	N: NetworkServiceTrait + Clone + Send + Sync + 'static,
+	T: TriggerServiceTrait + Clone + Send + Sync + 'static,
+	TR: TenantRepositoryTrait + Clone + Send + Sync + 'static,
+	A: AuditServiceTrait + Clone + Send + Sync + 'static,
+{
+	// Public routes (no auth required)
+	let public_routes = Router::new()
+		.route("/health", get(handlers::liveness_check))
+		.route("/health/live", get(handlers::liveness_check))
+		.route("/health/ready", get(handlers::readiness_check))
+		.route(
+			"/api/v1/auth/register",
+			post(auth::register::<M, N, T, TR, A>),
+		)
+		.route("/api/v1/auth/login", post(auth::login::<M, N, T, TR, A>))
+		.route(
+			"/api/v1/auth/verify-email",
+			post(auth::verify_email::<M, N, T, TR, A>),
+		)
+		.route(
+			"/api/v1/auth/sso-provision",
+			post(auth::sso_provision::<M, N, T, TR, A>),
+		)
+		// Authenticated by the caller's own token, across all their tenants
+		.route(
+			"/api/v1/me/accessible-tenants",
+			get(auth::list_accessible_tenants::<M, N, T, TR, A>),
+		)
+		// Authenticated by the invitee's JWT rather than a tenant membership
+		.route(
+			"/api/v1/invitations/:token/accept",
+			post(auth::accept_invitation::<M, N, T, TR, A>),
+		);
+
+	// Tenant-scoped routes (require auth and tenant context)
+	let tenant_routes = tenant_resource_routes(&state)
+		// RPC quota applies to the routes above; API key management is exempt
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::rpc_quota_middleware,
+		))
 		// API key routes
 		.route("/api-keys", post(auth::create_api_key))
-		.route("/api-keys", get(auth::list_api_keys))
+		.route(
+			"/api-keys",
+			get(auth::list_api_keys)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
 		.route("/api-keys/:key_id", delete(auth::revoke_api_key))
+		.route("/api-keys/:key_id/rotate", post(auth::rotate_api_key))
+		// Invitation routes
+		.route("/invitations", post(handlers::create_invitation))
+		.route(
+			"/invitations",
+			get(handlers::list_invitations)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		.route(
+			"/invitations/:invitation_id",
+			delete(handlers::revoke_invitation),
+		)
+		// Private caching for GET responses; the routes above marked no-store keep it
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::cache_control_middleware,
+		))
+		// Throttle every tenant route once the tenant has been authenticated
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::rate_limit_middleware,
+		))
 		.layer(middleware::from_fn_with_state(
 			state.clone(),
 			api_middleware::tenant_auth_middleware,
 		));
 
+	// The caller's current tenant, chosen by API key, tenant header or the
+	// user's only membership
+	let current_tenant_routes = Router::new()
+		.route("/quota", get(handlers::get_quota_status))
+		.route("/monitors/batch/plan", post(handlers::plan_monitor_batch))
+		.route(
+			"/export",
+			get(handlers::export_current_tenant)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		.route("/import", post(handlers::import_tenant))
+		.route(
+			"/audit-logs/stream",
+			get(handlers::stream_audit_logs)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		// Webhook subscriptions to the tenant's audit events
+		.route(
+			"/webhooks",
+			get(handlers::list_webhooks)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		.route("/webhooks", post(handlers::create_webhook))
+		.route("/webhooks/:webhook_id", get(handlers::get_webhook))
+		.route("/webhooks/:webhook_id", put(handlers::update_webhook))
+		.route("/webhooks/:webhook_id", delete(handlers::delete_webhook))
+		.route(
+			"/webhooks/:webhook_id/deliveries",
+			get(handlers::list_webhook_deliveries)
+				.layer(middleware::from_fn(api_middleware::no_store_middleware)),
+		)
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::rate_limit_middleware,
+		))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::current_tenant_auth_middleware,
+		));
+
+	// Platform admin routes (require the admin token)
+	let admin_routes = Router::new()
+		.route("/quota/batch", post(handlers::batch_quota_status))
+		.route("/tenants/:tenant/suspend", post(handlers::suspend_tenant))
+		.route(
+			"/tenants/:tenant/reactivate",
+			post(handlers::reactivate_tenant),
+		)
+		// `:tenant` is the slug above and the tenant id here; the router needs one name per segment
+		.route(
+			"/tenants/:tenant/integrity-check",
+			get(handlers::tenant_integrity_check),
+		)
+		.route("/tenants/:tenant/quota", get(handlers::tenant_quota_status))
+		.route(
+			"/memberships/duplicates",
+			get(handlers::list_duplicate_memberships),
+		)
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::admin_auth_middleware,
+		));
+
+	// Internal routes for monitor runners (require the service token)
+	let internal_routes = Router::new()
+		.route("/usage", post(handlers::report_usage))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::service_auth_middleware,
+		));
+
+	// Platform-wide tenant listing, guarded like the admin routes
+	let tenant_list_route = Router::new().route(
+		"/api/v1/tenants",
+		get(handlers::list_tenants).route_layer(middleware::from_fn_with_state(
+			state.clone(),
+			api_middleware::admin_auth_middleware,
+		)),
+	);
+
 	// Combine all routes
 	Router::new()
 		.merge(public_routes)
+		.merge(tenant_list_route)
 		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.nest("/api/v1", current_tenant_routes)
+		.nest("/api/v1/admin", admin_routes)
+		.nest("/api/v1/internal", internal_routes)
+		// After routing, so requests are labelled by route template rather than raw path
+		.route_layer(middleware::from_fn(api_middleware::http_metrics_middleware))
 		.layer(
 			ServiceBuilder::new()
 				.layer(TraceLayer::new_for_http())
+				.layer(middleware::from_fn(api_middleware::request_id_middleware))
 				.layer(
 					CorsLayer::new()
 						.allow_origin(Any)
@@ -121,8 +351,44 @@ where
 			trigger_service,
 			tenant_repo,
 			audit_service,
-			pool,
 			auth_service,
+			api_config: crate::config::ApiConfig::default(),
+			rate_limiter: api_middleware::TenantRateLimiter::default(),
+			quota_cache: crate::utils::QuotaStatusCache::default(),
+			audit_events: crate::services::AuditEvents::default(),
+			health_checks: vec![std::sync::Arc::new(
+				crate::services::DatabaseHealthCheck::new(pool.clone()),
+			)],
+			pool,
 		}
 	}
+
+	pub fn with_api_config(mut self, api_config: crate::config::ApiConfig) -> Self {
+		self.api_config = api_config;
+		self
+	}
+
+	pub fn with_rate_limiter(mut self, rate_limiter: api_middleware::TenantRateLimiter) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+
+	pub fn with_quota_cache(mut self, quota_cache: crate::utils::QuotaStatusCache) -> Self {
+		self.quota_cache = quota_cache;
+		self
+	}
+
+	pub fn with_audit_events(mut self, audit_events: crate::services::AuditEvents) -> Self {
+		self.audit_events = audit_events;
+		self
+	}
+
+	/// Add a dependency to the readiness probe, after the database check
+	pub fn with_health_check(
+		mut self,
+		check: std::sync::Arc<dyn crate::services::HealthCheck>,
+	) -> Self {
+		self.health_checks.push(check);
+		self
+	}
 }
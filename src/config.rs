@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 
+/// Environment variable that enables strict configuration loading
+pub const REQUIRE_EXPLICIT_ENV: &str = "SMT__REQUIRE_EXPLICIT";
+
+/// Critical values strict mode refuses to default
+const REQUIRED_VALUES: [&str; 2] = ["auth.jwt_secret", "database.url"];
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Config {
 	pub server: ServerConfig,
@@ -8,6 +14,21 @@ pub struct Config {
 	pub auth: AuthConfig,
 	pub monitoring: MonitoringConfig,
 	pub quotas: DefaultQuotaConfig,
+	#[serde(default)]
+	pub tenants: TenantConfig,
+	#[serde(default)]
+	pub api: ApiConfig,
+	#[serde(default)]
+	pub monitors: MonitorConfig,
+	#[serde(default)]
+	pub networks: NetworkConfig,
+	#[serde(default)]
+	pub triggers: TriggerConfig,
+	#[serde(default)]
+	pub webhooks: WebhookConfig,
+	/// Fail startup instead of falling back to defaults for critical values
+	#[serde(default)]
+	pub require_explicit: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -40,12 +61,65 @@ pub struct AuthConfig {
 	pub jwt_expiration_hours: i64,
 	pub refresh_token_expiration_days: i64,
 	pub api_key_prefix: String,
+	/// Bearer token for the platform admin API; admin routes are disabled when unset
+	#[serde(default)]
+	pub admin_token: Option<String>,
+	/// Bearer token monitor runners use for the internal API; internal routes are disabled when unset
+	#[serde(default)]
+	pub service_token: Option<String>,
+	/// Deactivate API keys unused for this many days; disabled when unset
+	#[serde(default)]
+	pub api_key_max_idle_days: Option<i64>,
+	/// Give API keys with no recorded permissions full access instead of none
+	#[serde(default = "default_full_access")]
+	pub default_full_access: bool,
+	/// External identity provider for SSO provisioning; the endpoint is disabled when unset
+	#[serde(default)]
+	pub sso: Option<SsoConfig>,
+	/// Reject logins from users who have not verified their email address
+	#[serde(default)]
+	pub require_email_verification: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SsoConfig {
+	/// Expected `iss` claim of ID tokens
+	pub issuer: String,
+	/// Expected `aud` claim, i.e. this service's client id at the provider
+	pub audience: String,
+	/// Signing algorithm of ID tokens, e.g. `RS256`, `ES256` or `HS256`
+	#[serde(default = "default_sso_algorithm")]
+	pub algorithm: String,
+	/// PEM public key for RSA/EC algorithms, or the shared secret for HMAC
+	pub verification_key: String,
+	/// Create a tenant, owned by the user, for SSO users without one
+	#[serde(default = "default_sso_provision_tenants")]
+	pub provision_tenants: bool,
+}
+
+fn default_full_access() -> bool {
+	true
+}
+
+fn default_metrics_refresh_interval_secs() -> u64 {
+	60
+}
+
+fn default_sso_algorithm() -> String {
+	"RS256".to_string()
+}
+
+fn default_sso_provision_tenants() -> bool {
+	true
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MonitoringConfig {
 	pub metrics_enabled: bool,
 	pub metrics_port: u16,
+	/// How often tenant resource gauges and pool metrics are recomputed
+	#[serde(default = "default_metrics_refresh_interval_secs")]
+	pub metrics_refresh_interval_secs: u64,
 	pub tracing_level: String,
 	pub log_format: LogFormat,
 }
@@ -65,6 +139,270 @@ pub struct DefaultQuotaConfig {
 	pub max_triggers_per_monitor: i32,
 	pub max_rpc_requests_per_minute: i32,
 	pub max_storage_mb: i32,
+	/// `hard` blocks monitor creation once storage is over quota; `soft` only warns
+	#[serde(default)]
+	pub storage_enforcement: crate::models::StorageEnforcement,
+	/// Percent of a quota at which the quota status starts reporting a warning
+	#[serde(default = "default_warn_threshold_percent")]
+	pub warn_threshold_percent: u8,
+	/// Milliseconds a tenant's quota status is reused between creates; not cached when unset
+	#[serde(default)]
+	pub status_cache_ttl_ms: Option<i64>,
+}
+
+fn default_warn_threshold_percent() -> u8 {
+	crate::models::DEFAULT_QUOTA_WARN_THRESHOLD_PERCENT
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TenantConfig {
+	/// Slugs that tenants cannot claim on creation or rename
+	pub reserved_slugs: Vec<String>,
+	/// Minimum seconds between slug renames; disabled when unset
+	#[serde(default)]
+	pub slug_rename_cooldown_secs: Option<i64>,
+	/// Days a deleted monitor stays restorable before it is purged; kept forever when unset
+	#[serde(default)]
+	pub deleted_monitor_retention_days: Option<i64>,
+	/// Reject a monitor whose contract address is already monitored on the same network
+	#[serde(default)]
+	pub unique_contract_per_network: bool,
+	/// Run one monitor create at a time per tenant so concurrent creates cannot race the quota
+	#[serde(default)]
+	pub serialize_monitor_creates: bool,
+	/// Most members a tenant can have; unlimited when unset
+	#[serde(default)]
+	pub max_members: Option<i64>,
+	/// Days a deleted tenant's slug stays reserved so the tenant can be
+	/// restored under it; reusable right away when unset
+	#[serde(default)]
+	pub deleted_slug_retention_days: Option<i64>,
+}
+
+impl Default for TenantConfig {
+	fn default() -> Self {
+		Self {
+			reserved_slugs: crate::models::DEFAULT_RESERVED_SLUGS
+				.iter()
+				.map(|s| s.to_string())
+				.collect(),
+			slug_rename_cooldown_secs: None,
+			deleted_monitor_retention_days: None,
+			unique_contract_per_network: false,
+			serialize_monitor_creates: false,
+			max_members: None,
+			deleted_slug_retention_days: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MonitorConfig {
+	/// Regex every new monitor name must match; names are not checked when unset
+	#[serde(default)]
+	pub name_pattern: Option<String>,
+	/// Most monitors a tenant can create in any 60 second window; unlimited when unset
+	#[serde(default)]
+	pub max_creates_per_minute: Option<u32>,
+	/// Only activate a monitor once each of its webhook, Slack and Discord
+	/// triggers has had a successful test delivery
+	#[serde(default)]
+	pub require_tested_triggers: bool,
+}
+
+impl MonitorConfig {
+	/// The compiled `name_pattern`, if one is configured
+	pub fn name_regex(&self) -> Result<Option<regex::Regex>, String> {
+		self.name_pattern
+			.as_deref()
+			.map(regex::Regex::new)
+			.transpose()
+			.map_err(|e| format!("Invalid monitor name pattern: {}", e))
+	}
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+	/// Allow `validate_connectivity` requests to call the network's RPC endpoint;
+	/// turn off in air-gapped deployments, where the check is then skipped
+	pub connectivity_probe_enabled: bool,
+	/// Seconds the connectivity probe waits for the RPC endpoint
+	pub probe_timeout_secs: u64,
+}
+
+impl Default for NetworkConfig {
+	fn default() -> Self {
+		Self {
+			connectivity_probe_enabled: true,
+			probe_timeout_secs: 5,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TriggerConfig {
+	/// HTTP methods a webhook trigger may be configured with; others are rejected on create and update
+	pub webhook_allowed_methods: Vec<String>,
+	/// Most triggers of one type (webhook, email, ...) a single monitor may have; unlimited when unset
+	#[serde(default)]
+	pub max_per_type_per_monitor: Option<i64>,
+}
+
+impl Default for TriggerConfig {
+	fn default() -> Self {
+		Self {
+			webhook_allowed_methods: crate::services::DEFAULT_WEBHOOK_METHODS
+				.iter()
+				.map(|method| method.to_string())
+				.collect(),
+			max_per_type_per_monitor: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+	/// Deliver audit events to tenant webhooks; when off, webhooks can still be managed
+	pub delivery_enabled: bool,
+	/// Attempts per delivery, including the first, before it is marked failed
+	pub max_attempts: u32,
+	/// Milliseconds before the first retry; each later retry waits twice as long
+	pub initial_backoff_ms: u64,
+	/// Seconds a single delivery attempt may take
+	pub timeout_secs: u64,
+}
+
+impl Default for WebhookConfig {
+	fn default() -> Self {
+		Self {
+			delivery_enabled: true,
+			max_attempts: 5,
+			initial_backoff_ms: 1000,
+			timeout_secs: 10,
+		}
+	}
+}
+
+impl WebhookConfig {
+	pub fn retry_policy(&self) -> crate::services::WebhookRetryPolicy {
+		crate::services::WebhookRetryPolicy {
+			max_attempts: self.max_attempts,
+			initial_backoff: std::time::Duration::from_millis(self.initial_backoff_ms),
+			timeout: std::time::Duration::from_secs(self.timeout_secs),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ApiConfig {
+	/// Largest number of items accepted by a single batch request
+	pub max_batch_size: usize,
+	/// Widest from/to window, in days, a single audit log query may span
+	pub audit_max_range_days: i64,
+	/// Most audit log entries returned by a single query (the service never returns more than 500)
+	pub audit_max_results: i64,
+	/// Seconds a create response is replayed for repeats of its Idempotency-Key
+	pub idempotency_ttl_secs: i64,
+	/// Hours a tenant invitation can be accepted for after it is sent
+	pub invitation_ttl_hours: i64,
+	/// `max-age` of the private Cache-Control header on successful GET
+	/// responses; 0 leaves the header off
+	pub cache_max_age_secs: u64,
+	/// Most fields a single `fields` projection may name
+	pub max_projection_fields: usize,
+}
+
+impl Default for ApiConfig {
+	fn default() -> Self {
+		Self {
+			max_batch_size: 100,
+			audit_max_range_days: 90,
+			audit_max_results: 500,
+			idempotency_ttl_secs: 86400,
+			invitation_ttl_hours: 168,
+			cache_max_age_secs: 30,
+			max_projection_fields: 20,
+		}
+	}
+}
+
+impl ApiConfig {
+	/// Cache-Control value for cacheable GET responses, if caching is enabled
+	pub fn cache_control(&self) -> Option<String> {
+		(self.cache_max_age_secs > 0)
+			.then(|| format!("private, max-age={}", self.cache_max_age_secs))
+	}
+
+	pub fn check_batch_size(&self, size: usize) -> Result<(), String> {
+		if size > self.max_batch_size {
+			return Err(format!(
+				"Batch size {} exceeds the maximum of {}",
+				size, self.max_batch_size
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Split a comma-separated `fields` projection, rejecting lists longer
+	/// than `max_projection_fields` and fields missing from `allowed`
+	pub fn parse_projection(&self, fields: &str, allowed: &[&str]) -> Result<Vec<String>, String> {
+		let count = fields.split(',').count();
+		if count > self.max_projection_fields {
+			return Err(format!(
+				"Projection names {} fields, more than the maximum of {}",
+				count, self.max_projection_fields
+			));
+		}
+
+		let fields: Vec<String> = fields
+			.split(',')
+			.map(str::trim)
+			.filter(|field| !field.is_empty())
+			.map(str::to_string)
+			.collect();
+		if fields.is_empty() {
+			return Err("Projection must name at least one field".to_string());
+		}
+		if let Some(unknown) = fields
+			.iter()
+			.find(|field| !allowed.contains(&field.as_str()))
+		{
+			return Err(format!(
+				"Unknown field '{}'; expected any of: {}",
+				unknown,
+				allowed.join(", ")
+			));
+		}
+
+		Ok(fields)
+	}
+
+	/// Rejects audit log queries whose time range is too wide and caps the
+	/// requested page size; an open-ended range is measured up to `now`
+	pub fn limit_audit_query(
+		&self,
+		filter: &mut crate::models::AuditLogFilter,
+		now: chrono::DateTime<chrono::Utc>,
+	) -> Result<(), String> {
+		if let Some(from) = filter.from {
+			let to = filter.to.unwrap_or(now);
+			if to - from > chrono::Duration::days(self.audit_max_range_days) {
+				return Err(format!(
+					"Audit log time range exceeds the maximum of {} days",
+					self.audit_max_range_days
+				));
+			}
+		}
+
+		filter.limit = filter.limit.map(|limit| limit.min(self.audit_max_results));
+
+		Ok(())
+	}
 }
 
 impl Default for Config {
@@ -87,10 +425,17 @@ impl Default for Config {
 				jwt_expiration_hours: 24,
 				refresh_token_expiration_days: 30,
 				api_key_prefix: "smt_".to_string(),
+				admin_token: None,
+				service_token: None,
+				api_key_max_idle_days: None,
+				default_full_access: true,
+				sso: None,
+				require_email_verification: false,
 			},
 			monitoring: MonitoringConfig {
 				metrics_enabled: true,
 				metrics_port: 9090,
+				metrics_refresh_interval_secs: default_metrics_refresh_interval_secs(),
 				tracing_level: "info".to_string(),
 				log_format: LogFormat::Json,
 			},
@@ -100,34 +445,145 @@ impl Default for Config {
 				max_triggers_per_monitor: 10,
 				max_rpc_requests_per_minute: 1000,
 				max_storage_mb: 1000,
+				storage_enforcement: crate::models::StorageEnforcement::Hard,
+				warn_threshold_percent: default_warn_threshold_percent(),
+				status_cache_ttl_ms: None,
 			},
+			tenants: TenantConfig::default(),
+			api: ApiConfig::default(),
+			monitors: MonitorConfig::default(),
+			networks: NetworkConfig::default(),
+			triggers: TriggerConfig::default(),
+			webhooks: WebhookConfig::default(),
+			require_explicit: false,
 		}
 	}
 }
 
+/// `SMT__*` environment variables; list settings take comma-separated values
+fn environment() -> config::Environment {
+	config::Environment::with_prefix("SMT")
+		.separator("__")
+		.list_separator(",")
+		.with_list_parse_key("triggers.webhook_allowed_methods")
+		.try_parsing(true)
+}
+
 impl Config {
 	/// Load configuration from environment variables with fallback to defaults
 	pub fn from_env() -> Result<Self, config::ConfigError> {
+		Self::sources()?.try_deserialize()
+	}
+
+	/// Raw settings from the environment, layered over the file named by
+	/// `SMT_CONFIG_PATH` if set
+	fn sources() -> Result<config::Config, config::ConfigError> {
 		let mut config = config::Config::builder()
-			.add_source(config::Environment::with_prefix("SMT").separator("__"))
+			.add_source(environment())
 			.build()?;
 
 		// Try to load from a config file if specified
 		if let Ok(config_path) = std::env::var("SMT_CONFIG_PATH") {
 			config = config::Config::builder()
 				.add_source(config::File::with_name(&config_path))
-				.add_source(config::Environment::with_prefix("SMT").separator("__"))
+				.add_source(environment())
 				.build()?;
 		}
 
-		config.try_deserialize()
+		Ok(config)
+	}
+
+	/// Load configuration from the environment, falling back to defaults unless
+	/// strict mode is enabled through `SMT__REQUIRE_EXPLICIT`
+	pub fn load() -> Result<Self, String> {
+		let require_explicit = std::env::var(REQUIRE_EXPLICIT_ENV)
+			.map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+			.unwrap_or(false);
+
+		let sources = Self::sources();
+		let absent = match &sources {
+			Ok(sources) => Self::absent_required_values(sources),
+			Err(_) => REQUIRED_VALUES.to_vec(),
+		};
+
+		Self::resolve(
+			sources.and_then(config::Config::try_deserialize),
+			&absent,
+			require_explicit,
+		)
+	}
+
+	/// Resolve a loaded configuration according to the strict mode setting;
+	/// `absent` lists the required values the sources did not provide
+	pub fn resolve(
+		loaded: Result<Self, config::ConfigError>,
+		absent: &[&str],
+		require_explicit: bool,
+	) -> Result<Self, String> {
+		let config = match loaded {
+			Ok(config) => config,
+			Err(e) if require_explicit => {
+				return Err(if absent.is_empty() {
+					format!("Failed to load configuration ({})", e)
+				} else {
+					format!(
+						"Failed to load configuration ({}); missing required values: {}",
+						e,
+						absent.join(", ")
+					)
+				});
+			}
+			Err(e) => {
+				eprintln!(
+					"Failed to load configuration from environment ({}), using defaults",
+					e
+				);
+				Self::default()
+			}
+		};
+
+		if require_explicit || config.require_explicit {
+			let missing = config.defaulted_required_values();
+			if !missing.is_empty() {
+				return Err(format!(
+					"Missing required configuration values: {}",
+					missing.join(", ")
+				));
+			}
+		}
+
+		Ok(config)
+	}
+
+	/// Required values that are unset or empty in the raw `sources`
+	fn absent_required_values(sources: &config::Config) -> Vec<&'static str> {
+		REQUIRED_VALUES
+			.into_iter()
+			.filter(|key| !sources.get_string(key).is_ok_and(|value| !value.is_empty()))
+			.collect()
+	}
+
+	/// Critical values that are empty or still set to their built-in default
+	pub fn defaulted_required_values(&self) -> Vec<&'static str> {
+		let defaults = Self::default();
+		let mut missing = Vec::new();
+
+		if self.auth.jwt_secret.is_empty() || self.auth.jwt_secret == defaults.auth.jwt_secret {
+			missing.push("auth.jwt_secret");
+		}
+
+		if self.database.url.is_empty() || self.database.url == defaults.database.url {
+			missing.push("database.url");
+		}
+
+		missing
 	}
 
 	/// Load configuration from a specific file path
 	pub fn from_file(path: &str) -> Result<Self, config::ConfigError> {
 		config::Config::builder()
 			.add_source(config::File::with_name(path))
-			.add_source(config::Environment::with_prefix("SMT").separator("__"))
+			.add_source(environment())
 			.build()?
 			.try_deserialize()
 	}
@@ -150,6 +606,76 @@ impl Config {
 			return Err("JWT expiration hours must be positive".to_string());
 		}
 
+		if self.api.max_batch_size == 0 {
+			return Err("API max batch size must be positive".to_string());
+		}
+
+		if self.api.max_projection_fields == 0 {
+			return Err("API max projection fields must be positive".to_string());
+		}
+
+		if self.api.audit_max_range_days <= 0 || self.api.audit_max_results <= 0 {
+			return Err("API audit query limits must be positive".to_string());
+		}
+
+		if self.api.invitation_ttl_hours <= 0 {
+			return Err("Invitation TTL hours must be positive".to_string());
+		}
+
+		if self.quotas.warn_threshold_percent == 0 || self.quotas.warn_threshold_percent > 100 {
+			return Err("Quota warning threshold must be between 1 and 100 percent".to_string());
+		}
+
+		if matches!(self.quotas.status_cache_ttl_ms, Some(ms) if ms <= 0) {
+			return Err("Quota status cache TTL must be positive".to_string());
+		}
+
+		if matches!(self.auth.api_key_max_idle_days, Some(days) if days <= 0) {
+			return Err("API key max idle days must be positive".to_string());
+		}
+
+		if matches!(self.tenants.slug_rename_cooldown_secs, Some(secs) if secs <= 0) {
+			return Err("Slug rename cooldown must be positive".to_string());
+		}
+
+		if matches!(self.tenants.max_members, Some(max) if max <= 0) {
+			return Err("Tenant max members must be positive".to_string());
+		}
+
+		if matches!(self.tenants.deleted_monitor_retention_days, Some(days) if days <= 0) {
+			return Err("Deleted monitor retention days must be positive".to_string());
+		}
+
+		if matches!(self.tenants.deleted_slug_retention_days, Some(days) if days <= 0) {
+			return Err("Deleted slug retention days must be positive".to_string());
+		}
+
+		self.monitors.name_regex()?;
+
+		if self.monitors.max_creates_per_minute == Some(0) {
+			return Err("Max monitor creates per minute must be positive".to_string());
+		}
+
+		if self.networks.probe_timeout_secs == 0 {
+			return Err("Network probe timeout must be positive".to_string());
+		}
+
+		if self.triggers.webhook_allowed_methods.is_empty() {
+			return Err("At least one webhook method must be allowed".to_string());
+		}
+
+		if matches!(self.triggers.max_per_type_per_monitor, Some(max) if max <= 0) {
+			return Err("Max triggers per type per monitor must be positive".to_string());
+		}
+
+		if self.webhooks.max_attempts == 0 || self.webhooks.timeout_secs == 0 {
+			return Err("Webhook max attempts and timeout must be positive".to_string());
+		}
+
+		if self.monitoring.metrics_refresh_interval_secs == 0 {
+			return Err("Metrics refresh interval must be positive".to_string());
+		}
+
 		Ok(())
 	}
 }
@@ -178,4 +704,225 @@ mod tests {
 		config.database.min_connections = 1;
 		assert!(config.validate().is_err());
 	}
+
+	#[test]
+	fn test_default_reserved_slugs() {
+		let config = Config::default();
+		assert!(config.tenants.reserved_slugs.contains(&"admin".to_string()));
+		assert!(config
+			.tenants
+			.reserved_slugs
+			.contains(&"health".to_string()));
+	}
+
+	#[test]
+	fn test_audit_query_limits() {
+		let config = ApiConfig::default();
+		let now = chrono::Utc::now();
+
+		let mut within = crate::models::AuditLogFilter {
+			from: Some(now - chrono::Duration::days(90)),
+			limit: Some(10),
+			..Default::default()
+		};
+		assert!(config.limit_audit_query(&mut within, now).is_ok());
+		assert_eq!(within.limit, Some(10));
+
+		let mut too_wide = crate::models::AuditLogFilter {
+			from: Some(now - chrono::Duration::days(91)),
+			..Default::default()
+		};
+		assert!(config.limit_audit_query(&mut too_wide, now).is_err());
+	}
+
+	#[test]
+	fn test_slug_rename_cooldown_must_be_positive() {
+		let mut config = Config::default();
+		config.tenants.slug_rename_cooldown_secs = Some(0);
+		assert!(config.validate().is_err());
+
+		config.tenants.slug_rename_cooldown_secs = Some(3600);
+		assert!(config.validate().is_ok());
+	}
+
+	#[test]
+	fn test_quota_warn_threshold_must_be_a_percentage() {
+		let mut config = Config::default();
+		assert_eq!(config.quotas.warn_threshold_percent, 80);
+
+		config.quotas.warn_threshold_percent = 101;
+		assert!(config.validate().is_err());
+
+		config.quotas.warn_threshold_percent = 0;
+		assert!(config.validate().is_err());
+	}
+
+	#[test]
+	fn test_monitor_name_pattern_must_compile() {
+		let mut config = Config::default();
+		config.monitors.name_pattern = Some("^prod-[a-z".to_string());
+		assert!(config.validate().is_err());
+
+		config.monitors.name_pattern = Some("^prod-[a-z0-9-]+$".to_string());
+		assert!(config.validate().is_ok());
+	}
+
+	#[test]
+	fn test_monitor_create_rate_must_be_positive() {
+		let mut config = Config::default();
+		config.monitors.max_creates_per_minute = Some(0);
+		assert!(config.validate().is_err());
+
+		config.monitors.max_creates_per_minute = Some(30);
+		assert!(config.validate().is_ok());
+	}
+
+	#[test]
+	fn test_webhook_methods_default_and_must_not_be_empty() {
+		let mut config = Config::default();
+		assert_eq!(config.triggers.webhook_allowed_methods, vec!["POST", "PUT"]);
+
+		config.triggers.webhook_allowed_methods.clear();
+		assert!(config.validate().is_err());
+	}
+
+	#[test]
+	fn test_webhook_delivery_attempts_must_be_positive() {
+		let mut config = Config::default();
+		config.webhooks.max_attempts = 0;
+		assert!(config.validate().is_err());
+
+		config.webhooks.max_attempts = 3;
+		let policy = config.webhooks.retry_policy();
+		assert_eq!(policy.backoff(1), std::time::Duration::from_secs(1));
+		assert_eq!(policy.backoff(3), std::time::Duration::from_secs(4));
+	}
+
+	#[test]
+	fn test_metrics_refresh_interval_must_be_positive() {
+		let mut config = Config::default();
+		assert_eq!(config.monitoring.metrics_refresh_interval_secs, 60);
+
+		config.monitoring.metrics_refresh_interval_secs = 0;
+		assert!(config.validate().is_err());
+	}
+
+	#[test]
+	fn test_batch_size_limit() {
+		let api = ApiConfig {
+			max_batch_size: 3,
+			..Default::default()
+		};
+
+		assert!(api.check_batch_size(3).is_ok());
+		assert!(api.check_batch_size(4).is_err());
+	}
+
+	#[test]
+	fn test_projection_accepts_allowed_fields() {
+		let fields = ApiConfig::default()
+			.parse_projection("id, name", &["id", "name", "is_active"])
+			.unwrap();
+
+		assert_eq!(fields, vec!["id", "name"]);
+	}
+
+	#[test]
+	fn test_projection_rejects_too_many_fields() {
+		let api = ApiConfig {
+			max_projection_fields: 2,
+			..Default::default()
+		};
+
+		let err = api
+			.parse_projection("id,name,is_active", &["id", "name", "is_active"])
+			.unwrap_err();
+		assert!(err.contains("maximum of 2"));
+	}
+
+	#[test]
+	fn test_projection_rejects_unknown_fields() {
+		let err = ApiConfig::default()
+			.parse_projection("id,secret", &["id", "name"])
+			.unwrap_err();
+
+		assert!(err.contains("'secret'"));
+	}
+
+	#[test]
+	fn test_cache_control_is_omitted_when_max_age_is_zero() {
+		let api = ApiConfig {
+			cache_max_age_secs: 0,
+			..Default::default()
+		};
+
+		assert_eq!(
+			ApiConfig::default().cache_control().as_deref(),
+			Some("private, max-age=30")
+		);
+		assert_eq!(api.cache_control(), None);
+	}
+
+	#[test]
+	fn test_strict_mode_rejects_missing_jwt_secret() {
+		let mut config = Config::default();
+		config.database.url = "postgres://db.internal/smt".to_string();
+
+		let err = Config::resolve(Ok(config), &[], true).unwrap_err();
+		assert!(err.contains("auth.jwt_secret"));
+		assert!(!err.contains("database.url"));
+	}
+
+	#[test]
+	fn test_strict_mode_rejects_failed_load() {
+		let loaded = Err(config::ConfigError::NotFound("auth".to_string()));
+
+		let err = Config::resolve(loaded, &["database.url"], true).unwrap_err();
+		assert!(err.contains("database.url"));
+		assert!(!err.contains("auth.jwt_secret"));
+	}
+
+	#[test]
+	fn test_absent_required_values_ignores_provided_ones() {
+		let sources = config::Config::builder()
+			.set_override("auth.jwt_secret", "a-real-secret")
+			.unwrap()
+			.set_override("database.url", "")
+			.unwrap()
+			.build()
+			.unwrap();
+
+		assert_eq!(
+			Config::absent_required_values(&sources),
+			vec!["database.url"]
+		);
+	}
+
+	#[test]
+	fn test_strict_mode_from_config_field() {
+		let config = Config {
+			require_explicit: true,
+			..Default::default()
+		};
+
+		assert!(Config::resolve(Ok(config), &[], false).is_err());
+	}
+
+	#[test]
+	fn test_strict_mode_accepts_explicit_values() {
+		let mut config = Config::default();
+		config.auth.jwt_secret = "a-real-secret".to_string();
+		config.database.url = "postgres://db.internal/smt".to_string();
+
+		assert!(Config::resolve(Ok(config), &[], true).is_ok());
+	}
+
+	#[test]
+	fn test_permissive_mode_falls_back_to_defaults() {
+		let loaded = Err(config::ConfigError::NotFound("auth".to_string()));
+
+		let config = Config::resolve(loaded, &REQUIRED_VALUES, false).unwrap();
+		assert_eq!(config.auth.jwt_secret, Config::default().auth.jwt_secret);
+		assert_eq!(config.database.url, Config::default().database.url);
+	}
 }
@@ -1,22 +1,23 @@
 use anyhow::Result;
 use sqlx::postgres::PgPoolOptions;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tracing::info;
 
 use stellar_monitor_tenant_isolation::{
 	api::{create_router, AppState},
 	repositories::*,
 	services::*,
-	utils::AuthService,
+	utils::{metrics, AuthService, QuotaStatusCache, SsoVerifier},
 	Config,
 };
 
 #[tokio::main]
 async fn main() -> Result<()> {
 	// Load configuration
-	let config = Config::from_env().unwrap_or_else(|_| {
-		eprintln!("Failed to load configuration from environment, using defaults");
-		Config::default()
+	let config = Config::load().unwrap_or_else(|e| {
+		eprintln!("Configuration error: {}", e);
+		std::process::exit(1);
 	});
 
 	// Validate configuration
@@ -47,37 +48,158 @@ async fn main() -> Result<()> {
 
 	// Run migrations
 	info!("Running database migrations...");
-	sqlx::migrate!("./migrations").run(&pool).await?;
+	MIGRATOR.run(&pool).await?;
 	info!("Database migrations completed");
 
 	// Initialize repositories
-	let tenant_repo = TenantRepository::new(pool.clone());
-	let monitor_repo = TenantMonitorRepository::new(pool.clone());
-	let network_repo = TenantNetworkRepository::new(pool.clone());
-	let trigger_repo = TenantTriggerRepository::new(pool.clone());
+	let mut tenant_repo = TenantRepository::new(pool.clone())
+		.with_reserved_slugs(config.tenants.reserved_slugs.clone())
+		.with_quota_warn_threshold(config.quotas.warn_threshold_percent);
+	if let Some(secs) = config.tenants.slug_rename_cooldown_secs {
+		tenant_repo = tenant_repo.with_slug_rename_cooldown(chrono::Duration::seconds(secs));
+	}
+	if let Some(max_members) = config.tenants.max_members {
+		tenant_repo = tenant_repo.with_max_members(max_members);
+	}
+	if let Some(days) = config.tenants.deleted_slug_retention_days {
+		tenant_repo = tenant_repo.with_deleted_slug_retention(chrono::Duration::days(days));
+	}
+	// One cache shared by every repository whose writes change quota usage
+	let quota_cache = config
+		.quotas
+		.status_cache_ttl_ms
+		.map(|ms| QuotaStatusCache::new(std::time::Duration::from_millis(ms as u64)))
+		.unwrap_or_default();
+	tenant_repo = tenant_repo.with_quota_cache(quota_cache.clone());
+	let monitor_repo =
+		TenantMonitorRepository::new(pool.clone()).with_quota_cache(quota_cache.clone());
+	let network_repo =
+		TenantNetworkRepository::new(pool.clone()).with_quota_cache(quota_cache.clone());
+	let trigger_repo =
+		TenantTriggerRepository::new(pool.clone()).with_quota_cache(quota_cache.clone());
 
 	// Initialize services
-	let auth_service = AuthService::new(config.auth.jwt_secret.clone());
-	let audit_service = AuditService::new(pool.clone());
+	let sso_verifier = config
+		.auth
+		.sso
+		.as_ref()
+		.map(SsoVerifier::new)
+		.transpose()
+		.unwrap_or_else(|e| {
+			eprintln!("Configuration error: {}", e);
+			std::process::exit(1);
+		});
+	let auth_service = AuthService::new(config.auth.jwt_secret.clone())
+		.with_admin_token(config.auth.admin_token.clone())
+		.with_service_token(config.auth.service_token.clone())
+		.with_sso(sso_verifier)
+		.with_default_full_access(config.auth.default_full_access)
+		.with_require_email_verification(config.auth.require_email_verification);
+	let audit_events = AuditEvents::default();
+	let audit_service = AuditService::new(pool.clone()).with_events(audit_events.clone());
+
+	// API writes add estimated config storage between runner reports
+	let storage_accounting = StorageAccounting::new(
+		Some(
+			Arc::new(UsageService::new(UsageRepository::new(pool.clone())))
+				as Arc<dyn StorageRecorder>,
+		),
+		config.quotas.storage_enforcement,
+	);
 
 	let monitor_service = MonitorService::new(
 		monitor_repo.clone(),
+		network_repo.clone(),
 		tenant_repo.clone(),
 		audit_service.clone(),
-	);
+	)
+	.with_unique_contract_addresses(config.tenants.unique_contract_per_network)
+	.with_serialized_creates(config.tenants.serialize_monitor_creates)
+	.with_create_rate_limit(config.monitors.max_creates_per_minute)
+	.with_required_trigger_tests(config.monitors.require_tested_triggers)
+	.with_storage_accounting(storage_accounting.clone())
+	.with_name_pattern(config.monitors.name_regex().unwrap_or_else(|e| {
+		eprintln!("Configuration error: {}", e);
+		std::process::exit(1);
+	}));
 
+	let network_probe = config.networks.connectivity_probe_enabled.then(|| {
+		Arc::new(RpcNetworkProbe::new(std::time::Duration::from_secs(
+			config.networks.probe_timeout_secs,
+		))) as Arc<dyn NetworkProbe>
+	});
 	let network_service = NetworkService::new(
 		network_repo.clone(),
 		tenant_repo.clone(),
 		audit_service.clone(),
-	);
+	)
+	.with_probe(network_probe)
+	.with_storage_accounting(storage_accounting.clone());
 
 	let trigger_service = TriggerService::new(
 		trigger_repo.clone(),
 		monitor_repo.clone(),
 		tenant_repo.clone(),
 		audit_service.clone(),
-	);
+	)
+	.with_storage_accounting(storage_accounting)
+	.with_webhook_methods(config.triggers.webhook_allowed_methods.clone())
+	.with_max_triggers_per_type(config.triggers.max_per_type_per_monitor);
+
+	// Periodically disable API keys that have gone unused
+	if let Some(max_idle_days) = config.auth.api_key_max_idle_days {
+		ApiKeyCleanupService::new(
+			ApiKeyRepository::new(pool.clone()),
+			audit_service.clone(),
+			max_idle_days,
+		)
+		.spawn(API_KEY_CLEANUP_INTERVAL);
+		info!(
+			"API keys unused for {} days will be disabled",
+			max_idle_days
+		);
+	}
+
+	// Periodically purge monitors past the soft-delete retention window
+	if let Some(retention_days) = config.tenants.deleted_monitor_retention_days {
+		MonitorPurgeService::new(monitor_repo.clone(), audit_service.clone(), retention_days)
+			.spawn(MONITOR_PURGE_INTERVAL);
+		info!(
+			"Deleted monitors will be purged after {} days",
+			retention_days
+		);
+	}
+
+	// Push audit events to tenant webhooks as they are recorded
+	if config.webhooks.delivery_enabled {
+		WebhookDispatcher::new(
+			WebhookRepository::new(pool.clone()),
+			config.webhooks.retry_policy(),
+		)
+		.spawn(&audit_events);
+		info!("Webhook delivery enabled");
+	}
+
+	// Seed per-tenant gauges before serving traffic, then keep them from
+	// drifting, e.g. after imports that bypass the services
+	if config.monitoring.metrics_enabled {
+		metrics::register_metrics();
+		refresh_metrics(&tenant_repo, &pool).await;
+
+		let tenant_repo = tenant_repo.clone();
+		let pool = pool.clone();
+		let interval =
+			std::time::Duration::from_secs(config.monitoring.metrics_refresh_interval_secs);
+		tokio::spawn(async move {
+			let mut ticker = tokio::time::interval(interval);
+			// The first tick completes immediately and the refresh above covered it
+			ticker.tick().await;
+			loop {
+				ticker.tick().await;
+				refresh_metrics(&tenant_repo, &pool).await;
+			}
+		});
+	}
 
 	// Create app state
 	let app_state = AppState::new(
@@ -88,7 +210,14 @@ async fn main() -> Result<()> {
 		audit_service,
 		pool.clone(),
 		auth_service,
-	);
+	)
+	.with_api_config(config.api.clone())
+	.with_quota_cache(quota_cache)
+	.with_audit_events(audit_events)
+	.with_health_check(Arc::new(MigrationsHealthCheck::new(
+		pool.clone(),
+		&MIGRATOR,
+	)));
 
 	// Create router
 	let app = create_router(app_state);
@@ -132,20 +261,43 @@ fn init_tracing(config: &Config) -> Result<()> {
 	Ok(())
 }
 
+/// Recompute the per-tenant resource gauges from the database and sample the
+/// connection pool; services keep the gauges current in between
+async fn refresh_metrics(tenant_repo: &TenantRepository, pool: &sqlx::PgPool) {
+	const PAGE_SIZE: i64 = 100;
+
+	metrics::record_pool_metrics(pool).await;
+
+	let mut offset = 0;
+	loop {
+		let tenants = match tenant_repo.list(PAGE_SIZE, offset).await {
+			Ok(tenants) => tenants,
+			Err(e) => {
+				tracing::warn!(error = %e, "Failed to list tenants for metrics");
+				return;
+			}
+		};
+
+		for tenant in &tenants {
+			match tenant_repo.get_resource_counts(tenant.id).await {
+				Ok(counts) => metrics::set_tenant_resource_counts(&tenant.slug, &counts),
+				Err(e) => {
+					tracing::warn!(tenant_id = %tenant.id, error = %e, "Failed to count tenant resources for metrics")
+				}
+			}
+		}
+
+		if (tenants.len() as i64) < PAGE_SIZE {
+			return;
+		}
+		offset += PAGE_SIZE;
+	}
+}
+
 async fn start_metrics_server(port: u16) {
 	use axum::{routing::get, Router};
-	use prometheus::{Encoder, TextEncoder};
-
-	let app = Router::new().route(
-		"/metrics",
-		get(|| async {
-			let encoder = TextEncoder::new();
-			let metric_families = prometheus::gather();
-			let mut buffer = Vec::new();
-			encoder.encode(&metric_families, &mut buffer).unwrap();
-			String::from_utf8(buffer).unwrap()
-		}),
-	);
+
+	let app = Router::new().route("/metrics", get(|| async { metrics::render_metrics() }));
 
 	let addr = SocketAddr::from(([0, 0, 0, 0], port));
 	info!("Starting metrics server on {}", addr);
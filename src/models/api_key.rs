@@ -18,11 +18,26 @@ pub struct ApiKey {
 	pub updated_at: DateTime<Utc>,
 }
 
+impl ApiKey {
+	/// When the key was last used, or when it was created if it never was
+	pub fn last_activity(&self) -> DateTime<Utc> {
+		self.last_used_at.unwrap_or(self.created_at)
+	}
+
+	/// Whether an active key has gone unused since `cutoff`
+	pub fn is_unused_since(&self, cutoff: DateTime<Utc>) -> bool {
+		self.is_active && self.last_activity() < cutoff
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateApiKeyRequest {
 	pub name: String,
 	pub permissions: Vec<ApiPermission>,
 	pub expires_at: Option<DateTime<Utc>>,
+	/// Authentications allowed before the key is deactivated; unlimited when unset
+	#[serde(default)]
+	pub max_uses: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,9 +47,60 @@ pub struct CreateApiKeyResponse {
 	pub key: String, // Only returned once during creation
 	pub permissions: Vec<ApiPermission>,
 	pub expires_at: Option<DateTime<Utc>>,
+	pub max_uses: Option<i32>,
 	pub created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RotateApiKeyRequest {
+	/// How long the replaced key keeps authenticating; it stops immediately when unset
+	#[serde(default)]
+	pub grace_period_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotateApiKeyResponse {
+	pub id: Uuid,
+	pub name: String,
+	pub key: String, // Only returned once during rotation
+	pub expires_at: Option<DateTime<Utc>>,
+	pub previous_key_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Whether a key with the given `expires_at` can no longer authenticate
+pub fn api_key_expired(expires_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+	expires_at.is_some_and(|expires_at| expires_at <= now)
+}
+
+/// Outcome of authenticating once more with a key that has `remaining_uses` left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyUse {
+	Unlimited,
+	Remaining(i32),
+	Exhausted,
+}
+
+impl ApiKeyUse {
+	pub fn consume(remaining_uses: Option<i32>) -> Self {
+		match remaining_uses {
+			None => Self::Unlimited,
+			Some(remaining) if remaining > 0 => Self::Remaining(remaining - 1),
+			Some(_) => Self::Exhausted,
+		}
+	}
+}
+
+/// Whether a key replaced by rotation is still inside its grace period
+pub fn previous_key_valid(
+	previous_key_expires_at: Option<DateTime<Utc>>,
+	now: DateTime<Utc>,
+) -> bool {
+	previous_key_expires_at.is_some_and(|expires_at| expires_at > now)
+}
+
+/// Action that lets a caller see trigger secrets instead of their redacted form
+pub const REVEAL_SECRETS_ACTION: &str = "reveal_secrets";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiPermission {
 	pub resource: String,
@@ -42,6 +108,11 @@ pub struct ApiPermission {
 }
 
 impl ApiPermission {
+	/// Whether this permission grants `action` (read, write or delete) on `resource`
+	pub fn allows(&self, resource: &str, action: &str) -> bool {
+		self.resource == resource && self.actions.iter().any(|a| a == action)
+	}
+
 	pub fn all_monitors() -> Self {
 		Self {
 			resource: "monitors".to_string(),
@@ -134,7 +205,8 @@ mod tests {
 			id: Uuid::new_v4(),
 			tenant_id: Uuid::new_v4(),
 			name: "Test API Key".to_string(),
-			key_hash: "$argon2id$v=19$m=65536,t=3,p=4$test$hash".to_string(),
+			key_hash: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+				.to_string(),
 			permissions: serde_json::json!([
 				{
 					"resource": "monitors",
@@ -163,6 +235,7 @@ mod tests {
 				ApiPermission::read_only_monitors(),
 			],
 			expires_at: Some(Utc::now() + Duration::days(90)),
+			max_uses: None,
 		};
 
 		assert_eq!(request.name, "Production Key");
@@ -178,6 +251,7 @@ mod tests {
 			key: "sk_test_1234567890".to_string(),
 			permissions: vec![ApiPermission::all_monitors()],
 			expires_at: None,
+			max_uses: None,
 			created_at: Utc::now(),
 		};
 
@@ -203,4 +277,58 @@ mod tests {
 		assert_eq!(deserialized.resource, "custom");
 		assert_eq!(deserialized.actions, vec!["action1", "action2"]);
 	}
+
+	#[test]
+	fn test_previous_key_valid_during_grace_period() {
+		let now = Utc::now();
+		assert!(previous_key_valid(Some(now + Duration::seconds(60)), now));
+		assert!(!previous_key_valid(Some(now - Duration::seconds(1)), now));
+		assert!(!previous_key_valid(None, now));
+	}
+
+	#[test]
+	fn test_two_use_api_key_exhausted_on_third_use() {
+		let request: CreateApiKeyRequest = serde_json::from_value(serde_json::json!({
+			"name": "Onboarding",
+			"permissions": [],
+			"expires_at": null,
+			"max_uses": 2
+		}))
+		.unwrap();
+
+		let mut remaining_uses = request.max_uses;
+		for expected in [1, 0] {
+			assert_eq!(
+				ApiKeyUse::consume(remaining_uses),
+				ApiKeyUse::Remaining(expected)
+			);
+			remaining_uses = Some(expected);
+		}
+		assert_eq!(ApiKeyUse::consume(remaining_uses), ApiKeyUse::Exhausted);
+	}
+
+	#[test]
+	fn test_api_key_without_max_uses_is_unlimited() {
+		let request: CreateApiKeyRequest =
+			serde_json::from_str(r#"{"name": "CI", "permissions": [], "expires_at": null}"#)
+				.unwrap();
+
+		assert_eq!(request.max_uses, None);
+		assert_eq!(ApiKeyUse::consume(request.max_uses), ApiKeyUse::Unlimited);
+	}
+
+	#[test]
+	fn test_rotate_api_key_request_defaults_to_no_grace_period() {
+		let request: RotateApiKeyRequest = serde_json::from_str("{}").unwrap();
+		assert_eq!(request.grace_period_seconds, None);
+	}
+
+	#[test]
+	fn test_api_key_expired() {
+		let now = Utc::now();
+		assert!(api_key_expired(Some(now - Duration::seconds(1)), now));
+		assert!(api_key_expired(Some(now), now));
+		assert!(!api_key_expired(Some(now + Duration::days(1)), now));
+		assert!(!api_key_expired(None, now));
+	}
 }
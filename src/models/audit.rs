@@ -16,9 +16,60 @@ pub struct AuditLog {
 	pub changes: Option<JsonValue>,
 	pub ip_address: Option<IpAddr>,
 	pub user_agent: Option<String>,
+	/// `X-Request-Id` of the API request that wrote the entry
+	pub request_id: Option<String>,
 	pub created_at: Option<DateTime<Utc>>,
 }
 
+pub const DEFAULT_AUDIT_LOG_LIMIT: i64 = 50;
+pub const MAX_AUDIT_LOG_LIMIT: i64 = 500;
+
+/// Optional filters for reading back a tenant's audit log
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditLogFilter {
+	pub action: Option<String>,
+	pub resource_type: Option<String>,
+	pub resource_id: Option<Uuid>,
+	pub user_id: Option<Uuid>,
+	pub from: Option<DateTime<Utc>>,
+	pub to: Option<DateTime<Utc>>,
+	pub limit: Option<i64>,
+	pub offset: Option<i64>,
+}
+
+impl AuditLogFilter {
+	/// Requested page size, defaulted and clamped to what a single query may return
+	pub fn page_limit(&self) -> i64 {
+		self.limit
+			.unwrap_or(DEFAULT_AUDIT_LOG_LIMIT)
+			.clamp(1, MAX_AUDIT_LOG_LIMIT)
+	}
+
+	pub fn page_offset(&self) -> i64 {
+		self.offset.unwrap_or(0).max(0)
+	}
+
+	/// The latest `limit` entries recorded against one resource
+	pub fn for_resource(
+		resource_type: ResourceType,
+		resource_id: Uuid,
+		limit: Option<i64>,
+	) -> Self {
+		Self {
+			resource_type: Some(resource_type.as_str().to_string()),
+			resource_id: Some(resource_id),
+			limit,
+			..Default::default()
+		}
+	}
+}
+
+/// Query parameters for a single resource's audit history
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ResourceAuditQuery {
+	pub limit: Option<i64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAuditLogRequest {
 	pub tenant_id: Uuid,
@@ -30,6 +81,7 @@ pub struct CreateAuditLogRequest {
 	pub changes: Option<JsonValue>,
 	pub ip_address: Option<IpAddr>,
 	pub user_agent: Option<String>,
+	pub request_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,19 +90,31 @@ pub enum AuditAction {
 	Login,
 	Logout,
 	ApiKeyCreated,
+	ApiKeyRotated,
 	ApiKeyDeleted,
+	ApiKeyDisabled,
 	// Tenant management
 	TenantCreated,
 	TenantUpdated,
 	TenantDeleted,
+	TenantSuspended,
+	TenantReactivated,
+	ConfigurationImported,
+	QuotaExceeded,
 	// User management
 	UserInvited,
 	UserRemoved,
 	UserRoleChanged,
+	MemberAdded,
+	InvitationCreated,
+	InvitationRevoked,
+	InvitationAccepted,
 	// Monitor operations
 	MonitorCreated,
 	MonitorUpdated,
 	MonitorDeleted,
+	MonitorRestored,
+	MonitorPurged,
 	MonitorEnabled,
 	MonitorDisabled,
 	// Network operations
@@ -63,6 +127,10 @@ pub enum AuditAction {
 	TriggerDeleted,
 	TriggerEnabled,
 	TriggerDisabled,
+	// Webhook operations
+	WebhookCreated,
+	WebhookUpdated,
+	WebhookDeleted,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,24 +141,80 @@ pub enum ResourceType {
 	Monitor,
 	Network,
 	Trigger,
+	Invitation,
+	Webhook,
 }
 
 impl AuditAction {
+	/// Every action, e.g. for checking a webhook's event filter
+	pub const ALL: &'static [AuditAction] = &[
+		AuditAction::Login,
+		AuditAction::Logout,
+		AuditAction::ApiKeyCreated,
+		AuditAction::ApiKeyRotated,
+		AuditAction::ApiKeyDeleted,
+		AuditAction::ApiKeyDisabled,
+		AuditAction::TenantCreated,
+		AuditAction::TenantUpdated,
+		AuditAction::TenantDeleted,
+		AuditAction::TenantSuspended,
+		AuditAction::TenantReactivated,
+		AuditAction::ConfigurationImported,
+		AuditAction::QuotaExceeded,
+		AuditAction::UserInvited,
+		AuditAction::UserRemoved,
+		AuditAction::UserRoleChanged,
+		AuditAction::MemberAdded,
+		AuditAction::InvitationCreated,
+		AuditAction::InvitationRevoked,
+		AuditAction::InvitationAccepted,
+		AuditAction::MonitorCreated,
+		AuditAction::MonitorUpdated,
+		AuditAction::MonitorDeleted,
+		AuditAction::MonitorRestored,
+		AuditAction::MonitorPurged,
+		AuditAction::MonitorEnabled,
+		AuditAction::MonitorDisabled,
+		AuditAction::NetworkCreated,
+		AuditAction::NetworkUpdated,
+		AuditAction::NetworkDeleted,
+		AuditAction::TriggerCreated,
+		AuditAction::TriggerUpdated,
+		AuditAction::TriggerDeleted,
+		AuditAction::TriggerEnabled,
+		AuditAction::TriggerDisabled,
+		AuditAction::WebhookCreated,
+		AuditAction::WebhookUpdated,
+		AuditAction::WebhookDeleted,
+	];
+
 	pub fn as_str(&self) -> &'static str {
 		match self {
 			AuditAction::Login => "login",
 			AuditAction::Logout => "logout",
 			AuditAction::ApiKeyCreated => "api_key_created",
+			AuditAction::ApiKeyRotated => "api_key_rotated",
 			AuditAction::ApiKeyDeleted => "api_key_deleted",
+			AuditAction::ApiKeyDisabled => "api_key_disabled",
 			AuditAction::TenantCreated => "tenant_created",
 			AuditAction::TenantUpdated => "tenant_updated",
 			AuditAction::TenantDeleted => "tenant_deleted",
+			AuditAction::TenantSuspended => "tenant_suspended",
+			AuditAction::TenantReactivated => "tenant_reactivated",
+			AuditAction::ConfigurationImported => "configuration_imported",
+			AuditAction::QuotaExceeded => "quota_exceeded",
 			AuditAction::UserInvited => "user_invited",
 			AuditAction::UserRemoved => "user_removed",
 			AuditAction::UserRoleChanged => "user_role_changed",
+			AuditAction::MemberAdded => "member_added",
+			AuditAction::InvitationCreated => "invitation_created",
+			AuditAction::InvitationRevoked => "invitation_revoked",
+			AuditAction::InvitationAccepted => "invitation_accepted",
 			AuditAction::MonitorCreated => "monitor_created",
 			AuditAction::MonitorUpdated => "monitor_updated",
 			AuditAction::MonitorDeleted => "monitor_deleted",
+			AuditAction::MonitorRestored => "monitor_restored",
+			AuditAction::MonitorPurged => "monitor_purged",
 			AuditAction::MonitorEnabled => "monitor_enabled",
 			AuditAction::MonitorDisabled => "monitor_disabled",
 			AuditAction::NetworkCreated => "network_created",
@@ -101,6 +225,9 @@ impl AuditAction {
 			AuditAction::TriggerDeleted => "trigger_deleted",
 			AuditAction::TriggerEnabled => "trigger_enabled",
 			AuditAction::TriggerDisabled => "trigger_disabled",
+			AuditAction::WebhookCreated => "webhook_created",
+			AuditAction::WebhookUpdated => "webhook_updated",
+			AuditAction::WebhookDeleted => "webhook_deleted",
 		}
 	}
 }
@@ -114,6 +241,8 @@ impl ResourceType {
 			ResourceType::Monitor => "monitor",
 			ResourceType::Network => "network",
 			ResourceType::Trigger => "trigger",
+			ResourceType::Invitation => "invitation",
+			ResourceType::Webhook => "webhook",
 		}
 	}
 }
@@ -128,16 +257,43 @@ mod tests {
 		assert_eq!(AuditAction::Login.as_str(), "login");
 		assert_eq!(AuditAction::Logout.as_str(), "logout");
 		assert_eq!(AuditAction::ApiKeyCreated.as_str(), "api_key_created");
+		assert_eq!(AuditAction::ApiKeyRotated.as_str(), "api_key_rotated");
 		assert_eq!(AuditAction::ApiKeyDeleted.as_str(), "api_key_deleted");
+		assert_eq!(AuditAction::ApiKeyDisabled.as_str(), "api_key_disabled");
 		assert_eq!(AuditAction::TenantCreated.as_str(), "tenant_created");
 		assert_eq!(AuditAction::TenantUpdated.as_str(), "tenant_updated");
 		assert_eq!(AuditAction::TenantDeleted.as_str(), "tenant_deleted");
+		assert_eq!(AuditAction::TenantSuspended.as_str(), "tenant_suspended");
+		assert_eq!(
+			AuditAction::TenantReactivated.as_str(),
+			"tenant_reactivated"
+		);
+		assert_eq!(
+			AuditAction::ConfigurationImported.as_str(),
+			"configuration_imported"
+		);
+		assert_eq!(AuditAction::QuotaExceeded.as_str(), "quota_exceeded");
 		assert_eq!(AuditAction::UserInvited.as_str(), "user_invited");
 		assert_eq!(AuditAction::UserRemoved.as_str(), "user_removed");
 		assert_eq!(AuditAction::UserRoleChanged.as_str(), "user_role_changed");
+		assert_eq!(AuditAction::MemberAdded.as_str(), "member_added");
+		assert_eq!(
+			AuditAction::InvitationCreated.as_str(),
+			"invitation_created"
+		);
+		assert_eq!(
+			AuditAction::InvitationRevoked.as_str(),
+			"invitation_revoked"
+		);
+		assert_eq!(
+			AuditAction::InvitationAccepted.as_str(),
+			"invitation_accepted"
+		);
 		assert_eq!(AuditAction::MonitorCreated.as_str(), "monitor_created");
 		assert_eq!(AuditAction::MonitorUpdated.as_str(), "monitor_updated");
 		assert_eq!(AuditAction::MonitorDeleted.as_str(), "monitor_deleted");
+		assert_eq!(AuditAction::MonitorRestored.as_str(), "monitor_restored");
+		assert_eq!(AuditAction::MonitorPurged.as_str(), "monitor_purged");
 		assert_eq!(AuditAction::MonitorEnabled.as_str(), "monitor_enabled");
 		assert_eq!(AuditAction::MonitorDisabled.as_str(), "monitor_disabled");
 		assert_eq!(AuditAction::NetworkCreated.as_str(), "network_created");
@@ -148,6 +304,10 @@ mod tests {
 		assert_eq!(AuditAction::TriggerDeleted.as_str(), "trigger_deleted");
 		assert_eq!(AuditAction::TriggerEnabled.as_str(), "trigger_enabled");
 		assert_eq!(AuditAction::TriggerDisabled.as_str(), "trigger_disabled");
+		assert_eq!(AuditAction::WebhookCreated.as_str(), "webhook_created");
+		assert_eq!(AuditAction::WebhookUpdated.as_str(), "webhook_updated");
+		assert_eq!(AuditAction::WebhookDeleted.as_str(), "webhook_deleted");
+		assert_eq!(AuditAction::ALL.len(), 38);
 	}
 
 	#[test]
@@ -158,6 +318,8 @@ mod tests {
 		assert_eq!(ResourceType::Monitor.as_str(), "monitor");
 		assert_eq!(ResourceType::Network.as_str(), "network");
 		assert_eq!(ResourceType::Trigger.as_str(), "trigger");
+		assert_eq!(ResourceType::Invitation.as_str(), "invitation");
+		assert_eq!(ResourceType::Webhook.as_str(), "webhook");
 	}
 
 	#[test]
@@ -176,6 +338,7 @@ mod tests {
 			})),
 			ip_address: Some(IpAddr::from_str("192.168.1.1").unwrap()),
 			user_agent: Some("Mozilla/5.0".to_string()),
+			request_id: None,
 			created_at: Some(Utc::now()),
 		};
 
@@ -198,6 +361,7 @@ mod tests {
 			changes: Some(serde_json::json!({"status": "created"})),
 			ip_address: Some(IpAddr::from_str("10.0.0.1").unwrap()),
 			user_agent: Some("Test Agent".to_string()),
+			request_id: None,
 		};
 
 		assert!(matches!(request.action, AuditAction::MonitorCreated));
@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+use super::{TenantMonitor, TenantNetwork, TenantTrigger};
+
+/// Version written into every [`TenantExportDocument`]; imports accept only this one
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Formats offered by the tenant export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+	/// openzeppelin-monitor's directory layout, zipped
+	#[default]
+	Zip,
+	/// One [`TenantExportDocument`]
+	Json,
+}
+
+/// A network file as laid out in openzeppelin-monitor's `config/networks/`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedNetwork {
+	pub network_type: String,
+	pub slug: String,
+	pub name: String,
+	#[serde(flatten)]
+	pub config: Map<String, JsonValue>,
+}
+
+/// A monitor file as laid out in openzeppelin-monitor's `config/monitors/`;
+/// networks and triggers are referenced by their slugs and ids
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedMonitor {
+	pub name: String,
+	pub networks: Vec<String>,
+	pub paused: bool,
+	pub triggers: Vec<String>,
+	#[serde(flatten)]
+	pub config: Map<String, JsonValue>,
+}
+
+/// One trigger in an openzeppelin-monitor `config/triggers/` file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedTrigger {
+	pub name: String,
+	pub trigger_type: String,
+	pub config: JsonValue,
+}
+
+/// Trigger files map trigger ids to their definitions
+pub type ExportedTriggerFile = HashMap<String, ExportedTrigger>;
+
+/// Everything a tenant has configured, ready to be written as an archive
+#[derive(Debug, Clone, Default)]
+pub struct TenantExport {
+	pub networks: Vec<TenantNetwork>,
+	pub monitors: Vec<TenantMonitor>,
+	pub triggers: Vec<TenantTrigger>,
+}
+
+impl TenantExport {
+	/// Archive entries as `(path, document)` pairs: one file per network, monitor
+	/// and trigger under `networks/`, `monitors/` and `triggers/`
+	pub fn entries(&self) -> Vec<(String, JsonValue)> {
+		let network_slugs: HashMap<Uuid, &str> = self
+			.networks
+			.iter()
+			.map(|network| (network.id, network.network_id.as_str()))
+			.collect();
+		let mut monitor_triggers: HashMap<Uuid, Vec<String>> = HashMap::new();
+		for trigger in &self.triggers {
+			monitor_triggers
+				.entry(trigger.monitor_id)
+				.or_default()
+				.push(trigger.trigger_id.clone());
+		}
+
+		let networks = self.networks.iter().map(|network| {
+			let file = ExportedNetwork {
+				network_type: network.blockchain.clone(),
+				slug: network.network_id.clone(),
+				name: network.name.clone(),
+				config: config_without(&network.configuration, &["network_type", "slug", "name"]),
+			};
+			(
+				format!("networks/{}.json", export_file_stem(&network.network_id)),
+				serde_json::to_value(file).unwrap_or(JsonValue::Null),
+			)
+		});
+
+		let monitors = self.monitors.iter().map(|monitor| {
+			let file = ExportedMonitor {
+				name: monitor.name.clone(),
+				networks: network_slugs
+					.get(&monitor.network_id)
+					.map(|slug| vec![slug.to_string()])
+					.unwrap_or_default(),
+				paused: !monitor.is_active.unwrap_or(true),
+				triggers: monitor_triggers
+					.get(&monitor.id)
+					.cloned()
+					.unwrap_or_default(),
+				config: config_without(
+					&monitor.configuration,
+					&["name", "networks", "paused", "triggers"],
+				),
+			};
+			(
+				format!("monitors/{}.json", export_file_stem(&monitor.monitor_id)),
+				serde_json::to_value(file).unwrap_or(JsonValue::Null),
+			)
+		});
+
+		let triggers = self.triggers.iter().map(|trigger| {
+			let file = BTreeMap::from([(
+				trigger.trigger_id.clone(),
+				ExportedTrigger {
+					name: trigger.name.clone(),
+					trigger_type: trigger.trigger_type.clone(),
+					config: trigger.configuration.clone(),
+				},
+			)]);
+			(
+				format!("triggers/{}.json", export_file_stem(&trigger.trigger_id)),
+				serde_json::to_value(file).unwrap_or(JsonValue::Null),
+			)
+		});
+
+		networks.chain(monitors).chain(triggers).collect()
+	}
+}
+
+impl TenantExport {
+	/// The export as a single document that references networks and monitors
+	/// by their external ids. Triggers whose monitor is not part of the export,
+	/// such as those of a deleted monitor, are left out.
+	pub fn document(&self) -> TenantExportDocument {
+		let network_ids: HashMap<Uuid, &str> = self
+			.networks
+			.iter()
+			.map(|network| (network.id, network.network_id.as_str()))
+			.collect();
+		let monitor_ids: HashMap<Uuid, &str> = self
+			.monitors
+			.iter()
+			.map(|monitor| (monitor.id, monitor.monitor_id.as_str()))
+			.collect();
+
+		TenantExportDocument {
+			version: EXPORT_FORMAT_VERSION,
+			networks: self
+				.networks
+				.iter()
+				.map(|network| NetworkExportEntry {
+					network_id: network.network_id.clone(),
+					name: network.name.clone(),
+					blockchain: network.blockchain.clone(),
+					configuration: network.configuration.clone(),
+					is_active: network.is_active.unwrap_or(true),
+				})
+				.collect(),
+			monitors: self
+				.monitors
+				.iter()
+				.filter_map(|monitor| {
+					Some(MonitorExportEntry {
+						monitor_id: monitor.monitor_id.clone(),
+						name: monitor.name.clone(),
+						network_id: network_ids.get(&monitor.network_id)?.to_string(),
+						configuration: monitor.configuration.clone(),
+						is_active: monitor.is_active.unwrap_or(true),
+					})
+				})
+				.collect(),
+			triggers: self
+				.triggers
+				.iter()
+				.filter_map(|trigger| {
+					Some(TriggerExportEntry {
+						trigger_id: trigger.trigger_id.clone(),
+						name: trigger.name.clone(),
+						monitor_id: monitor_ids.get(&trigger.monitor_id)?.to_string(),
+						trigger_type: trigger.trigger_type.clone(),
+						configuration: trigger.configuration.clone(),
+						is_active: trigger.is_active.unwrap_or(true),
+					})
+				})
+				.collect(),
+		}
+	}
+}
+
+/// A tenant's whole configuration as one JSON document, free of internal ids
+/// so it can be imported into another tenant or instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TenantExportDocument {
+	/// Documents written before the format was versioned count as version 1
+	#[serde(default = "export_format_version")]
+	pub version: u32,
+	pub networks: Vec<NetworkExportEntry>,
+	pub monitors: Vec<MonitorExportEntry>,
+	pub triggers: Vec<TriggerExportEntry>,
+}
+
+impl Default for TenantExportDocument {
+	fn default() -> Self {
+		Self {
+			version: EXPORT_FORMAT_VERSION,
+			networks: Vec::new(),
+			monitors: Vec::new(),
+			triggers: Vec::new(),
+		}
+	}
+}
+
+fn export_format_version() -> u32 {
+	EXPORT_FORMAT_VERSION
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkExportEntry {
+	pub network_id: String,
+	pub name: String,
+	pub blockchain: String,
+	pub configuration: JsonValue,
+	pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorExportEntry {
+	pub monitor_id: String,
+	pub name: String,
+	/// `network_id` of an entry in the same document
+	pub network_id: String,
+	pub configuration: JsonValue,
+	pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggerExportEntry {
+	pub trigger_id: String,
+	pub name: String,
+	/// `monitor_id` of an entry in the same document
+	pub monitor_id: String,
+	pub trigger_type: String,
+	pub configuration: JsonValue,
+	pub is_active: bool,
+}
+
+/// How an import treats a network, monitor or trigger whose id the tenant already uses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+	/// Keep the existing resource; imported resources that reference it use it instead
+	SkipExisting,
+	/// Abort the whole import
+	#[default]
+	Fail,
+	/// Overwrite the existing resource with the imported definition
+	Replace,
+}
+
+/// Networks, monitors and triggers handled one way by an import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportCounts {
+	pub networks: usize,
+	pub monitors: usize,
+	pub triggers: usize,
+}
+
+/// A document entry whose id the tenant already uses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportConflict {
+	/// `network`, `monitor` or `trigger`
+	pub resource_type: String,
+	/// The entry's `network_id`, `monitor_id` or `trigger_id`
+	pub id: String,
+}
+
+/// What an import of a [`TenantExportDocument`] did, or would have done for a dry run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportSummary {
+	pub mode: ImportMode,
+	pub dry_run: bool,
+	pub created: ImportCounts,
+	pub skipped: ImportCounts,
+	pub replaced: ImportCounts,
+	/// Entries that were skipped or replaced because their id was taken
+	pub conflicts: Vec<ImportConflict>,
+}
+
+/// The configuration object minus the fields the export sets itself
+fn config_without(configuration: &JsonValue, fields: &[&str]) -> Map<String, JsonValue> {
+	let mut config = configuration.as_object().cloned().unwrap_or_default();
+	for field in fields {
+		config.remove(*field);
+	}
+	config
+}
+
+/// A resource id made safe to use as a file name inside the archive
+pub fn export_file_stem(id: &str) -> String {
+	let stem: String = id
+		.chars()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect();
+	stem.trim_start_matches('.').to_string()
+}
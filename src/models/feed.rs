@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ResourceChangeEvent {
+	pub cursor: i64,
+	pub kind: String, // 'monitor', 'network', 'trigger'
+	pub resource_id: Uuid,
+	pub action: String, // 'created', 'updated', 'deleted'
+	pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedPage {
+	pub events: Vec<ResourceChangeEvent>,
+	/// Cursor to pass as `since` to resume after the last returned event
+	pub next_cursor: i64,
+}
+
+impl FeedPage {
+	pub fn new(events: Vec<ResourceChangeEvent>, since: i64) -> Self {
+		let next_cursor = events.last().map(|e| e.cursor).unwrap_or(since);
+		Self {
+			events,
+			next_cursor,
+		}
+	}
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::TenantRole;
+
+/// An invitation for an email address to join a tenant with a given role
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TenantInvitation {
+	pub id: Uuid,
+	pub tenant_id: Uuid,
+	pub email: String,
+	pub role: TenantRole,
+	/// Sent to the invitee; accepting also requires signing in as `email`
+	pub token: String,
+	pub invited_by: Option<Uuid>,
+	pub expires_at: DateTime<Utc>,
+	pub accepted_at: Option<DateTime<Utc>>,
+	pub accepted_by: Option<Uuid>,
+	pub revoked_at: Option<DateTime<Utc>>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InvitationStatus {
+	Pending,
+	Accepted,
+	Revoked,
+	Expired,
+}
+
+impl TenantInvitation {
+	pub fn status(&self, now: DateTime<Utc>) -> InvitationStatus {
+		if self.accepted_at.is_some() {
+			InvitationStatus::Accepted
+		} else if self.revoked_at.is_some() {
+			InvitationStatus::Revoked
+		} else if self.expires_at <= now {
+			InvitationStatus::Expired
+		} else {
+			InvitationStatus::Pending
+		}
+	}
+
+	/// Whether the invitation was sent to `email`; addresses compare case-insensitively
+	pub fn is_for(&self, email: &str) -> bool {
+		self.email.eq_ignore_ascii_case(email.trim())
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInvitationRequest {
+	pub email: String,
+	#[serde(default = "default_invitation_role")]
+	pub role: TenantRole,
+}
+
+fn default_invitation_role() -> TenantRole {
+	TenantRole::Member
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::Duration;
+
+	fn invitation(expires_at: DateTime<Utc>) -> TenantInvitation {
+		let now = Utc::now();
+		TenantInvitation {
+			id: Uuid::new_v4(),
+			tenant_id: Uuid::new_v4(),
+			email: "jane@example.com".to_string(),
+			role: TenantRole::Member,
+			token: "token".to_string(),
+			invited_by: None,
+			expires_at,
+			accepted_at: None,
+			accepted_by: None,
+			revoked_at: None,
+			created_at: now,
+			updated_at: now,
+		}
+	}
+
+	#[test]
+	fn test_invitation_status() {
+		let now = Utc::now();
+		let pending = invitation(now + Duration::days(1));
+		assert_eq!(pending.status(now), InvitationStatus::Pending);
+		assert_eq!(
+			invitation(now - Duration::seconds(1)).status(now),
+			InvitationStatus::Expired
+		);
+
+		let mut revoked = pending.clone();
+		revoked.revoked_at = Some(now);
+		assert_eq!(revoked.status(now), InvitationStatus::Revoked);
+
+		// An accepted invitation stays accepted after it would have expired
+		let mut accepted = invitation(now - Duration::days(1));
+		accepted.accepted_at = Some(now - Duration::days(2));
+		assert_eq!(accepted.status(now), InvitationStatus::Accepted);
+	}
+
+	#[test]
+	fn test_invitation_email_match_ignores_case() {
+		let invitation = invitation(Utc::now());
+		assert!(invitation.is_for("Jane@Example.com"));
+		assert!(!invitation.is_for("john@example.com"));
+	}
+}
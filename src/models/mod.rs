@@ -1,17 +1,46 @@
 pub mod api_key;
 pub mod audit;
+pub mod export;
+pub mod feed;
+pub mod invitation;
 pub mod monitor;
+pub mod pagination;
 pub mod request_context;
 pub mod resource_quota;
 pub mod tenant;
+pub mod usage;
 pub mod user;
+pub mod webhook;
 
 pub use api_key::*;
-pub use audit::{AuditAction, AuditLog, CreateAuditLogRequest};
+pub use audit::{AuditAction, AuditLog, AuditLogFilter, CreateAuditLogRequest, ResourceAuditQuery};
+pub use export::{
+	export_file_stem, ExportFormat, ExportedMonitor, ExportedNetwork, ExportedTrigger,
+	ExportedTriggerFile, ImportConflict, ImportCounts, ImportMode, ImportSummary,
+	MonitorExportEntry, NetworkExportEntry, TenantExport, TenantExportDocument, TriggerExportEntry,
+	EXPORT_FORMAT_VERSION,
+};
+pub use feed::{FeedPage, ResourceChangeEvent};
+pub use invitation::{CreateInvitationRequest, InvitationStatus, TenantInvitation};
 pub use monitor::*;
+pub use pagination::{next_cursor, CursorPosition, ListCursor};
 pub use request_context::RequestMetadata;
-pub use resource_quota::{AvailableResources, CurrentUsage, ResourceQuotaStatus, TenantQuotas};
+pub use resource_quota::{
+	AvailableResources, BatchQuotaCheckRequest, BatchQuotaStatusRequest, BlockchainQuota,
+	BlockchainQuotaStatus, CurrentUsage, MonitorTriggerQuota, QuotaCheck, QuotaCheckQuery,
+	QuotaCheckResponse, QuotaCheckResult, QuotaWarning, ResourceCounts, ResourceQuotaStatus,
+	StorageEnforcement, TenantQuotas, DEFAULT_QUOTA_WARN_THRESHOLD_PERCENT,
+};
 pub use tenant::*;
+pub use usage::{
+	config_storage_mb, daily_series, ReportUsageRequest, UsagePoint, API_REQUESTS_RESOURCE,
+	BYTES_PER_MB, RPC_REQUESTS_RESOURCE, STORAGE_RESOURCE,
+};
 pub use user::*;
+pub use webhook::{
+	validate_webhook, webhook_payload, CreateWebhookRequest, CreatedWebhook, TenantWebhook,
+	UpdateWebhookRequest, WebhookDelivery, WebhookDeliveryQuery, WebhookDeliveryStatus,
+	MAX_WEBHOOK_DELIVERIES,
+};
 // Re-export ResourceType from audit module to avoid ambiguity
 pub use audit::ResourceType;
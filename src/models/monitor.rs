@@ -12,8 +12,30 @@ pub struct TenantMonitor {
 	pub network_id: Uuid,
 	pub configuration: JsonValue, // Full monitor config from OZ Monitor
 	pub is_active: Option<bool>,
+	pub creation_source: String, // 'api', 'import', 'template'
 	pub created_at: Option<DateTime<Utc>>,
 	pub updated_at: Option<DateTime<Utc>>,
+	pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Code path a monitor, network or trigger was created through
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CreationSource {
+	#[default]
+	Api,
+	Import,
+	Template,
+}
+
+impl CreationSource {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			CreationSource::Api => "api",
+			CreationSource::Import => "import",
+			CreationSource::Template => "template",
+		}
+	}
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +46,45 @@ pub struct CreateMonitorRequest {
 	pub configuration: JsonValue,
 }
 
+/// Body of `POST /monitors/{id}/clone`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneMonitorRequest {
+	pub new_monitor_id: String,
+	/// Network for the copy; the source monitor's network when absent
+	pub target_network_id: Option<Uuid>,
+}
+
+/// Body of `POST /monitors/batch/plan`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorBatchPlanRequest {
+	pub monitors: Vec<PlannedMonitor>,
+}
+
+/// A monitor to be imported, with the networks it runs on by their `network_id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedMonitor {
+	pub monitor_id: String,
+	pub networks: Vec<String>,
+}
+
+/// Networks a monitor batch refers to, split by whether the tenant already has them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorBatchPlan {
+	pub existing_networks: Vec<PlannedNetwork>,
+	/// Networks that would have to be created before the batch can be imported
+	pub missing_networks: Vec<PlannedNetwork>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedNetwork {
+	pub network_id: String,
+	/// Internal id of an existing network; monitors are created against it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<Uuid>,
+	/// Monitors in the batch that run on this network
+	pub monitor_ids: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateMonitorRequest {
 	pub name: Option<String>,
@@ -31,6 +92,30 @@ pub struct UpdateMonitorRequest {
 	pub is_active: Option<bool>,
 }
 
+/// Order of a filtered monitor listing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MonitorSort {
+	#[serde(rename = "name")]
+	Name,
+	#[serde(rename = "created_at")]
+	CreatedAt,
+	#[default]
+	#[serde(rename = "-created_at")]
+	CreatedAtDesc,
+}
+
+/// Optional filters for the monitor listing; set filters must all match.
+/// Soft-deleted monitors are never listed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorFilter {
+	pub is_active: Option<bool>,
+	/// Internal id or external `network_id` of the monitor's network
+	pub network: Option<String>,
+	/// Case-insensitive substring of the name or `monitor_id`
+	pub search: Option<String>,
+	pub sort: MonitorSort,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct TenantNetwork {
 	pub id: Uuid,
@@ -40,6 +125,7 @@ pub struct TenantNetwork {
 	pub blockchain: String,       // 'stellar', 'evm'
 	pub configuration: JsonValue, // Full network config from OZ Monitor
 	pub is_active: Option<bool>,
+	pub creation_source: String, // 'api', 'import', 'template'
 	pub created_at: Option<DateTime<Utc>>,
 	pub updated_at: Option<DateTime<Utc>>,
 }
@@ -70,10 +156,43 @@ pub struct TenantTrigger {
 	pub trigger_type: String, // 'webhook', 'email', 'slack', etc.
 	pub configuration: JsonValue, // Full trigger config from OZ Monitor
 	pub is_active: Option<bool>,
+	pub creation_source: String, // 'api', 'import', 'template'
 	pub created_at: Option<DateTime<Utc>>,
 	pub updated_at: Option<DateTime<Utc>>,
 }
 
+/// Replaces each secret in a redacted trigger configuration
+pub const REDACTED_SECRET: &str = "********";
+
+/// Top-level trigger configuration fields that hold credentials
+const SECRET_CONFIG_FIELDS: &[&str] = &["signing_secret", "bot_token", "token"];
+
+impl TenantTrigger {
+	/// The trigger with the credentials in its configuration masked: `signing_secret`,
+	/// the Telegram bot token and any `Authorization` header
+	pub fn redacted(mut self) -> Self {
+		let Some(config) = self.configuration.as_object_mut() else {
+			return self;
+		};
+		let mask = || JsonValue::String(REDACTED_SECRET.to_string());
+
+		for field in SECRET_CONFIG_FIELDS {
+			if let Some(value) = config.get_mut(*field).filter(|value| !value.is_null()) {
+				*value = mask();
+			}
+		}
+		if let Some(headers) = config.get_mut("headers").and_then(JsonValue::as_object_mut) {
+			for (name, value) in headers.iter_mut() {
+				if name.eq_ignore_ascii_case("authorization") {
+					*value = mask();
+				}
+			}
+		}
+
+		self
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTriggerRequest {
 	pub trigger_id: String,
@@ -89,3 +208,70 @@ pub struct UpdateTriggerRequest {
 	pub configuration: Option<JsonValue>,
 	pub is_active: Option<bool>,
 }
+
+/// Optional filters for the trigger listing; set filters must all match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TriggerFilter {
+	pub trigger_type: Option<String>,
+	/// Internal id or external `monitor_id` of the trigger's monitor
+	pub monitor: Option<String>,
+	pub is_active: Option<bool>,
+}
+
+/// Outcome of sending a sample event to a trigger's channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestDeliveryStatus {
+	/// The channel answered with a 2xx status
+	Delivered,
+	/// The channel answered with another status, or could not be reached in time
+	Failed,
+	/// The trigger type is not delivered over HTTP, e.g. email
+	Skipped,
+}
+
+/// Result of a test delivery; test deliveries are not counted as trigger firings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestDeliveryResult {
+	pub trigger_id: String,
+	pub trigger_type: String,
+	pub status: TestDeliveryStatus,
+	/// HTTP status returned by the channel, when it answered
+	pub status_code: Option<u16>,
+	pub latency_ms: Option<u64>,
+	pub error: Option<String>,
+}
+
+/// A monitor after restoring an earlier configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorRollback {
+	pub monitor: TenantMonitor,
+	/// History version whose configuration was restored
+	pub restored_version: i32,
+	/// Version number of the configuration now in effect
+	pub version: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorDeactivation {
+	pub monitor: TenantMonitor,
+	pub triggers: Vec<TenantTrigger>,
+}
+
+/// Everything removed by a forced network deletion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkDeletion {
+	pub network: TenantNetwork,
+	pub monitors: Vec<TenantMonitor>,
+	pub triggers: Vec<TenantTrigger>,
+}
+
+/// Monitor that keeps its network from being deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependentMonitor {
+	pub id: Uuid,
+	pub monitor_id: String,
+	pub name: String,
+	/// Soft-deleted monitors keep their network until they are purged
+	pub deleted: bool,
+}
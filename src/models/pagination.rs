@@ -0,0 +1,99 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, SecondsFormat, Utc};
+use uuid::Uuid;
+
+use super::{TenantMonitor, TenantNetwork, TenantTrigger};
+
+/// Position in a list ordered by `(created_at, id)` descending; clients see it
+/// as an opaque base64 string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListCursor {
+	pub created_at: DateTime<Utc>,
+	pub id: Uuid,
+}
+
+impl ListCursor {
+	pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+		Self { created_at, id }
+	}
+
+	pub fn encode(&self) -> String {
+		URL_SAFE_NO_PAD.encode(format!(
+			"{}|{}",
+			self.created_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+			self.id
+		))
+	}
+
+	/// `None` for anything that was not produced by `encode`
+	pub fn decode(cursor: &str) -> Option<Self> {
+		let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+		let decoded = String::from_utf8(bytes).ok()?;
+		let (created_at, id) = decoded.split_once('|')?;
+		Some(Self {
+			created_at: DateTime::parse_from_rfc3339(created_at)
+				.ok()?
+				.with_timezone(&Utc),
+			id: Uuid::parse_str(id).ok()?,
+		})
+	}
+}
+
+/// Resources that can be listed with a `ListCursor`
+pub trait CursorPosition {
+	fn cursor_position(&self) -> Option<ListCursor>;
+}
+
+impl CursorPosition for TenantMonitor {
+	fn cursor_position(&self) -> Option<ListCursor> {
+		self.created_at
+			.map(|created_at| ListCursor::new(created_at, self.id))
+	}
+}
+
+impl CursorPosition for TenantNetwork {
+	fn cursor_position(&self) -> Option<ListCursor> {
+		self.created_at
+			.map(|created_at| ListCursor::new(created_at, self.id))
+	}
+}
+
+impl CursorPosition for TenantTrigger {
+	fn cursor_position(&self) -> Option<ListCursor> {
+		self.created_at
+			.map(|created_at| ListCursor::new(created_at, self.id))
+	}
+}
+
+/// Cursor for the page after `items`, or `None` when a short page shows there is nothing left
+pub fn next_cursor<T: CursorPosition>(items: &[T], limit: i64) -> Option<String> {
+	if (items.len() as i64) < limit {
+		return None;
+	}
+	items
+		.last()
+		.and_then(CursorPosition::cursor_position)
+		.map(|cursor| cursor.encode())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cursor_round_trip() {
+		let cursor = ListCursor::new(Utc::now(), Uuid::new_v4());
+		let decoded = ListCursor::decode(&cursor.encode()).unwrap();
+		assert_eq!(decoded.id, cursor.id);
+		assert_eq!(
+			decoded.created_at.timestamp_micros(),
+			cursor.created_at.timestamp_micros()
+		);
+	}
+
+	#[test]
+	fn test_invalid_cursor_is_rejected() {
+		assert!(ListCursor::decode("not a cursor").is_none());
+		assert!(ListCursor::decode(&URL_SAFE_NO_PAD.encode("2024-01-01|nope")).is_none());
+	}
+}
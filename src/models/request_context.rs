@@ -5,6 +5,8 @@ use std::net::IpAddr;
 pub struct RequestMetadata {
 	pub ip_address: Option<IpAddr>,
 	pub user_agent: Option<String>,
+	/// Id of the API request, as returned in its `X-Request-Id` header
+	pub request_id: Option<String>,
 }
 
 impl RequestMetadata {
@@ -12,6 +14,7 @@ impl RequestMetadata {
 		Self {
 			ip_address: None,
 			user_agent: None,
+			request_id: None,
 		}
 	}
 
@@ -24,6 +27,11 @@ impl RequestMetadata {
 		self.user_agent = user_agent;
 		self
 	}
+
+	pub fn with_request_id(mut self, request_id: Option<String>) -> Self {
+		self.request_id = request_id;
+		self
+	}
 }
 
 impl Default for RequestMetadata {
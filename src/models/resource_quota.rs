@@ -1,5 +1,6 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -32,6 +33,88 @@ pub struct ResourceQuotaStatus {
 	pub quotas: TenantQuotas,
 	pub usage: CurrentUsage,
 	pub available: AvailableResources,
+	#[serde(default)]
+	pub blockchains: Vec<BlockchainQuotaStatus>,
+	/// Resources at or above the warning threshold of their quota, including
+	/// ones exceeded without blocking, e.g. storage under soft enforcement
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub warnings: Vec<QuotaWarning>,
+	/// Trigger usage of each monitor, keyed by monitor id; `available.triggers`
+	/// only sums the headroom across all monitors
+	#[serde(default)]
+	pub monitor_triggers: BTreeMap<String, MonitorTriggerQuota>,
+}
+
+/// Triggers attached to one monitor against the per-monitor limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorTriggerQuota {
+	pub triggers_count: i32,
+	pub max_triggers: i32,
+	pub available: i32,
+}
+
+impl MonitorTriggerQuota {
+	pub fn new(triggers_count: i32, max_triggers: i32) -> Self {
+		Self {
+			triggers_count,
+			max_triggers,
+			available: (max_triggers - triggers_count).max(0),
+		}
+	}
+}
+
+/// Percent of a quota at which a warning is reported unless configured otherwise
+pub const DEFAULT_QUOTA_WARN_THRESHOLD_PERCENT: u8 = 80;
+
+/// A resource whose usage has crossed the warning threshold of its quota
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaWarning {
+	/// `monitors`, `networks`, `triggers`, `rpc_requests_per_minute` or `storage_mb`
+	pub resource: String,
+	pub usage: i32,
+	pub limit: i32,
+	pub percent_used: i32,
+}
+
+/// How an exceeded storage quota is enforced when writing configurations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageEnforcement {
+	#[default]
+	Hard,
+	/// Over-quota tenants are warned but may still write configurations
+	Soft,
+}
+
+/// Sub-quota for a single blockchain, stored per tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockchainQuota {
+	pub blockchain: String,
+	pub max_monitors: Option<i32>,
+	pub max_networks: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockchainQuotaStatus {
+	pub blockchain: String,
+	pub max_monitors: Option<i32>,
+	pub max_networks: Option<i32>,
+	pub monitors_count: i32,
+	pub networks_count: i32,
+}
+
+impl BlockchainQuotaStatus {
+	pub fn can_create_monitor(&self) -> bool {
+		self.max_monitors
+			.map(|max| self.monitors_count < max)
+			.unwrap_or(true)
+	}
+
+	pub fn can_create_network(&self) -> bool {
+		self.max_networks
+			.map(|max| self.networks_count < max)
+			.unwrap_or(true)
+	}
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -61,6 +144,14 @@ pub struct CurrentUsage {
 	pub storage_mb_used: i32,
 }
 
+/// Live resource counts for a tenant, without quota details
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ResourceCounts {
+	pub monitors: i64,
+	pub networks: i64,
+	pub triggers: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailableResources {
 	pub monitors: i32,
@@ -70,7 +161,143 @@ pub struct AvailableResources {
 	pub storage_mb: i32,
 }
 
+/// Capacity needed for one resource, as checked by a batch quota check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaCheck {
+	/// `monitors`, `networks`, `triggers`, `rpc_requests` or `storage_mb`
+	pub resource: String,
+	pub amount: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchQuotaCheckRequest {
+	pub checks: Vec<QuotaCheck>,
+}
+
+/// Whether the tenant has room for one requested amount
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaCheckResult {
+	pub resource: String,
+	pub requested: i32,
+	pub available: i32,
+	pub allowed: bool,
+}
+
+/// Query for a single dry-run quota check; `amount` defaults to 1
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaCheckQuery {
+	/// `monitors`, `networks`, `triggers`, `rpc_requests` or `storage_mb`
+	pub resource: String,
+	#[serde(default = "default_check_amount")]
+	pub amount: i32,
+}
+
+fn default_check_amount() -> i32 {
+	1
+}
+
+/// Outcome of a single dry-run quota check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaCheckResponse {
+	pub allowed: bool,
+	pub available: i32,
+	pub limit: i32,
+}
+
+/// Tenant ids for a batch quota status lookup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchQuotaStatusRequest {
+	pub tenant_ids: Vec<Uuid>,
+}
+
+impl ResourceCounts {
+	pub fn is_empty(&self) -> bool {
+		self.monitors == 0 && self.networks == 0 && self.triggers == 0
+	}
+}
+
 impl ResourceQuotaStatus {
+	/// Build a status from quotas and usage, deriving the available headroom
+	pub fn from_usage(
+		tenant_id: Uuid,
+		quotas: TenantQuotas,
+		usage: CurrentUsage,
+		blockchains: Vec<BlockchainQuotaStatus>,
+	) -> Self {
+		let available = AvailableResources {
+			monitors: (quotas.max_monitors - usage.monitors_count).max(0),
+			networks: (quotas.max_networks - usage.networks_count).max(0),
+			triggers: (quotas.max_triggers_per_monitor * usage.monitors_count
+				- usage.triggers_count)
+				.max(0),
+			rpc_requests_per_minute: (quotas.max_rpc_requests_per_minute
+				- usage.rpc_requests_last_minute)
+				.max(0),
+			storage_mb: (quotas.max_storage_mb - usage.storage_mb_used).max(0),
+		};
+
+		Self {
+			tenant_id,
+			quotas,
+			usage,
+			available,
+			blockchains,
+			warnings: Vec::new(),
+			monitor_triggers: BTreeMap::new(),
+		}
+		.with_warn_threshold(DEFAULT_QUOTA_WARN_THRESHOLD_PERCENT)
+	}
+
+	/// Set the per-monitor trigger breakdown from each monitor's trigger count
+	pub fn with_monitor_triggers(
+		mut self,
+		trigger_counts: impl IntoIterator<Item = (String, i32)>,
+	) -> Self {
+		let max_triggers = self.quotas.max_triggers_per_monitor;
+		self.monitor_triggers = trigger_counts
+			.into_iter()
+			.map(|(monitor_id, count)| (monitor_id, MonitorTriggerQuota::new(count, max_triggers)))
+			.collect();
+		self
+	}
+
+	/// Recompute `warnings` for every resource whose usage is at or above
+	/// `threshold_percent` of its limit; unlimited (zero) quotas never warn
+	pub fn with_warn_threshold(mut self, threshold_percent: u8) -> Self {
+		let quotas = &self.quotas;
+		let usage = &self.usage;
+		let resources = [
+			("monitors", usage.monitors_count, quotas.max_monitors),
+			("networks", usage.networks_count, quotas.max_networks),
+			(
+				"triggers",
+				usage.triggers_count,
+				quotas.max_triggers_per_monitor * usage.monitors_count,
+			),
+			(
+				"rpc_requests_per_minute",
+				usage.rpc_requests_last_minute,
+				quotas.max_rpc_requests_per_minute,
+			),
+			("storage_mb", usage.storage_mb_used, quotas.max_storage_mb),
+		];
+
+		self.warnings = resources
+			.into_iter()
+			.filter(|&(_, used, limit)| {
+				limit > 0 && used as i64 * 100 >= limit as i64 * threshold_percent as i64
+			})
+			.map(|(resource, used, limit)| QuotaWarning {
+				resource: resource.to_string(),
+				usage: used,
+				limit,
+				percent_used: (used as i64 * 100 / limit as i64) as i32,
+			})
+			.collect();
+
+		self
+	}
+
 	pub fn can_create_monitor(&self) -> bool {
 		self.available.monitors > 0
 	}
@@ -90,4 +317,78 @@ impl ResourceQuotaStatus {
 	pub fn has_storage_capacity(&self, mb: i32) -> bool {
 		self.available.storage_mb >= mb
 	}
+
+	/// Remaining capacity for a resource by name, or `None` for an unknown resource
+	pub fn available_for(&self, resource: &str) -> Option<i32> {
+		match resource {
+			"monitors" => Some(self.available.monitors),
+			"networks" => Some(self.available.networks),
+			"triggers" => Some(self.available.triggers),
+			"rpc_requests" => Some(self.available.rpc_requests_per_minute),
+			"storage_mb" => Some(self.available.storage_mb),
+			_ => None,
+		}
+	}
+
+	/// Quota limit for a resource by name, or `None` for an unknown resource;
+	/// the trigger limit scales with the number of monitors
+	pub fn limit_for(&self, resource: &str) -> Option<i32> {
+		match resource {
+			"monitors" => Some(self.quotas.max_monitors),
+			"networks" => Some(self.quotas.max_networks),
+			"triggers" => Some(self.quotas.max_triggers_per_monitor * self.usage.monitors_count),
+			"rpc_requests" => Some(self.quotas.max_rpc_requests_per_minute),
+			"storage_mb" => Some(self.quotas.max_storage_mb),
+			_ => None,
+		}
+	}
+
+	/// Check each requested amount against the remaining capacity; fails on
+	/// the first unknown resource
+	pub fn check_all(&self, checks: &[QuotaCheck]) -> Result<Vec<QuotaCheckResult>, String> {
+		checks
+			.iter()
+			.map(|check| {
+				let available = self
+					.available_for(&check.resource)
+					.ok_or_else(|| format!("Unknown resource type: {}", check.resource))?;
+
+				Ok(QuotaCheckResult {
+					resource: check.resource.clone(),
+					requested: check.amount,
+					available,
+					allowed: available >= check.amount,
+				})
+			})
+			.collect()
+	}
+
+	pub fn storage_exceeded(&self) -> bool {
+		self.usage.storage_mb_used > self.quotas.max_storage_mb
+	}
+
+	/// Whether `mb` more storage still fits under the tenant's limit
+	pub fn can_store(&self, mb: i32) -> bool {
+		self.usage.storage_mb_used + mb <= self.quotas.max_storage_mb
+	}
+
+	pub fn blockchain(&self, blockchain: &str) -> Option<&BlockchainQuotaStatus> {
+		self.blockchains.iter().find(|b| b.blockchain == blockchain)
+	}
+
+	pub fn can_create_monitor_on(&self, blockchain: &str) -> bool {
+		self.can_create_monitor()
+			&& self
+				.blockchain(blockchain)
+				.map(|b| b.can_create_monitor())
+				.unwrap_or(true)
+	}
+
+	pub fn can_create_network_on(&self, blockchain: &str) -> bool {
+		self.can_create_network()
+			&& self
+				.blockchain(blockchain)
+				.map(|b| b.can_create_network())
+				.unwrap_or(true)
+	}
 }
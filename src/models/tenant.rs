@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
 use super::resource_quota::{ApiRateLimits, TenantQuotas};
@@ -32,11 +33,25 @@ impl Tenant {
 			api_rate_limits: ApiRateLimits::default(),
 		}
 	}
+
+	/// Check a slug against the format rules and the `reserved` slugs, usually
+	/// the configured list
+	pub fn validate_slug(slug: &str, reserved: &[String]) -> Result<(), String> {
+		validate_slug_format(slug)?;
+
+		if is_reserved_slug(slug, reserved) {
+			return Err(format!("Slug '{}' is reserved", slug));
+		}
+
+		Ok(())
+	}
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTenantRequest {
 	pub name: String,
+	/// Derived from `name` with [`slugify`] when omitted or empty
+	#[serde(default)]
 	pub slug: String,
 	pub max_monitors: Option<i32>,
 	pub max_networks: Option<i32>,
@@ -46,6 +61,91 @@ pub struct CreateTenantRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameTenantSlugRequest {
+	pub slug: String,
+}
+
+/// Hand the caller's owner role to another member, who must already belong to the tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferOwnershipRequest {
+	pub to_user_id: Uuid,
+}
+
+/// Slugs reserved by default because they clash with routes or read as system names
+pub const DEFAULT_RESERVED_SLUGS: &[&str] = &[
+	"admin", "api", "auth", "current", "health", "metrics", "system", "tenants",
+];
+
+/// Slug in `/api/v1/tenants/{slug}` that selects the tenant from the
+/// `X-Tenant-Id` or `X-Tenant-Slug` header, or the caller's only membership
+pub const CURRENT_TENANT_SLUG: &str = "current";
+
+/// Shortest and longest slugs accepted; 63 keeps a slug usable as a DNS label
+pub const SLUG_MIN_LENGTH: usize = 3;
+pub const SLUG_MAX_LENGTH: usize = 63;
+
+/// Check that a slug is safe to use as a URL path segment: 3-63 lowercase
+/// ASCII letters, digits and hyphens, not starting or ending with a hyphen
+pub fn validate_slug_format(slug: &str) -> Result<(), String> {
+	if slug.len() < SLUG_MIN_LENGTH || slug.len() > SLUG_MAX_LENGTH {
+		return Err(format!(
+			"Slug must be between {} and {} characters long",
+			SLUG_MIN_LENGTH, SLUG_MAX_LENGTH
+		));
+	}
+
+	if !slug
+		.chars()
+		.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+	{
+		return Err(
+			"Slug may only contain lowercase letters (a-z), digits (0-9) and hyphens".to_string(),
+		);
+	}
+
+	if slug.starts_with('-') || slug.ends_with('-') {
+		return Err("Slug must not start or end with a hyphen".to_string());
+	}
+
+	Ok(())
+}
+
+/// Derive a slug from a display name: ASCII letters and digits are kept
+/// (lowercased), any other run of characters becomes a single hyphen, and the
+/// result is cut to the maximum length. The result may still be too short or
+/// reserved, so it must go through the usual validation
+pub fn slugify(name: &str) -> String {
+	let mut slug = String::with_capacity(name.len().min(SLUG_MAX_LENGTH));
+
+	for c in name.chars() {
+		if c.is_ascii_alphanumeric() {
+			slug.push(c.to_ascii_lowercase());
+		} else if !slug.is_empty() && !slug.ends_with('-') {
+			slug.push('-');
+		}
+	}
+
+	slug.truncate(SLUG_MAX_LENGTH);
+	slug.trim_end_matches('-').to_string()
+}
+
+/// Check a slug against the reserved list, ignoring case
+pub fn is_reserved_slug(slug: &str, reserved: &[String]) -> bool {
+	reserved.iter().any(|r| r.eq_ignore_ascii_case(slug.trim()))
+}
+
+/// Earliest time the slug may be renamed again, or `None` if it may be renamed now
+pub fn slug_rename_blocked_until(
+	last_renamed_at: Option<DateTime<Utc>>,
+	cooldown: chrono::Duration,
+	now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+	last_renamed_at
+		.map(|renamed_at| renamed_at + cooldown)
+		.filter(|available_at| *available_at > now)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateTenantRequest {
 	pub name: Option<String>,
 	pub is_active: Option<bool>,
@@ -56,6 +156,52 @@ pub struct UpdateTenantRequest {
 	pub max_storage_mb: Option<i32>,
 }
 
+/// Optional filters for the platform-wide tenant listing; set filters must all match
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TenantFilter {
+	/// Case-insensitive substring of the tenant name
+	pub name_contains: Option<String>,
+	pub is_active: Option<bool>,
+	pub slug_prefix: Option<String>,
+}
+
+/// Tenant-wide defaults applied to triggers that don't set their own values
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TriggerDefaults {
+	pub signing_secret: Option<String>,
+	/// Secret replaced by the last rotation, kept to find triggers still using it
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub previous_signing_secret: Option<String>,
+	pub timeout: Option<u64>,
+}
+
+impl TriggerDefaults {
+	/// Merge the defaults into a trigger configuration without overriding its own keys
+	pub fn apply(&self, configuration: &JsonValue) -> JsonValue {
+		let mut effective = configuration.clone();
+
+		if let Some(fields) = effective.as_object_mut() {
+			if let Some(signing_secret) = &self.signing_secret {
+				fields
+					.entry("signing_secret")
+					.or_insert_with(|| JsonValue::from(signing_secret.clone()));
+			}
+			if let Some(timeout) = self.timeout {
+				fields
+					.entry("timeout")
+					.or_insert_with(|| JsonValue::from(timeout));
+			}
+		}
+
+		effective
+	}
+
+	/// Replace the signing secret, remembering the one it replaces
+	pub fn rotate_signing_secret(&mut self, signing_secret: String) {
+		self.previous_signing_secret = self.signing_secret.replace(signing_secret);
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct TenantMembership {
 	pub id: Uuid,
@@ -66,6 +212,162 @@ pub struct TenantMembership {
 	pub updated_at: Option<DateTime<Utc>>,
 }
 
+/// A (tenant, user) pair with more than one membership row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateMembership {
+	pub tenant_id: Uuid,
+	pub user_id: Uuid,
+	/// Membership rows for the pair, oldest first
+	pub membership_ids: Vec<Uuid>,
+}
+
+/// One member to add in a batch, identified by user id or by email
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchMemberEntry {
+	#[serde(default)]
+	pub user_id: Option<Uuid>,
+	#[serde(default)]
+	pub email: Option<String>,
+	#[serde(default = "default_batch_member_role")]
+	pub role: TenantRole,
+}
+
+fn default_batch_member_role() -> TenantRole {
+	TenantRole::Member
+}
+
+/// What happened to one entry of a batch member import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMemberStatus {
+	Added,
+	/// The user already belongs to the tenant and keeps their current role
+	AlreadyMember,
+	UserNotFound,
+	/// The user appears earlier in the same batch
+	Duplicate,
+	/// The entry names neither or both of `user_id` and `email`
+	Invalid,
+}
+
+/// Per-entry result of a batch member import, in request order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchMemberResult {
+	pub index: usize,
+	/// The resolved user, when one was found
+	pub user_id: Option<Uuid>,
+	pub email: Option<String>,
+	pub role: TenantRole,
+	pub status: BatchMemberStatus,
+}
+
+impl BatchMemberEntry {
+	/// An entry must name exactly one of `user_id` and `email`
+	pub fn is_valid(&self) -> bool {
+		match (&self.user_id, &self.email) {
+			(Some(_), None) => true,
+			(None, Some(email)) => !email.trim().is_empty(),
+			_ => false,
+		}
+	}
+}
+
+/// The complete membership a tenant should have, e.g. as exported by an identity provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncMembersRequest {
+	pub members: Vec<BatchMemberEntry>,
+}
+
+/// How a membership sync changed one user's membership
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberSyncAction {
+	Added,
+	RoleChanged,
+	Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberSyncChange {
+	pub user_id: Uuid,
+	pub action: MemberSyncAction,
+	/// Role before the sync; absent for added members
+	pub previous_role: Option<TenantRole>,
+	/// Role after the sync; absent for removed members
+	pub role: Option<TenantRole>,
+}
+
+/// Outcome of a membership sync: what changed and the resulting members
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberSyncResult {
+	pub changes: Vec<MemberSyncChange>,
+	pub members: Vec<TenantMembership>,
+}
+
+/// A broken invariant found by a tenant integrity check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityViolationKind {
+	/// A monitor watches a network owned by another tenant
+	ForeignNetwork,
+	/// A trigger is attached to a monitor owned by another tenant
+	ForeignMonitor,
+	/// A monitor whose network no longer exists
+	OrphanedMonitor,
+	/// A trigger whose monitor no longer exists
+	OrphanedTrigger,
+	/// A per-tenant resource gauge disagrees with the stored rows
+	CountMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityViolation {
+	pub kind: IntegrityViolationKind,
+	pub resource_type: String,
+	/// Row at fault; absent for count mismatches
+	pub resource_id: Option<Uuid>,
+	pub detail: String,
+}
+
+impl IntegrityViolation {
+	/// Violations for every resource whose tracked count differs from the stored one
+	pub fn count_mismatches(stored: &[(&str, i64)], tracked: &[(&str, i64)]) -> Vec<Self> {
+		stored
+			.iter()
+			.filter_map(|(resource, stored_count)| {
+				let (_, tracked_count) = tracked.iter().find(|(name, _)| name == resource)?;
+				(tracked_count != stored_count).then(|| IntegrityViolation {
+					kind: IntegrityViolationKind::CountMismatch,
+					resource_type: resource.to_string(),
+					resource_id: None,
+					detail: format!(
+						"{} stored but the counter reports {}",
+						stored_count, tracked_count
+					),
+				})
+			})
+			.collect()
+	}
+}
+
+/// Result of checking one tenant's data invariants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReport {
+	pub tenant_id: Uuid,
+	pub healthy: bool,
+	pub violations: Vec<IntegrityViolation>,
+}
+
+impl IntegrityReport {
+	pub fn new(tenant_id: Uuid, violations: Vec<IntegrityViolation>) -> Self {
+		Self {
+			tenant_id,
+			healthy: violations.is_empty(),
+			violations,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "text")]
 pub enum TenantRole {
@@ -95,6 +397,11 @@ impl TenantRole {
 		)
 	}
 
+	/// Deleting monitors, networks and triggers is reserved for admins and owners
+	pub fn can_delete(&self) -> bool {
+		self.can_manage_tenant()
+	}
+
 	pub fn can_read(&self) -> bool {
 		true // All roles can read
 	}
@@ -110,21 +417,25 @@ mod tests {
 		// Test Owner permissions
 		assert!(TenantRole::Owner.can_manage_tenant());
 		assert!(TenantRole::Owner.can_write());
+		assert!(TenantRole::Owner.can_delete());
 		assert!(TenantRole::Owner.can_read());
 
 		// Test Admin permissions
 		assert!(TenantRole::Admin.can_manage_tenant());
 		assert!(TenantRole::Admin.can_write());
+		assert!(TenantRole::Admin.can_delete());
 		assert!(TenantRole::Admin.can_read());
 
 		// Test Member permissions
 		assert!(!TenantRole::Member.can_manage_tenant());
 		assert!(TenantRole::Member.can_write());
+		assert!(!TenantRole::Member.can_delete());
 		assert!(TenantRole::Member.can_read());
 
 		// Test Viewer permissions
 		assert!(!TenantRole::Viewer.can_manage_tenant());
 		assert!(!TenantRole::Viewer.can_write());
+		assert!(!TenantRole::Viewer.can_delete());
 		assert!(TenantRole::Viewer.can_read());
 	}
 
@@ -223,4 +534,38 @@ mod tests {
 		assert!(membership.created_at.is_some());
 		assert!(membership.updated_at.is_some());
 	}
+
+	#[test]
+	fn test_trigger_defaults_apply() {
+		let defaults = TriggerDefaults {
+			signing_secret: Some("tenant-secret".to_string()),
+			previous_signing_secret: None,
+			timeout: Some(45),
+		};
+
+		// Missing keys are inherited from the tenant
+		let effective = defaults.apply(&serde_json::json!({"url": "https://example.com"}));
+		assert_eq!(effective["timeout"], 45);
+		assert_eq!(effective["signing_secret"], "tenant-secret");
+
+		// The trigger's own values take precedence
+		let effective = defaults.apply(&serde_json::json!({"timeout": 10}));
+		assert_eq!(effective["timeout"], 10);
+	}
+
+	#[test]
+	fn test_trigger_defaults_rotate_signing_secret() {
+		let mut defaults = TriggerDefaults {
+			signing_secret: Some("old-secret".to_string()),
+			..Default::default()
+		};
+
+		defaults.rotate_signing_secret("new-secret".to_string());
+
+		assert_eq!(defaults.signing_secret.as_deref(), Some("new-secret"));
+		assert_eq!(
+			defaults.previous_signing_secret.as_deref(),
+			Some("old-secret")
+		);
+	}
 }
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Usage recorded in `resource_usage` for tenant API requests
+pub const API_REQUESTS_RESOURCE: &str = "api_requests";
+
+/// Usage recorded in `resource_usage` for RPC requests, counted per one-minute window
+pub const RPC_REQUESTS_RESOURCE: &str = "rpc_requests";
+
+/// Usage recorded in `resource_usage` for storage, as the latest reported MB for the day
+pub const STORAGE_RESOURCE: &str = "storage";
+
+/// Bytes counted as one MB of storage
+pub const BYTES_PER_MB: usize = 1024 * 1024;
+
+/// Storage a configuration is estimated to take from its JSON length, in
+/// whole MB rounded up so every stored configuration counts
+pub fn config_storage_mb(configuration: &serde_json::Value) -> i64 {
+	let bytes = serde_json::to_vec(configuration)
+		.map(|json| json.len())
+		.unwrap_or_default();
+	bytes.div_ceil(BYTES_PER_MB) as i64
+}
+
+/// Consumption reported by a monitor runner for one tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportUsageRequest {
+	pub tenant_id: Uuid,
+	/// RPC requests made since the previous report
+	#[serde(default)]
+	pub rpc_requests: Option<i64>,
+	/// Storage currently in use
+	#[serde(default)]
+	pub storage_mb: Option<i64>,
+}
+
+/// Total usage for one daily bucket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct UsagePoint {
+	pub date: NaiveDate,
+	pub value: i64,
+}
+
+/// One point per day from `from` to `to` inclusive, summing duplicate days and
+/// filling days without usage with zero
+pub fn daily_series(from: NaiveDate, to: NaiveDate, points: &[UsagePoint]) -> Vec<UsagePoint> {
+	from.iter_days()
+		.take_while(|date| *date <= to)
+		.map(|date| UsagePoint {
+			date,
+			value: points
+				.iter()
+				.filter(|p| p.date == date)
+				.map(|p| p.value)
+				.sum(),
+		})
+		.collect()
+}
@@ -9,6 +9,7 @@ pub struct User {
 	#[serde(skip_serializing)]
 	pub password_hash: String,
 	pub is_active: bool,
+	pub email_verified: bool,
 	pub created_at: Option<DateTime<Utc>>,
 	pub updated_at: Option<DateTime<Utc>>,
 }
@@ -54,3 +55,24 @@ pub struct UserTenant {
 	pub tenant_slug: String,
 	pub role: crate::models::tenant::TenantRole,
 }
+
+/// An identity asserted by a verified token from the configured SSO provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SsoIdentity {
+	pub issuer: String,
+	pub subject: String,
+	pub email: String,
+	pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoProvisionRequest {
+	/// ID token issued by the SSO provider
+	pub id_token: String,
+	/// Name of the tenant provisioned for a user without one; defaults to the user's name
+	#[serde(default)]
+	pub tenant_name: Option<String>,
+	/// Slug of the provisioned tenant; derived from the tenant name when omitted
+	#[serde(default)]
+	pub tenant_slug: Option<String>,
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use super::{AuditAction, AuditLog};
+
+/// Most deliveries `GET /webhooks/{id}/deliveries` returns
+pub const MAX_WEBHOOK_DELIVERIES: i64 = 100;
+
+/// An endpoint that receives a tenant's audit events
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TenantWebhook {
+	pub id: Uuid,
+	pub tenant_id: Uuid,
+	pub url: String,
+	/// HMAC-SHA256 key for the `X-Signature` header; only returned when the
+	/// webhook is created
+	#[serde(skip_serializing)]
+	pub secret: String,
+	/// Audit actions to send; empty sends every action
+	pub events: Vec<String>,
+	pub is_active: bool,
+	pub created_by: Option<Uuid>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+impl TenantWebhook {
+	/// Whether an audit entry with `action` should be sent to this webhook
+	pub fn wants(&self, action: &str) -> bool {
+		self.is_active && (self.events.is_empty() || self.events.iter().any(|e| e == action))
+	}
+}
+
+/// A newly created webhook together with its signing secret
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedWebhook {
+	#[serde(flatten)]
+	pub webhook: TenantWebhook,
+	pub secret: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+	pub url: String,
+	/// Generated when not given
+	#[serde(default)]
+	pub secret: Option<String>,
+	#[serde(default)]
+	pub events: Vec<String>,
+	#[serde(default)]
+	pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateWebhookRequest {
+	pub url: Option<String>,
+	pub secret: Option<String>,
+	pub events: Option<Vec<String>>,
+	pub is_active: Option<bool>,
+}
+
+/// Check a webhook's URL and event filter before it is stored
+pub fn validate_webhook(url: Option<&str>, events: Option<&[String]>) -> Result<(), String> {
+	if let Some(url) = url {
+		if !(url.starts_with("https://") || url.starts_with("http://")) {
+			return Err("Webhook url must be an http(s) URL".to_string());
+		}
+	}
+	if let Some(unknown) = events.unwrap_or_default().iter().find(|event| {
+		!AuditAction::ALL
+			.iter()
+			.any(|a| a.as_str() == event.as_str())
+	}) {
+		return Err(format!("Unknown webhook event '{}'", unknown));
+	}
+
+	Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookDeliveryStatus {
+	Pending,
+	Delivered,
+	Failed,
+}
+
+/// One event sent, or being sent, to a webhook
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WebhookDelivery {
+	pub id: Uuid,
+	pub webhook_id: Uuid,
+	pub tenant_id: Uuid,
+	pub audit_log_id: Option<Uuid>,
+	pub event: String,
+	pub payload: JsonValue,
+	pub status: WebhookDeliveryStatus,
+	/// Attempts made so far, including the last one
+	pub attempts: i32,
+	/// HTTP status of the last attempt, if the endpoint answered
+	pub response_status: Option<i32>,
+	pub error: Option<String>,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WebhookDeliveryQuery {
+	pub limit: Option<i64>,
+}
+
+impl WebhookDeliveryQuery {
+	pub fn page_limit(&self) -> i64 {
+		self.limit.unwrap_or(20).clamp(1, MAX_WEBHOOK_DELIVERIES)
+	}
+}
+
+/// JSON body POSTed to a webhook for one audit entry
+pub fn webhook_payload(log: &AuditLog) -> JsonValue {
+	serde_json::json!({
+		"event": log.action,
+		"tenant_id": log.tenant_id,
+		"audit_log_id": log.id,
+		"resource_type": log.resource_type,
+		"resource_id": log.resource_id,
+		"user_id": log.user_id,
+		"api_key_id": log.api_key_id,
+		"changes": log.changes,
+		"occurred_at": log.created_at,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn webhook(events: &[&str], is_active: bool) -> TenantWebhook {
+		let now = Utc::now();
+		TenantWebhook {
+			id: Uuid::new_v4(),
+			tenant_id: Uuid::new_v4(),
+			url: "https://example.com/hook".to_string(),
+			secret: "secret".to_string(),
+			events: events.iter().map(|e| e.to_string()).collect(),
+			is_active,
+			created_by: None,
+			created_at: now,
+			updated_at: now,
+		}
+	}
+
+	#[test]
+	fn test_webhook_event_filter() {
+		assert!(webhook(&[], true).wants("monitor_created"));
+		assert!(webhook(&["monitor_created"], true).wants("monitor_created"));
+		assert!(!webhook(&["monitor_created"], true).wants("monitor_deleted"));
+		assert!(!webhook(&[], false).wants("monitor_created"));
+	}
+
+	#[test]
+	fn test_validate_webhook() {
+		let events = vec!["quota_exceeded".to_string(), "member_added".to_string()];
+		assert!(validate_webhook(Some("https://example.com/hook"), Some(&events)).is_ok());
+		assert!(validate_webhook(Some("ftp://example.com"), None).is_err());
+		assert!(validate_webhook(None, Some(&["monitor_exploded".to_string()])).is_err());
+	}
+
+	#[test]
+	fn test_secret_is_not_serialized() {
+		let json = serde_json::to_value(webhook(&[], true)).unwrap();
+		assert!(json.get("secret").is_none());
+	}
+}
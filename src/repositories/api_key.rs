@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use crate::models::ApiKey;
+
+/// Platform-level API key access; these queries span all tenants and are
+/// intended for background maintenance rather than request handling
+#[async_trait]
+pub trait ApiKeyRepositoryTrait: Clone + Send + Sync {
+	/// Active keys whose last use (or creation, if never used) is before `cutoff`
+	async fn list_unused_since(
+		&self,
+		cutoff: DateTime<Utc>,
+	) -> Result<Vec<ApiKey>, TenantRepositoryError>;
+	async fn deactivate(&self, key_id: Uuid) -> Result<ApiKey, TenantRepositoryError>;
+	/// Stamp the key as used now and add `requests` to its request count
+	async fn update_last_used(
+		&self,
+		key_id: Uuid,
+		requests: i64,
+	) -> Result<(), TenantRepositoryError>;
+}
+
+#[derive(Clone)]
+pub struct ApiKeyRepository {
+	pool: Pool<Postgres>,
+}
+
+impl ApiKeyRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+}
+
+#[async_trait]
+impl ApiKeyRepositoryTrait for ApiKeyRepository {
+	async fn list_unused_since(
+		&self,
+		cutoff: DateTime<Utc>,
+	) -> Result<Vec<ApiKey>, TenantRepositoryError> {
+		let keys = sqlx::query_as!(
+			ApiKey,
+			r#"
+			SELECT id, tenant_id, name, key_hash,
+			       COALESCE(permissions, '[]'::jsonb) as "permissions!",
+			       last_used_at, expires_at,
+			       COALESCE(is_active, true) as "is_active!",
+			       COALESCE(created_at, NOW()) as "created_at!",
+			       COALESCE(updated_at, NOW()) as "updated_at!"
+			FROM api_keys
+			WHERE is_active = true
+			AND COALESCE(last_used_at, created_at) < $1
+			ORDER BY tenant_id, id
+			"#,
+			cutoff
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(keys)
+	}
+
+	async fn deactivate(&self, key_id: Uuid) -> Result<ApiKey, TenantRepositoryError> {
+		let key = sqlx::query_as!(
+			ApiKey,
+			r#"
+			UPDATE api_keys
+			SET is_active = false, updated_at = NOW()
+			WHERE id = $1
+			RETURNING id, tenant_id, name, key_hash,
+			          COALESCE(permissions, '[]'::jsonb) as "permissions!",
+			          last_used_at, expires_at,
+			          COALESCE(is_active, true) as "is_active!",
+			          COALESCE(created_at, NOW()) as "created_at!",
+			          COALESCE(updated_at, NOW()) as "updated_at!"
+			"#,
+			key_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "api_key".to_string(),
+			resource_id: key_id.to_string(),
+		})?;
+
+		Ok(key)
+	}
+
+	async fn update_last_used(
+		&self,
+		key_id: Uuid,
+		requests: i64,
+	) -> Result<(), TenantRepositoryError> {
+		sqlx::query!(
+			"UPDATE api_keys SET last_used_at = NOW(), request_count = request_count + $2 WHERE id = $1",
+			key_id,
+			requests
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use crate::models::{AuditLog, AuditLogFilter};
+
+#[async_trait]
+pub trait AuditRepositoryTrait: Clone + Send + Sync {
+	/// One page of the tenant's audit entries matching `filter`, newest first
+	async fn query(
+		&self,
+		tenant_id: Uuid,
+		filter: &AuditLogFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<AuditLog>, TenantRepositoryError>;
+
+	/// Number of the tenant's audit entries matching `filter`, ignoring pagination
+	async fn count(
+		&self,
+		tenant_id: Uuid,
+		filter: &AuditLogFilter,
+	) -> Result<i64, TenantRepositoryError>;
+}
+
+#[derive(Clone)]
+pub struct AuditRepository {
+	pool: Pool<Postgres>,
+}
+
+impl AuditRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for AuditRepository {
+	async fn query(
+		&self,
+		tenant_id: Uuid,
+		filter: &AuditLogFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<AuditLog>, TenantRepositoryError> {
+		let logs = audit_log_query(tenant_id, filter, limit, offset)
+			.build_query_as::<AuditLog>()
+			.fetch_all(&self.pool)
+			.await?;
+
+		Ok(logs)
+	}
+
+	async fn count(
+		&self,
+		tenant_id: Uuid,
+		filter: &AuditLogFilter,
+	) -> Result<i64, TenantRepositoryError> {
+		let mut query = QueryBuilder::new("SELECT COUNT(*) FROM audit_logs WHERE tenant_id = ");
+		query.push_bind(tenant_id);
+		push_filters(&mut query, filter);
+
+		let count = query
+			.build_query_scalar::<i64>()
+			.fetch_one(&self.pool)
+			.await?;
+
+		Ok(count)
+	}
+}
+
+/// Builds one page of the filtered audit log query; the tenant condition is
+/// always the first bound value, and (tenant_id, created_at) is indexed
+fn audit_log_query(
+	tenant_id: Uuid,
+	filter: &AuditLogFilter,
+	limit: i64,
+	offset: i64,
+) -> QueryBuilder<'_, Postgres> {
+	let mut query = QueryBuilder::new(
+		r#"
+		SELECT
+			id, tenant_id, user_id, api_key_id, action,
+			resource_type, resource_id, changes, ip_address,
+			user_agent, request_id, created_at
+		FROM audit_logs
+		WHERE tenant_id = "#,
+	);
+	query.push_bind(tenant_id);
+	push_filters(&mut query, filter);
+
+	query
+		.push(" ORDER BY created_at DESC, id DESC LIMIT ")
+		.push_bind(limit)
+		.push(" OFFSET ")
+		.push_bind(offset);
+
+	query
+}
+
+fn push_filters<'a>(query: &mut QueryBuilder<'a, Postgres>, filter: &'a AuditLogFilter) {
+	if let Some(action) = &filter.action {
+		query.push(" AND action = ").push_bind(action);
+	}
+	if let Some(resource_type) = &filter.resource_type {
+		query.push(" AND resource_type = ").push_bind(resource_type);
+	}
+	if let Some(resource_id) = filter.resource_id {
+		query.push(" AND resource_id = ").push_bind(resource_id);
+	}
+	if let Some(user_id) = filter.user_id {
+		query.push(" AND user_id = ").push_bind(user_id);
+	}
+	if let Some(from) = filter.from {
+		query.push(" AND created_at >= ").push_bind(from);
+	}
+	if let Some(to) = filter.to {
+		query.push(" AND created_at <= ").push_bind(to);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::Utc;
+
+	#[test]
+	fn test_audit_log_query_always_scoped_to_tenant() {
+		let no_filter = AuditLogFilter::default();
+		let unfiltered = audit_log_query(Uuid::new_v4(), &no_filter, 50, 0);
+		assert!(unfiltered.sql().contains("WHERE tenant_id = $1 ORDER BY"));
+
+		// Other filters only narrow the tenant's own entries
+		let filter = AuditLogFilter {
+			user_id: Some(Uuid::new_v4()),
+			..Default::default()
+		};
+		let filtered = audit_log_query(Uuid::new_v4(), &filter, 50, 0);
+		assert!(filtered
+			.sql()
+			.contains("WHERE tenant_id = $1 AND user_id = $2 ORDER BY"));
+	}
+
+	#[test]
+	fn test_audit_log_query_binds_filter_values() {
+		let filter = AuditLogFilter {
+			action: Some("monitor_created' OR '1'='1".to_string()),
+			resource_type: Some("monitor".to_string()),
+			resource_id: Some(Uuid::new_v4()),
+			user_id: Some(Uuid::new_v4()),
+			from: Some(Utc::now()),
+			to: Some(Utc::now()),
+			limit: None,
+			offset: None,
+		};
+		let query = audit_log_query(Uuid::new_v4(), &filter, 10, 20);
+		let sql = query.sql();
+
+		assert!(sql.contains("WHERE tenant_id = $1 AND action = $2"));
+		assert!(sql.contains("AND resource_type = $3 AND resource_id = $4 AND user_id = $5"));
+		assert!(sql.contains("AND created_at >= $6 AND created_at <= $7"));
+		assert!(sql.ends_with("LIMIT $8 OFFSET $9"));
+		assert!(!sql.contains("monitor_created"));
+	}
+}
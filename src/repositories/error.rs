@@ -1,6 +1,8 @@
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::models::{DependentMonitor, ImportConflict};
+
 #[derive(Error, Debug)]
 pub enum TenantRepositoryError {
 	#[error("Database error: {0}")]
@@ -21,6 +23,9 @@ pub enum TenantRepositoryError {
 	#[error("Quota exceeded: {0}")]
 	QuotaExceeded(String),
 
+	#[error("Rate limited: {0}")]
+	RateLimited(String),
+
 	#[error("Access denied: {0}")]
 	AccessDenied(String),
 
@@ -30,6 +35,27 @@ pub enum TenantRepositoryError {
 	#[error("Validation error: {0}")]
 	ValidationError(String),
 
+	#[error("User {0} is the tenant's only owner")]
+	CannotRemoveLastOwner(Uuid),
+
+	#[error("Slug '{0}' belongs to a recently deleted tenant; restore that tenant or choose another slug")]
+	DeletedTenantSlug(String),
+
+	#[error("Monitor {monitor_id} still has {} trigger(s)", .trigger_ids.len())]
+	MonitorHasTriggers {
+		monitor_id: String,
+		trigger_ids: Vec<String>,
+	},
+
+	#[error("Network {network_id} has {} dependent monitor(s)", .monitors.len())]
+	NetworkHasMonitors {
+		network_id: String,
+		monitors: Vec<DependentMonitor>,
+	},
+
+	#[error("{} imported resource(s) already exist", .0.len())]
+	ImportConflicts(Vec<ImportConflict>),
+
 	#[error("Already exists: {resource_type} {resource_id}")]
 	AlreadyExists {
 		resource_type: String,
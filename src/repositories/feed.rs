@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+use super::error::TenantRepositoryError;
+use crate::models::ResourceChangeEvent;
+use crate::utils::current_tenant_id;
+
+#[async_trait]
+pub trait ResourceFeedRepositoryTrait: Clone + Send + Sync {
+	/// Change events for the current tenant with a cursor greater than `since`, oldest first
+	async fn list_since(
+		&self,
+		since: i64,
+		limit: i64,
+	) -> Result<Vec<ResourceChangeEvent>, TenantRepositoryError>;
+}
+
+#[derive(Clone)]
+pub struct ResourceFeedRepository {
+	pool: Pool<Postgres>,
+}
+
+impl ResourceFeedRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+}
+
+#[async_trait]
+impl ResourceFeedRepositoryTrait for ResourceFeedRepository {
+	async fn list_since(
+		&self,
+		since: i64,
+		limit: i64,
+	) -> Result<Vec<ResourceChangeEvent>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let events = sqlx::query_as!(
+			ResourceChangeEvent,
+			r#"
+			SELECT seq as cursor, kind, resource_id, action, occurred_at
+			FROM resource_change_events
+			WHERE tenant_id = $1 AND seq > $2
+			ORDER BY seq ASC
+			LIMIT $3
+			"#,
+			tenant_id,
+			since,
+			limit
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(events)
+	}
+}
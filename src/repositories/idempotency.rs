@@ -0,0 +1,312 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+
+/// How long a claim's lease may go unrenewed before it counts as abandoned, e.g.
+/// by a process that crashed mid-request, and another request may take the key over
+pub const IDEMPOTENCY_CLAIM_TIMEOUT_SECS: i64 = 60;
+
+/// How often a running request renews its claim's lease
+const LEASE_RENEW_INTERVAL: std::time::Duration =
+	std::time::Duration::from_secs(IDEMPOTENCY_CLAIM_TIMEOUT_SECS as u64 / 3);
+
+/// How often a request waiting on another request's claim checks for its response
+const CLAIM_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Response stored for an idempotency key, replayed for repeats of the request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+	pub status: u16,
+	pub body: Vec<u8>,
+}
+
+/// Whether a response stored at `created_at` may still be replayed at `now`
+pub fn idempotent_response_fresh(
+	created_at: DateTime<Utc>,
+	ttl: Duration,
+	now: DateTime<Utc>,
+) -> bool {
+	now - created_at < ttl
+}
+
+/// Outcome of claiming an idempotency key
+pub enum IdempotencyClaim {
+	/// The key is held for this request; store its response through the lock
+	Claimed(IdempotencyLock),
+	/// An earlier request with the key finished with this response
+	Replay(StoredResponse),
+	/// The key is held or answered for a request with another fingerprint
+	Mismatch,
+}
+
+#[derive(Clone)]
+pub struct IdempotencyRepository {
+	pool: Pool<Postgres>,
+}
+
+impl IdempotencyRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+
+	/// Claims the tenant's key for the current request, waiting while another
+	/// request holds it. Returns the response stored within `ttl`, if any; otherwise
+	/// the caller runs the request and saves its response through the lock. A key
+	/// held or answered for a request with another `fingerprint` is a mismatch.
+	pub async fn claim(
+		&self,
+		tenant_id: Uuid,
+		key: &str,
+		fingerprint: &str,
+		ttl: Duration,
+	) -> Result<IdempotencyClaim, TenantRepositoryError> {
+		loop {
+			// The pending row is committed straight away, so no connection is
+			// held while the request runs
+			let claimed_at = sqlx::query_scalar!(
+				r#"
+				INSERT INTO idempotency_keys (tenant_id, key, request_fingerprint)
+				VALUES ($1, $2, $3)
+				ON CONFLICT (tenant_id, key) DO NOTHING
+				RETURNING created_at
+				"#,
+				tenant_id,
+				key,
+				fingerprint
+			)
+			.fetch_optional(&self.pool)
+			.await?;
+			if let Some(claimed_at) = claimed_at {
+				return Ok(IdempotencyClaim::Claimed(
+					self.lock(tenant_id, key, claimed_at),
+				));
+			}
+
+			let Some(row) = sqlx::query!(
+				r#"
+				SELECT response_status, response_body, request_fingerprint, created_at,
+				       lease_renewed_at
+				FROM idempotency_keys
+				WHERE tenant_id = $1 AND key = $2
+				"#,
+				tenant_id,
+				key
+			)
+			.fetch_optional(&self.pool)
+			.await?
+			else {
+				// Released since the insert; claim it again
+				continue;
+			};
+
+			let now = Utc::now();
+			let response = row.response_status.zip(row.response_body);
+			let live = match response {
+				Some(_) => idempotent_response_fresh(row.created_at, ttl, now),
+				None => {
+					now - row.lease_renewed_at < Duration::seconds(IDEMPOTENCY_CLAIM_TIMEOUT_SECS)
+				}
+			};
+			// Rows from before fingerprints were stored match any request
+			if live
+				&& row
+					.request_fingerprint
+					.is_some_and(|stored| stored != fingerprint)
+			{
+				return Ok(IdempotencyClaim::Mismatch);
+			}
+			match response {
+				Some((status, body)) if live => {
+					return Ok(IdempotencyClaim::Replay(StoredResponse {
+						status: u16::try_from(status).unwrap_or(500),
+						body,
+					}));
+				}
+				None if live => {
+					tokio::time::sleep(CLAIM_POLL_INTERVAL).await;
+					continue;
+				}
+				_ => {}
+			}
+
+			// The expired response or abandoned claim goes to whichever waiting
+			// request resets it first
+			let taken_over = sqlx::query_scalar!(
+				r#"
+				UPDATE idempotency_keys
+				SET response_status = NULL, response_body = NULL, request_fingerprint = $4,
+				    created_at = NOW(), lease_renewed_at = NOW()
+				WHERE tenant_id = $1 AND key = $2 AND created_at = $3
+				RETURNING created_at
+				"#,
+				tenant_id,
+				key,
+				row.created_at,
+				fingerprint
+			)
+			.fetch_optional(&self.pool)
+			.await?;
+			if let Some(claimed_at) = taken_over {
+				return Ok(IdempotencyClaim::Claimed(
+					self.lock(tenant_id, key, claimed_at),
+				));
+			}
+		}
+	}
+
+	fn lock(&self, tenant_id: Uuid, key: &str, claimed_at: DateTime<Utc>) -> IdempotencyLock {
+		let lease = tokio::spawn(renew_lease(
+			self.pool.clone(),
+			tenant_id,
+			key.to_string(),
+			claimed_at,
+		));
+		IdempotencyLock {
+			pool: self.pool.clone(),
+			tenant_id,
+			key: key.to_string(),
+			claimed_at,
+			lease: lease.abort_handle(),
+			finished: false,
+		}
+	}
+}
+
+/// Keeps a pending claim's lease fresh until the claim is stored, released or
+/// taken over, so a request running past the claim timeout keeps its key
+async fn renew_lease(
+	pool: Pool<Postgres>,
+	tenant_id: Uuid,
+	key: String,
+	claimed_at: DateTime<Utc>,
+) {
+	loop {
+		tokio::time::sleep(LEASE_RENEW_INTERVAL).await;
+		let renewed = sqlx::query!(
+			r#"
+			UPDATE idempotency_keys
+			SET lease_renewed_at = NOW()
+			WHERE tenant_id = $1 AND key = $2 AND created_at = $3 AND response_status IS NULL
+			"#,
+			tenant_id,
+			key,
+			claimed_at
+		)
+		.execute(&pool)
+		.await;
+		match renewed {
+			Ok(result) if result.rows_affected() == 0 => return,
+			Ok(_) => {}
+			Err(e) => {
+				tracing::warn!(tenant_id = %tenant_id, error = %e, "Failed to renew idempotency key lease");
+			}
+		}
+	}
+}
+
+/// Holds an idempotency key until the request's response is stored, renewing
+/// its lease meanwhile. Releasing or dropping it without storing deletes the
+/// claim, so the key can be retried.
+pub struct IdempotencyLock {
+	pool: Pool<Postgres>,
+	tenant_id: Uuid,
+	key: String,
+	claimed_at: DateTime<Utc>,
+	lease: tokio::task::AbortHandle,
+	finished: bool,
+}
+
+impl IdempotencyLock {
+	/// Save the response for later repeats of the key and release it
+	pub async fn store(mut self, response: &StoredResponse) -> Result<(), TenantRepositoryError> {
+		self.finished = true;
+		// Matching on the claim time leaves a key another request took over alone
+		sqlx::query!(
+			r#"
+			UPDATE idempotency_keys
+			SET response_status = $4, response_body = $5, created_at = NOW()
+			WHERE tenant_id = $1 AND key = $2 AND created_at = $3
+			"#,
+			self.tenant_id,
+			self.key,
+			self.claimed_at,
+			i32::from(response.status),
+			&response.body
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+
+	/// Give the key up without a response, so a retry runs the request again
+	pub async fn release(mut self) -> Result<(), TenantRepositoryError> {
+		self.finished = true;
+		delete_claim(&self.pool, self.tenant_id, &self.key, self.claimed_at).await
+	}
+}
+
+impl Drop for IdempotencyLock {
+	fn drop(&mut self) {
+		self.lease.abort();
+		if self.finished {
+			return;
+		}
+		// Reached when the request is cancelled or fails before finishing
+		let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+			return;
+		};
+		let pool = self.pool.clone();
+		let (tenant_id, key, claimed_at) = (self.tenant_id, self.key.clone(), self.claimed_at);
+		runtime.spawn(async move {
+			if let Err(e) = delete_claim(&pool, tenant_id, &key, claimed_at).await {
+				tracing::warn!(tenant_id = %tenant_id, error = %e, "Failed to release idempotency key");
+			}
+		});
+	}
+}
+
+async fn delete_claim(
+	pool: &Pool<Postgres>,
+	tenant_id: Uuid,
+	key: &str,
+	claimed_at: DateTime<Utc>,
+) -> Result<(), TenantRepositoryError> {
+	sqlx::query!(
+		r#"
+		DELETE FROM idempotency_keys
+		WHERE tenant_id = $1 AND key = $2 AND created_at = $3 AND response_status IS NULL
+		"#,
+		tenant_id,
+		key,
+		claimed_at
+	)
+	.execute(pool)
+	.await?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_stored_response_expires_after_ttl() {
+		let now = Utc::now();
+		let ttl = Duration::hours(24);
+
+		assert!(idempotent_response_fresh(
+			now - Duration::hours(23),
+			ttl,
+			now
+		));
+		assert!(!idempotent_response_fresh(
+			now - Duration::hours(24),
+			ttl,
+			now
+		));
+	}
+}
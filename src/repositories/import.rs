@@ -0,0 +1,547 @@
+use sqlx::{Pool, Postgres, Transaction};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use super::monitor::TenantMonitorRepository;
+use crate::models::{
+	CreationSource, ImportConflict, ImportMode, ImportSummary, TenantExportDocument, TenantMonitor,
+};
+use crate::utils::{current_tenant_id, QuotaStatusCache};
+
+/// Tenant-wide limits read once, under the tenant row lock, at the start of an import
+struct TenantLimits {
+	max_monitors: i64,
+	max_networks: i64,
+	max_triggers_per_monitor: i64,
+}
+
+#[derive(Clone)]
+pub struct TenantImportRepository {
+	pool: Pool<Postgres>,
+	quota_cache: QuotaStatusCache,
+}
+
+impl TenantImportRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self {
+			pool,
+			quota_cache: QuotaStatusCache::default(),
+		}
+	}
+
+	/// Invalidate the tenant's cached quota status once an import commits
+	pub fn with_quota_cache(mut self, cache: QuotaStatusCache) -> Self {
+		self.quota_cache = cache;
+		self
+	}
+
+	/// Recreate `document` in the current tenant: networks, then monitors, then
+	/// triggers, all in one transaction. Created resources get fresh ids, and
+	/// monitors and triggers are attached to whatever their `network_id` and
+	/// `monitor_id` resolve to in this tenant, so references within the document
+	/// survive the import. Nothing is kept if a collision aborts the import or any
+	/// quota ends up exceeded; in `fail` mode every colliding entry is reported.
+	pub async fn import(
+		&self,
+		document: &TenantExportDocument,
+		mode: ImportMode,
+	) -> Result<ImportSummary, TenantRepositoryError> {
+		self.run(document, mode, false).await
+	}
+
+	/// Run the import with the same checks and outcome, then roll it back
+	pub async fn dry_run(
+		&self,
+		document: &TenantExportDocument,
+		mode: ImportMode,
+	) -> Result<ImportSummary, TenantRepositoryError> {
+		self.run(document, mode, true).await
+	}
+
+	async fn run(
+		&self,
+		document: &TenantExportDocument,
+		mode: ImportMode,
+		dry_run: bool,
+	) -> Result<ImportSummary, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+		let mut summary = ImportSummary {
+			mode,
+			dry_run,
+			..Default::default()
+		};
+
+		// Dropping the transaction on error rolls back every write
+		let mut tx = self.pool.begin().await?;
+
+		// Lock the tenant row so concurrent creates count the imported rows
+		let limits = sqlx::query_as!(
+			TenantLimits,
+			r#"
+			SELECT
+				COALESCE(max_monitors, 10)::BIGINT AS "max_monitors!",
+				COALESCE(max_networks, 5)::BIGINT AS "max_networks!",
+				COALESCE(max_triggers_per_monitor, 0)::BIGINT AS "max_triggers_per_monitor!"
+			FROM tenants WHERE id = $1 FOR NO KEY UPDATE
+			"#,
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+		Self::check_document_size(document, mode, &limits)?;
+
+		let mut networks: HashMap<&str, Uuid> = HashMap::new();
+		for entry in &document.networks {
+			let existing = sqlx::query_scalar!(
+				"SELECT id FROM tenant_networks WHERE tenant_id = $1 AND network_id = $2",
+				tenant_id,
+				entry.network_id
+			)
+			.fetch_optional(&mut *tx)
+			.await?;
+
+			let id = match existing {
+				None => {
+					summary.created.networks += 1;
+					sqlx::query_scalar!(
+						r#"
+						INSERT INTO tenant_networks (tenant_id, network_id, name, blockchain, configuration, is_active, creation_source)
+						VALUES ($1, $2, $3, $4, $5, $6, $7)
+						RETURNING id
+						"#,
+						tenant_id,
+						entry.network_id,
+						entry.name,
+						entry.blockchain,
+						entry.configuration,
+						entry.is_active,
+						CreationSource::Import.as_str()
+					)
+					.fetch_one(&mut *tx)
+					.await?
+				}
+				Some(id) if mode == ImportMode::Fail => {
+					summary
+						.conflicts
+						.push(conflict("network", &entry.network_id));
+					id
+				}
+				Some(id) if mode == ImportMode::SkipExisting => {
+					summary
+						.conflicts
+						.push(conflict("network", &entry.network_id));
+					summary.skipped.networks += 1;
+					id
+				}
+				Some(id) => {
+					summary
+						.conflicts
+						.push(conflict("network", &entry.network_id));
+					summary.replaced.networks += 1;
+					sqlx::query!(
+						r#"
+						UPDATE tenant_networks
+						SET name = $2, blockchain = $3, configuration = $4, is_active = $5, updated_at = NOW()
+						WHERE id = $1
+						"#,
+						id,
+						entry.name,
+						entry.blockchain,
+						entry.configuration,
+						entry.is_active
+					)
+					.execute(&mut *tx)
+					.await?;
+					id
+				}
+			};
+			networks.insert(entry.network_id.as_str(), id);
+		}
+
+		// `None` marks a soft-deleted monitor kept by `skip_existing`; its
+		// imported triggers are skipped along with it
+		let mut monitors: HashMap<&str, Option<Uuid>> = HashMap::new();
+		for entry in &document.monitors {
+			let network_id = match networks.get(entry.network_id.as_str()) {
+				Some(id) => *id,
+				None => Self::network_uuid(&mut tx, tenant_id, &entry.network_id).await?,
+			};
+
+			// Soft-deleted monitors still hold their ids
+			let existing = sqlx::query_as!(
+				TenantMonitor,
+				"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND monitor_id = $2 FOR UPDATE",
+				tenant_id,
+				entry.monitor_id
+			)
+			.fetch_optional(&mut *tx)
+			.await?;
+
+			let id = match existing {
+				None => {
+					summary.created.monitors += 1;
+					let id = sqlx::query_scalar!(
+						r#"
+						INSERT INTO tenant_monitors (tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source)
+						VALUES ($1, $2, $3, $4, $5, $6, $7)
+						RETURNING id
+						"#,
+						tenant_id,
+						entry.monitor_id,
+						entry.name,
+						network_id,
+						entry.configuration,
+						entry.is_active,
+						CreationSource::Import.as_str()
+					)
+					.fetch_one(&mut *tx)
+					.await?;
+					Some(id)
+				}
+				Some(existing) if mode == ImportMode::Fail => {
+					summary
+						.conflicts
+						.push(conflict("monitor", &entry.monitor_id));
+					existing.deleted_at.is_none().then_some(existing.id)
+				}
+				Some(existing) if mode == ImportMode::SkipExisting => {
+					summary
+						.conflicts
+						.push(conflict("monitor", &entry.monitor_id));
+					summary.skipped.monitors += 1;
+					existing.deleted_at.is_none().then_some(existing.id)
+				}
+				Some(existing) => {
+					summary
+						.conflicts
+						.push(conflict("monitor", &entry.monitor_id));
+					summary.replaced.monitors += 1;
+					// Replacing the configuration is recorded like any update
+					if existing.configuration != entry.configuration {
+						TenantMonitorRepository::record_config_history(
+							&mut tx,
+							&existing,
+							&existing.configuration,
+						)
+						.await?;
+					}
+					sqlx::query!(
+						r#"
+						UPDATE tenant_monitors
+						SET name = $2, network_id = $3, configuration = $4, is_active = $5,
+							deleted_at = NULL, updated_at = NOW()
+						WHERE id = $1
+						"#,
+						existing.id,
+						entry.name,
+						network_id,
+						entry.configuration,
+						entry.is_active
+					)
+					.execute(&mut *tx)
+					.await?;
+					Some(existing.id)
+				}
+			};
+			monitors.insert(entry.monitor_id.as_str(), id);
+		}
+
+		for entry in &document.triggers {
+			let monitor_id = match monitors.get(entry.monitor_id.as_str()) {
+				Some(Some(id)) => *id,
+				Some(None) => {
+					summary.skipped.triggers += 1;
+					continue;
+				}
+				None => Self::monitor_uuid(&mut tx, tenant_id, &entry.monitor_id).await?,
+			};
+
+			let existing = sqlx::query_scalar!(
+				"SELECT id FROM tenant_triggers WHERE tenant_id = $1 AND trigger_id = $2",
+				tenant_id,
+				entry.trigger_id
+			)
+			.fetch_optional(&mut *tx)
+			.await?;
+
+			match existing {
+				None => {
+					summary.created.triggers += 1;
+					sqlx::query!(
+						r#"
+						INSERT INTO tenant_triggers (tenant_id, trigger_id, monitor_id, name, type, configuration, is_active, creation_source)
+						VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+						"#,
+						tenant_id,
+						entry.trigger_id,
+						monitor_id,
+						entry.name,
+						entry.trigger_type,
+						entry.configuration,
+						entry.is_active,
+						CreationSource::Import.as_str()
+					)
+					.execute(&mut *tx)
+					.await?;
+				}
+				Some(_) if mode == ImportMode::Fail => {
+					summary
+						.conflicts
+						.push(conflict("trigger", &entry.trigger_id));
+				}
+				Some(_) if mode == ImportMode::SkipExisting => {
+					summary
+						.conflicts
+						.push(conflict("trigger", &entry.trigger_id));
+					summary.skipped.triggers += 1;
+				}
+				Some(id) => {
+					summary
+						.conflicts
+						.push(conflict("trigger", &entry.trigger_id));
+					summary.replaced.triggers += 1;
+					sqlx::query!(
+						r#"
+						UPDATE tenant_triggers
+						SET monitor_id = $2, name = $3, type = $4, configuration = $5, is_active = $6,
+							updated_at = NOW()
+						WHERE id = $1
+						"#,
+						id,
+						monitor_id,
+						entry.name,
+						entry.trigger_type,
+						entry.configuration,
+						entry.is_active
+					)
+					.execute(&mut *tx)
+					.await?;
+				}
+			}
+		}
+
+		if mode == ImportMode::Fail && !summary.conflicts.is_empty() {
+			return Err(TenantRepositoryError::ImportConflicts(summary.conflicts));
+		}
+
+		Self::check_quotas(&mut tx, tenant_id, &limits, &summary).await?;
+
+		if dry_run {
+			tx.rollback().await?;
+		} else {
+			tx.commit().await?;
+			self.quota_cache.invalidate(tenant_id);
+		}
+
+		Ok(summary)
+	}
+
+	/// Id of a network the document references but does not contain
+	async fn network_uuid(
+		tx: &mut Transaction<'_, Postgres>,
+		tenant_id: Uuid,
+		network_id: &str,
+	) -> Result<Uuid, TenantRepositoryError> {
+		sqlx::query_scalar!(
+			"SELECT id FROM tenant_networks WHERE tenant_id = $1 AND network_id = $2",
+			tenant_id,
+			network_id
+		)
+		.fetch_optional(&mut **tx)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "network".to_string(),
+			resource_id: network_id.to_string(),
+		})
+	}
+
+	/// Id of a live monitor the document references but does not contain
+	async fn monitor_uuid(
+		tx: &mut Transaction<'_, Postgres>,
+		tenant_id: Uuid,
+		monitor_id: &str,
+	) -> Result<Uuid, TenantRepositoryError> {
+		sqlx::query_scalar!(
+			"SELECT id FROM tenant_monitors WHERE tenant_id = $1 AND monitor_id = $2 AND deleted_at IS NULL FOR UPDATE",
+			tenant_id,
+			monitor_id
+		)
+		.fetch_optional(&mut **tx)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "monitor".to_string(),
+			resource_id: monitor_id.to_string(),
+		})
+	}
+
+	/// Refuse a document whose own counts exceed the tenant's quotas before
+	/// anything is written. `skip_existing` may leave a soft-deleted monitor and
+	/// its triggers out, so only networks are counted up front in that mode.
+	fn check_document_size(
+		document: &TenantExportDocument,
+		mode: ImportMode,
+		limits: &TenantLimits,
+	) -> Result<(), TenantRepositoryError> {
+		let networks = document.networks.len() as i64;
+		if networks > limits.max_networks {
+			return Err(TenantRepositoryError::QuotaExceeded(format!(
+				"Network quota exceeded: {}/{}",
+				networks, limits.max_networks
+			)));
+		}
+		if mode == ImportMode::SkipExisting {
+			return Ok(());
+		}
+
+		let monitors = document.monitors.len() as i64;
+		if monitors > limits.max_monitors {
+			return Err(TenantRepositoryError::QuotaExceeded(format!(
+				"Monitor quota exceeded: {}/{}",
+				monitors, limits.max_monitors
+			)));
+		}
+
+		let mut triggers_per_monitor: HashMap<&str, i64> = HashMap::new();
+		for trigger in &document.triggers {
+			*triggers_per_monitor
+				.entry(trigger.monitor_id.as_str())
+				.or_default() += 1;
+		}
+		if let Some((monitor_id, _)) = triggers_per_monitor
+			.into_iter()
+			.find(|(_, count)| *count > limits.max_triggers_per_monitor)
+		{
+			return Err(TenantRepositoryError::QuotaExceeded(format!(
+				"Trigger quota exceeded for monitor {}",
+				monitor_id
+			)));
+		}
+
+		Ok(())
+	}
+
+	/// Fail if the import took the tenant past a quota. Only the kinds of
+	/// resources the import wrote are checked, so a tenant already over a
+	/// lowered quota can still import the rest.
+	async fn check_quotas(
+		tx: &mut Transaction<'_, Postgres>,
+		tenant_id: Uuid,
+		limits: &TenantLimits,
+		summary: &ImportSummary,
+	) -> Result<(), TenantRepositoryError> {
+		let wrote_networks = summary.created.networks + summary.replaced.networks > 0;
+		let wrote_monitors = summary.created.monitors + summary.replaced.monitors > 0;
+		let wrote_triggers = summary.created.triggers + summary.replaced.triggers > 0;
+
+		if summary.created.networks > 0 {
+			let networks_count = sqlx::query_scalar!(
+				"SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1",
+				tenant_id
+			)
+			.fetch_one(&mut **tx)
+			.await?
+			.unwrap_or(0);
+
+			if networks_count > limits.max_networks {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Network quota exceeded: {}/{}",
+					networks_count, limits.max_networks
+				)));
+			}
+		}
+
+		if wrote_monitors {
+			let monitors_count = sqlx::query_scalar!(
+				"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL",
+				tenant_id
+			)
+			.fetch_one(&mut **tx)
+			.await?
+			.unwrap_or(0);
+
+			if monitors_count > limits.max_monitors {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Monitor quota exceeded: {}/{}",
+					monitors_count, limits.max_monitors
+				)));
+			}
+		}
+
+		if wrote_triggers {
+			let over_quota = sqlx::query_scalar!(
+				r#"
+				SELECT m.monitor_id
+				FROM tenant_triggers t
+				JOIN tenant_monitors m ON m.id = t.monitor_id
+				WHERE t.tenant_id = $1
+				GROUP BY m.monitor_id
+				HAVING COUNT(*) > $2
+				LIMIT 1
+				"#,
+				tenant_id,
+				limits.max_triggers_per_monitor
+			)
+			.fetch_optional(&mut **tx)
+			.await?;
+
+			if let Some(monitor_id) = over_quota {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Trigger quota exceeded for monitor {}",
+					monitor_id
+				)));
+			}
+		}
+
+		if wrote_networks || wrote_monitors {
+			let blockchains = sqlx::query!(
+				r#"
+				SELECT
+					q.blockchain,
+					q.max_monitors,
+					q.max_networks,
+					(SELECT COUNT(*) FROM tenant_networks n
+					 WHERE n.tenant_id = q.tenant_id AND n.blockchain = q.blockchain) AS "networks_count!",
+					(SELECT COUNT(*) FROM tenant_monitors m
+					 JOIN tenant_networks n ON n.id = m.network_id
+					 WHERE m.tenant_id = q.tenant_id AND m.deleted_at IS NULL
+					   AND n.blockchain = q.blockchain) AS "monitors_count!"
+				FROM tenant_blockchain_quotas q
+				WHERE q.tenant_id = $1
+				"#,
+				tenant_id
+			)
+			.fetch_all(&mut **tx)
+			.await?;
+
+			for quota in blockchains {
+				if let Some(max) = quota.max_networks {
+					if quota.networks_count > max as i64 {
+						return Err(TenantRepositoryError::QuotaExceeded(format!(
+							"{} network quota exceeded: {}/{}",
+							quota.blockchain, quota.networks_count, max
+						)));
+					}
+				}
+				if let Some(max) = quota.max_monitors {
+					if quota.monitors_count > max as i64 {
+						return Err(TenantRepositoryError::QuotaExceeded(format!(
+							"{} monitor quota exceeded: {}/{}",
+							quota.blockchain, quota.monitors_count, max
+						)));
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn conflict(resource_type: &str, id: &str) -> ImportConflict {
+	ImportConflict {
+		resource_type: resource_type.to_string(),
+		id: id.to_string(),
+	}
+}
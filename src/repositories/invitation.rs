@@ -0,0 +1,245 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use crate::models::{InvitationStatus, TenantInvitation, TenantRole};
+
+#[derive(Clone)]
+pub struct InvitationRepository {
+	pool: Pool<Postgres>,
+}
+
+impl InvitationRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+
+	/// Invite `email` to the tenant. Inviting an address that already has a
+	/// pending invitation updates its role but keeps its token and expiry; an
+	/// expired, revoked or accepted invitation is reissued with `token`.
+	pub async fn create(
+		&self,
+		tenant_id: Uuid,
+		email: &str,
+		role: TenantRole,
+		invited_by: Option<Uuid>,
+		token: &str,
+		expires_at: DateTime<Utc>,
+	) -> Result<TenantInvitation, TenantRepositoryError> {
+		let invitation = sqlx::query_as!(
+			TenantInvitation,
+			r#"
+			INSERT INTO tenant_invitations (tenant_id, email, role, token, invited_by, expires_at)
+			VALUES ($1, LOWER($2), $3, $4, $5, $6)
+			ON CONFLICT (tenant_id, email) DO UPDATE SET
+				role = EXCLUDED.role,
+				invited_by = EXCLUDED.invited_by,
+				token = CASE WHEN tenant_invitations.accepted_at IS NULL
+				             AND tenant_invitations.revoked_at IS NULL
+				             AND tenant_invitations.expires_at > NOW()
+				        THEN tenant_invitations.token ELSE EXCLUDED.token END,
+				expires_at = CASE WHEN tenant_invitations.accepted_at IS NULL
+				                  AND tenant_invitations.revoked_at IS NULL
+				                  AND tenant_invitations.expires_at > NOW()
+				             THEN tenant_invitations.expires_at ELSE EXCLUDED.expires_at END,
+				accepted_at = NULL,
+				accepted_by = NULL,
+				revoked_at = NULL,
+				updated_at = NOW()
+			RETURNING id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+			          expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+			"#,
+			tenant_id,
+			email.trim(),
+			role as TenantRole,
+			token,
+			invited_by,
+			expires_at
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(invitation)
+	}
+
+	/// Invitations that have been neither accepted nor revoked, newest first;
+	/// expired ones are included until they are reissued or revoked
+	pub async fn list_open(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<TenantInvitation>, TenantRepositoryError> {
+		let invitations = sqlx::query_as!(
+			TenantInvitation,
+			r#"
+			SELECT id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+			       expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+			FROM tenant_invitations
+			WHERE tenant_id = $1 AND accepted_at IS NULL AND revoked_at IS NULL
+			ORDER BY created_at DESC, id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(invitations)
+	}
+
+	pub async fn revoke(
+		&self,
+		tenant_id: Uuid,
+		invitation_id: Uuid,
+	) -> Result<TenantInvitation, TenantRepositoryError> {
+		sqlx::query_as!(
+			TenantInvitation,
+			r#"
+			UPDATE tenant_invitations
+			SET revoked_at = NOW(), updated_at = NOW()
+			WHERE tenant_id = $1 AND id = $2 AND accepted_at IS NULL AND revoked_at IS NULL
+			RETURNING id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+			          expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+			"#,
+			tenant_id,
+			invitation_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "invitation".to_string(),
+			resource_id: invitation_id.to_string(),
+		})
+	}
+
+	/// Accept the invitation with `token` as the user signed in with `email`,
+	/// adding them to the tenant. A user who is already a member keeps their role.
+	pub async fn accept(
+		&self,
+		token: &str,
+		user_id: Uuid,
+		email: &str,
+	) -> Result<TenantInvitation, TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
+
+		let invitation = sqlx::query_as!(
+			TenantInvitation,
+			r#"
+			SELECT id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+			       expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+			FROM tenant_invitations
+			WHERE token = $1
+			FOR UPDATE
+			"#,
+			token
+		)
+		.fetch_optional(&mut *tx)
+		.await?;
+
+		// A revoked invitation is treated as if it had never been sent
+		let status = invitation
+			.as_ref()
+			.map(|invitation| invitation.status(Utc::now()));
+		let invitation = match invitation {
+			Some(invitation) if status != Some(InvitationStatus::Revoked) => invitation,
+			_ => {
+				return Err(TenantRepositoryError::ResourceNotFound {
+					resource_type: "invitation".to_string(),
+					resource_id: token.to_string(),
+				})
+			}
+		};
+
+		if !invitation.is_for(email) {
+			return Err(TenantRepositoryError::AccessDenied(
+				"invitation was sent to a different email address".to_string(),
+			));
+		}
+		match status {
+			Some(InvitationStatus::Accepted) => {
+				return Err(TenantRepositoryError::AlreadyExists {
+					resource_type: "invitation acceptance".to_string(),
+					resource_id: invitation.id.to_string(),
+				})
+			}
+			Some(InvitationStatus::Expired) => {
+				return Err(TenantRepositoryError::ValidationError(
+					"invitation has expired".to_string(),
+				))
+			}
+			_ => {}
+		}
+
+		let accepted = accept_invitation(&mut tx, invitation, user_id).await?;
+
+		tx.commit().await?;
+
+		Ok(accepted)
+	}
+}
+
+/// Accept every pending invitation for `email` on behalf of the newly
+/// registered `user_id`, as part of the registration transaction
+pub async fn accept_pending_invitations(
+	tx: &mut Transaction<'_, Postgres>,
+	user_id: Uuid,
+	email: &str,
+) -> Result<Vec<TenantInvitation>, TenantRepositoryError> {
+	let pending = sqlx::query_as!(
+		TenantInvitation,
+		r#"
+		SELECT id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+		       expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+		FROM tenant_invitations
+		WHERE email = LOWER($1)
+		  AND accepted_at IS NULL AND revoked_at IS NULL AND expires_at > NOW()
+		ORDER BY created_at, id
+		FOR UPDATE
+		"#,
+		email.trim()
+	)
+	.fetch_all(&mut **tx)
+	.await?;
+
+	let mut accepted = Vec::with_capacity(pending.len());
+	for invitation in pending {
+		accepted.push(accept_invitation(tx, invitation, user_id).await?);
+	}
+
+	Ok(accepted)
+}
+
+async fn accept_invitation(
+	tx: &mut Transaction<'_, Postgres>,
+	invitation: TenantInvitation,
+	user_id: Uuid,
+) -> Result<TenantInvitation, TenantRepositoryError> {
+	sqlx::query!(
+		r#"
+		INSERT INTO tenant_memberships (tenant_id, user_id, role)
+		VALUES ($1, $2, $3)
+		ON CONFLICT (tenant_id, user_id) DO NOTHING
+		"#,
+		invitation.tenant_id,
+		user_id,
+		invitation.role as TenantRole
+	)
+	.execute(&mut **tx)
+	.await?;
+
+	let accepted = sqlx::query_as!(
+		TenantInvitation,
+		r#"
+		UPDATE tenant_invitations
+		SET accepted_at = NOW(), accepted_by = $2, updated_at = NOW()
+		WHERE id = $1
+		RETURNING id, tenant_id, email, role as "role: TenantRole", token, invited_by,
+		          expires_at, accepted_at, accepted_by, revoked_at, created_at, updated_at
+		"#,
+		invitation.id,
+		user_id
+	)
+	.fetch_one(&mut **tx)
+	.await?;
+
+	Ok(accepted)
+}
@@ -1,11 +1,30 @@
+pub mod api_key;
+pub mod audit;
 pub mod error;
+pub mod feed;
+pub mod idempotency;
+pub mod import;
+pub mod invitation;
 pub mod monitor;
 pub mod network;
 pub mod tenant;
 pub mod trigger;
+pub mod usage;
+pub mod webhook;
 
+pub use api_key::*;
+pub use audit::*;
 pub use error::*;
+pub use feed::*;
+pub use idempotency::*;
+pub use import::*;
+pub use invitation::*;
 pub use monitor::*;
 pub use network::*;
 pub use tenant::*;
 pub use trigger::*;
+pub use usage::*;
+pub use webhook::*;
+
+/// The schema migrations, embedded at build time
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
@@ -1,11 +1,17 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+use sqlx::{Pool, Postgres, QueryBuilder, Transaction};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::error::TenantRepositoryError;
-use crate::models::{CreateMonitorRequest, TenantMonitor, UpdateMonitorRequest};
-use crate::utils::current_tenant_id;
+use super::tenant::escape_like;
+use crate::models::{
+	CreateMonitorRequest, CreationSource, ListCursor, MonitorFilter, MonitorRollback, MonitorSort,
+	TenantMonitor, TenantTrigger, UpdateMonitorRequest,
+};
+use crate::utils::{current_tenant_id, QuotaStatusCache};
 
 // This trait mimics the OpenZeppelin Monitor's MonitorRepositoryTrait
 // but adds tenant isolation
@@ -15,33 +21,162 @@ pub trait TenantMonitorRepositoryTrait: Clone + Send + Sync {
 		&self,
 		request: CreateMonitorRequest,
 	) -> Result<TenantMonitor, TenantRepositoryError>;
+	async fn create_with_source(
+		&self,
+		request: CreateMonitorRequest,
+		source: CreationSource,
+	) -> Result<TenantMonitor, TenantRepositoryError>;
 	async fn get(&self, monitor_id: &str) -> Result<TenantMonitor, TenantRepositoryError>;
 	async fn get_by_uuid(&self, id: Uuid) -> Result<TenantMonitor, TenantRepositoryError>;
 	async fn get_all(&self) -> Result<HashMap<String, TenantMonitor>, TenantRepositoryError>;
+	/// Replacing the configuration records the previous one in `monitor_config_history`
 	async fn update(
 		&self,
 		monitor_id: &str,
 		request: UpdateMonitorRequest,
 	) -> Result<TenantMonitor, TenantRepositoryError>;
+	/// Restore the configuration saved as `version`; the replaced one is recorded like any update
+	async fn rollback(
+		&self,
+		monitor_id: &str,
+		version: i32,
+	) -> Result<MonitorRollback, TenantRepositoryError>;
+	/// Soft-delete: the monitor is hidden until restored or purged
+	/// Soft-delete a monitor that has no triggers left
 	async fn delete(&self, monitor_id: &str) -> Result<(), TenantRepositoryError>;
+	// Delete a monitor's triggers and then the monitor in a single transaction
+	async fn delete_with_triggers(
+		&self,
+		monitor_id: &str,
+	) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError>;
+	async fn restore(&self, monitor_id: &str) -> Result<TenantMonitor, TenantRepositoryError>;
 	async fn list(
 		&self,
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+	/// Keyset page ordered by `(created_at, id)` descending, starting after `cursor`
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+	async fn list_including_deleted(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+	async fn list_filtered(
+		&self,
+		filter: MonitorFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+	async fn count_filtered(&self, filter: MonitorFilter) -> Result<i64, TenantRepositoryError>;
+
+	// Deactivate a monitor and all of its triggers in a single transaction
+	async fn deactivate_with_triggers(
+		&self,
+		monitor_id: &str,
+	) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError>;
 
 	// Check if we can create more monitors
 	async fn check_quota(&self) -> Result<bool, TenantRepositoryError>;
+
+	/// Whether a live monitor on `network_id` already watches `contract_address` (case-insensitive)
+	async fn contract_address_in_use(
+		&self,
+		network_id: Uuid,
+		contract_address: &str,
+	) -> Result<bool, TenantRepositoryError>;
+
+	/// Permanently remove monitors soft-deleted before `cutoff`, across all tenants
+	async fn purge_deleted_before(
+		&self,
+		cutoff: DateTime<Utc>,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+
+	/// The monitor's active triggers without a successful test delivery since
+	/// their configuration last changed, oldest first
+	async fn list_untested_triggers(
+		&self,
+		monitor_id: Uuid,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
 }
 
 #[derive(Clone)]
 pub struct TenantMonitorRepository {
 	pool: Pool<Postgres>,
+	quota_cache: QuotaStatusCache,
 }
 
 impl TenantMonitorRepository {
 	pub fn new(pool: Pool<Postgres>) -> Self {
-		Self { pool }
+		Self {
+			pool,
+			quota_cache: QuotaStatusCache::default(),
+		}
+	}
+
+	/// Invalidate the tenant's cached quota status whenever a write changes its counts
+	pub fn with_quota_cache(mut self, cache: QuotaStatusCache) -> Self {
+		self.quota_cache = cache;
+		self
+	}
+
+	/// Lock a live monitor for the rest of the transaction
+	async fn lock_for_update(
+		tx: &mut Transaction<'_, Postgres>,
+		tenant_id: Uuid,
+		monitor_id: &str,
+	) -> Result<TenantMonitor, TenantRepositoryError> {
+		sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			FROM tenant_monitors
+			WHERE tenant_id = $1 AND monitor_id = $2 AND deleted_at IS NULL
+			FOR UPDATE
+			"#,
+			tenant_id,
+			monitor_id
+		)
+		.fetch_optional(&mut **tx)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "monitor".to_string(),
+			resource_id: monitor_id.to_string(),
+		})
+	}
+
+	/// Save `configuration` as the monitor's next history version and return that version
+	pub(super) async fn record_config_history(
+		tx: &mut Transaction<'_, Postgres>,
+		monitor: &TenantMonitor,
+		configuration: &JsonValue,
+	) -> Result<i32, TenantRepositoryError> {
+		let version = sqlx::query_scalar!(
+			r#"
+			INSERT INTO monitor_config_history (tenant_id, monitor_id, version, configuration)
+			SELECT $1, $2, COALESCE(MAX(version), 0) + 1, $3
+			FROM monitor_config_history
+			WHERE monitor_id = $2
+			RETURNING version
+			"#,
+			monitor.tenant_id,
+			monitor.id,
+			configuration
+		)
+		.fetch_one(&mut **tx)
+		.await?;
+
+		Ok(version)
 	}
 }
 
@@ -50,11 +185,38 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 	async fn create(
 		&self,
 		request: CreateMonitorRequest,
+	) -> Result<TenantMonitor, TenantRepositoryError> {
+		self.create_with_source(request, CreationSource::Api).await
+	}
+
+	async fn create_with_source(
+		&self,
+		request: CreateMonitorRequest,
+		source: CreationSource,
 	) -> Result<TenantMonitor, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
-		// Check quota
-		if !self.check_quota().await? {
+		// Dropping the transaction on error rolls back the insert
+		let mut tx = self.pool.begin().await?;
+
+		// Lock the tenant row so concurrent creates count each other's inserts
+		let max_monitors = sqlx::query_scalar!(
+			"SELECT max_monitors FROM tenants WHERE id = $1 FOR NO KEY UPDATE",
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		let current_count = sqlx::query_scalar!(
+			"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL",
+			tenant_id
+		)
+		.fetch_one(&mut *tx)
+		.await?
+		.unwrap_or(0);
+
+		if current_count >= max_monitors.unwrap_or(10) as i64 {
 			return Err(TenantRepositoryError::QuotaExceeded(
 				"Monitor quota exceeded".to_string(),
 			));
@@ -66,7 +228,7 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 			tenant_id,
 			request.monitor_id
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
 		if existing.unwrap_or(0) > 0 {
@@ -79,19 +241,23 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 		let monitor = sqlx::query_as!(
 			TenantMonitor,
 			r#"
-			INSERT INTO tenant_monitors (tenant_id, monitor_id, name, network_id, configuration)
-			VALUES ($1, $2, $3, $4, $5)
-			RETURNING *
+			INSERT INTO tenant_monitors (tenant_id, monitor_id, name, network_id, configuration, creation_source)
+			VALUES ($1, $2, $3, $4, $5, $6)
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
 			"#,
 			tenant_id,
 			request.monitor_id,
 			request.name,
 			request.network_id,
-			request.configuration
+			request.configuration,
+			source.as_str()
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
 		Ok(monitor)
 	}
 
@@ -100,7 +266,7 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		let monitor = sqlx::query_as!(
 			TenantMonitor,
-			"SELECT * FROM tenant_monitors WHERE tenant_id = $1 AND monitor_id = $2",
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND monitor_id = $2 AND deleted_at IS NULL",
 			tenant_id,
 			monitor_id
 		)
@@ -119,7 +285,7 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		let monitor = sqlx::query_as!(
 			TenantMonitor,
-			"SELECT * FROM tenant_monitors WHERE tenant_id = $1 AND id = $2",
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND id = $2 AND deleted_at IS NULL",
 			tenant_id,
 			id
 		)
@@ -138,7 +304,7 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		let monitors = sqlx::query_as!(
 			TenantMonitor,
-			"SELECT * FROM tenant_monitors WHERE tenant_id = $1 AND is_active = true",
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND is_active = true AND deleted_at IS NULL",
 			tenant_id
 		)
 		.fetch_all(&self.pool)
@@ -159,55 +325,206 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 	) -> Result<TenantMonitor, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
+		// The row lock keeps history versions in step with concurrent updates
+		let mut tx = self.pool.begin().await?;
+		let existing = Self::lock_for_update(&mut tx, tenant_id, monitor_id).await?;
+
+		if request
+			.configuration
+			.as_ref()
+			.is_some_and(|configuration| *configuration != existing.configuration)
+		{
+			Self::record_config_history(&mut tx, &existing, &existing.configuration).await?;
+		}
+
 		let monitor = sqlx::query_as!(
 			TenantMonitor,
 			r#"
 			UPDATE tenant_monitors
 			SET 
-				name = COALESCE($3, name),
-				configuration = COALESCE($4, configuration),
-				is_active = COALESCE($5, is_active),
+				name = COALESCE($2, name),
+				configuration = COALESCE($3, configuration),
+				is_active = COALESCE($4, is_active),
 				updated_at = NOW()
-			WHERE tenant_id = $1 AND monitor_id = $2
-			RETURNING *
+			WHERE id = $1
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
 			"#,
-			tenant_id,
-			monitor_id,
+			existing.id,
 			request.name,
 			request.configuration,
 			request.is_active
 		)
-		.fetch_optional(&self.pool)
+		.fetch_one(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(monitor)
+	}
+
+	async fn rollback(
+		&self,
+		monitor_id: &str,
+		version: i32,
+	) -> Result<MonitorRollback, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let mut tx = self.pool.begin().await?;
+		let existing = Self::lock_for_update(&mut tx, tenant_id, monitor_id).await?;
+
+		let configuration = sqlx::query_scalar!(
+			r#"
+			SELECT configuration FROM monitor_config_history
+			WHERE tenant_id = $1 AND monitor_id = $2 AND version = $3
+			"#,
+			tenant_id,
+			existing.id,
+			version
+		)
+		.fetch_optional(&mut *tx)
 		.await?
 		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
-			resource_type: "monitor".to_string(),
-			resource_id: monitor_id.to_string(),
+			resource_type: "monitor version".to_string(),
+			resource_id: format!("{}@{}", monitor_id, version),
 		})?;
 
-		Ok(monitor)
+		// The replaced configuration takes the next history slot, so the
+		// restored one is in effect as the version after it
+		let archived =
+			Self::record_config_history(&mut tx, &existing, &existing.configuration).await?;
+
+		let monitor = sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			UPDATE tenant_monitors
+			SET configuration = $2, updated_at = NOW()
+			WHERE id = $1
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			"#,
+			existing.id,
+			configuration
+		)
+		.fetch_one(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(MonitorRollback {
+			monitor,
+			restored_version: version,
+			version: archived + 1,
+		})
 	}
 
 	async fn delete(&self, monitor_id: &str) -> Result<(), TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
-		let result = sqlx::query!(
-			"DELETE FROM tenant_monitors WHERE tenant_id = $1 AND monitor_id = $2",
+		// The row lock keeps a trigger from being added between the check and the delete
+		let mut tx = self.pool.begin().await?;
+		let monitor = Self::lock_for_update(&mut tx, tenant_id, monitor_id).await?;
+
+		let trigger_ids = sqlx::query_scalar!(
+			r#"
+			SELECT trigger_id FROM tenant_triggers
+			WHERE tenant_id = $1 AND monitor_id = $2
+			ORDER BY trigger_id
+			"#,
 			tenant_id,
-			monitor_id
+			monitor.id
 		)
-		.execute(&self.pool)
+		.fetch_all(&mut *tx)
 		.await?;
-
-		if result.rows_affected() == 0 {
-			return Err(TenantRepositoryError::ResourceNotFound {
-				resource_type: "monitor".to_string(),
-				resource_id: monitor_id.to_string(),
+		if !trigger_ids.is_empty() {
+			return Err(TenantRepositoryError::MonitorHasTriggers {
+				monitor_id: monitor_id.to_string(),
+				trigger_ids,
 			});
 		}
 
+		sqlx::query!(
+			r#"
+			UPDATE tenant_monitors
+			SET deleted_at = NOW(), updated_at = NOW()
+			WHERE id = $1
+			"#,
+			monitor.id
+		)
+		.execute(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
 		Ok(())
 	}
 
+	async fn delete_with_triggers(
+		&self,
+		monitor_id: &str,
+	) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		// Both deletes share one transaction; dropping it on error rolls back
+		let mut tx = self.pool.begin().await?;
+		let monitor = Self::lock_for_update(&mut tx, tenant_id, monitor_id).await?;
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			DELETE FROM tenant_triggers
+			WHERE tenant_id = $1 AND monitor_id = $2
+			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			"#,
+			tenant_id,
+			monitor.id
+		)
+		.fetch_all(&mut *tx)
+		.await?;
+
+		let monitor = sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			UPDATE tenant_monitors
+			SET deleted_at = NOW(), updated_at = NOW()
+			WHERE id = $1
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			"#,
+			monitor.id
+		)
+		.fetch_one(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok((monitor, triggers))
+	}
+
+	async fn restore(&self, monitor_id: &str) -> Result<TenantMonitor, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let monitor = sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			UPDATE tenant_monitors
+			SET deleted_at = NULL, updated_at = NOW()
+			WHERE tenant_id = $1 AND monitor_id = $2 AND deleted_at IS NOT NULL
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			"#,
+			tenant_id,
+			monitor_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "deleted monitor".to_string(),
+			resource_id: monitor_id.to_string(),
+		})?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(monitor)
+	}
+
 	async fn list(
 		&self,
 		limit: i64,
@@ -217,8 +534,78 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		let monitors = sqlx::query_as!(
 			TenantMonitor,
-			"SELECT * FROM tenant_monitors WHERE tenant_id = $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3",
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC LIMIT $2 OFFSET $3",
+			tenant_id,
+			limit,
+			offset
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(monitors)
+	}
+
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let items = sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			FROM tenant_monitors
+			WHERE tenant_id = $1 AND deleted_at IS NULL
+			AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3::uuid))
+			ORDER BY created_at DESC, id DESC
+			LIMIT $4
+			"#,
+			tenant_id,
+			cursor.map(|c| c.created_at),
+			cursor.map(|c| c.id),
+			limit
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(items)
+	}
+
+	async fn list_including_deleted(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let monitors = sqlx::query_as!(
+			TenantMonitor,
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3",
+			tenant_id,
+			limit,
+			offset
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(monitors)
+	}
+
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let monitors = sqlx::query_as!(
+			TenantMonitor,
+			"SELECT id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at FROM tenant_monitors WHERE tenant_id = $1 AND creation_source = $2 AND deleted_at IS NULL ORDER BY created_at DESC LIMIT $3 OFFSET $4",
 			tenant_id,
+			source.as_str(),
 			limit,
 			offset
 		)
@@ -228,6 +615,98 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 		Ok(monitors)
 	}
 
+	async fn list_filtered(
+		&self,
+		filter: MonitorFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let mut query = QueryBuilder::new("SELECT m.* FROM tenant_monitors m WHERE m.tenant_id = ");
+		query.push_bind(tenant_id);
+		push_monitor_filters(&mut query, &filter);
+		query
+			.push(match filter.sort {
+				MonitorSort::Name => " ORDER BY m.name ASC, m.id ASC",
+				MonitorSort::CreatedAt => " ORDER BY m.created_at ASC, m.id ASC",
+				MonitorSort::CreatedAtDesc => " ORDER BY m.created_at DESC, m.id DESC",
+			})
+			.push(" LIMIT ")
+			.push_bind(limit)
+			.push(" OFFSET ")
+			.push_bind(offset);
+
+		let monitors = query
+			.build_query_as::<TenantMonitor>()
+			.fetch_all(&self.pool)
+			.await?;
+
+		Ok(monitors)
+	}
+
+	async fn count_filtered(&self, filter: MonitorFilter) -> Result<i64, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let mut query =
+			QueryBuilder::new("SELECT COUNT(*) FROM tenant_monitors m WHERE m.tenant_id = ");
+		query.push_bind(tenant_id);
+		push_monitor_filters(&mut query, &filter);
+
+		let count = query
+			.build_query_scalar::<i64>()
+			.fetch_one(&self.pool)
+			.await?;
+
+		Ok(count)
+	}
+
+	async fn deactivate_with_triggers(
+		&self,
+		monitor_id: &str,
+	) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		// Both updates share one transaction; dropping it on error rolls back
+		let mut tx = self.pool.begin().await?;
+
+		let monitor = sqlx::query_as!(
+			TenantMonitor,
+			r#"
+			UPDATE tenant_monitors
+			SET is_active = false, updated_at = NOW()
+			WHERE tenant_id = $1 AND monitor_id = $2 AND deleted_at IS NULL
+			RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at
+			"#,
+			tenant_id,
+			monitor_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "monitor".to_string(),
+			resource_id: monitor_id.to_string(),
+		})?;
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			UPDATE tenant_triggers
+			SET is_active = false, updated_at = NOW()
+			WHERE tenant_id = $1 AND monitor_id = $2 AND is_active = true
+			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			"#,
+			tenant_id,
+			monitor.id
+		)
+		.fetch_all(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok((monitor, triggers))
+	}
+
 	async fn check_quota(&self) -> Result<bool, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
@@ -240,7 +719,7 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		// Get current count
 		let current_count = sqlx::query_scalar!(
-			"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1",
+			"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL",
 			tenant_id
 		)
 		.fetch_one(&self.pool)
@@ -249,4 +728,100 @@ impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 
 		Ok(current_count < max_monitors.unwrap_or(10) as i64)
 	}
+
+	async fn contract_address_in_use(
+		&self,
+		network_id: Uuid,
+		contract_address: &str,
+	) -> Result<bool, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let in_use = sqlx::query_scalar!(
+			r#"
+			SELECT EXISTS (
+				SELECT 1 FROM tenant_monitors
+				WHERE tenant_id = $1 AND network_id = $2 AND deleted_at IS NULL
+				AND LOWER(configuration->>'contract_address') = LOWER($3)
+			) as "in_use!"
+			"#,
+			tenant_id,
+			network_id,
+			contract_address
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(in_use)
+	}
+
+	async fn list_untested_triggers(
+		&self,
+		monitor_id: Uuid,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			FROM tenant_triggers
+			WHERE tenant_id = $1 AND monitor_id = $2 AND is_active = true
+			AND last_successful_test_at IS NULL
+			ORDER BY created_at, id
+			"#,
+			tenant_id,
+			monitor_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(triggers)
+	}
+
+	async fn purge_deleted_before(
+		&self,
+		cutoff: DateTime<Utc>,
+	) -> Result<Vec<TenantMonitor>, TenantRepositoryError> {
+		let monitors = sqlx::query_as!(
+			TenantMonitor,
+			"DELETE FROM tenant_monitors WHERE deleted_at < $1 RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at",
+			cutoff
+		)
+		.fetch_all(&self.pool)
+		.await?;
+		// Purged monitors take their triggers with them
+		for monitor in &monitors {
+			self.quota_cache.invalidate(monitor.tenant_id);
+		}
+
+		Ok(monitors)
+	}
+}
+
+/// Conditions on `tenant_monitors m` for the filtered listing
+fn push_monitor_filters<'a>(query: &mut QueryBuilder<'a, Postgres>, filter: &'a MonitorFilter) {
+	query.push(" AND m.deleted_at IS NULL");
+	if let Some(is_active) = filter.is_active {
+		query
+			.push(" AND COALESCE(m.is_active, true) = ")
+			.push_bind(is_active);
+	}
+	if let Some(network) = filter.network.as_deref() {
+		query
+			.push(" AND m.network_id IN (SELECT n.id FROM tenant_networks n WHERE n.tenant_id = m.tenant_id AND (n.network_id = ")
+			.push_bind(network);
+		if let Ok(id) = Uuid::parse_str(network) {
+			query.push(" OR n.id = ").push_bind(id);
+		}
+		query.push("))");
+	}
+	if let Some(search) = filter.search.as_deref().filter(|search| !search.is_empty()) {
+		let pattern = format!("%{}%", escape_like(search));
+		query
+			.push(" AND (m.name ILIKE ")
+			.push_bind(pattern.clone())
+			.push(" OR m.monitor_id ILIKE ")
+			.push_bind(pattern)
+			.push(")");
+	}
 }
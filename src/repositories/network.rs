@@ -1,11 +1,14 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use sqlx::{Pool, Postgres, Transaction};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::error::TenantRepositoryError;
-use crate::models::{CreateNetworkRequest, TenantNetwork, UpdateNetworkRequest};
-use crate::utils::current_tenant_id;
+use crate::models::{
+	CreateNetworkRequest, CreationSource, DependentMonitor, ListCursor, NetworkDeletion,
+	TenantMonitor, TenantNetwork, TenantTrigger, UpdateNetworkRequest,
+};
+use crate::utils::{current_tenant_id, QuotaStatusCache};
 
 #[async_trait]
 pub trait TenantNetworkRepositoryTrait: Clone + Send + Sync {
@@ -21,12 +24,30 @@ pub trait TenantNetworkRepositoryTrait: Clone + Send + Sync {
 		network_id: &str,
 		request: UpdateNetworkRequest,
 	) -> Result<TenantNetwork, TenantRepositoryError>;
+	/// Delete a network no monitor uses any more
 	async fn delete(&self, network_id: &str) -> Result<(), TenantRepositoryError>;
+	// Delete a network with its monitors and their triggers in a single transaction
+	async fn delete_with_monitors(
+		&self,
+		network_id: &str,
+	) -> Result<NetworkDeletion, TenantRepositoryError>;
 	async fn list(
 		&self,
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
+	/// Keyset page ordered by `(created_at, id)` descending, starting after `cursor`
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
 
 	// Check if we can create more networks
 	async fn check_quota(&self) -> Result<bool, TenantRepositoryError>;
@@ -35,11 +56,40 @@ pub trait TenantNetworkRepositoryTrait: Clone + Send + Sync {
 #[derive(Clone)]
 pub struct TenantNetworkRepository {
 	pool: Pool<Postgres>,
+	quota_cache: QuotaStatusCache,
 }
 
 impl TenantNetworkRepository {
 	pub fn new(pool: Pool<Postgres>) -> Self {
-		Self { pool }
+		Self {
+			pool,
+			quota_cache: QuotaStatusCache::default(),
+		}
+	}
+
+	/// Invalidate the tenant's cached quota status whenever a write changes its counts
+	pub fn with_quota_cache(mut self, cache: QuotaStatusCache) -> Self {
+		self.quota_cache = cache;
+		self
+	}
+
+	/// Lock a network for the rest of the transaction and return its internal id
+	async fn lock_for_update(
+		tx: &mut Transaction<'_, Postgres>,
+		tenant_id: Uuid,
+		network_id: &str,
+	) -> Result<Uuid, TenantRepositoryError> {
+		sqlx::query_scalar!(
+			"SELECT id FROM tenant_networks WHERE tenant_id = $1 AND network_id = $2 FOR UPDATE",
+			tenant_id,
+			network_id
+		)
+		.fetch_optional(&mut **tx)
+		.await?
+		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+			resource_type: "network".to_string(),
+			resource_id: network_id.to_string(),
+		})
 	}
 }
 
@@ -51,8 +101,27 @@ impl TenantNetworkRepositoryTrait for TenantNetworkRepository {
 	) -> Result<TenantNetwork, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
-		// Check quota
-		if !self.check_quota().await? {
+		// Dropping the transaction on error rolls back the insert
+		let mut tx = self.pool.begin().await?;
+
+		// Lock the tenant row so concurrent creates count each other's inserts
+		let max_networks = sqlx::query_scalar!(
+			"SELECT max_networks FROM tenants WHERE id = $1 FOR NO KEY UPDATE",
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		let current_count = sqlx::query_scalar!(
+			"SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1",
+			tenant_id
+		)
+		.fetch_one(&mut *tx)
+		.await?
+		.unwrap_or(0);
+
+		if current_count >= max_networks.unwrap_or(5) as i64 {
 			return Err(TenantRepositoryError::QuotaExceeded(
 				"Network quota exceeded".to_string(),
 			));
@@ -64,7 +133,7 @@ impl TenantNetworkRepositoryTrait for TenantNetworkRepository {
 			tenant_id,
 			request.network_id
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
 		if existing.unwrap_or(0) > 0 {
@@ -87,9 +156,12 @@ impl TenantNetworkRepositoryTrait for TenantNetworkRepository {
 			request.blockchain,
 			request.configuration
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
 		Ok(network)
 	}
 
@@ -188,44 +260,89 @@ impl TenantNetworkRepositoryTrait for TenantNetworkRepository {
 	async fn delete(&self, network_id: &str) -> Result<(), TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
-		// Check if any monitors are using this network
-		let monitors_using = sqlx::query_scalar!(
+		// The row lock keeps the check and the delete consistent with each other
+		let mut tx = self.pool.begin().await?;
+		let id = Self::lock_for_update(&mut tx, tenant_id, network_id).await?;
+
+		let monitors = sqlx::query_as!(
+			DependentMonitor,
 			r#"
-			SELECT COUNT(*) 
-			FROM tenant_monitors 
-			WHERE tenant_id = $1 
-			AND network_id = (SELECT id FROM tenant_networks WHERE tenant_id = $1 AND network_id = $2)
+			SELECT id, monitor_id, name, deleted_at IS NOT NULL as "deleted!"
+			FROM tenant_monitors
+			WHERE tenant_id = $1 AND network_id = $2
+			ORDER BY monitor_id
 			"#,
 			tenant_id,
-			network_id
+			id
 		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0);
-
-		if monitors_using > 0 {
-			return Err(TenantRepositoryError::ValidationError(format!(
-				"Cannot delete network: {} monitors are using it",
-				monitors_using
-			)));
+		.fetch_all(&mut *tx)
+		.await?;
+		if !monitors.is_empty() {
+			return Err(TenantRepositoryError::NetworkHasMonitors {
+				network_id: network_id.to_string(),
+				monitors,
+			});
 		}
 
-		let result = sqlx::query!(
-			"DELETE FROM tenant_networks WHERE tenant_id = $1 AND network_id = $2",
+		sqlx::query!("DELETE FROM tenant_networks WHERE id = $1", id)
+			.execute(&mut *tx)
+			.await?;
+
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(())
+	}
+
+	async fn delete_with_monitors(
+		&self,
+		network_id: &str,
+	) -> Result<NetworkDeletion, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		// All three deletes share one transaction; dropping it on error rolls back
+		let mut tx = self.pool.begin().await?;
+		let id = Self::lock_for_update(&mut tx, tenant_id, network_id).await?;
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			DELETE FROM tenant_triggers
+			WHERE tenant_id = $1
+			AND monitor_id IN (SELECT id FROM tenant_monitors WHERE tenant_id = $1 AND network_id = $2)
+			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			"#,
 			tenant_id,
-			network_id
+			id
 		)
-		.execute(&self.pool)
+		.fetch_all(&mut *tx)
 		.await?;
 
-		if result.rows_affected() == 0 {
-			return Err(TenantRepositoryError::ResourceNotFound {
-				resource_type: "network".to_string(),
-				resource_id: network_id.to_string(),
-			});
-		}
+		let monitors = sqlx::query_as!(
+			TenantMonitor,
+			"DELETE FROM tenant_monitors WHERE tenant_id = $1 AND network_id = $2 RETURNING id, tenant_id, monitor_id, name, network_id, configuration, is_active, creation_source, created_at, updated_at, deleted_at",
+			tenant_id,
+			id
+		)
+		.fetch_all(&mut *tx)
+		.await?;
 
-		Ok(())
+		let network = sqlx::query_as!(
+			TenantNetwork,
+			"DELETE FROM tenant_networks WHERE id = $1 RETURNING *",
+			id
+		)
+		.fetch_one(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(NetworkDeletion {
+			network,
+			monitors,
+			triggers,
+		})
 	}
 
 	async fn list(
@@ -248,6 +365,55 @@ impl TenantNetworkRepositoryTrait for TenantNetworkRepository {
 		Ok(networks)
 	}
 
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantNetwork>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let items = sqlx::query_as!(
+			TenantNetwork,
+			r#"
+			SELECT * FROM tenant_networks
+			WHERE tenant_id = $1
+			AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3::uuid))
+			ORDER BY created_at DESC, id DESC
+			LIMIT $4
+			"#,
+			tenant_id,
+			cursor.map(|c| c.created_at),
+			cursor.map(|c| c.id),
+			limit
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(items)
+	}
+
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let networks = sqlx::query_as!(
+			TenantNetwork,
+			"SELECT * FROM tenant_networks WHERE tenant_id = $1 AND creation_source = $2 ORDER BY created_at DESC LIMIT $3 OFFSET $4",
+			tenant_id,
+			source.as_str(),
+			limit,
+			offset
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(networks)
+	}
+
 	async fn check_quota(&self) -> Result<bool, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
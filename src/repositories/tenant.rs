@@ -1,16 +1,34 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use sqlx::{Pool, Postgres, QueryBuilder};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use uuid::Uuid;
 
 use super::error::TenantRepositoryError;
 use crate::models::{
-	AvailableResources, CreateTenantRequest, CurrentUsage, ResourceQuotaStatus, Tenant,
-	TenantMembership, TenantQuotas, TenantRole, UpdateTenantRequest,
+	slug_rename_blocked_until, slugify, BatchMemberEntry, BatchMemberResult, BatchMemberStatus,
+	BlockchainQuota, BlockchainQuotaStatus, CreateTenantRequest, CurrentUsage, DuplicateMembership,
+	IntegrityViolation, IntegrityViolationKind, MemberSyncAction, MemberSyncChange,
+	MemberSyncResult, QuotaCheck, QuotaCheckResult, ResourceCounts, ResourceQuotaStatus,
+	SsoIdentity, Tenant, TenantFilter, TenantMembership, TenantQuotas, TenantRole, TriggerDefaults,
+	UpdateTenantRequest, User, DEFAULT_RESERVED_SLUGS,
 };
+use crate::utils::QuotaStatusCache;
 
 #[async_trait]
 pub trait TenantRepositoryTrait: Clone + Send + Sync {
 	async fn create(&self, request: CreateTenantRequest) -> Result<Tenant, TenantRepositoryError>;
+	/// Create a tenant and make `owner_id` its owner in one transaction
+	async fn create_with_owner(
+		&self,
+		request: CreateTenantRequest,
+		owner_id: Uuid,
+	) -> Result<Tenant, TenantRepositoryError>;
+	/// Reject slugs that break the slug format rules or are on the configured reserved list
+	fn validate_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
+	/// Reject a slug held by a tenant deleted within the configured retention
+	/// window, so it can still be restored under it
+	async fn check_deleted_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
 	async fn get(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
 	async fn get_by_slug(&self, slug: &str) -> Result<Tenant, TenantRepositoryError>;
 	async fn update(
@@ -18,8 +36,29 @@ pub trait TenantRepositoryTrait: Clone + Send + Sync {
 		tenant_id: Uuid,
 		request: UpdateTenantRequest,
 	) -> Result<Tenant, TenantRepositoryError>;
-	async fn delete(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
+	async fn rename_slug(
+		&self,
+		tenant_id: Uuid,
+		slug: &str,
+	) -> Result<Tenant, TenantRepositoryError>;
+	/// Soft-delete: the tenant is hidden and deactivated but its data is kept.
+	/// Rejected while the tenant still has resources unless `force` is set, in
+	/// which case its monitors are soft-deleted along with it
+	async fn delete(&self, tenant_id: Uuid, force: bool) -> Result<(), TenantRepositoryError>;
+	/// Undo a soft-delete
+	async fn restore(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
+	/// Permanently delete the tenant and everything it owns
+	async fn purge(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
 	async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError>;
+	/// Tenants matching `filter`, newest first
+	async fn list_filtered(
+		&self,
+		filter: TenantFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<Tenant>, TenantRepositoryError>;
+	/// Number of tenants matching `filter`, ignoring pagination
+	async fn count_filtered(&self, filter: TenantFilter) -> Result<i64, TenantRepositoryError>;
 
 	// Membership management
 	async fn add_member(
@@ -28,17 +67,26 @@ pub trait TenantRepositoryTrait: Clone + Send + Sync {
 		user_id: Uuid,
 		role: TenantRole,
 	) -> Result<TenantMembership, TenantRepositoryError>;
+	/// Rejected if it would leave the tenant without an owner
 	async fn remove_member(
 		&self,
 		tenant_id: Uuid,
 		user_id: Uuid,
 	) -> Result<(), TenantRepositoryError>;
+	/// Rejected if it would leave the tenant without an owner
 	async fn update_member_role(
 		&self,
 		tenant_id: Uuid,
 		user_id: Uuid,
 		role: TenantRole,
 	) -> Result<TenantMembership, TenantRepositoryError>;
+	/// Promote `to_user` to owner and demote `from_user`, who must be an owner, to admin
+	async fn transfer_ownership(
+		&self,
+		tenant_id: Uuid,
+		from_user: Uuid,
+		to_user: Uuid,
+	) -> Result<(), TenantRepositoryError>;
 	async fn get_members(
 		&self,
 		tenant_id: Uuid,
@@ -47,62 +95,334 @@ pub trait TenantRepositoryTrait: Clone + Send + Sync {
 		&self,
 		user_id: Uuid,
 	) -> Result<Vec<(Tenant, TenantRole)>, TenantRepositoryError>;
+	/// Add several members in one transaction. Unknown users, existing members
+	/// and repeats are reported per entry and skipped; the whole batch fails if
+	/// the members it adds would take the tenant past its member limit
+	async fn add_members_batch(
+		&self,
+		tenant_id: Uuid,
+		entries: Vec<BatchMemberEntry>,
+	) -> Result<Vec<BatchMemberResult>, TenantRepositoryError>;
+	/// Make the tenant's members exactly `entries` in one transaction: add the
+	/// missing users, change differing roles and remove everyone else. Fails
+	/// as a whole on an unknown or repeated user, a set without an owner, or
+	/// a changed set of owners unless `change_owners` is set
+	async fn sync_members(
+		&self,
+		tenant_id: Uuid,
+		entries: Vec<BatchMemberEntry>,
+		change_owners: bool,
+	) -> Result<MemberSyncResult, TenantRepositoryError>;
+	/// Memberships recorded more than once for the same tenant and user, across all tenants
+	async fn find_duplicate_memberships(
+		&self,
+	) -> Result<Vec<DuplicateMembership>, TenantRepositoryError>;
+	/// Monitors and triggers of the tenant that point at another tenant's rows or at none
+	async fn find_integrity_violations(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<IntegrityViolation>, TenantRepositoryError>;
+
+	// SSO identities
+	/// User linked to an external identity, if any
+	async fn find_user_by_identity(
+		&self,
+		issuer: &str,
+		subject: &str,
+	) -> Result<Option<User>, TenantRepositoryError>;
+	/// Link an external identity to the active user with the same email,
+	/// creating a user without a password when there is none
+	async fn link_identity(&self, identity: &SsoIdentity) -> Result<User, TenantRepositoryError>;
 
 	// Resource quota management
 	async fn get_quota_status(
 		&self,
 		tenant_id: Uuid,
 	) -> Result<ResourceQuotaStatus, TenantRepositoryError>;
+	/// Quota status for several tenants at once, in the order requested
+	async fn get_quota_statuses(
+		&self,
+		tenant_ids: &[Uuid],
+	) -> Result<Vec<ResourceQuotaStatus>, TenantRepositoryError>;
+	/// Monitor, network and trigger counts in a single query
+	async fn get_resource_counts(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<ResourceCounts, TenantRepositoryError>;
 	async fn check_quota(
 		&self,
 		tenant_id: Uuid,
 		resource: &str,
 		amount: i32,
 	) -> Result<bool, TenantRepositoryError>;
+	/// Check several resources against one quota status lookup
+	async fn check_quota_batch(
+		&self,
+		tenant_id: Uuid,
+		checks: &[QuotaCheck],
+	) -> Result<Vec<QuotaCheckResult>, TenantRepositoryError>;
+
+	async fn set_blockchain_quota(
+		&self,
+		tenant_id: Uuid,
+		quota: BlockchainQuota,
+	) -> Result<BlockchainQuota, TenantRepositoryError>;
+
+	// Tenant-level trigger defaults
+	async fn get_trigger_defaults(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<TriggerDefaults, TenantRepositoryError>;
+	/// Set a new default signing secret; the replaced one becomes `previous_signing_secret`
+	async fn rotate_signing_secret(
+		&self,
+		tenant_id: Uuid,
+		signing_secret: String,
+	) -> Result<TriggerDefaults, TenantRepositoryError>;
 }
 
 #[derive(Clone)]
 pub struct TenantRepository {
 	pool: Pool<Postgres>,
+	reserved_slugs: Vec<String>,
+	slug_rename_cooldown: Option<chrono::Duration>,
+	quota_warn_threshold_percent: u8,
+	max_members: Option<i64>,
+	deleted_slug_retention: Option<chrono::Duration>,
+	quota_cache: QuotaStatusCache,
 }
 
 impl TenantRepository {
 	pub fn new(pool: Pool<Postgres>) -> Self {
-		Self { pool }
+		Self {
+			pool,
+			reserved_slugs: DEFAULT_RESERVED_SLUGS
+				.iter()
+				.map(|s| s.to_string())
+				.collect(),
+			slug_rename_cooldown: None,
+			quota_warn_threshold_percent: crate::models::DEFAULT_QUOTA_WARN_THRESHOLD_PERCENT,
+			max_members: None,
+			deleted_slug_retention: None,
+			quota_cache: QuotaStatusCache::default(),
+		}
 	}
+
+	pub fn with_reserved_slugs(mut self, reserved_slugs: Vec<String>) -> Self {
+		self.reserved_slugs = reserved_slugs;
+		self
+	}
+
+	pub fn with_slug_rename_cooldown(mut self, cooldown: chrono::Duration) -> Self {
+		self.slug_rename_cooldown = Some(cooldown);
+		self
+	}
+
+	pub fn with_quota_warn_threshold(mut self, percent: u8) -> Self {
+		self.quota_warn_threshold_percent = percent;
+		self
+	}
+
+	pub fn with_max_members(mut self, max_members: i64) -> Self {
+		self.max_members = Some(max_members);
+		self
+	}
+
+	pub fn with_deleted_slug_retention(mut self, retention: chrono::Duration) -> Self {
+		self.deleted_slug_retention = Some(retention);
+		self
+	}
+
+	/// Serve quota statuses from `cache`; share it with the resource
+	/// repositories so their writes invalidate it
+	pub fn with_quota_cache(mut self, cache: QuotaStatusCache) -> Self {
+		self.quota_cache = cache;
+		self
+	}
+
+	/// Fill in a missing slug from the name and validate it
+	fn prepare_create(
+		&self,
+		mut request: CreateTenantRequest,
+	) -> Result<CreateTenantRequest, TenantRepositoryError> {
+		if request.slug.is_empty() {
+			request.slug = slugify(&request.name);
+		}
+		self.validate_slug(&request.slug)?;
+
+		Ok(request)
+	}
+}
+
+/// Lock the tenant's owner memberships for the rest of the transaction, so
+/// concurrent role changes can't both see another owner and remove it
+async fn lock_owners(
+	tx: &mut sqlx::Transaction<'_, Postgres>,
+	tenant_id: Uuid,
+) -> Result<Vec<Uuid>, TenantRepositoryError> {
+	let owners = sqlx::query_scalar!(
+		r#"
+		SELECT user_id
+		FROM tenant_memberships
+		WHERE tenant_id = $1 AND role = 'owner'
+		FOR UPDATE
+		"#,
+		tenant_id
+	)
+	.fetch_all(&mut **tx)
+	.await?;
+
+	Ok(owners)
+}
+
+/// Fail if `user_id` is the tenant's only owner
+async fn ensure_other_owner(
+	tx: &mut sqlx::Transaction<'_, Postgres>,
+	tenant_id: Uuid,
+	user_id: Uuid,
+) -> Result<(), TenantRepositoryError> {
+	let owners = lock_owners(tx, tenant_id).await?;
+
+	if owners == [user_id] {
+		return Err(TenantRepositoryError::CannotRemoveLastOwner(user_id));
+	}
+
+	Ok(())
+}
+
+async fn set_member_role(
+	tx: &mut sqlx::Transaction<'_, Postgres>,
+	tenant_id: Uuid,
+	user_id: Uuid,
+	role: TenantRole,
+) -> Result<TenantMembership, TenantRepositoryError> {
+	sqlx::query_as!(
+		TenantMembership,
+		r#"
+		UPDATE tenant_memberships
+		SET role = $3, updated_at = NOW()
+		WHERE tenant_id = $1 AND user_id = $2
+		RETURNING id, tenant_id, user_id, role as "role: TenantRole", created_at, updated_at
+		"#,
+		tenant_id,
+		user_id,
+		role as TenantRole
+	)
+	.fetch_optional(&mut **tx)
+	.await?
+	.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+		resource_type: "membership".to_string(),
+		resource_id: format!("{}/{}", tenant_id, user_id),
+	})
+}
+
+async fn insert_tenant<'e, E>(
+	executor: E,
+	request: &CreateTenantRequest,
+) -> Result<Tenant, TenantRepositoryError>
+where
+	E: sqlx::PgExecutor<'e>,
+{
+	let tenant = sqlx::query_as!(
+		Tenant,
+		r#"
+		INSERT INTO tenants (name, slug, max_monitors, max_networks, max_triggers_per_monitor, max_rpc_requests_per_minute, max_storage_mb)
+		VALUES ($1, $2, $3, $4, $5, $6, $7)
+		RETURNING id, name, slug,
+		          COALESCE(is_active, true) as "is_active!",
+		          COALESCE(max_monitors, 10) as "max_monitors!",
+		          COALESCE(max_networks, 5) as "max_networks!",
+		          COALESCE(max_triggers_per_monitor, 3) as "max_triggers_per_monitor!",
+		          COALESCE(max_rpc_requests_per_minute, 1000) as "max_rpc_requests_per_minute!",
+		          COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
+		          created_at, updated_at
+		"#,
+		request.name,
+		request.slug,
+		request.max_monitors.unwrap_or(10),
+		request.max_networks.unwrap_or(5),
+		request.max_triggers_per_monitor.unwrap_or(10),
+		request.max_rpc_requests_per_minute.unwrap_or(1000),
+		request.max_storage_mb.unwrap_or(1000)
+	)
+	.fetch_one(executor)
+	.await
+	.map_err(|e| match e {
+		sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+			TenantRepositoryError::AlreadyExists {
+				resource_type: "tenant".to_string(),
+				resource_id: request.slug.clone(),
+			}
+		}
+		e => TenantRepositoryError::Database(e),
+	})?;
+
+	Ok(tenant)
 }
 
 #[async_trait]
 impl TenantRepositoryTrait for TenantRepository {
 	async fn create(&self, request: CreateTenantRequest) -> Result<Tenant, TenantRepositoryError> {
-		let tenant = sqlx::query_as!(
-			Tenant,
+		let request = self.prepare_create(request)?;
+		self.check_deleted_slug(&request.slug).await?;
+
+		insert_tenant(&self.pool, &request).await
+	}
+
+	async fn create_with_owner(
+		&self,
+		request: CreateTenantRequest,
+		owner_id: Uuid,
+	) -> Result<Tenant, TenantRepositoryError> {
+		let request = self.prepare_create(request)?;
+		self.check_deleted_slug(&request.slug).await?;
+
+		let mut tx = self.pool.begin().await?;
+
+		let tenant = insert_tenant(&mut *tx, &request).await?;
+
+		sqlx::query!(
 			r#"
-			INSERT INTO tenants (name, slug, max_monitors, max_networks, max_triggers_per_monitor, max_rpc_requests_per_minute, max_storage_mb)
-			VALUES ($1, $2, $3, $4, $5, $6, $7)
-			RETURNING id, name, slug,
-			          COALESCE(is_active, true) as "is_active!",
-			          COALESCE(max_monitors, 10) as "max_monitors!",
-			          COALESCE(max_networks, 5) as "max_networks!",
-			          COALESCE(max_triggers_per_monitor, 3) as "max_triggers_per_monitor!",
-			          COALESCE(max_rpc_requests_per_minute, 1000) as "max_rpc_requests_per_minute!",
-			          COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
-			          created_at, updated_at
+			INSERT INTO tenant_memberships (tenant_id, user_id, role)
+			VALUES ($1, $2, 'owner')
 			"#,
-			request.name,
-			request.slug,
-			request.max_monitors.unwrap_or(10),
-			request.max_networks.unwrap_or(5),
-			request.max_triggers_per_monitor.unwrap_or(10),
-			request.max_rpc_requests_per_minute.unwrap_or(1000),
-			request.max_storage_mb.unwrap_or(1000)
+			tenant.id,
+			owner_id
 		)
-		.fetch_one(&self.pool)
+		.execute(&mut *tx)
 		.await?;
 
+		tx.commit().await?;
+
 		Ok(tenant)
 	}
 
+	fn validate_slug(&self, slug: &str) -> Result<(), TenantRepositoryError> {
+		Tenant::validate_slug(slug, &self.reserved_slugs)
+			.map_err(TenantRepositoryError::ValidationError)
+	}
+
+	async fn check_deleted_slug(&self, slug: &str) -> Result<(), TenantRepositoryError> {
+		let Some(retention) = self.deleted_slug_retention else {
+			return Ok(());
+		};
+
+		let retained = sqlx::query_scalar!(
+			"SELECT EXISTS(SELECT 1 FROM tenants WHERE slug = $1 AND deleted_at > $2)",
+			slug,
+			chrono::Utc::now() - retention
+		)
+		.fetch_one(&self.pool)
+		.await?
+		.unwrap_or(false);
+
+		if retained {
+			return Err(TenantRepositoryError::DeletedTenantSlug(slug.to_string()));
+		}
+
+		Ok(())
+	}
+
 	async fn get(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError> {
 		let tenant = sqlx::query_as!(
 			Tenant,
@@ -116,7 +436,7 @@ impl TenantRepositoryTrait for TenantRepository {
 			       COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
 			       created_at, updated_at
 			FROM tenants 
-			WHERE id = $1
+			WHERE id = $1 AND deleted_at IS NULL
 			"#,
 			tenant_id
 		)
@@ -140,7 +460,7 @@ impl TenantRepositoryTrait for TenantRepository {
 			       COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
 			       created_at, updated_at
 			FROM tenants 
-			WHERE slug = $1
+			WHERE slug = $1 AND deleted_at IS NULL
 			"#,
 			slug
 		)
@@ -159,60 +479,130 @@ impl TenantRepositoryTrait for TenantRepository {
 		tenant_id: Uuid,
 		request: UpdateTenantRequest,
 	) -> Result<Tenant, TenantRepositoryError> {
-		let mut query = String::from("UPDATE tenants SET updated_at = NOW()");
-		let mut params: Vec<String> = vec![];
-		let mut param_count = 1;
+		let tenant = tenant_update_query(tenant_id, &request)
+			.build_query_as::<Tenant>()
+			.fetch_one(&self.pool)
+			.await?;
+		self.quota_cache.invalidate(tenant_id);
 
-		if let Some(_name) = &request.name {
-			params.push(format!(", name = ${}", param_count + 1));
-			param_count += 1;
-		}
-		if let Some(_is_active) = request.is_active {
-			params.push(format!(", is_active = ${}", param_count + 1));
-			param_count += 1;
-		}
-		if let Some(_max_monitors) = request.max_monitors {
-			params.push(format!(", max_monitors = ${}", param_count + 1));
-			param_count += 1;
-		}
-		if let Some(_max_networks) = request.max_networks {
-			params.push(format!(", max_networks = ${}", param_count + 1));
-			param_count += 1;
+		Ok(tenant)
+	}
+
+	async fn rename_slug(
+		&self,
+		tenant_id: Uuid,
+		slug: &str,
+	) -> Result<Tenant, TenantRepositoryError> {
+		self.validate_slug(slug)?;
+		self.check_deleted_slug(slug).await?;
+
+		if let Some(cooldown) = self.slug_rename_cooldown {
+			let last_renamed_at = sqlx::query_scalar!(
+				"SELECT slug_renamed_at FROM tenants WHERE id = $1 AND deleted_at IS NULL",
+				tenant_id
+			)
+			.fetch_optional(&self.pool)
+			.await?
+			.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+			if let Some(available_at) =
+				slug_rename_blocked_until(last_renamed_at, cooldown, chrono::Utc::now())
+			{
+				return Err(TenantRepositoryError::RateLimited(format!(
+					"Slug was renamed recently; try again after {}",
+					available_at.to_rfc3339()
+				)));
+			}
 		}
-		if let Some(_max_triggers_per_monitor) = request.max_triggers_per_monitor {
-			params.push(format!(", max_triggers_per_monitor = ${}", param_count + 1));
-			param_count += 1;
+
+		let tenant = sqlx::query_as!(
+			Tenant,
+			r#"
+			UPDATE tenants
+			SET slug = $2, slug_renamed_at = NOW(), updated_at = NOW()
+			WHERE id = $1 AND deleted_at IS NULL
+			RETURNING id, name, slug,
+			          COALESCE(is_active, true) as "is_active!",
+			          COALESCE(max_monitors, 10) as "max_monitors!",
+			          COALESCE(max_networks, 5) as "max_networks!",
+			          COALESCE(max_triggers_per_monitor, 3) as "max_triggers_per_monitor!",
+			          COALESCE(max_rpc_requests_per_minute, 1000) as "max_rpc_requests_per_minute!",
+			          COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
+			          created_at, updated_at
+			"#,
+			tenant_id,
+			slug
+		)
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| match e {
+			sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+				TenantRepositoryError::AlreadyExists {
+					resource_type: "tenant".to_string(),
+					resource_id: slug.to_string(),
+				}
+			}
+			e => TenantRepositoryError::Database(e),
+		})?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		Ok(tenant)
+	}
+
+	async fn delete(&self, tenant_id: Uuid, force: bool) -> Result<(), TenantRepositoryError> {
+		if !force {
+			let counts = self.get_resource_counts(tenant_id).await?;
+			if !counts.is_empty() {
+				return Err(TenantRepositoryError::ValidationError(format!(
+					"Tenant still has {} monitors, {} networks and {} triggers; remove them or force the deletion",
+					counts.monitors, counts.networks, counts.triggers
+				)));
+			}
 		}
-		if let Some(_max_rpc_requests_per_minute) = request.max_rpc_requests_per_minute {
-			params.push(format!(
-				", max_rpc_requests_per_minute = ${}",
-				param_count + 1
-			));
-			param_count += 1;
+
+		let mut tx = self.pool.begin().await?;
+
+		let result = sqlx::query!(
+			r#"
+			UPDATE tenants
+			SET deleted_at = NOW(), is_active = false, updated_at = NOW()
+			WHERE id = $1 AND deleted_at IS NULL
+			"#,
+			tenant_id
+		)
+		.execute(&mut *tx)
+		.await?;
+
+		if result.rows_affected() == 0 {
+			return Err(TenantRepositoryError::TenantNotFound(tenant_id));
 		}
-		if let Some(_max_storage_mb) = request.max_storage_mb {
-			params.push(format!(", max_storage_mb = ${}", param_count + 1));
-			// param_count += 1; // Removed as it's the last one
+
+		if force {
+			sqlx::query!(
+				r#"
+				UPDATE tenant_monitors
+				SET deleted_at = NOW(), updated_at = NOW()
+				WHERE tenant_id = $1 AND deleted_at IS NULL
+				"#,
+				tenant_id
+			)
+			.execute(&mut *tx)
+			.await?;
 		}
 
-		query.push_str(&params.join(""));
-		query.push_str(" WHERE id = $1 RETURNING *");
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(())
+	}
 
-		// For now, we'll use a simpler approach with direct query
+	async fn restore(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError> {
 		let tenant = sqlx::query_as!(
 			Tenant,
 			r#"
-			UPDATE tenants 
-			SET 
-				name = COALESCE($2, name),
-				is_active = COALESCE($3, is_active),
-				max_monitors = COALESCE($4, max_monitors),
-				max_networks = COALESCE($5, max_networks),
-				max_triggers_per_monitor = COALESCE($6, max_triggers_per_monitor),
-				max_rpc_requests_per_minute = COALESCE($7, max_rpc_requests_per_minute),
-				max_storage_mb = COALESCE($8, max_storage_mb),
-				updated_at = NOW()
-			WHERE id = $1
+			UPDATE tenants
+			SET deleted_at = NULL, is_active = true, updated_at = NOW()
+			WHERE id = $1 AND deleted_at IS NOT NULL
 			RETURNING id, name, slug,
 			          COALESCE(is_active, true) as "is_active!",
 			          COALESCE(max_monitors, 10) as "max_monitors!",
@@ -222,25 +612,30 @@ impl TenantRepositoryTrait for TenantRepository {
 			          COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
 			          created_at, updated_at
 			"#,
-			tenant_id,
-			request.name,
-			request.is_active,
-			request.max_monitors,
-			request.max_networks,
-			request.max_triggers_per_monitor,
-			request.max_rpc_requests_per_minute,
-			request.max_storage_mb
+			tenant_id
 		)
-		.fetch_one(&self.pool)
-		.await?;
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| match e {
+			// Another tenant has claimed the slug since the deletion
+			sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+				TenantRepositoryError::AlreadyExists {
+					resource_type: "tenant".to_string(),
+					resource_id: tenant_id.to_string(),
+				}
+			}
+			e => TenantRepositoryError::Database(e),
+		})?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
 
 		Ok(tenant)
 	}
 
-	async fn delete(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError> {
+	async fn purge(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError> {
 		let result = sqlx::query!("DELETE FROM tenants WHERE id = $1", tenant_id)
 			.execute(&self.pool)
 			.await?;
+		self.quota_cache.invalidate(tenant_id);
 
 		if result.rows_affected() == 0 {
 			return Err(TenantRepositoryError::TenantNotFound(tenant_id));
@@ -262,6 +657,7 @@ impl TenantRepositoryTrait for TenantRepository {
 			       COALESCE(max_storage_mb, 1000) as "max_storage_mb!",
 			       created_at, updated_at
 			FROM tenants 
+			WHERE deleted_at IS NULL
 			ORDER BY created_at DESC 
 			LIMIT $1 OFFSET $2
 			"#,
@@ -274,6 +670,32 @@ impl TenantRepositoryTrait for TenantRepository {
 		Ok(tenants)
 	}
 
+	async fn list_filtered(
+		&self,
+		filter: TenantFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<Tenant>, TenantRepositoryError> {
+		let tenants = tenant_list_query(&filter, limit, offset)
+			.build_query_as::<Tenant>()
+			.fetch_all(&self.pool)
+			.await?;
+
+		Ok(tenants)
+	}
+
+	async fn count_filtered(&self, filter: TenantFilter) -> Result<i64, TenantRepositoryError> {
+		let mut query = QueryBuilder::new("SELECT COUNT(*) FROM tenants WHERE deleted_at IS NULL");
+		push_tenant_filters(&mut query, &filter);
+
+		let count = query
+			.build_query_scalar::<i64>()
+			.fetch_one(&self.pool)
+			.await?;
+
+		Ok(count)
+	}
+
 	async fn add_member(
 		&self,
 		tenant_id: Uuid,
@@ -292,7 +714,16 @@ impl TenantRepositoryTrait for TenantRepository {
 			role as TenantRole
 		)
 		.fetch_one(&self.pool)
-		.await?;
+		.await
+		.map_err(|e| match e {
+			sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+				TenantRepositoryError::AlreadyExists {
+					resource_type: "membership".to_string(),
+					resource_id: format!("{}/{}", tenant_id, user_id),
+				}
+			}
+			e => TenantRepositoryError::Database(e),
+		})?;
 
 		Ok(membership)
 	}
@@ -302,12 +733,16 @@ impl TenantRepositoryTrait for TenantRepository {
 		tenant_id: Uuid,
 		user_id: Uuid,
 	) -> Result<(), TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
+
+		ensure_other_owner(&mut tx, tenant_id, user_id).await?;
+
 		let result = sqlx::query!(
 			"DELETE FROM tenant_memberships WHERE tenant_id = $1 AND user_id = $2",
 			tenant_id,
 			user_id
 		)
-		.execute(&self.pool)
+		.execute(&mut *tx)
 		.await?;
 
 		if result.rows_affected() == 0 {
@@ -317,6 +752,8 @@ impl TenantRepositoryTrait for TenantRepository {
 			});
 		}
 
+		tx.commit().await?;
+
 		Ok(())
 	}
 
@@ -326,28 +763,48 @@ impl TenantRepositoryTrait for TenantRepository {
 		user_id: Uuid,
 		role: TenantRole,
 	) -> Result<TenantMembership, TenantRepositoryError> {
-		let membership = sqlx::query_as!(
-			TenantMembership,
-			r#"
-			UPDATE tenant_memberships 
-			SET role = $3, updated_at = NOW()
-			WHERE tenant_id = $1 AND user_id = $2
-			RETURNING id, tenant_id, user_id, role as "role: TenantRole", created_at, updated_at
-			"#,
-			tenant_id,
-			user_id,
-			role as TenantRole
-		)
-		.fetch_optional(&self.pool)
-		.await?
-		.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
-			resource_type: "membership".to_string(),
-			resource_id: format!("{}/{}", tenant_id, user_id),
-		})?;
+		let mut tx = self.pool.begin().await?;
+
+		if role != TenantRole::Owner {
+			ensure_other_owner(&mut tx, tenant_id, user_id).await?;
+		}
+
+		let membership = set_member_role(&mut tx, tenant_id, user_id, role).await?;
+
+		tx.commit().await?;
 
 		Ok(membership)
 	}
 
+	async fn transfer_ownership(
+		&self,
+		tenant_id: Uuid,
+		from_user: Uuid,
+		to_user: Uuid,
+	) -> Result<(), TenantRepositoryError> {
+		if from_user == to_user {
+			return Err(TenantRepositoryError::ValidationError(
+				"cannot transfer ownership to the current owner".to_string(),
+			));
+		}
+
+		let mut tx = self.pool.begin().await?;
+
+		let owners = lock_owners(&mut tx, tenant_id).await?;
+		if !owners.contains(&from_user) {
+			return Err(TenantRepositoryError::ValidationError(
+				"only an owner can transfer ownership".to_string(),
+			));
+		}
+
+		set_member_role(&mut tx, tenant_id, to_user, TenantRole::Owner).await?;
+		set_member_role(&mut tx, tenant_id, from_user, TenantRole::Admin).await?;
+
+		tx.commit().await?;
+
+		Ok(())
+	}
+
 	async fn get_members(
 		&self,
 		tenant_id: Uuid,
@@ -377,7 +834,7 @@ impl TenantRepositoryTrait for TenantRepository {
 			SELECT t.*, tm.role
 			FROM tenants t
 			INNER JOIN tenant_memberships tm ON t.id = tm.tenant_id
-			WHERE tm.user_id = $1 AND t.is_active = true
+			WHERE tm.user_id = $1 AND t.is_active = true AND t.deleted_at IS NULL
 			ORDER BY t.created_at DESC
 			"#,
 			user_id
@@ -417,129 +874,1162 @@ impl TenantRepositoryTrait for TenantRepository {
 		Ok(tenants)
 	}
 
-	async fn get_quota_status(
+	async fn add_members_batch(
 		&self,
 		tenant_id: Uuid,
-	) -> Result<ResourceQuotaStatus, TenantRepositoryError> {
-		// Get tenant quotas
-		let tenant = self.get(tenant_id).await?;
+		entries: Vec<BatchMemberEntry>,
+	) -> Result<Vec<BatchMemberResult>, TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
 
-		// Get current usage counts
-		let monitor_count = sqlx::query_scalar!(
-			"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1",
-			tenant_id
-		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0) as i32;
+		// Lock the tenant so concurrent imports can't both pass the member limit
+		sqlx::query_scalar!("SELECT id FROM tenants WHERE id = $1 FOR UPDATE", tenant_id)
+			.fetch_optional(&mut *tx)
+			.await?
+			.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
 
-		let network_count = sqlx::query_scalar!(
-			"SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1",
+		let members: HashSet<Uuid> = sqlx::query_scalar!(
+			"SELECT user_id FROM tenant_memberships WHERE tenant_id = $1",
 			tenant_id
 		)
-		.fetch_one(&self.pool)
+		.fetch_all(&mut *tx)
 		.await?
-		.unwrap_or(0) as i32;
+		.into_iter()
+		.collect();
 
-		let trigger_count = sqlx::query_scalar!(
-			"SELECT COUNT(*) FROM tenant_triggers WHERE tenant_id = $1",
-			tenant_id
-		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0) as i32;
+		let mut added = HashSet::new();
+		let mut to_add = Vec::new();
+		let mut results = Vec::with_capacity(entries.len());
+		for (index, entry) in entries.into_iter().enumerate() {
+			let valid = entry.is_valid();
+			let user_id = if valid {
+				sqlx::query_scalar!(
+					"SELECT id FROM users WHERE id = $1 OR LOWER(email) = LOWER($2)",
+					entry.user_id,
+					entry.email.as_deref().map(str::trim)
+				)
+				.fetch_optional(&mut *tx)
+				.await?
+			} else {
+				None
+			};
 
-		// Get RPC usage from last minute
-		let rpc_requests = sqlx::query_scalar!(
-			r#"
-			SELECT COALESCE(SUM(usage_value), 0)::integer
-			FROM resource_usage
-			WHERE tenant_id = $1 
-			AND resource_type = 'rpc_requests'
-			AND created_at >= NOW() - INTERVAL '1 minute'
-			"#,
-			tenant_id
-		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0);
+			let status = match user_id {
+				_ if !valid => BatchMemberStatus::Invalid,
+				None => BatchMemberStatus::UserNotFound,
+				Some(user_id) if members.contains(&user_id) => BatchMemberStatus::AlreadyMember,
+				Some(user_id) if !added.insert(user_id) => BatchMemberStatus::Duplicate,
+				Some(user_id) => {
+					to_add.push((user_id, entry.role));
+					BatchMemberStatus::Added
+				}
+			};
 
-		// Get storage usage
-		let storage_mb = sqlx::query_scalar!(
-			r#"
-			SELECT COALESCE(usage_value, 0)::integer
-			FROM resource_usage
-			WHERE tenant_id = $1 
-			AND resource_type = 'storage'
-			AND usage_date = CURRENT_DATE
-			ORDER BY created_at DESC
-			LIMIT 1
-			"#,
-			tenant_id
-		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0);
+			results.push(BatchMemberResult {
+				index,
+				user_id: user_id.or(entry.user_id),
+				email: entry.email,
+				role: entry.role,
+				status,
+			});
+		}
 
-		let quotas = TenantQuotas {
-			max_monitors: tenant.max_monitors,
-			max_networks: tenant.max_networks,
-			max_triggers_per_monitor: tenant.max_triggers_per_monitor,
-			max_rpc_requests_per_minute: tenant.max_rpc_requests_per_minute,
-			max_storage_mb: tenant.max_storage_mb,
-			api_rate_limits: crate::models::resource_quota::ApiRateLimits::default(),
-		};
+		if let Some(max_members) = self.max_members {
+			let total = (members.len() + to_add.len()) as i64;
+			if total > max_members {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Adding {} members would exceed the limit of {} (currently {})",
+					to_add.len(),
+					max_members,
+					members.len()
+				)));
+			}
+		}
 
-		let usage = CurrentUsage {
-			monitors_count: monitor_count,
-			networks_count: network_count,
-			triggers_count: trigger_count,
-			rpc_requests_last_minute: rpc_requests,
-			storage_mb_used: storage_mb,
-		};
+		for (user_id, role) in to_add {
+			sqlx::query!(
+				r#"
+				INSERT INTO tenant_memberships (tenant_id, user_id, role)
+				VALUES ($1, $2, $3)
+				"#,
+				tenant_id,
+				user_id,
+				role as TenantRole
+			)
+			.execute(&mut *tx)
+			.await?;
+		}
 
-		let available = AvailableResources {
-			monitors: (quotas.max_monitors - usage.monitors_count).max(0),
-			networks: (quotas.max_networks - usage.networks_count).max(0),
-			triggers: (quotas.max_triggers_per_monitor * usage.monitors_count
-				- usage.triggers_count)
-				.max(0),
-			rpc_requests_per_minute: (quotas.max_rpc_requests_per_minute
-				- usage.rpc_requests_last_minute)
-				.max(0),
-			storage_mb: (quotas.max_storage_mb - usage.storage_mb_used).max(0),
-		};
+		tx.commit().await?;
 
-		Ok(ResourceQuotaStatus {
-			tenant_id,
-			quotas,
-			usage,
-			available,
-		})
+		Ok(results)
 	}
 
-	async fn check_quota(
+	async fn sync_members(
 		&self,
 		tenant_id: Uuid,
-		resource: &str,
-		amount: i32,
-	) -> Result<bool, TenantRepositoryError> {
-		let status = self.get_quota_status(tenant_id).await?;
+		entries: Vec<BatchMemberEntry>,
+		change_owners: bool,
+	) -> Result<MemberSyncResult, TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
 
-		let has_capacity = match resource {
-			"monitors" => status.available.monitors >= amount,
-			"networks" => status.available.networks >= amount,
-			"triggers" => status.available.triggers >= amount,
-			"rpc_requests" => status.available.rpc_requests_per_minute >= amount,
-			"storage_mb" => status.available.storage_mb >= amount,
-			_ => {
-				return Err(TenantRepositoryError::InvalidConfiguration(format!(
-					"Unknown resource type: {}",
-					resource
-				)))
-			}
-		};
+		// Lock the tenant against concurrent imports and syncs, and its owners
+		// against concurrent role changes
+		sqlx::query_scalar!("SELECT id FROM tenants WHERE id = $1 FOR UPDATE", tenant_id)
+			.fetch_optional(&mut *tx)
+			.await?
+			.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+		let owners: HashSet<Uuid> = lock_owners(&mut tx, tenant_id).await?.into_iter().collect();
+
+		let current = sqlx::query!(
+			r#"
+			SELECT user_id, role as "role: TenantRole"
+			FROM tenant_memberships
+			WHERE tenant_id = $1
+			ORDER BY created_at, id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&mut *tx)
+		.await?;
+
+		let mut desired: Vec<(Uuid, TenantRole)> = Vec::with_capacity(entries.len());
+		for (index, entry) in entries.iter().enumerate() {
+			if !entry.is_valid() {
+				return Err(TenantRepositoryError::ValidationError(format!(
+					"Member {} must name exactly one of user_id and email",
+					index
+				)));
+			}
+			let user_id = sqlx::query_scalar!(
+				"SELECT id FROM users WHERE id = $1 OR LOWER(email) = LOWER($2)",
+				entry.user_id,
+				entry.email.as_deref().map(str::trim)
+			)
+			.fetch_optional(&mut *tx)
+			.await?
+			.ok_or_else(|| {
+				TenantRepositoryError::ValidationError(format!(
+					"Member {} does not match a user",
+					index
+				))
+			})?;
+			if desired.iter().any(|(desired_id, _)| *desired_id == user_id) {
+				return Err(TenantRepositoryError::ValidationError(format!(
+					"Member {} repeats an earlier member",
+					index
+				)));
+			}
+			desired.push((user_id, entry.role));
+		}
+
+		let desired_owners: HashSet<Uuid> = desired
+			.iter()
+			.filter(|(_, role)| *role == TenantRole::Owner)
+			.map(|(user_id, _)| *user_id)
+			.collect();
+		if desired_owners.is_empty() {
+			return Err(TenantRepositoryError::ValidationError(
+				"A membership sync must keep at least one owner".to_string(),
+			));
+		}
+		if !change_owners && desired_owners != owners {
+			return Err(TenantRepositoryError::AccessDenied(
+				"Only an owner can add or remove owners".to_string(),
+			));
+		}
+
+		if let Some(max_members) = self.max_members {
+			if desired.len() > current.len() && desired.len() as i64 > max_members {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Syncing {} members would exceed the limit of {}",
+					desired.len(),
+					max_members
+				)));
+			}
+		}
+
+		let mut changes = Vec::new();
+		for member in &current {
+			if desired
+				.iter()
+				.any(|(user_id, _)| *user_id == member.user_id)
+			{
+				continue;
+			}
+			sqlx::query!(
+				"DELETE FROM tenant_memberships WHERE tenant_id = $1 AND user_id = $2",
+				tenant_id,
+				member.user_id
+			)
+			.execute(&mut *tx)
+			.await?;
+			changes.push(MemberSyncChange {
+				user_id: member.user_id,
+				action: MemberSyncAction::Removed,
+				previous_role: Some(member.role),
+				role: None,
+			});
+		}
+
+		for (user_id, role) in desired {
+			match current.iter().find(|member| member.user_id == user_id) {
+				Some(member) if member.role == role => {}
+				Some(member) => {
+					set_member_role(&mut tx, tenant_id, user_id, role).await?;
+					changes.push(MemberSyncChange {
+						user_id,
+						action: MemberSyncAction::RoleChanged,
+						previous_role: Some(member.role),
+						role: Some(role),
+					});
+				}
+				None => {
+					sqlx::query!(
+						r#"
+						INSERT INTO tenant_memberships (tenant_id, user_id, role)
+						VALUES ($1, $2, $3)
+						"#,
+						tenant_id,
+						user_id,
+						role as TenantRole
+					)
+					.execute(&mut *tx)
+					.await?;
+					changes.push(MemberSyncChange {
+						user_id,
+						action: MemberSyncAction::Added,
+						previous_role: None,
+						role: Some(role),
+					});
+				}
+			}
+		}
+
+		let members = sqlx::query_as!(
+			TenantMembership,
+			r#"
+			SELECT id, tenant_id, user_id, role as "role: TenantRole", created_at, updated_at
+			FROM tenant_memberships
+			WHERE tenant_id = $1
+			ORDER BY created_at
+			"#,
+			tenant_id
+		)
+		.fetch_all(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(MemberSyncResult { changes, members })
+	}
+
+	async fn find_duplicate_memberships(
+		&self,
+	) -> Result<Vec<DuplicateMembership>, TenantRepositoryError> {
+		let duplicates = sqlx::query_as!(
+			DuplicateMembership,
+			r#"
+			SELECT tenant_id, user_id,
+			       ARRAY_AGG(id ORDER BY created_at, id) as "membership_ids!"
+			FROM tenant_memberships
+			GROUP BY tenant_id, user_id
+			HAVING COUNT(*) > 1
+			ORDER BY tenant_id, user_id
+			"#
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(duplicates)
+	}
+
+	async fn find_integrity_violations(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<IntegrityViolation>, TenantRepositoryError> {
+		let monitors = sqlx::query!(
+			r#"
+			SELECT m.id, m.monitor_id, m.network_id, n.tenant_id as "network_tenant_id?"
+			FROM tenant_monitors m
+			LEFT JOIN tenant_networks n ON n.id = m.network_id
+			WHERE m.tenant_id = $1 AND (n.id IS NULL OR n.tenant_id <> m.tenant_id)
+			ORDER BY m.monitor_id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		let triggers = sqlx::query!(
+			r#"
+			SELECT t.id, t.trigger_id, t.monitor_id, m.tenant_id as "monitor_tenant_id?"
+			FROM tenant_triggers t
+			LEFT JOIN tenant_monitors m ON m.id = t.monitor_id
+			WHERE t.tenant_id = $1 AND (m.id IS NULL OR m.tenant_id <> t.tenant_id)
+			ORDER BY t.trigger_id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		let monitor_violations = monitors.into_iter().map(|row| match row.network_tenant_id {
+			Some(owner) => IntegrityViolation {
+				kind: IntegrityViolationKind::ForeignNetwork,
+				resource_type: "monitor".to_string(),
+				resource_id: Some(row.id),
+				detail: format!(
+					"monitor {} uses network {} owned by tenant {}",
+					row.monitor_id, row.network_id, owner
+				),
+			},
+			None => IntegrityViolation {
+				kind: IntegrityViolationKind::OrphanedMonitor,
+				resource_type: "monitor".to_string(),
+				resource_id: Some(row.id),
+				detail: format!(
+					"monitor {} uses missing network {}",
+					row.monitor_id, row.network_id
+				),
+			},
+		});
+		let trigger_violations = triggers.into_iter().map(|row| match row.monitor_tenant_id {
+			Some(owner) => IntegrityViolation {
+				kind: IntegrityViolationKind::ForeignMonitor,
+				resource_type: "trigger".to_string(),
+				resource_id: Some(row.id),
+				detail: format!(
+					"trigger {} is attached to monitor {} owned by tenant {}",
+					row.trigger_id, row.monitor_id, owner
+				),
+			},
+			None => IntegrityViolation {
+				kind: IntegrityViolationKind::OrphanedTrigger,
+				resource_type: "trigger".to_string(),
+				resource_id: Some(row.id),
+				detail: format!(
+					"trigger {} is attached to missing monitor {}",
+					row.trigger_id, row.monitor_id
+				),
+			},
+		});
+
+		Ok(monitor_violations.chain(trigger_violations).collect())
+	}
+
+	async fn find_user_by_identity(
+		&self,
+		issuer: &str,
+		subject: &str,
+	) -> Result<Option<User>, TenantRepositoryError> {
+		let user = sqlx::query_as!(
+			User,
+			r#"
+			SELECT u.id, u.email, u.password_hash,
+			       COALESCE(u.is_active, true) as "is_active!",
+			       u.email_verified, u.created_at, u.updated_at
+			FROM users u
+			INNER JOIN user_identities ui ON ui.user_id = u.id
+			WHERE ui.issuer = $1 AND ui.subject = $2
+			"#,
+			issuer,
+			subject
+		)
+		.fetch_optional(&self.pool)
+		.await?;
+
+		Ok(user)
+	}
+
+	async fn link_identity(&self, identity: &SsoIdentity) -> Result<User, TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
+
+		let existing = sqlx::query_as!(
+			User,
+			r#"
+			SELECT id, email, password_hash,
+			       COALESCE(is_active, true) as "is_active!",
+			       email_verified, created_at, updated_at
+			FROM users
+			WHERE email = $1 AND COALESCE(is_active, true) = true
+			"#,
+			identity.email
+		)
+		.fetch_optional(&mut *tx)
+		.await?;
+
+		let user = match existing {
+			Some(user) => user,
+			// An empty hash never verifies, so the user can only sign in through
+			// SSO; the provider has already verified the email
+			None => {
+				sqlx::query_as!(
+					User,
+					r#"
+					INSERT INTO users (email, password_hash, email_verified)
+					VALUES ($1, '', true)
+					RETURNING id, email, password_hash,
+					          COALESCE(is_active, true) as "is_active!",
+					          email_verified, created_at, updated_at
+					"#,
+					identity.email
+				)
+				.fetch_one(&mut *tx)
+				.await?
+			}
+		};
+
+		sqlx::query!(
+			r#"
+			INSERT INTO user_identities (user_id, issuer, subject)
+			VALUES ($1, $2, $3)
+			"#,
+			user.id,
+			identity.issuer,
+			identity.subject
+		)
+		.execute(&mut *tx)
+		.await
+		.map_err(|e| match e {
+			sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+				TenantRepositoryError::AlreadyExists {
+					resource_type: "identity".to_string(),
+					resource_id: format!("{}/{}", identity.issuer, identity.subject),
+				}
+			}
+			e => TenantRepositoryError::Database(e),
+		})?;
+
+		tx.commit().await?;
+
+		Ok(user)
+	}
+
+	async fn get_quota_status(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<ResourceQuotaStatus, TenantRepositoryError> {
+		if let Some(status) = self.quota_cache.get(tenant_id) {
+			return Ok(status);
+		}
+		let generation = self.quota_cache.generation();
+
+		// Quotas, usage, per-blockchain sub-quotas and per-monitor trigger counts
+		// in one round trip; this runs ahead of every create
+		let row = sqlx::query!(
+			r#"
+			SELECT
+				COALESCE(t.max_monitors, 10) as "max_monitors!",
+				COALESCE(t.max_networks, 5) as "max_networks!",
+				COALESCE(t.max_triggers_per_monitor, 3) as "max_triggers_per_monitor!",
+				COALESCE(t.max_rpc_requests_per_minute, 1000) as "max_rpc_requests_per_minute!",
+				COALESCE(t.max_storage_mb, 1000) as "max_storage_mb!",
+				(SELECT COUNT(*)::integer FROM tenant_monitors m WHERE m.tenant_id = t.id AND m.deleted_at IS NULL) as "monitors_count!",
+				(SELECT COUNT(*)::integer FROM tenant_networks n WHERE n.tenant_id = t.id) as "networks_count!",
+				(SELECT COUNT(*)::integer FROM tenant_triggers tr WHERE tr.tenant_id = t.id) as "triggers_count!",
+				(SELECT COALESCE(SUM(r.usage_value), 0)::integer
+				 FROM resource_usage r
+				 WHERE r.tenant_id = t.id
+				 AND r.resource_type = 'rpc_requests'
+				 AND r.created_at >= NOW() - INTERVAL '1 minute') as "rpc_requests!",
+				(SELECT r.usage_value::integer
+				 FROM resource_usage r
+				 WHERE r.tenant_id = t.id
+				 AND r.resource_type = 'storage'
+				 AND r.usage_date = CURRENT_DATE
+				 ORDER BY r.created_at DESC
+				 LIMIT 1) as storage_mb,
+				(SELECT COALESCE(json_agg(json_build_object(
+					'blockchain', q.blockchain,
+					'max_monitors', q.max_monitors,
+					'max_networks', q.max_networks,
+					'monitors_count', (SELECT COUNT(*)::integer
+						FROM tenant_monitors m
+						INNER JOIN tenant_networks n ON m.network_id = n.id
+						WHERE m.tenant_id = q.tenant_id AND n.blockchain = q.blockchain
+						AND m.deleted_at IS NULL),
+					'networks_count', (SELECT COUNT(*)::integer
+						FROM tenant_networks n
+						WHERE n.tenant_id = q.tenant_id AND n.blockchain = q.blockchain)
+				 ) ORDER BY q.blockchain), '[]'::json)
+				 FROM tenant_blockchain_quotas q
+				 WHERE q.tenant_id = t.id) as "blockchains!: sqlx::types::Json<Vec<BlockchainQuotaStatus>>",
+				(SELECT COALESCE(json_object_agg(m.monitor_id, (SELECT COUNT(*)::integer
+					FROM tenant_triggers tr
+					WHERE tr.monitor_id = m.id AND tr.tenant_id = m.tenant_id)), '{}'::json)
+				 FROM tenant_monitors m
+				 WHERE m.tenant_id = t.id AND m.deleted_at IS NULL) as "monitor_triggers!: sqlx::types::Json<HashMap<String, i32>>"
+			FROM tenants t
+			WHERE t.id = $1 AND t.deleted_at IS NULL
+			"#,
+			tenant_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		let quotas = TenantQuotas {
+			max_monitors: row.max_monitors,
+			max_networks: row.max_networks,
+			max_triggers_per_monitor: row.max_triggers_per_monitor,
+			max_rpc_requests_per_minute: row.max_rpc_requests_per_minute,
+			max_storage_mb: row.max_storage_mb,
+			api_rate_limits: crate::models::resource_quota::ApiRateLimits::default(),
+		};
+		let usage = CurrentUsage {
+			monitors_count: row.monitors_count,
+			networks_count: row.networks_count,
+			triggers_count: row.triggers_count,
+			rpc_requests_last_minute: row.rpc_requests,
+			storage_mb_used: row.storage_mb.unwrap_or(0),
+		};
+
+		let status = ResourceQuotaStatus::from_usage(tenant_id, quotas, usage, row.blockchains.0)
+			.with_warn_threshold(self.quota_warn_threshold_percent)
+			.with_monitor_triggers(row.monitor_triggers.0);
+		self.quota_cache
+			.insert(tenant_id, status.clone(), generation);
+
+		Ok(status)
+	}
+
+	async fn get_quota_statuses(
+		&self,
+		tenant_ids: &[Uuid],
+	) -> Result<Vec<ResourceQuotaStatus>, TenantRepositoryError> {
+		if tenant_ids.is_empty() {
+			return Ok(vec![]);
+		}
+
+		// Quotas and usage for every requested tenant in a single pass
+		let rows = sqlx::query!(
+			r#"
+			SELECT
+				t.id,
+				COALESCE(t.max_monitors, 10) as "max_monitors!",
+				COALESCE(t.max_networks, 5) as "max_networks!",
+				COALESCE(t.max_triggers_per_monitor, 3) as "max_triggers_per_monitor!",
+				COALESCE(t.max_rpc_requests_per_minute, 1000) as "max_rpc_requests_per_minute!",
+				COALESCE(t.max_storage_mb, 1000) as "max_storage_mb!",
+				(SELECT COUNT(*)::integer FROM tenant_monitors m WHERE m.tenant_id = t.id AND m.deleted_at IS NULL) as "monitors_count!",
+				(SELECT COUNT(*)::integer FROM tenant_networks n WHERE n.tenant_id = t.id) as "networks_count!",
+				(SELECT COUNT(*)::integer FROM tenant_triggers tr WHERE tr.tenant_id = t.id) as "triggers_count!",
+				(SELECT COALESCE(SUM(r.usage_value), 0)::integer
+				 FROM resource_usage r
+				 WHERE r.tenant_id = t.id
+				 AND r.resource_type = 'rpc_requests'
+				 AND r.created_at >= NOW() - INTERVAL '1 minute') as "rpc_requests!",
+				(SELECT r.usage_value::integer
+				 FROM resource_usage r
+				 WHERE r.tenant_id = t.id
+				 AND r.resource_type = 'storage'
+				 AND r.usage_date = CURRENT_DATE
+				 ORDER BY r.created_at DESC
+				 LIMIT 1) as storage_mb
+			FROM tenants t
+			WHERE t.id = ANY($1)
+			"#,
+			tenant_ids
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		let blockchain_rows = sqlx::query!(
+			r#"
+			SELECT
+				q.tenant_id,
+				q.blockchain,
+				q.max_monitors,
+				q.max_networks,
+				(SELECT COUNT(*)::integer
+				 FROM tenant_monitors m
+				 INNER JOIN tenant_networks n ON m.network_id = n.id
+				 WHERE m.tenant_id = q.tenant_id AND n.blockchain = q.blockchain
+				 AND m.deleted_at IS NULL) as "monitors_count!",
+				(SELECT COUNT(*)::integer
+				 FROM tenant_networks n
+				 WHERE n.tenant_id = q.tenant_id AND n.blockchain = q.blockchain) as "networks_count!"
+			FROM tenant_blockchain_quotas q
+			WHERE q.tenant_id = ANY($1)
+			ORDER BY q.blockchain
+			"#,
+			tenant_ids
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		let mut blockchains: HashMap<Uuid, Vec<BlockchainQuotaStatus>> = HashMap::new();
+		for row in blockchain_rows {
+			blockchains
+				.entry(row.tenant_id)
+				.or_default()
+				.push(BlockchainQuotaStatus {
+					blockchain: row.blockchain,
+					max_monitors: row.max_monitors,
+					max_networks: row.max_networks,
+					monitors_count: row.monitors_count,
+					networks_count: row.networks_count,
+				});
+		}
+
+		let mut statuses: HashMap<Uuid, ResourceQuotaStatus> = rows
+			.into_iter()
+			.map(|row| {
+				let quotas = TenantQuotas {
+					max_monitors: row.max_monitors,
+					max_networks: row.max_networks,
+					max_triggers_per_monitor: row.max_triggers_per_monitor,
+					max_rpc_requests_per_minute: row.max_rpc_requests_per_minute,
+					max_storage_mb: row.max_storage_mb,
+					api_rate_limits: crate::models::resource_quota::ApiRateLimits::default(),
+				};
+				let usage = CurrentUsage {
+					monitors_count: row.monitors_count,
+					networks_count: row.networks_count,
+					triggers_count: row.triggers_count,
+					rpc_requests_last_minute: row.rpc_requests,
+					storage_mb_used: row.storage_mb.unwrap_or(0),
+				};
+				let status = ResourceQuotaStatus::from_usage(
+					row.id,
+					quotas,
+					usage,
+					blockchains.remove(&row.id).unwrap_or_default(),
+				)
+				.with_warn_threshold(self.quota_warn_threshold_percent);
+				(row.id, status)
+			})
+			.collect();
+
+		// Preserve the requested order, skipping unknown tenants
+		Ok(tenant_ids
+			.iter()
+			.filter_map(|id| statuses.remove(id))
+			.collect())
+	}
+
+	async fn get_resource_counts(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<ResourceCounts, TenantRepositoryError> {
+		let counts = sqlx::query_as!(
+			ResourceCounts,
+			r#"
+			SELECT
+				(SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL) as "monitors!",
+				(SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1) as "networks!",
+				(SELECT COUNT(*) FROM tenant_triggers WHERE tenant_id = $1) as "triggers!"
+			"#,
+			tenant_id
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(counts)
+	}
+
+	async fn check_quota(
+		&self,
+		tenant_id: Uuid,
+		resource: &str,
+		amount: i32,
+	) -> Result<bool, TenantRepositoryError> {
+		let status = self.get_quota_status(tenant_id).await?;
+
+		let available = status.available_for(resource).ok_or_else(|| {
+			TenantRepositoryError::InvalidConfiguration(format!(
+				"Unknown resource type: {}",
+				resource
+			))
+		})?;
+
+		Ok(available >= amount)
+	}
+
+	async fn check_quota_batch(
+		&self,
+		tenant_id: Uuid,
+		checks: &[QuotaCheck],
+	) -> Result<Vec<QuotaCheckResult>, TenantRepositoryError> {
+		let status = self.get_quota_status(tenant_id).await?;
+
+		status
+			.check_all(checks)
+			.map_err(TenantRepositoryError::ValidationError)
+	}
+
+	async fn set_blockchain_quota(
+		&self,
+		tenant_id: Uuid,
+		quota: BlockchainQuota,
+	) -> Result<BlockchainQuota, TenantRepositoryError> {
+		let quota = sqlx::query_as!(
+			BlockchainQuota,
+			r#"
+			INSERT INTO tenant_blockchain_quotas (tenant_id, blockchain, max_monitors, max_networks)
+			VALUES ($1, $2, $3, $4)
+			ON CONFLICT (tenant_id, blockchain)
+			DO UPDATE SET max_monitors = EXCLUDED.max_monitors, max_networks = EXCLUDED.max_networks
+			RETURNING blockchain, max_monitors, max_networks
+			"#,
+			tenant_id,
+			quota.blockchain,
+			quota.max_monitors,
+			quota.max_networks
+		)
+		.fetch_one(&self.pool)
+		.await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(quota)
+	}
+
+	async fn get_trigger_defaults(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<TriggerDefaults, TenantRepositoryError> {
+		let defaults = sqlx::query_scalar!(
+			"SELECT trigger_defaults FROM tenants WHERE id = $1",
+			tenant_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		match defaults {
+			Some(value) => serde_json::from_value(value).map_err(|e| {
+				TenantRepositoryError::InvalidConfiguration(format!(
+					"Invalid trigger defaults: {}",
+					e
+				))
+			}),
+			None => Ok(TriggerDefaults::default()),
+		}
+	}
+
+	async fn rotate_signing_secret(
+		&self,
+		tenant_id: Uuid,
+		signing_secret: String,
+	) -> Result<TriggerDefaults, TenantRepositoryError> {
+		let mut tx = self.pool.begin().await?;
+
+		let defaults = sqlx::query_scalar!(
+			"SELECT trigger_defaults FROM tenants WHERE id = $1 FOR UPDATE",
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?;
+
+		let mut defaults: TriggerDefaults = match defaults {
+			Some(value) => serde_json::from_value(value).map_err(|e| {
+				TenantRepositoryError::InvalidConfiguration(format!(
+					"Invalid trigger defaults: {}",
+					e
+				))
+			})?,
+			None => TriggerDefaults::default(),
+		};
+		defaults.rotate_signing_secret(signing_secret);
+
+		sqlx::query!(
+			"UPDATE tenants SET trigger_defaults = $2, updated_at = NOW() WHERE id = $1",
+			tenant_id,
+			serde_json::to_value(&defaults)
+				.map_err(|e| TenantRepositoryError::Internal(e.to_string()))?
+		)
+		.execute(&mut *tx)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(defaults)
+	}
+}
+
+/// Update touching only the columns set in `request`, plus `updated_at`
+fn tenant_update_query(
+	tenant_id: Uuid,
+	request: &UpdateTenantRequest,
+) -> QueryBuilder<'_, Postgres> {
+	let mut query = QueryBuilder::new("UPDATE tenants SET updated_at = NOW()");
+
+	if let Some(name) = &request.name {
+		query.push(", name = ").push_bind(name.as_str());
+	}
+	if let Some(is_active) = request.is_active {
+		query.push(", is_active = ").push_bind(is_active);
+	}
+	if let Some(max_monitors) = request.max_monitors {
+		query.push(", max_monitors = ").push_bind(max_monitors);
+	}
+	if let Some(max_networks) = request.max_networks {
+		query.push(", max_networks = ").push_bind(max_networks);
+	}
+	if let Some(max_triggers_per_monitor) = request.max_triggers_per_monitor {
+		query
+			.push(", max_triggers_per_monitor = ")
+			.push_bind(max_triggers_per_monitor);
+	}
+	if let Some(max_rpc_requests_per_minute) = request.max_rpc_requests_per_minute {
+		query
+			.push(", max_rpc_requests_per_minute = ")
+			.push_bind(max_rpc_requests_per_minute);
+	}
+	if let Some(max_storage_mb) = request.max_storage_mb {
+		query.push(", max_storage_mb = ").push_bind(max_storage_mb);
+	}
+
+	query.push(" WHERE id = ").push_bind(tenant_id).push(
+		r#"
+		RETURNING id, name, slug,
+		          COALESCE(is_active, true) as is_active,
+		          COALESCE(max_monitors, 10) as max_monitors,
+		          COALESCE(max_networks, 5) as max_networks,
+		          COALESCE(max_triggers_per_monitor, 3) as max_triggers_per_monitor,
+		          COALESCE(max_rpc_requests_per_minute, 1000) as max_rpc_requests_per_minute,
+		          COALESCE(max_storage_mb, 1000) as max_storage_mb,
+		          created_at, updated_at"#,
+	);
+
+	query
+}
+
+/// One page of the filtered tenant listing; soft-deleted tenants are never listed
+fn tenant_list_query(filter: &TenantFilter, limit: i64, offset: i64) -> QueryBuilder<'_, Postgres> {
+	let mut query = QueryBuilder::new(
+		r#"
+		SELECT id, name, slug,
+		       COALESCE(is_active, true) as is_active,
+		       COALESCE(max_monitors, 10) as max_monitors,
+		       COALESCE(max_networks, 5) as max_networks,
+		       COALESCE(max_triggers_per_monitor, 3) as max_triggers_per_monitor,
+		       COALESCE(max_rpc_requests_per_minute, 1000) as max_rpc_requests_per_minute,
+		       COALESCE(max_storage_mb, 1000) as max_storage_mb,
+		       created_at, updated_at
+		FROM tenants
+		WHERE deleted_at IS NULL"#,
+	);
+	push_tenant_filters(&mut query, filter);
+
+	query
+		.push(" ORDER BY created_at DESC, id DESC LIMIT ")
+		.push_bind(limit)
+		.push(" OFFSET ")
+		.push_bind(offset);
+
+	query
+}
+
+fn push_tenant_filters<'a>(query: &mut QueryBuilder<'a, Postgres>, filter: &'a TenantFilter) {
+	if let Some(name) = filter
+		.name_contains
+		.as_deref()
+		.filter(|name| !name.is_empty())
+	{
+		query
+			.push(" AND name ILIKE ")
+			.push_bind(format!("%{}%", escape_like(name)));
+	}
+	if let Some(is_active) = filter.is_active {
+		query
+			.push(" AND COALESCE(is_active, true) = ")
+			.push_bind(is_active);
+	}
+	if let Some(prefix) = filter
+		.slug_prefix
+		.as_deref()
+		.filter(|prefix| !prefix.is_empty())
+	{
+		query
+			.push(" AND slug LIKE ")
+			.push_bind(format!("{}%", escape_like(prefix)));
+	}
+}
+
+/// Match `%`, `_` and `\` literally inside a LIKE pattern
+pub(super) fn escape_like(value: &str) -> String {
+	let mut escaped = String::with_capacity(value.len());
+	for c in value.chars() {
+		if matches!(c, '%' | '_' | '\\') {
+			escaped.push('\\');
+		}
+		escaped.push(c);
+	}
+	escaped
+}
+
+#[async_trait]
+impl<T: TenantRepositoryTrait> TenantRepositoryTrait for Arc<T> {
+	async fn create(&self, request: CreateTenantRequest) -> Result<Tenant, TenantRepositoryError> {
+		(**self).create(request).await
+	}
+
+	async fn create_with_owner(
+		&self,
+		request: CreateTenantRequest,
+		owner_id: Uuid,
+	) -> Result<Tenant, TenantRepositoryError> {
+		(**self).create_with_owner(request, owner_id).await
+	}
+
+	fn validate_slug(&self, slug: &str) -> Result<(), TenantRepositoryError> {
+		(**self).validate_slug(slug)
+	}
+
+	async fn check_deleted_slug(&self, slug: &str) -> Result<(), TenantRepositoryError> {
+		(**self).check_deleted_slug(slug).await
+	}
+
+	async fn get(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError> {
+		(**self).get(tenant_id).await
+	}
+
+	async fn get_by_slug(&self, slug: &str) -> Result<Tenant, TenantRepositoryError> {
+		(**self).get_by_slug(slug).await
+	}
+
+	async fn update(
+		&self,
+		tenant_id: Uuid,
+		request: UpdateTenantRequest,
+	) -> Result<Tenant, TenantRepositoryError> {
+		(**self).update(tenant_id, request).await
+	}
+
+	async fn rename_slug(
+		&self,
+		tenant_id: Uuid,
+		slug: &str,
+	) -> Result<Tenant, TenantRepositoryError> {
+		(**self).rename_slug(tenant_id, slug).await
+	}
+
+	async fn delete(&self, tenant_id: Uuid, force: bool) -> Result<(), TenantRepositoryError> {
+		(**self).delete(tenant_id, force).await
+	}
+
+	async fn restore(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError> {
+		(**self).restore(tenant_id).await
+	}
+
+	async fn purge(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError> {
+		(**self).purge(tenant_id).await
+	}
+
+	async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError> {
+		(**self).list(limit, offset).await
+	}
+
+	async fn list_filtered(
+		&self,
+		filter: TenantFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<Tenant>, TenantRepositoryError> {
+		(**self).list_filtered(filter, limit, offset).await
+	}
+
+	async fn count_filtered(&self, filter: TenantFilter) -> Result<i64, TenantRepositoryError> {
+		(**self).count_filtered(filter).await
+	}
+
+	async fn add_member(
+		&self,
+		tenant_id: Uuid,
+		user_id: Uuid,
+		role: TenantRole,
+	) -> Result<TenantMembership, TenantRepositoryError> {
+		(**self).add_member(tenant_id, user_id, role).await
+	}
+
+	async fn remove_member(
+		&self,
+		tenant_id: Uuid,
+		user_id: Uuid,
+	) -> Result<(), TenantRepositoryError> {
+		(**self).remove_member(tenant_id, user_id).await
+	}
+
+	async fn update_member_role(
+		&self,
+		tenant_id: Uuid,
+		user_id: Uuid,
+		role: TenantRole,
+	) -> Result<TenantMembership, TenantRepositoryError> {
+		(**self).update_member_role(tenant_id, user_id, role).await
+	}
+
+	async fn transfer_ownership(
+		&self,
+		tenant_id: Uuid,
+		from_user: Uuid,
+		to_user: Uuid,
+	) -> Result<(), TenantRepositoryError> {
+		(**self)
+			.transfer_ownership(tenant_id, from_user, to_user)
+			.await
+	}
+
+	async fn get_members(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<TenantMembership>, TenantRepositoryError> {
+		(**self).get_members(tenant_id).await
+	}
+
+	async fn get_user_tenants(
+		&self,
+		user_id: Uuid,
+	) -> Result<Vec<(Tenant, TenantRole)>, TenantRepositoryError> {
+		(**self).get_user_tenants(user_id).await
+	}
+
+	async fn add_members_batch(
+		&self,
+		tenant_id: Uuid,
+		entries: Vec<BatchMemberEntry>,
+	) -> Result<Vec<BatchMemberResult>, TenantRepositoryError> {
+		(**self).add_members_batch(tenant_id, entries).await
+	}
+
+	async fn sync_members(
+		&self,
+		tenant_id: Uuid,
+		entries: Vec<BatchMemberEntry>,
+		change_owners: bool,
+	) -> Result<MemberSyncResult, TenantRepositoryError> {
+		(**self)
+			.sync_members(tenant_id, entries, change_owners)
+			.await
+	}
+
+	async fn find_duplicate_memberships(
+		&self,
+	) -> Result<Vec<DuplicateMembership>, TenantRepositoryError> {
+		(**self).find_duplicate_memberships().await
+	}
+
+	async fn find_integrity_violations(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<IntegrityViolation>, TenantRepositoryError> {
+		(**self).find_integrity_violations(tenant_id).await
+	}
+
+	async fn find_user_by_identity(
+		&self,
+		issuer: &str,
+		subject: &str,
+	) -> Result<Option<User>, TenantRepositoryError> {
+		(**self).find_user_by_identity(issuer, subject).await
+	}
+
+	async fn link_identity(&self, identity: &SsoIdentity) -> Result<User, TenantRepositoryError> {
+		(**self).link_identity(identity).await
+	}
+
+	async fn get_quota_status(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<ResourceQuotaStatus, TenantRepositoryError> {
+		(**self).get_quota_status(tenant_id).await
+	}
+
+	async fn get_quota_statuses(
+		&self,
+		tenant_ids: &[Uuid],
+	) -> Result<Vec<ResourceQuotaStatus>, TenantRepositoryError> {
+		(**self).get_quota_statuses(tenant_ids).await
+	}
+
+	async fn get_resource_counts(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<ResourceCounts, TenantRepositoryError> {
+		(**self).get_resource_counts(tenant_id).await
+	}
+
+	async fn check_quota(
+		&self,
+		tenant_id: Uuid,
+		resource: &str,
+		amount: i32,
+	) -> Result<bool, TenantRepositoryError> {
+		(**self).check_quota(tenant_id, resource, amount).await
+	}
+
+	async fn check_quota_batch(
+		&self,
+		tenant_id: Uuid,
+		checks: &[QuotaCheck],
+	) -> Result<Vec<QuotaCheckResult>, TenantRepositoryError> {
+		(**self).check_quota_batch(tenant_id, checks).await
+	}
+
+	async fn set_blockchain_quota(
+		&self,
+		tenant_id: Uuid,
+		quota: BlockchainQuota,
+	) -> Result<BlockchainQuota, TenantRepositoryError> {
+		(**self).set_blockchain_quota(tenant_id, quota).await
+	}
+
+	async fn get_trigger_defaults(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<TriggerDefaults, TenantRepositoryError> {
+		(**self).get_trigger_defaults(tenant_id).await
+	}
+
+	async fn rotate_signing_secret(
+		&self,
+		tenant_id: Uuid,
+		signing_secret: String,
+	) -> Result<TriggerDefaults, TenantRepositoryError> {
+		(**self)
+			.rotate_signing_secret(tenant_id, signing_secret)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_tenant_list_query_binds_filter_values() {
+		let filter = TenantFilter {
+			name_contains: Some("acme' OR '1'='1".to_string()),
+			is_active: Some(true),
+			slug_prefix: Some("acme".to_string()),
+		};
+		let query = tenant_list_query(&filter, 10, 20);
+		let sql = query.sql();
+
+		assert!(sql.contains("WHERE deleted_at IS NULL AND name ILIKE $1"));
+		assert!(sql.contains("AND COALESCE(is_active, true) = $2 AND slug LIKE $3"));
+		assert!(sql.ends_with("LIMIT $4 OFFSET $5"));
+		assert!(!sql.contains("acme"));
+	}
+
+	#[test]
+	fn test_unfiltered_tenant_list_query_skips_empty_filters() {
+		let filter = TenantFilter {
+			name_contains: Some(String::new()),
+			..Default::default()
+		};
+		let query = tenant_list_query(&filter, 10, 0);
+
+		assert!(query
+			.sql()
+			.contains("WHERE deleted_at IS NULL ORDER BY created_at DESC"));
+	}
 
-		Ok(has_capacity)
+	#[test]
+	fn test_escape_like_matches_wildcards_literally() {
+		assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+		assert_eq!(escape_like("acme"), "acme");
 	}
 }
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use sqlx::{Pool, Postgres, QueryBuilder};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::error::TenantRepositoryError;
-use crate::models::{CreateTriggerRequest, TenantTrigger, UpdateTriggerRequest};
-use crate::utils::current_tenant_id;
+use crate::models::{
+	CreateTriggerRequest, CreationSource, ListCursor, TenantTrigger, TriggerFilter,
+	UpdateTriggerRequest,
+};
+use crate::utils::{current_tenant_id, QuotaStatusCache};
 
 #[async_trait]
 pub trait TenantTriggerRepositoryTrait: Clone + Send + Sync {
@@ -13,6 +16,11 @@ pub trait TenantTriggerRepositoryTrait: Clone + Send + Sync {
 		&self,
 		request: CreateTriggerRequest,
 	) -> Result<TenantTrigger, TenantRepositoryError>;
+	/// Create all triggers in one transaction; nothing is kept if any insert fails
+	async fn create_batch(
+		&self,
+		requests: Vec<CreateTriggerRequest>,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
 	async fn get(&self, trigger_id: &str) -> Result<TenantTrigger, TenantRepositoryError>;
 	async fn get_by_uuid(&self, id: Uuid) -> Result<TenantTrigger, TenantRepositoryError>;
 	async fn get_all(&self) -> Result<HashMap<String, TenantTrigger>, TenantRepositoryError>;
@@ -20,6 +28,15 @@ pub trait TenantTriggerRepositoryTrait: Clone + Send + Sync {
 		&self,
 		monitor_id: Uuid,
 	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	/// One page of the monitor's active triggers, oldest first
+	async fn list_by_monitor(
+		&self,
+		monitor_id: Uuid,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	/// Number of the monitor's active triggers
+	async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, TenantRepositoryError>;
 	async fn update(
 		&self,
 		trigger_id: &str,
@@ -31,7 +48,37 @@ pub trait TenantTriggerRepositoryTrait: Clone + Send + Sync {
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	/// Keyset page ordered by `(created_at, id)` descending, starting after `cursor`
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	/// Page ordered by `(created_at, id)` descending
+	async fn list_filtered(
+		&self,
+		filter: TriggerFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+	async fn count_filtered(&self, filter: TriggerFilter) -> Result<i64, TenantRepositoryError>;
 	async fn count(&self) -> Result<i64, TenantRepositoryError>;
+	/// Number of the tenant's triggers per trigger type
+	async fn count_by_type(&self) -> Result<HashMap<String, i64>, TenantRepositoryError>;
+	/// Triggers whose own configuration sets `signing_secret` to the given value
+	async fn list_by_signing_secret(
+		&self,
+		signing_secret: &str,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+
+	/// Note that a test delivery reached the trigger's channel
+	async fn record_successful_test(&self, trigger_id: &str) -> Result<(), TenantRepositoryError>;
 
 	// Check if we can create more triggers for a monitor
 	async fn check_quota(&self, monitor_id: Uuid) -> Result<bool, TenantRepositoryError>;
@@ -40,11 +87,21 @@ pub trait TenantTriggerRepositoryTrait: Clone + Send + Sync {
 #[derive(Clone)]
 pub struct TenantTriggerRepository {
 	pool: Pool<Postgres>,
+	quota_cache: QuotaStatusCache,
 }
 
 impl TenantTriggerRepository {
 	pub fn new(pool: Pool<Postgres>) -> Self {
-		Self { pool }
+		Self {
+			pool,
+			quota_cache: QuotaStatusCache::default(),
+		}
+	}
+
+	/// Invalidate the tenant's cached quota status whenever a write changes its counts
+	pub fn with_quota_cache(mut self, cache: QuotaStatusCache) -> Self {
+		self.quota_cache = cache;
+		self
 	}
 }
 
@@ -56,37 +113,57 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 	) -> Result<TenantTrigger, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
-		// Check quota
-		if !self.check_quota(request.monitor_id).await? {
-			return Err(TenantRepositoryError::QuotaExceeded(
-				"Trigger quota exceeded for monitor".to_string(),
-			));
-		}
+		// Dropping the transaction on error rolls back the insert
+		let mut tx = self.pool.begin().await?;
 
-		// Verify monitor exists and belongs to tenant
-		let monitor_exists = sqlx::query_scalar!(
-			"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND id = $2",
+		let max_triggers_per_monitor = sqlx::query_scalar!(
+			"SELECT max_triggers_per_monitor FROM tenants WHERE id = $1",
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?
+		.unwrap_or(0) as i64;
+
+		// Verify the monitor belongs to the tenant, locking it so concurrent
+		// creates can't overshoot its quota
+		let monitor = sqlx::query_scalar!(
+			"SELECT id FROM tenant_monitors WHERE tenant_id = $1 AND id = $2 AND deleted_at IS NULL FOR UPDATE",
 			tenant_id,
 			request.monitor_id
 		)
-		.fetch_one(&self.pool)
-		.await?
-		.unwrap_or(0);
+		.fetch_optional(&mut *tx)
+		.await?;
 
-		if monitor_exists == 0 {
+		if monitor.is_none() {
 			return Err(TenantRepositoryError::ResourceNotFound {
 				resource_type: "monitor".to_string(),
 				resource_id: request.monitor_id.to_string(),
 			});
 		}
 
+		let current_count = sqlx::query_scalar!(
+			"SELECT COUNT(*) FROM tenant_triggers WHERE tenant_id = $1 AND monitor_id = $2",
+			tenant_id,
+			request.monitor_id
+		)
+		.fetch_one(&mut *tx)
+		.await?
+		.unwrap_or(0);
+
+		if current_count >= max_triggers_per_monitor {
+			return Err(TenantRepositoryError::QuotaExceeded(
+				"Trigger quota exceeded for monitor".to_string(),
+			));
+		}
+
 		// Check if trigger_id already exists for this tenant
 		let existing = sqlx::query_scalar!(
 			"SELECT COUNT(*) FROM tenant_triggers WHERE tenant_id = $1 AND trigger_id = $2",
 			tenant_id,
 			request.trigger_id
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
 		if existing.unwrap_or(0) > 0 {
@@ -101,7 +178,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 			r#"
 			INSERT INTO tenant_triggers (tenant_id, trigger_id, monitor_id, name, type, configuration)
 			VALUES ($1, $2, $3, $4, $5, $6)
-			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			"#,
 			tenant_id,
 			request.trigger_id,
@@ -110,19 +187,109 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 			request.trigger_type,
 			request.configuration
 		)
-		.fetch_one(&self.pool)
+		.fetch_one(&mut *tx)
 		.await?;
 
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
 		Ok(trigger)
 	}
 
+	async fn create_batch(
+		&self,
+		requests: Vec<CreateTriggerRequest>,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		// Dropping the transaction on error rolls back every insert
+		let mut tx = self.pool.begin().await?;
+
+		let max_triggers_per_monitor = sqlx::query_scalar!(
+			"SELECT max_triggers_per_monitor FROM tenants WHERE id = $1",
+			tenant_id
+		)
+		.fetch_optional(&mut *tx)
+		.await?
+		.ok_or(TenantRepositoryError::TenantNotFound(tenant_id))?
+		.unwrap_or(0) as i64;
+
+		let mut triggers = Vec::with_capacity(requests.len());
+		for request in requests {
+			// Lock the monitor so concurrent creates can't overshoot its quota
+			let monitor = sqlx::query_scalar!(
+				"SELECT id FROM tenant_monitors WHERE tenant_id = $1 AND id = $2 AND deleted_at IS NULL FOR UPDATE",
+				tenant_id,
+				request.monitor_id
+			)
+			.fetch_optional(&mut *tx)
+			.await?;
+
+			if monitor.is_none() {
+				return Err(TenantRepositoryError::ResourceNotFound {
+					resource_type: "monitor".to_string(),
+					resource_id: request.monitor_id.to_string(),
+				});
+			}
+
+			let current_count = sqlx::query_scalar!(
+				"SELECT COUNT(*) FROM tenant_triggers WHERE tenant_id = $1 AND monitor_id = $2",
+				tenant_id,
+				request.monitor_id
+			)
+			.fetch_one(&mut *tx)
+			.await?
+			.unwrap_or(0);
+
+			if current_count >= max_triggers_per_monitor {
+				return Err(TenantRepositoryError::QuotaExceeded(format!(
+					"Trigger quota exceeded for monitor {}",
+					request.monitor_id
+				)));
+			}
+
+			let trigger = sqlx::query_as!(
+				TenantTrigger,
+				r#"
+				INSERT INTO tenant_triggers (tenant_id, trigger_id, monitor_id, name, type, configuration)
+				VALUES ($1, $2, $3, $4, $5, $6)
+				RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+				"#,
+				tenant_id,
+				request.trigger_id,
+				request.monitor_id,
+				request.name,
+				request.trigger_type,
+				request.configuration
+			)
+			.fetch_one(&mut *tx)
+			.await
+			.map_err(|e| match e {
+				sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+					TenantRepositoryError::AlreadyExists {
+						resource_type: "trigger".to_string(),
+						resource_id: request.trigger_id.clone(),
+					}
+				}
+				e => TenantRepositoryError::Database(e),
+			})?;
+
+			triggers.push(trigger);
+		}
+
+		tx.commit().await?;
+		self.quota_cache.invalidate(tenant_id);
+
+		Ok(triggers)
+	}
+
 	async fn get(&self, trigger_id: &str) -> Result<TenantTrigger, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
 		let trigger = sqlx::query_as!(
 			TenantTrigger,
 			r#"
-			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			FROM tenant_triggers 
 			WHERE tenant_id = $1 AND trigger_id = $2
 			"#,
@@ -145,7 +312,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		let trigger = sqlx::query_as!(
 			TenantTrigger,
 			r#"
-			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			FROM tenant_triggers 
 			WHERE tenant_id = $1 AND id = $2
 			"#,
@@ -168,7 +335,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		let triggers = sqlx::query_as!(
 			TenantTrigger,
 			r#"
-			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			FROM tenant_triggers 
 			WHERE tenant_id = $1 AND is_active = true
 			"#,
@@ -194,7 +361,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		let triggers = sqlx::query_as!(
 			TenantTrigger,
 			r#"
-			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			FROM tenant_triggers 
 			WHERE tenant_id = $1 AND monitor_id = $2 AND is_active = true
 			ORDER BY created_at
@@ -208,6 +375,52 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		Ok(triggers)
 	}
 
+	async fn list_by_monitor(
+		&self,
+		monitor_id: Uuid,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			FROM tenant_triggers
+			WHERE tenant_id = $1 AND monitor_id = $2 AND is_active = true
+			ORDER BY created_at, id
+			LIMIT $3 OFFSET $4
+			"#,
+			tenant_id,
+			monitor_id,
+			limit,
+			offset
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(triggers)
+	}
+
+	async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let count = sqlx::query_scalar!(
+			r#"
+			SELECT COUNT(*) as "count!"
+			FROM tenant_triggers
+			WHERE tenant_id = $1 AND monitor_id = $2 AND is_active = true
+			"#,
+			tenant_id,
+			monitor_id
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(count)
+	}
+
 	async fn update(
 		&self,
 		trigger_id: &str,
@@ -223,9 +436,11 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 				name = COALESCE($3, name),
 				configuration = COALESCE($4, configuration),
 				is_active = COALESCE($5, is_active),
+				-- A new configuration has to be tested again
+				last_successful_test_at = CASE WHEN $4::jsonb IS NULL THEN last_successful_test_at END,
 				updated_at = NOW()
 			WHERE tenant_id = $1 AND trigger_id = $2
-			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			RETURNING id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			"#,
 			tenant_id,
 			trigger_id,
@@ -260,6 +475,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 				resource_id: trigger_id.to_string(),
 			});
 		}
+		self.quota_cache.invalidate(tenant_id);
 
 		Ok(())
 	}
@@ -274,7 +490,7 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		let triggers = sqlx::query_as!(
 			TenantTrigger,
 			r#"
-			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, created_at, updated_at
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
 			FROM tenant_triggers 
 			WHERE tenant_id = $1 
 			ORDER BY created_at DESC 
@@ -290,6 +506,131 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		Ok(triggers)
 	}
 
+	async fn list_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let items = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			FROM tenant_triggers
+			WHERE tenant_id = $1
+			AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3::uuid))
+			ORDER BY created_at DESC, id DESC
+			LIMIT $4
+			"#,
+			tenant_id,
+			cursor.map(|c| c.created_at),
+			cursor.map(|c| c.id),
+			limit
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(items)
+	}
+
+	async fn list_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			FROM tenant_triggers
+			WHERE tenant_id = $1 AND creation_source = $2
+			ORDER BY created_at DESC
+			LIMIT $3 OFFSET $4
+			"#,
+			tenant_id,
+			source.as_str(),
+			limit,
+			offset
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(triggers)
+	}
+
+	async fn list_filtered(
+		&self,
+		filter: TriggerFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let mut query = QueryBuilder::new(
+			r#"
+			SELECT t.id, t.tenant_id, t.trigger_id, t.monitor_id, t.name, t.type, t.configuration, t.is_active, t.creation_source, t.created_at, t.updated_at
+			FROM tenant_triggers t
+			WHERE t.tenant_id = "#,
+		);
+		query.push_bind(tenant_id);
+		push_trigger_filters(&mut query, &filter);
+		query
+			.push(" ORDER BY t.created_at DESC, t.id DESC LIMIT ")
+			.push_bind(limit)
+			.push(" OFFSET ")
+			.push_bind(offset);
+
+		let triggers = query
+			.build_query_as::<TenantTrigger>()
+			.fetch_all(&self.pool)
+			.await?;
+
+		Ok(triggers)
+	}
+
+	async fn count_filtered(&self, filter: TriggerFilter) -> Result<i64, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let mut query =
+			QueryBuilder::new("SELECT COUNT(*) FROM tenant_triggers t WHERE t.tenant_id = ");
+		query.push_bind(tenant_id);
+		push_trigger_filters(&mut query, &filter);
+
+		let count = query
+			.build_query_scalar::<i64>()
+			.fetch_one(&self.pool)
+			.await?;
+
+		Ok(count)
+	}
+
+	async fn list_by_signing_secret(
+		&self,
+		signing_secret: &str,
+	) -> Result<Vec<TenantTrigger>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let triggers = sqlx::query_as!(
+			TenantTrigger,
+			r#"
+			SELECT id, tenant_id, trigger_id, monitor_id, name, type as trigger_type, configuration, is_active, creation_source, created_at, updated_at
+			FROM tenant_triggers
+			WHERE tenant_id = $1 AND configuration->>'signing_secret' = $2
+			ORDER BY created_at DESC
+			"#,
+			tenant_id,
+			signing_secret
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(triggers)
+	}
+
 	async fn count(&self) -> Result<i64, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
@@ -307,6 +648,52 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		Ok(count)
 	}
 
+	async fn count_by_type(&self) -> Result<HashMap<String, i64>, TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let rows = sqlx::query!(
+			r#"
+			SELECT type AS trigger_type, COUNT(*) as "count!"
+			FROM tenant_triggers
+			WHERE tenant_id = $1
+			GROUP BY type
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(rows
+			.into_iter()
+			.map(|row| (row.trigger_type, row.count))
+			.collect())
+	}
+
+	async fn record_successful_test(&self, trigger_id: &str) -> Result<(), TenantRepositoryError> {
+		let tenant_id = current_tenant_id();
+
+		let result = sqlx::query!(
+			r#"
+			UPDATE tenant_triggers
+			SET last_successful_test_at = NOW()
+			WHERE tenant_id = $1 AND trigger_id = $2
+			"#,
+			tenant_id,
+			trigger_id
+		)
+		.execute(&self.pool)
+		.await?;
+
+		if result.rows_affected() == 0 {
+			return Err(TenantRepositoryError::ResourceNotFound {
+				resource_type: "trigger".to_string(),
+				resource_id: trigger_id.to_string(),
+			});
+		}
+
+		Ok(())
+	}
+
 	async fn check_quota(&self, monitor_id: Uuid) -> Result<bool, TenantRepositoryError> {
 		let tenant_id = current_tenant_id();
 
@@ -332,3 +719,24 @@ impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		Ok(current_count < max_triggers_per_monitor.unwrap_or(0) as i64)
 	}
 }
+
+/// Conditions on `tenant_triggers t` for the filtered listing
+fn push_trigger_filters<'a>(query: &mut QueryBuilder<'a, Postgres>, filter: &'a TriggerFilter) {
+	if let Some(trigger_type) = filter.trigger_type.as_deref() {
+		query.push(" AND t.type = ").push_bind(trigger_type);
+	}
+	if let Some(monitor) = filter.monitor.as_deref() {
+		query
+			.push(" AND t.monitor_id IN (SELECT m.id FROM tenant_monitors m WHERE m.tenant_id = t.tenant_id AND (m.monitor_id = ")
+			.push_bind(monitor);
+		if let Ok(id) = Uuid::parse_str(monitor) {
+			query.push(" OR m.id = ").push_bind(id);
+		}
+		query.push("))");
+	}
+	if let Some(is_active) = filter.is_active {
+		query
+			.push(" AND COALESCE(t.is_active, true) = ")
+			.push_bind(is_active);
+	}
+}
@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use crate::models::{UsagePoint, API_REQUESTS_RESOURCE, RPC_REQUESTS_RESOURCE, STORAGE_RESOURCE};
+
+#[async_trait]
+pub trait UsageRepositoryTrait: Clone + Send + Sync {
+	/// Count one API request against the tenant for the given day
+	async fn record_api_request(
+		&self,
+		tenant_id: Uuid,
+		date: NaiveDate,
+	) -> Result<(), TenantRepositoryError>;
+	/// Count `count` RPC requests against the tenant's current one-minute window
+	async fn record_rpc_requests(
+		&self,
+		tenant_id: Uuid,
+		count: i64,
+	) -> Result<(), TenantRepositoryError>;
+	/// Set the tenant's storage in use for today, replacing any earlier report
+	async fn record_storage(&self, tenant_id: Uuid, mb: i64) -> Result<(), TenantRepositoryError>;
+	/// Add `delta_mb` (possibly negative) to the tenant's storage for today,
+	/// starting from the latest earlier level; never drops below zero
+	async fn add_storage(
+		&self,
+		tenant_id: Uuid,
+		delta_mb: i64,
+	) -> Result<(), TenantRepositoryError>;
+	/// Daily API request totals between `from` and `to` inclusive; days without requests are omitted
+	async fn api_request_counts(
+		&self,
+		tenant_id: Uuid,
+		from: NaiveDate,
+		to: NaiveDate,
+	) -> Result<Vec<UsagePoint>, TenantRepositoryError>;
+}
+
+#[derive(Clone)]
+pub struct UsageRepository {
+	pool: Pool<Postgres>,
+}
+
+impl UsageRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+}
+
+#[async_trait]
+impl UsageRepositoryTrait for UsageRepository {
+	async fn record_api_request(
+		&self,
+		tenant_id: Uuid,
+		date: NaiveDate,
+	) -> Result<(), TenantRepositoryError> {
+		sqlx::query!(
+			r#"
+			INSERT INTO resource_usage (tenant_id, resource_type, usage_value, usage_date)
+			VALUES ($1, $2, 1, $3)
+			ON CONFLICT (tenant_id, resource_type, usage_date)
+			DO UPDATE SET usage_value = resource_usage.usage_value + 1
+			"#,
+			tenant_id,
+			API_REQUESTS_RESOURCE,
+			date
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+
+	async fn record_rpc_requests(
+		&self,
+		tenant_id: Uuid,
+		count: i64,
+	) -> Result<(), TenantRepositoryError> {
+		// The row is a fixed one-minute window: once it is older than the
+		// `INTERVAL '1 minute'` used by get_quota_status it restarts at `count`
+		sqlx::query!(
+			r#"
+			INSERT INTO resource_usage (tenant_id, resource_type, usage_value, usage_date)
+			VALUES ($1, $2, $3, CURRENT_DATE)
+			ON CONFLICT (tenant_id, resource_type, usage_date)
+			DO UPDATE SET
+				usage_value = CASE
+					WHEN resource_usage.created_at >= NOW() - INTERVAL '1 minute'
+					THEN resource_usage.usage_value + EXCLUDED.usage_value
+					ELSE EXCLUDED.usage_value
+				END,
+				created_at = CASE
+					WHEN resource_usage.created_at >= NOW() - INTERVAL '1 minute'
+					THEN resource_usage.created_at
+					ELSE NOW()
+				END
+			"#,
+			tenant_id,
+			RPC_REQUESTS_RESOURCE,
+			count
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+
+	async fn record_storage(&self, tenant_id: Uuid, mb: i64) -> Result<(), TenantRepositoryError> {
+		// Storage is a level rather than a counter, so the latest report wins
+		sqlx::query!(
+			r#"
+			INSERT INTO resource_usage (tenant_id, resource_type, usage_value, usage_date)
+			VALUES ($1, $2, $3, CURRENT_DATE)
+			ON CONFLICT (tenant_id, resource_type, usage_date)
+			DO UPDATE SET usage_value = EXCLUDED.usage_value, created_at = NOW()
+			"#,
+			tenant_id,
+			STORAGE_RESOURCE,
+			mb
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+
+	async fn add_storage(
+		&self,
+		tenant_id: Uuid,
+		delta_mb: i64,
+	) -> Result<(), TenantRepositoryError> {
+		// The first change of the day carries the last known level forward
+		sqlx::query!(
+			r#"
+			INSERT INTO resource_usage (tenant_id, resource_type, usage_value, usage_date)
+			VALUES (
+				$1, $2::VARCHAR,
+				GREATEST(
+					COALESCE(
+						(SELECT usage_value FROM resource_usage
+						 WHERE tenant_id = $1 AND resource_type = $2::VARCHAR
+						 ORDER BY usage_date DESC LIMIT 1),
+						0
+					) + $3::BIGINT,
+					0
+				),
+				CURRENT_DATE
+			)
+			ON CONFLICT (tenant_id, resource_type, usage_date)
+			DO UPDATE SET
+				usage_value = GREATEST(resource_usage.usage_value + $3::BIGINT, 0),
+				created_at = NOW()
+			"#,
+			tenant_id,
+			STORAGE_RESOURCE,
+			delta_mb
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+
+	async fn api_request_counts(
+		&self,
+		tenant_id: Uuid,
+		from: NaiveDate,
+		to: NaiveDate,
+	) -> Result<Vec<UsagePoint>, TenantRepositoryError> {
+		let points = sqlx::query_as!(
+			UsagePoint,
+			r#"
+			SELECT usage_date as date, usage_value as value
+			FROM resource_usage
+			WHERE tenant_id = $1 AND resource_type = $2
+			AND usage_date BETWEEN $3 AND $4
+			ORDER BY usage_date ASC
+			"#,
+			tenant_id,
+			API_REQUESTS_RESOURCE,
+			from,
+			to
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(points)
+	}
+}
@@ -0,0 +1,251 @@
+use serde_json::Value as JsonValue;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use super::error::TenantRepositoryError;
+use crate::models::{
+	CreateWebhookRequest, TenantWebhook, UpdateWebhookRequest, WebhookDelivery,
+	WebhookDeliveryStatus,
+};
+
+#[derive(Clone)]
+pub struct WebhookRepository {
+	pool: Pool<Postgres>,
+}
+
+fn webhook_not_found(webhook_id: Uuid) -> TenantRepositoryError {
+	TenantRepositoryError::ResourceNotFound {
+		resource_type: "webhook".to_string(),
+		resource_id: webhook_id.to_string(),
+	}
+}
+
+impl WebhookRepository {
+	pub fn new(pool: Pool<Postgres>) -> Self {
+		Self { pool }
+	}
+
+	pub async fn create(
+		&self,
+		tenant_id: Uuid,
+		request: &CreateWebhookRequest,
+		secret: &str,
+		created_by: Option<Uuid>,
+	) -> Result<TenantWebhook, TenantRepositoryError> {
+		let webhook = sqlx::query_as!(
+			TenantWebhook,
+			r#"
+			INSERT INTO tenant_webhooks (tenant_id, url, secret, events, is_active, created_by)
+			VALUES ($1, $2, $3, $4, $5, $6)
+			RETURNING id, tenant_id, url, secret, events, is_active, created_by, created_at, updated_at
+			"#,
+			tenant_id,
+			request.url.trim(),
+			secret,
+			&request.events,
+			request.is_active.unwrap_or(true),
+			created_by
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(webhook)
+	}
+
+	pub async fn list(&self, tenant_id: Uuid) -> Result<Vec<TenantWebhook>, TenantRepositoryError> {
+		let webhooks = sqlx::query_as!(
+			TenantWebhook,
+			r#"
+			SELECT id, tenant_id, url, secret, events, is_active, created_by, created_at, updated_at
+			FROM tenant_webhooks
+			WHERE tenant_id = $1
+			ORDER BY created_at, id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(webhooks)
+	}
+
+	pub async fn get(
+		&self,
+		tenant_id: Uuid,
+		webhook_id: Uuid,
+	) -> Result<TenantWebhook, TenantRepositoryError> {
+		sqlx::query_as!(
+			TenantWebhook,
+			r#"
+			SELECT id, tenant_id, url, secret, events, is_active, created_by, created_at, updated_at
+			FROM tenant_webhooks
+			WHERE tenant_id = $1 AND id = $2
+			"#,
+			tenant_id,
+			webhook_id
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or_else(|| webhook_not_found(webhook_id))
+	}
+
+	/// Apply the fields set in `request`, leaving the others unchanged
+	pub async fn update(
+		&self,
+		tenant_id: Uuid,
+		webhook_id: Uuid,
+		request: &UpdateWebhookRequest,
+	) -> Result<TenantWebhook, TenantRepositoryError> {
+		sqlx::query_as!(
+			TenantWebhook,
+			r#"
+			UPDATE tenant_webhooks SET
+				url = COALESCE($3, url),
+				secret = COALESCE($4, secret),
+				events = COALESCE($5, events),
+				is_active = COALESCE($6, is_active),
+				updated_at = NOW()
+			WHERE tenant_id = $1 AND id = $2
+			RETURNING id, tenant_id, url, secret, events, is_active, created_by, created_at, updated_at
+			"#,
+			tenant_id,
+			webhook_id,
+			request.url.as_deref().map(str::trim),
+			request.secret.as_deref(),
+			request.events.as_deref(),
+			request.is_active
+		)
+		.fetch_optional(&self.pool)
+		.await?
+		.ok_or_else(|| webhook_not_found(webhook_id))
+	}
+
+	/// Remove the webhook along with its delivery history
+	pub async fn delete(
+		&self,
+		tenant_id: Uuid,
+		webhook_id: Uuid,
+	) -> Result<(), TenantRepositoryError> {
+		let result = sqlx::query!(
+			"DELETE FROM tenant_webhooks WHERE tenant_id = $1 AND id = $2",
+			tenant_id,
+			webhook_id
+		)
+		.execute(&self.pool)
+		.await?;
+
+		if result.rows_affected() == 0 {
+			return Err(webhook_not_found(webhook_id));
+		}
+
+		Ok(())
+	}
+
+	/// The webhook's latest deliveries, newest first
+	pub async fn list_deliveries(
+		&self,
+		tenant_id: Uuid,
+		webhook_id: Uuid,
+		limit: i64,
+	) -> Result<Vec<WebhookDelivery>, TenantRepositoryError> {
+		// Distinguish an unknown webhook from one with no deliveries yet
+		self.get(tenant_id, webhook_id).await?;
+
+		let deliveries = sqlx::query_as!(
+			WebhookDelivery,
+			r#"
+			SELECT id, webhook_id, tenant_id, audit_log_id, event, payload,
+			       status as "status: WebhookDeliveryStatus", attempts, response_status,
+			       error, created_at, updated_at
+			FROM tenant_webhook_deliveries
+			WHERE tenant_id = $1 AND webhook_id = $2
+			ORDER BY created_at DESC, id
+			LIMIT $3
+			"#,
+			tenant_id,
+			webhook_id,
+			limit
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(deliveries)
+	}
+
+	/// The tenant's active webhooks, for the dispatcher, which runs outside any tenant context
+	pub async fn list_active_for_tenant(
+		&self,
+		tenant_id: Uuid,
+	) -> Result<Vec<TenantWebhook>, TenantRepositoryError> {
+		let webhooks = sqlx::query_as!(
+			TenantWebhook,
+			r#"
+			SELECT id, tenant_id, url, secret, events, is_active, created_by, created_at, updated_at
+			FROM tenant_webhooks
+			WHERE tenant_id = $1 AND is_active
+			ORDER BY created_at, id
+			"#,
+			tenant_id
+		)
+		.fetch_all(&self.pool)
+		.await?;
+
+		Ok(webhooks)
+	}
+
+	/// Record a pending delivery before its first attempt
+	pub async fn create_delivery(
+		&self,
+		webhook: &TenantWebhook,
+		audit_log_id: Option<Uuid>,
+		event: &str,
+		payload: &JsonValue,
+	) -> Result<WebhookDelivery, TenantRepositoryError> {
+		let delivery = sqlx::query_as!(
+			WebhookDelivery,
+			r#"
+			INSERT INTO tenant_webhook_deliveries (webhook_id, tenant_id, audit_log_id, event, payload)
+			VALUES ($1, $2, $3, $4, $5)
+			RETURNING id, webhook_id, tenant_id, audit_log_id, event, payload,
+			          status as "status: WebhookDeliveryStatus", attempts, response_status,
+			          error, created_at, updated_at
+			"#,
+			webhook.id,
+			webhook.tenant_id,
+			audit_log_id,
+			event,
+			payload
+		)
+		.fetch_one(&self.pool)
+		.await?;
+
+		Ok(delivery)
+	}
+
+	/// Store the outcome of the delivery's latest attempt
+	pub async fn record_attempt(
+		&self,
+		delivery_id: Uuid,
+		status: WebhookDeliveryStatus,
+		attempts: i32,
+		response_status: Option<i32>,
+		error: Option<&str>,
+	) -> Result<(), TenantRepositoryError> {
+		sqlx::query!(
+			r#"
+			UPDATE tenant_webhook_deliveries
+			SET status = $2, attempts = $3, response_status = $4, error = $5, updated_at = NOW()
+			WHERE id = $1
+			"#,
+			delivery_id,
+			status as WebhookDeliveryStatus,
+			attempts,
+			response_status,
+			error
+		)
+		.execute(&self.pool)
+		.await?;
+
+		Ok(())
+	}
+}
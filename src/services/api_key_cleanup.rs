@@ -0,0 +1,90 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use tokio::task::JoinHandle;
+
+use super::monitor_service::{AuditServiceTrait, ServiceError};
+use crate::models::audit::ResourceType as AuditResourceType;
+use crate::models::{ApiKey, AuditAction, CreateAuditLogRequest};
+use crate::repositories::ApiKeyRepositoryTrait;
+
+/// How often the background task looks for stale API keys
+pub const API_KEY_CLEANUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Deactivates API keys that have not been used for a configured number of days
+#[derive(Clone)]
+pub struct ApiKeyCleanupService<R, A>
+where
+	R: ApiKeyRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	api_key_repo: R,
+	audit_service: A,
+	max_idle_days: i64,
+}
+
+impl<R, A> ApiKeyCleanupService<R, A>
+where
+	R: ApiKeyRepositoryTrait + 'static,
+	A: AuditServiceTrait + 'static,
+{
+	pub fn new(api_key_repo: R, audit_service: A, max_idle_days: i64) -> Self {
+		Self {
+			api_key_repo,
+			audit_service,
+			max_idle_days,
+		}
+	}
+
+	/// Deactivate every key unused since `now - max_idle_days`, returning the
+	/// keys that were disabled
+	pub async fn run_once(&self, now: DateTime<Utc>) -> Result<Vec<ApiKey>, ServiceError> {
+		let cutoff = now - Duration::days(self.max_idle_days);
+		let stale_keys = self.api_key_repo.list_unused_since(cutoff).await?;
+
+		let mut disabled = Vec::with_capacity(stale_keys.len());
+		for key in stale_keys {
+			let last_activity = key.last_activity();
+			let key = self.api_key_repo.deactivate(key.id).await?;
+
+			self.audit_service
+				.log(CreateAuditLogRequest {
+					tenant_id: key.tenant_id,
+					user_id: None,
+					api_key_id: Some(key.id),
+					action: AuditAction::ApiKeyDisabled,
+					resource_type: Some(AuditResourceType::ApiKey),
+					resource_id: Some(key.id),
+					changes: Some(json!({
+						"reason": "unused",
+						"last_activity": last_activity,
+						"max_idle_days": self.max_idle_days,
+					})),
+					ip_address: None,
+					user_agent: None,
+					request_id: None,
+				})
+				.await?;
+
+			disabled.push(key);
+		}
+
+		Ok(disabled)
+	}
+
+	/// Run the cleanup on a fixed interval until the task is aborted
+	pub fn spawn(self, interval: std::time::Duration) -> JoinHandle<()> {
+		tokio::spawn(async move {
+			let mut ticker = tokio::time::interval(interval);
+			loop {
+				ticker.tick().await;
+				match self.run_once(Utc::now()).await {
+					Ok(disabled) if !disabled.is_empty() => {
+						tracing::info!("Disabled {} unused API keys", disabled.len());
+					}
+					Ok(_) => {}
+					Err(e) => tracing::error!("API key cleanup failed: {}", e),
+				}
+			}
+		})
+	}
+}
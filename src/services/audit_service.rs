@@ -2,35 +2,99 @@ use async_trait::async_trait;
 use sqlx::types::ipnetwork::IpNetwork;
 use sqlx::{Pool, Postgres};
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 use super::monitor_service::{AuditServiceTrait, ServiceError};
 use crate::models::audit::ResourceType;
-use crate::models::{AuditLog, CreateAuditLogRequest};
+use crate::models::{AuditLog, AuditLogFilter, CreateAuditLogRequest};
+use crate::repositories::{AuditRepository, AuditRepositoryTrait};
+use crate::utils::current_tenant_id;
+
+/// Entries a live subscriber may fall behind by before it starts missing them
+pub const AUDIT_EVENT_CAPACITY: usize = 1024;
+
+/// Fan-out of newly written audit entries to live subscribers, across all tenants
+#[derive(Clone)]
+pub struct AuditEvents {
+	sender: broadcast::Sender<AuditLog>,
+}
+
+impl Default for AuditEvents {
+	fn default() -> Self {
+		Self::new(AUDIT_EVENT_CAPACITY)
+	}
+}
+
+impl AuditEvents {
+	pub fn new(capacity: usize) -> Self {
+		let (sender, _) = broadcast::channel(capacity);
+		Self { sender }
+	}
+
+	/// Hand `log` to every current subscriber; nobody listening is not an error
+	pub fn publish(&self, log: AuditLog) {
+		let _ = self.sender.send(log);
+	}
+
+	pub fn subscribe(&self) -> broadcast::Receiver<AuditLog> {
+		self.sender.subscribe()
+	}
+}
 
 #[derive(Clone)]
 pub struct AuditService {
 	pool: Pool<Postgres>,
+	audit_repo: AuditRepository,
+	events: AuditEvents,
 }
 
 impl AuditService {
 	pub fn new(pool: Pool<Postgres>) -> Self {
-		Self { pool }
+		Self {
+			audit_repo: AuditRepository::new(pool.clone()),
+			pool,
+			events: AuditEvents::default(),
+		}
+	}
+
+	/// Publish every written entry to `events`
+	pub fn with_events(mut self, events: AuditEvents) -> Self {
+		self.events = events;
+		self
 	}
 }
 
+fn validate_filter(filter: &AuditLogFilter) -> Result<(), ServiceError> {
+	if let (Some(from), Some(to)) = (filter.from, filter.to) {
+		if from > to {
+			return Err(ServiceError::ValidationError(
+				"from must not be after to".to_string(),
+			));
+		}
+	}
+
+	Ok(())
+}
+
 #[async_trait]
 impl AuditServiceTrait for AuditService {
 	async fn log(&self, request: CreateAuditLogRequest) -> Result<(), ServiceError> {
 		let action_str = request.action.as_str();
 		let resource_type_str = request.resource_type.as_ref().map(|rt| rt.as_str());
 
-		sqlx::query!(
+		let log = sqlx::query_as!(
+			AuditLog,
 			r#"
 			INSERT INTO audit_logs (
 				tenant_id, user_id, api_key_id, action, resource_type, 
-				resource_id, changes, ip_address, user_agent
+				resource_id, changes, ip_address, user_agent, request_id
 			)
-			VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+			VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+			RETURNING
+				id, tenant_id, user_id, api_key_id, action,
+				resource_type, resource_id, changes,
+				ip_address as "ip_address: _",
+				user_agent, request_id, created_at
 			"#,
 			request.tenant_id,
 			request.user_id,
@@ -40,14 +104,40 @@ impl AuditServiceTrait for AuditService {
 			request.resource_id,
 			request.changes,
 			request.ip_address.map(|ip| IpNetwork::from(ip)),
-			request.user_agent
+			request.user_agent,
+			request.request_id
 		)
-		.execute(&self.pool)
+		.fetch_one(&self.pool)
 		.await
 		.map_err(|e| ServiceError::Internal(format!("Failed to write audit log: {}", e)))?;
+		self.events.publish(log);
 
 		Ok(())
 	}
+
+	async fn query_logs(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, ServiceError> {
+		validate_filter(&filter)?;
+
+		let logs = self
+			.audit_repo
+			.query(
+				current_tenant_id(),
+				&filter,
+				filter.page_limit(),
+				filter.page_offset(),
+			)
+			.await?;
+
+		Ok(logs)
+	}
+
+	async fn count_logs(&self, filter: AuditLogFilter) -> Result<i64, ServiceError> {
+		validate_filter(&filter)?;
+
+		let count = self.audit_repo.count(current_tenant_id(), &filter).await?;
+
+		Ok(count)
+	}
 }
 
 // Additional audit query methods
@@ -65,7 +155,7 @@ impl AuditService {
 				id, tenant_id, user_id, api_key_id, action, 
 				resource_type, resource_id, changes, 
 				ip_address as "ip_address: _", 
-				user_agent, created_at
+				user_agent, request_id, created_at
 			FROM audit_logs
 			WHERE tenant_id = $1
 			ORDER BY created_at DESC
@@ -96,7 +186,7 @@ impl AuditService {
 				id, tenant_id, user_id, api_key_id, action, 
 				resource_type, resource_id, changes, 
 				ip_address as "ip_address: _", 
-				user_agent, created_at
+				user_agent, request_id, created_at
 			FROM audit_logs
 			WHERE tenant_id = $1 AND user_id = $2
 			ORDER BY created_at DESC
@@ -131,7 +221,7 @@ impl AuditService {
 				id, tenant_id, user_id, api_key_id, action, 
 				resource_type, resource_id, changes, 
 				ip_address as "ip_address: _", 
-				user_agent, created_at
+				user_agent, request_id, created_at
 			FROM audit_logs
 			WHERE tenant_id = $1 AND resource_type = $2 AND resource_id = $3
 			ORDER BY created_at DESC
@@ -158,4 +248,12 @@ impl<T: AuditServiceTrait> AuditServiceTrait for Arc<T> {
 	async fn log(&self, request: CreateAuditLogRequest) -> Result<(), ServiceError> {
 		(**self).log(request).await
 	}
+
+	async fn query_logs(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, ServiceError> {
+		(**self).query_logs(filter).await
+	}
+
+	async fn count_logs(&self, filter: AuditLogFilter) -> Result<i64, ServiceError> {
+		(**self).count_logs(filter).await
+	}
 }
@@ -0,0 +1,85 @@
+use uuid::Uuid;
+
+use super::monitor_service::{MonitorServiceTrait, ServiceError};
+use super::network_service::NetworkServiceTrait;
+use super::trigger_service::TriggerServiceTrait;
+use crate::models::{CursorPosition, ListCursor, TenantExport};
+
+/// Page size used while reading a tenant's resources for an export
+pub const EXPORT_PAGE_SIZE: i64 = 100;
+
+/// Collects everything a tenant has configured through the resource services,
+/// so the export sees exactly what the tenant's own reads would
+pub struct TenantExportService<'a, M, N, T>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+{
+	monitor_service: &'a M,
+	network_service: &'a N,
+	trigger_service: &'a T,
+}
+
+impl<'a, M, N, T> TenantExportService<'a, M, N, T>
+where
+	M: MonitorServiceTrait,
+	N: NetworkServiceTrait,
+	T: TriggerServiceTrait,
+{
+	pub fn new(monitor_service: &'a M, network_service: &'a N, trigger_service: &'a T) -> Self {
+		Self {
+			monitor_service,
+			network_service,
+			trigger_service,
+		}
+	}
+
+	/// Every network, monitor and trigger of `tenant_id`. The services read the
+	/// tenant of the current context, so exporting any other tenant is refused.
+	pub async fn export(&self, tenant_id: Uuid) -> Result<TenantExport, ServiceError> {
+		if tenant_id != crate::utils::current_tenant_id() {
+			return Err(ServiceError::AccessDenied(
+				"Only the current tenant can be exported".to_string(),
+			));
+		}
+
+		Ok(TenantExport {
+			networks: collect_pages(|cursor| {
+				self.network_service
+					.list_networks_after(cursor, EXPORT_PAGE_SIZE)
+			})
+			.await?,
+			monitors: collect_pages(|cursor| {
+				self.monitor_service
+					.list_monitors_after(cursor, EXPORT_PAGE_SIZE)
+			})
+			.await?,
+			triggers: collect_pages(|cursor| {
+				self.trigger_service
+					.list_triggers_after(cursor, EXPORT_PAGE_SIZE)
+			})
+			.await?,
+		})
+	}
+}
+
+/// Every item of a cursor-paged listing
+async fn collect_pages<R, F, Fut>(mut fetch_page: F) -> Result<Vec<R>, ServiceError>
+where
+	R: CursorPosition,
+	F: FnMut(Option<ListCursor>) -> Fut,
+	Fut: std::future::Future<Output = Result<Vec<R>, ServiceError>>,
+{
+	let mut items = Vec::new();
+	let mut cursor = None;
+	loop {
+		let page = fetch_page(cursor).await?;
+		let last_page = (page.len() as i64) < EXPORT_PAGE_SIZE;
+		cursor = page.last().and_then(CursorPosition::cursor_position);
+		items.extend(page);
+		if last_page || cursor.is_none() {
+			return Ok(items);
+		}
+	}
+}
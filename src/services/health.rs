@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use sqlx::migrate::Migrator;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Longest a single readiness check may take before it counts as failed
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A dependency the readiness probe checks, e.g. the database
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+	/// Name reported in the readiness response
+	fn name(&self) -> &'static str;
+
+	/// Whether a failure makes the service unready; other checks are only reported
+	fn is_critical(&self) -> bool {
+		true
+	}
+
+	async fn check(&self) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+	Up,
+	Down,
+}
+
+/// Outcome of one check in the readiness response
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheckResult {
+	pub name: &'static str,
+	pub status: HealthStatus,
+	pub critical: bool,
+	pub latency_ms: u64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+impl HealthCheckResult {
+	/// Whether this result makes the service unready
+	pub fn is_failing(&self) -> bool {
+		self.critical && self.status == HealthStatus::Down
+	}
+}
+
+/// Run every check concurrently, each limited to `timeout`
+pub async fn run_health_checks(
+	checks: &[Arc<dyn HealthCheck>],
+	timeout: Duration,
+) -> Vec<HealthCheckResult> {
+	futures::future::join_all(checks.iter().map(|check| async move {
+		let started = Instant::now();
+		let outcome = match tokio::time::timeout(timeout, check.check()).await {
+			Ok(outcome) => outcome,
+			Err(_) => Err(format!("Timed out after {}ms", timeout.as_millis())),
+		};
+		if let Err(e) = &outcome {
+			tracing::warn!(check = check.name(), error = %e, "Readiness check failed");
+		}
+
+		HealthCheckResult {
+			name: check.name(),
+			status: if outcome.is_ok() {
+				HealthStatus::Up
+			} else {
+				HealthStatus::Down
+			},
+			critical: check.is_critical(),
+			latency_ms: started.elapsed().as_millis() as u64,
+			error: outcome.err(),
+		}
+	}))
+	.await
+}
+
+/// Runs `SELECT 1` against the pool
+#[derive(Clone)]
+pub struct DatabaseHealthCheck {
+	pool: sqlx::PgPool,
+}
+
+impl DatabaseHealthCheck {
+	pub fn new(pool: sqlx::PgPool) -> Self {
+		Self { pool }
+	}
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseHealthCheck {
+	fn name(&self) -> &'static str {
+		"database"
+	}
+
+	async fn check(&self) -> Result<(), String> {
+		sqlx::query_scalar::<_, i32>("SELECT 1")
+			.fetch_one(&self.pool)
+			.await
+			.map(|_| ())
+			.map_err(|e| e.to_string())
+	}
+}
+
+/// Fails while any migration embedded in the binary has not been applied
+#[derive(Clone)]
+pub struct MigrationsHealthCheck {
+	pool: sqlx::PgPool,
+	migrator: &'static Migrator,
+}
+
+impl MigrationsHealthCheck {
+	pub fn new(pool: sqlx::PgPool, migrator: &'static Migrator) -> Self {
+		Self { pool, migrator }
+	}
+}
+
+/// Versions in `migrator` missing from `applied`, oldest first
+pub fn pending_migrations(migrator: &Migrator, applied: &HashSet<i64>) -> Vec<i64> {
+	migrator
+		.iter()
+		.filter(|migration| !migration.migration_type.is_down_migration())
+		.map(|migration| migration.version)
+		.filter(|version| !applied.contains(version))
+		.collect()
+}
+
+#[async_trait]
+impl HealthCheck for MigrationsHealthCheck {
+	fn name(&self) -> &'static str {
+		"migrations"
+	}
+
+	async fn check(&self) -> Result<(), String> {
+		let applied: HashSet<i64> =
+			sqlx::query_scalar::<_, i64>("SELECT version FROM _sqlx_migrations WHERE success")
+				.fetch_all(&self.pool)
+				.await
+				.map_err(|e| e.to_string())?
+				.into_iter()
+				.collect();
+
+		let pending = pending_migrations(self.migrator, &applied);
+		if pending.is_empty() {
+			Ok(())
+		} else {
+			Err(format!(
+				"{} pending migration(s): {}",
+				pending.len(),
+				pending
+					.iter()
+					.map(i64::to_string)
+					.collect::<Vec<_>>()
+					.join(", ")
+			))
+		}
+	}
+}
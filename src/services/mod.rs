@@ -1,9 +1,34 @@
+pub mod api_key_cleanup;
 pub mod audit_service;
+pub mod export_service;
+pub mod health;
+pub mod monitor_purge;
 pub mod monitor_service;
 pub mod network_service;
 pub mod trigger_service;
+pub mod usage_service;
+pub mod webhook_dispatcher;
 
-pub use audit_service::AuditService;
+pub use api_key_cleanup::{ApiKeyCleanupService, API_KEY_CLEANUP_INTERVAL};
+pub use audit_service::{AuditEvents, AuditService, AUDIT_EVENT_CAPACITY};
+pub use export_service::{TenantExportService, EXPORT_PAGE_SIZE};
+pub use health::{
+	pending_migrations, run_health_checks, DatabaseHealthCheck, HealthCheck, HealthCheckResult,
+	HealthStatus, MigrationsHealthCheck, HEALTH_CHECK_TIMEOUT,
+};
+pub use monitor_purge::{MonitorPurgeService, MONITOR_PURGE_INTERVAL};
 pub use monitor_service::{AuditServiceTrait, MonitorService, MonitorServiceTrait, ServiceError};
-pub use network_service::{NetworkService, NetworkServiceTrait};
-pub use trigger_service::{TriggerService, TriggerServiceTrait};
+pub use network_service::{
+	normalize_network_configuration, probe_rpc_url, NetworkProbe, NetworkService,
+	NetworkServiceTrait, RpcNetworkProbe, RPC_PROBE_TIMEOUT,
+};
+pub use trigger_service::{
+	send_test_payload, validate_trigger_config, validate_webhook_method, TriggerService,
+	TriggerServiceTrait, DEFAULT_WEBHOOK_METHODS, TEST_DELIVERED_TRIGGER_TYPES,
+	TEST_DELIVERY_TIMEOUT,
+};
+pub use usage_service::{StorageAccounting, StorageRecorder, UsageService};
+pub use webhook_dispatcher::{
+	generate_webhook_secret, send_webhook, sign_webhook_payload, WebhookAttempt, WebhookDispatcher,
+	WebhookRetryPolicy, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+};
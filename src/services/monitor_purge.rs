@@ -0,0 +1,85 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use tokio::task::JoinHandle;
+
+use super::monitor_service::{AuditServiceTrait, ServiceError};
+use crate::models::audit::ResourceType as AuditResourceType;
+use crate::models::{AuditAction, CreateAuditLogRequest, TenantMonitor};
+use crate::repositories::TenantMonitorRepositoryTrait;
+
+/// How often the background task purges expired soft-deleted monitors
+pub const MONITOR_PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// Permanently removes monitors that have been soft-deleted for longer than
+/// the retention window
+#[derive(Clone)]
+pub struct MonitorPurgeService<M, A>
+where
+	M: TenantMonitorRepositoryTrait,
+	A: AuditServiceTrait,
+{
+	monitor_repo: M,
+	audit_service: A,
+	retention_days: i64,
+}
+
+impl<M, A> MonitorPurgeService<M, A>
+where
+	M: TenantMonitorRepositoryTrait + 'static,
+	A: AuditServiceTrait + 'static,
+{
+	pub fn new(monitor_repo: M, audit_service: A, retention_days: i64) -> Self {
+		Self {
+			monitor_repo,
+			audit_service,
+			retention_days,
+		}
+	}
+
+	/// Purge every monitor deleted before `now - retention_days`, returning
+	/// the monitors that were removed
+	pub async fn run_once(&self, now: DateTime<Utc>) -> Result<Vec<TenantMonitor>, ServiceError> {
+		let cutoff = now - Duration::days(self.retention_days);
+		let purged = self.monitor_repo.purge_deleted_before(cutoff).await?;
+
+		for monitor in &purged {
+			self.audit_service
+				.log(CreateAuditLogRequest {
+					tenant_id: monitor.tenant_id,
+					user_id: None,
+					api_key_id: None,
+					action: AuditAction::MonitorPurged,
+					resource_type: Some(AuditResourceType::Monitor),
+					resource_id: Some(monitor.id),
+					changes: Some(json!({
+						"monitor_id": monitor.monitor_id,
+						"deleted_at": monitor.deleted_at,
+						"retention_days": self.retention_days,
+					})),
+					ip_address: None,
+					user_agent: None,
+					request_id: None,
+				})
+				.await?;
+		}
+
+		Ok(purged)
+	}
+
+	/// Run the purge on a fixed interval until the task is aborted
+	pub fn spawn(self, interval: std::time::Duration) -> JoinHandle<()> {
+		tokio::spawn(async move {
+			let mut ticker = tokio::time::interval(interval);
+			loop {
+				ticker.tick().await;
+				match self.run_once(Utc::now()).await {
+					Ok(purged) if !purged.is_empty() => {
+						tracing::info!("Purged {} deleted monitors", purged.len());
+					}
+					Ok(_) => {}
+					Err(e) => tracing::error!("Monitor purge failed: {}", e),
+				}
+			}
+		})
+	}
+}
@@ -1,15 +1,23 @@
 use async_trait::async_trait;
+use regex::Regex;
 use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use uuid::Uuid;
 
+use super::trigger_service::TEST_DELIVERED_TRIGGER_TYPES;
+use super::usage_service::StorageAccounting;
 use crate::models::audit::ResourceType as AuditResourceType;
 use crate::models::{
-	AuditAction, CreateAuditLogRequest, CreateMonitorRequest, RequestMetadata, TenantMonitor,
+	config_storage_mb, AuditAction, AuditLog, AuditLogFilter, CreateAuditLogRequest,
+	CreateMonitorRequest, CreationSource, ListCursor, MonitorDeactivation, MonitorFilter,
+	MonitorRollback, RequestMetadata, StorageEnforcement, TenantMonitor, TenantTrigger,
 	UpdateMonitorRequest,
 };
 use crate::repositories::{
-	TenantMonitorRepositoryTrait, TenantRepositoryError, TenantRepositoryTrait,
+	TenantMonitorRepositoryTrait, TenantNetworkRepositoryTrait, TenantRepositoryError,
+	TenantRepositoryTrait,
 };
-use crate::utils::current_tenant_context;
+use crate::utils::{current_tenant_context, metrics, CreationRateLimiter, TenantLocks};
 
 // Note: The TenantMonitor type in this service stores monitor configurations with multi-tenant isolation.
 // The actual monitor execution will be handled by a separate openzeppelin-monitor instance that reads
@@ -23,6 +31,20 @@ pub trait MonitorServiceTrait: Send + Sync {
 		request: CreateMonitorRequest,
 		metadata: RequestMetadata,
 	) -> Result<TenantMonitor, ServiceError>;
+	async fn import_monitors(
+		&self,
+		requests: Vec<CreateMonitorRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	/// Copy a monitor's name and configuration under `new_monitor_id`, optionally onto
+	/// another of the tenant's networks
+	async fn clone_monitor(
+		&self,
+		source_monitor_id: &str,
+		new_monitor_id: String,
+		target_network_id: Option<Uuid>,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError>;
 	async fn get_monitor(&self, monitor_id: &str) -> Result<TenantMonitor, ServiceError>;
 	async fn update_monitor(
 		&self,
@@ -35,52 +57,163 @@ pub trait MonitorServiceTrait: Send + Sync {
 		monitor_id: &str,
 		metadata: RequestMetadata,
 	) -> Result<(), ServiceError>;
+	/// Delete the monitor together with its triggers; returns the deleted triggers
+	async fn delete_monitor_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	async fn restore_monitor(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError>;
+	/// Apply the configuration saved as `version` as a new update
+	async fn rollback_monitor(
+		&self,
+		monitor_id: &str,
+		version: i32,
+		metadata: RequestMetadata,
+	) -> Result<MonitorRollback, ServiceError>;
 	async fn list_monitors(
 		&self,
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	/// Keyset pagination; `cursor` is the position of the last item already seen
+	async fn list_monitors_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	/// Includes soft-deleted monitors; restricted to tenant admins and owners
+	async fn list_monitors_including_deleted(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	async fn list_monitors_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	async fn list_monitors_filtered(
+		&self,
+		filter: MonitorFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError>;
+	async fn count_monitors_filtered(&self, filter: MonitorFilter) -> Result<i64, ServiceError>;
 	async fn get_monitor_count(&self) -> Result<i64, ServiceError>;
+	async fn deactivate_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<MonitorDeactivation, ServiceError>;
 }
 
 #[derive(Clone)]
-pub struct MonitorService<M, T, A>
+pub struct MonitorService<M, N, T, A>
 where
 	M: TenantMonitorRepositoryTrait,
+	N: TenantNetworkRepositoryTrait,
 	T: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
 	monitor_repo: M,
+	network_repo: N,
 	tenant_repo: T,
 	audit_service: A,
+	unique_contract_addresses: bool,
+	storage: StorageAccounting,
+	name_pattern: Option<Regex>,
+	create_locks: Option<TenantLocks>,
+	create_rate: Option<CreationRateLimiter>,
+	require_tested_triggers: bool,
 }
 
-impl<M, T, A> MonitorService<M, T, A>
+impl<M, N, T, A> MonitorService<M, N, T, A>
 where
 	M: TenantMonitorRepositoryTrait,
+	N: TenantNetworkRepositoryTrait,
 	T: TenantRepositoryTrait,
 	A: AuditServiceTrait,
 {
-	pub fn new(monitor_repo: M, tenant_repo: T, audit_service: A) -> Self {
+	pub fn new(monitor_repo: M, network_repo: N, tenant_repo: T, audit_service: A) -> Self {
 		Self {
 			monitor_repo,
+			network_repo,
 			tenant_repo,
 			audit_service,
+			unique_contract_addresses: false,
+			storage: StorageAccounting::default(),
+			name_pattern: None,
+			create_locks: None,
+			create_rate: None,
+			require_tested_triggers: false,
 		}
 	}
+
+	/// Reject monitors whose `contract_address` is already watched on the same network
+	pub fn with_unique_contract_addresses(mut self, enabled: bool) -> Self {
+		self.unique_contract_addresses = enabled;
+		self
+	}
+
+	/// Whether monitor creation is blocked or only warned about once storage is over quota
+	pub fn with_storage_enforcement(mut self, enforcement: StorageEnforcement) -> Self {
+		self.storage.set_enforcement(enforcement);
+		self
+	}
+
+	/// Storage checks and recording for monitor configurations
+	pub fn with_storage_accounting(mut self, storage: StorageAccounting) -> Self {
+		self.storage = storage;
+		self
+	}
+
+	/// Reject new monitors whose name does not match `pattern`
+	pub fn with_name_pattern(mut self, pattern: Option<Regex>) -> Self {
+		self.name_pattern = pattern;
+		self
+	}
+
+	/// Run creates for the same tenant one at a time; other tenants are not held up
+	pub fn with_serialized_creates(mut self, enabled: bool) -> Self {
+		self.create_locks = enabled.then(TenantLocks::new);
+		self
+	}
+
+	/// Refuse a tenant's creates beyond `max_per_minute` in any 60 second window
+	pub fn with_create_rate_limit(mut self, max_per_minute: Option<u32>) -> Self {
+		self.create_rate = max_per_minute.map(CreationRateLimiter::new);
+		self
+	}
+
+	/// Refuse to activate a monitor until each of its active triggers that a
+	/// test can reach has had a successful test delivery
+	pub fn with_required_trigger_tests(mut self, enabled: bool) -> Self {
+		self.require_tested_triggers = enabled;
+		self
+	}
 }
 
-#[async_trait]
-impl<M, T, A> MonitorServiceTrait for MonitorService<M, T, A>
+impl<M, N, T, A> MonitorService<M, N, T, A>
 where
 	M: TenantMonitorRepositoryTrait + Send + Sync,
+	N: TenantNetworkRepositoryTrait + Send + Sync,
 	T: TenantRepositoryTrait + Send + Sync,
 	A: AuditServiceTrait + Send + Sync,
 {
-	async fn create_monitor(
+	/// Shared create path; `source` records which code path created the monitor and
+	/// `cloned_from` the monitor it was copied from, if any
+	async fn create_monitor_from(
 		&self,
 		request: CreateMonitorRequest,
-		metadata: RequestMetadata,
+		metadata: &RequestMetadata,
+		source: CreationSource,
+		cloned_from: Option<&TenantMonitor>,
 	) -> Result<TenantMonitor, ServiceError> {
 		let context = current_tenant_context();
 
@@ -91,6 +224,38 @@ where
 			));
 		}
 
+		// Enforce the configured naming convention
+		if let Some(pattern) = &self.name_pattern {
+			if !pattern.is_match(&request.name) {
+				return Err(ServiceError::ValidationError(format!(
+					"Monitor name '{}' does not match the required pattern {}",
+					request.name,
+					pattern.as_str()
+				)));
+			}
+		}
+
+		// Throttle bursts of creates even while the tenant is under its quota
+		if let Some(rate) = &self.create_rate {
+			if let Err(retry_after) = rate.try_acquire(context.tenant_id, std::time::Instant::now())
+			{
+				return Err(ServiceError::Repository(
+					TenantRepositoryError::RateLimited(format!(
+						"Monitor creation rate exceeded: {} per minute; try again in {}s",
+						rate.max_per_minute(),
+						retry_after.as_secs_f64().ceil().max(1.0) as u64
+					)),
+				));
+			}
+		}
+
+		// Held until the monitor is created, so the quota check below sees
+		// every earlier create for this tenant
+		let _create_guard = match &self.create_locks {
+			Some(locks) => Some(locks.lock(context.tenant_id).await),
+			None => None,
+		};
+
 		// Check quota
 		let quota_status = self.tenant_repo.get_quota_status(context.tenant_id).await?;
 		if !quota_status.can_create_monitor() {
@@ -100,8 +265,65 @@ where
 			)));
 		}
 
+		let storage_mb = config_storage_mb(&request.configuration);
+		self.storage.ensure_available(&quota_status, storage_mb)?;
+
+		// Verify network exists and check its blockchain sub-quota
+		let network = self.network_repo.get_by_uuid(request.network_id).await?;
+		if !quota_status.can_create_monitor_on(&network.blockchain) {
+			let limit = quota_status.blockchain(&network.blockchain);
+			return Err(ServiceError::QuotaExceeded(format!(
+				"{} monitor quota exceeded: {}/{}",
+				network.blockchain,
+				limit.map(|b| b.monitors_count).unwrap_or_default(),
+				limit.and_then(|b| b.max_monitors).unwrap_or_default()
+			)));
+		}
+
+		// Optionally refuse a second monitor for the same contract on this network
+		if self.unique_contract_addresses {
+			if let Some(address) = request
+				.configuration
+				.get("contract_address")
+				.and_then(JsonValue::as_str)
+			{
+				if self
+					.monitor_repo
+					.contract_address_in_use(request.network_id, address)
+					.await?
+				{
+					return Err(ServiceError::Repository(
+						TenantRepositoryError::AlreadyExists {
+							resource_type: "monitor for contract".to_string(),
+							resource_id: address.to_string(),
+						},
+					));
+				}
+			}
+		}
+
 		// Create monitor
-		let monitor = self.monitor_repo.create(request.clone()).await?;
+		let monitor = match source {
+			CreationSource::Api => self.monitor_repo.create(request.clone()).await?,
+			source => {
+				self.monitor_repo
+					.create_with_source(request.clone(), source)
+					.await?
+			}
+		};
+		metrics::record_monitors_added(1);
+		self.storage.record(context.tenant_id, storage_mb).await;
+
+		let mut changes = serde_json::to_value(&request).unwrap_or(JsonValue::Null);
+		if let (Some(source_monitor), Some(fields)) = (cloned_from, changes.as_object_mut()) {
+			fields.insert(
+				"cloned_from".to_string(),
+				serde_json::json!({
+					"id": source_monitor.id,
+					"monitor_id": source_monitor.monitor_id,
+				}),
+			);
+		}
 
 		// Audit log
 		self.audit_service
@@ -112,14 +334,70 @@ where
 				action: AuditAction::MonitorCreated,
 				resource_type: Some(AuditResourceType::Monitor),
 				resource_id: Some(monitor.id),
-				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
+				changes: Some(changes),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
 		Ok(monitor)
 	}
+}
+
+#[async_trait]
+impl<M, N, T, A> MonitorServiceTrait for MonitorService<M, N, T, A>
+where
+	M: TenantMonitorRepositoryTrait + Send + Sync,
+	N: TenantNetworkRepositoryTrait + Send + Sync,
+	T: TenantRepositoryTrait + Send + Sync,
+	A: AuditServiceTrait + Send + Sync,
+{
+	async fn create_monitor(
+		&self,
+		request: CreateMonitorRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		self.create_monitor_from(request, &metadata, CreationSource::Api, None)
+			.await
+	}
+
+	async fn import_monitors(
+		&self,
+		requests: Vec<CreateMonitorRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		let mut monitors = Vec::with_capacity(requests.len());
+		for request in requests {
+			monitors.push(
+				self.create_monitor_from(request, &metadata, CreationSource::Import, None)
+					.await?,
+			);
+		}
+
+		Ok(monitors)
+	}
+
+	async fn clone_monitor(
+		&self,
+		source_monitor_id: &str,
+		new_monitor_id: String,
+		target_network_id: Option<Uuid>,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		let source = self.monitor_repo.get(source_monitor_id).await?;
+
+		// The shared create path checks the quota, the target network and the new id
+		let request = CreateMonitorRequest {
+			monitor_id: new_monitor_id,
+			name: format!("{} (copy)", source.name),
+			network_id: target_network_id.unwrap_or(source.network_id),
+			configuration: source.configuration.clone(),
+		};
+
+		self.create_monitor_from(request, &metadata, CreationSource::Api, Some(&source))
+			.await
+	}
 
 	async fn get_monitor(&self, monitor_id: &str) -> Result<TenantMonitor, ServiceError> {
 		// Read permission is checked by repository through tenant context
@@ -144,11 +422,42 @@ where
 		// Get existing monitor first to ensure it exists
 		let existing = self.monitor_repo.get(monitor_id).await?;
 
+		if self.require_tested_triggers
+			&& request.is_active == Some(true)
+			&& existing.is_active == Some(false)
+		{
+			let untested: Vec<String> = self
+				.monitor_repo
+				.list_untested_triggers(existing.id)
+				.await?
+				.into_iter()
+				.filter(|trigger| {
+					TEST_DELIVERED_TRIGGER_TYPES.contains(&trigger.trigger_type.as_str())
+				})
+				.map(|trigger| trigger.trigger_id)
+				.collect();
+			if !untested.is_empty() {
+				return Err(ServiceError::ValidationError(format!(
+					"Monitor cannot be activated until these triggers have a successful test: {}",
+					untested.join(", ")
+				)));
+			}
+		}
+
+		// A replacement configuration may grow the tenant's storage
+		let storage_delta = request.configuration.as_ref().map_or(0, |configuration| {
+			config_storage_mb(configuration) - config_storage_mb(&existing.configuration)
+		});
+		self.storage
+			.check_quota(&self.tenant_repo, context.tenant_id, storage_delta)
+			.await?;
+
 		// Update monitor
 		let monitor = self
 			.monitor_repo
 			.update(monitor_id, request.clone())
 			.await?;
+		self.storage.record(context.tenant_id, storage_delta).await;
 
 		// Audit log
 		self.audit_service
@@ -162,6 +471,7 @@ where
 				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
@@ -175,8 +485,8 @@ where
 	) -> Result<(), ServiceError> {
 		let context = current_tenant_context();
 
-		// Check write permissions
-		if !context.can_write() {
+		// Deleting is reserved for admins and owners
+		if !context.can_delete() {
 			return Err(ServiceError::AccessDenied(
 				"Insufficient permissions to delete monitors".to_string(),
 			));
@@ -187,6 +497,7 @@ where
 
 		// Delete monitor
 		self.monitor_repo.delete(monitor_id).await?;
+		metrics::record_monitors_removed(1);
 
 		// Audit log
 		self.audit_service
@@ -200,12 +511,157 @@ where
 				changes: None,
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
 		Ok(())
 	}
 
+	async fn delete_monitor_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		let context = current_tenant_context();
+
+		// Deleting is reserved for admins and owners
+		if !context.can_delete() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to delete monitors".to_string(),
+			));
+		}
+
+		// Triggers and monitor are deleted atomically by the repository
+		let (monitor, triggers) = self.monitor_repo.delete_with_triggers(monitor_id).await?;
+		metrics::record_triggers_removed(triggers.len() as i64);
+		metrics::record_monitors_removed(1);
+
+		for trigger in &triggers {
+			self.audit_service
+				.log(CreateAuditLogRequest {
+					tenant_id: context.tenant_id,
+					user_id: context.user.as_ref().map(|u| u.id),
+					api_key_id: context.api_key_id,
+					action: AuditAction::TriggerDeleted,
+					resource_type: Some(AuditResourceType::Trigger),
+					resource_id: Some(trigger.id),
+					changes: Some(serde_json::json!({ "monitor_id": monitor.id })),
+					ip_address: metadata.ip_address,
+					user_agent: metadata.user_agent.clone(),
+					request_id: metadata.request_id.clone(),
+				})
+				.await?;
+		}
+
+		self.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|u| u.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::MonitorDeleted,
+				resource_type: Some(AuditResourceType::Monitor),
+				resource_id: Some(monitor.id),
+				changes: Some(serde_json::json!({ "triggers_deleted": triggers.len() })),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+
+		Ok(triggers)
+	}
+
+	async fn restore_monitor(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		let context = current_tenant_context();
+
+		// Check write permissions
+		if !context.can_write() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to restore monitors".to_string(),
+			));
+		}
+
+		// A restored monitor counts against the quota again
+		let quota_status = self.tenant_repo.get_quota_status(context.tenant_id).await?;
+		if !quota_status.can_create_monitor() {
+			return Err(ServiceError::QuotaExceeded(format!(
+				"Monitor quota exceeded: {}/{}",
+				quota_status.usage.monitors_count, quota_status.quotas.max_monitors
+			)));
+		}
+
+		let monitor = self.monitor_repo.restore(monitor_id).await?;
+		metrics::record_monitors_added(1);
+
+		// Audit log
+		self.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|u| u.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::MonitorRestored,
+				resource_type: Some(AuditResourceType::Monitor),
+				resource_id: Some(monitor.id),
+				changes: None,
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+
+		Ok(monitor)
+	}
+
+	async fn rollback_monitor(
+		&self,
+		monitor_id: &str,
+		version: i32,
+		metadata: RequestMetadata,
+	) -> Result<MonitorRollback, ServiceError> {
+		let context = current_tenant_context();
+
+		// Check write permissions
+		if !context.can_write() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to update monitors".to_string(),
+			));
+		}
+
+		if version < 1 {
+			return Err(ServiceError::ValidationError(
+				"Version must be at least 1".to_string(),
+			));
+		}
+
+		let rollback = self.monitor_repo.rollback(monitor_id, version).await?;
+
+		// Audit log
+		self.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|u| u.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::MonitorUpdated,
+				resource_type: Some(AuditResourceType::Monitor),
+				resource_id: Some(rollback.monitor.id),
+				changes: Some(serde_json::json!({
+					"rolled_back_to": rollback.restored_version,
+					"version": rollback.version,
+				})),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+
+		Ok(rollback)
+	}
+
 	async fn list_monitors(
 		&self,
 		limit: i64,
@@ -214,10 +670,132 @@ where
 		Ok(self.monitor_repo.list(limit, offset).await?)
 	}
 
+	async fn list_monitors_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		Ok(self.monitor_repo.list_after(cursor, limit).await?)
+	}
+
+	async fn list_monitors_including_deleted(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		let context = current_tenant_context();
+
+		if !context.can_manage() {
+			return Err(ServiceError::AccessDenied(
+				"Only tenant admins and owners can list deleted monitors".to_string(),
+			));
+		}
+
+		Ok(self
+			.monitor_repo
+			.list_including_deleted(limit, offset)
+			.await?)
+	}
+
+	async fn list_monitors_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		Ok(self
+			.monitor_repo
+			.list_by_source(source, limit, offset)
+			.await?)
+	}
+
+	async fn list_monitors_filtered(
+		&self,
+		filter: MonitorFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		Ok(self
+			.monitor_repo
+			.list_filtered(filter, limit, offset)
+			.await?)
+	}
+
+	async fn count_monitors_filtered(&self, filter: MonitorFilter) -> Result<i64, ServiceError> {
+		Ok(self.monitor_repo.count_filtered(filter).await?)
+	}
+
 	async fn get_monitor_count(&self) -> Result<i64, ServiceError> {
 		let monitors = self.monitor_repo.get_all().await?;
 		Ok(monitors.len() as i64)
 	}
+
+	async fn deactivate_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<MonitorDeactivation, ServiceError> {
+		let context = current_tenant_context();
+
+		// Check write permissions
+		if !context.can_write() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to deactivate monitors".to_string(),
+			));
+		}
+
+		// Monitor and triggers are updated atomically by the repository
+		let (monitor, triggers) = self
+			.monitor_repo
+			.deactivate_with_triggers(monitor_id)
+			.await?;
+
+		// The deactivation is already committed, so a failed audit entry is
+		// logged rather than reported as a failed request
+		let mut entries = vec![CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|u| u.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::MonitorDisabled,
+			resource_type: Some(AuditResourceType::Monitor),
+			resource_id: Some(monitor.id),
+			changes: Some(serde_json::json!({
+				"is_active": false,
+				"triggers_deactivated": triggers.len(),
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent.clone(),
+			request_id: metadata.request_id.clone(),
+		}];
+		entries.extend(triggers.iter().map(|trigger| CreateAuditLogRequest {
+			tenant_id: context.tenant_id,
+			user_id: context.user.as_ref().map(|u| u.id),
+			api_key_id: context.api_key_id,
+			action: AuditAction::TriggerDisabled,
+			resource_type: Some(AuditResourceType::Trigger),
+			resource_id: Some(trigger.id),
+			changes: Some(serde_json::json!({
+				"is_active": false,
+				"monitor_id": monitor.id,
+			})),
+			ip_address: metadata.ip_address,
+			user_agent: metadata.user_agent.clone(),
+			request_id: metadata.request_id.clone(),
+		}));
+		for entry in entries {
+			let resource_id = entry.resource_id;
+			if let Err(e) = self.audit_service.log(entry).await {
+				tracing::warn!(
+					monitor_id = %monitor.id,
+					resource_id = ?resource_id,
+					error = %e,
+					"Failed to audit monitor deactivation"
+				);
+			}
+		}
+
+		Ok(MonitorDeactivation { monitor, triggers })
+	}
 }
 
 // Service error type
@@ -235,6 +813,9 @@ pub enum ServiceError {
 	#[error("Validation error: {0}")]
 	ValidationError(String),
 
+	#[error("Network unreachable: {0}")]
+	NetworkUnreachable(String),
+
 	#[error("Internal error: {0}")]
 	Internal(String),
 }
@@ -243,4 +824,160 @@ pub enum ServiceError {
 #[async_trait]
 pub trait AuditServiceTrait: Send + Sync {
 	async fn log(&self, request: CreateAuditLogRequest) -> Result<(), ServiceError>;
+
+	/// Audit entries for the current tenant, newest first
+	async fn query_logs(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, ServiceError>;
+
+	/// Number of the current tenant's audit entries matching the filter, ignoring pagination
+	async fn count_logs(&self, filter: AuditLogFilter) -> Result<i64, ServiceError>;
+}
+
+#[async_trait]
+impl<T: MonitorServiceTrait> MonitorServiceTrait for Arc<T> {
+	async fn create_monitor(
+		&self,
+		request: CreateMonitorRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		(**self).create_monitor(request, metadata).await
+	}
+
+	async fn import_monitors(
+		&self,
+		requests: Vec<CreateMonitorRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self).import_monitors(requests, metadata).await
+	}
+
+	async fn clone_monitor(
+		&self,
+		source_monitor_id: &str,
+		new_monitor_id: String,
+		target_network_id: Option<Uuid>,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		(**self)
+			.clone_monitor(
+				source_monitor_id,
+				new_monitor_id,
+				target_network_id,
+				metadata,
+			)
+			.await
+	}
+
+	async fn get_monitor(&self, monitor_id: &str) -> Result<TenantMonitor, ServiceError> {
+		(**self).get_monitor(monitor_id).await
+	}
+
+	async fn update_monitor(
+		&self,
+		monitor_id: &str,
+		request: UpdateMonitorRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		(**self).update_monitor(monitor_id, request, metadata).await
+	}
+
+	async fn delete_monitor(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<(), ServiceError> {
+		(**self).delete_monitor(monitor_id, metadata).await
+	}
+
+	async fn delete_monitor_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self)
+			.delete_monitor_with_triggers(monitor_id, metadata)
+			.await
+	}
+
+	async fn restore_monitor(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<TenantMonitor, ServiceError> {
+		(**self).restore_monitor(monitor_id, metadata).await
+	}
+
+	async fn rollback_monitor(
+		&self,
+		monitor_id: &str,
+		version: i32,
+		metadata: RequestMetadata,
+	) -> Result<MonitorRollback, ServiceError> {
+		(**self)
+			.rollback_monitor(monitor_id, version, metadata)
+			.await
+	}
+
+	async fn list_monitors(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self).list_monitors(limit, offset).await
+	}
+
+	async fn list_monitors_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self).list_monitors_after(cursor, limit).await
+	}
+
+	async fn list_monitors_including_deleted(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self)
+			.list_monitors_including_deleted(limit, offset)
+			.await
+	}
+
+	async fn list_monitors_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self)
+			.list_monitors_by_source(source, limit, offset)
+			.await
+	}
+
+	async fn list_monitors_filtered(
+		&self,
+		filter: MonitorFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantMonitor>, ServiceError> {
+		(**self).list_monitors_filtered(filter, limit, offset).await
+	}
+
+	async fn count_monitors_filtered(&self, filter: MonitorFilter) -> Result<i64, ServiceError> {
+		(**self).count_monitors_filtered(filter).await
+	}
+
+	async fn get_monitor_count(&self) -> Result<i64, ServiceError> {
+		(**self).get_monitor_count().await
+	}
+
+	async fn deactivate_with_triggers(
+		&self,
+		monitor_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<MonitorDeactivation, ServiceError> {
+		(**self)
+			.deactivate_with_triggers(monitor_id, metadata)
+			.await
+	}
 }
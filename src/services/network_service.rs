@@ -1,14 +1,17 @@
 use async_trait::async_trait;
-use serde_json::Value as JsonValue;
+use serde_json::{json, Value as JsonValue};
+use std::sync::Arc;
+use std::time::Duration;
 
 use super::monitor_service::{AuditServiceTrait, ServiceError};
+use super::usage_service::StorageAccounting;
 use crate::models::audit::ResourceType as AuditResourceType;
 use crate::models::{
-	AuditAction, CreateAuditLogRequest, CreateNetworkRequest, RequestMetadata, TenantNetwork,
-	UpdateNetworkRequest,
+	config_storage_mb, AuditAction, CreateAuditLogRequest, CreateNetworkRequest, CreationSource,
+	ListCursor, NetworkDeletion, RequestMetadata, TenantNetwork, UpdateNetworkRequest,
 };
 use crate::repositories::{TenantNetworkRepositoryTrait, TenantRepositoryTrait};
-use crate::utils::current_tenant_context;
+use crate::utils::{current_tenant_context, metrics};
 
 #[async_trait]
 pub trait NetworkServiceTrait: Send + Sync {
@@ -29,12 +32,223 @@ pub trait NetworkServiceTrait: Send + Sync {
 		network_id: &str,
 		metadata: RequestMetadata,
 	) -> Result<(), ServiceError>;
+	/// Delete the network together with its monitors and their triggers
+	async fn delete_network_with_monitors(
+		&self,
+		network_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<NetworkDeletion, ServiceError>;
 	async fn list_networks(
 		&self,
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantNetwork>, ServiceError>;
+	/// Keyset pagination; `cursor` is the position of the last item already seen
+	async fn list_networks_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError>;
+	async fn list_networks_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError>;
 	async fn get_network_count(&self) -> Result<i64, ServiceError>;
+	/// Probe the configuration's `rpc_url` with a cheap JSON-RPC call for the
+	/// blockchain; succeeds without probing when the probe is disabled
+	async fn validate_connectivity(
+		&self,
+		config: &JsonValue,
+		blockchain: &str,
+	) -> Result<(), ServiceError>;
+}
+
+/// How long the RPC reachability probe waits for a response
+pub const RPC_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// JSON-RPC method used to probe each blockchain's RPC endpoint
+fn rpc_probe_method(blockchain: &str) -> Result<&'static str, ServiceError> {
+	match blockchain {
+		"stellar" => Ok("getHealth"),
+		"evm" => Ok("eth_blockNumber"),
+		other => Err(ServiceError::ValidationError(format!(
+			"Invalid blockchain type: {}. Must be 'stellar' or 'evm'",
+			other
+		))),
+	}
+}
+
+/// Send a single JSON-RPC probe to `rpc_url`; any transport failure, non-2xx
+/// status or JSON-RPC error makes the network unreachable
+pub async fn probe_rpc_url(
+	rpc_url: &str,
+	blockchain: &str,
+	timeout: Duration,
+) -> Result<(), ServiceError> {
+	let method = rpc_probe_method(blockchain)?;
+	let unreachable = |e: reqwest::Error| ServiceError::NetworkUnreachable(e.to_string());
+
+	let client = reqwest::Client::builder()
+		.timeout(timeout)
+		.build()
+		.map_err(unreachable)?;
+	let response: JsonValue = client
+		.post(rpc_url)
+		.json(&json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"method": method,
+			"params": [],
+		}))
+		.send()
+		.await
+		.and_then(reqwest::Response::error_for_status)
+		.map_err(unreachable)?
+		.json()
+		.await
+		.map_err(unreachable)?;
+
+	if let Some(error) = response.get("error") {
+		return Err(ServiceError::NetworkUnreachable(format!(
+			"{} returned an error: {}",
+			method, error
+		)));
+	}
+	if response.get("result").is_none() {
+		return Err(ServiceError::NetworkUnreachable(format!(
+			"{} returned no result",
+			method
+		)));
+	}
+
+	Ok(())
+}
+
+/// Checks that a network's RPC endpoint answers before it is stored
+#[async_trait]
+pub trait NetworkProbe: Send + Sync {
+	async fn probe(&self, rpc_url: &str, blockchain: &str) -> Result<(), ServiceError>;
+}
+
+/// Probes the endpoint over HTTP JSON-RPC with `probe_rpc_url`
+#[derive(Debug, Clone)]
+pub struct RpcNetworkProbe {
+	timeout: Duration,
+}
+
+impl RpcNetworkProbe {
+	pub fn new(timeout: Duration) -> Self {
+		Self { timeout }
+	}
+}
+
+impl Default for RpcNetworkProbe {
+	fn default() -> Self {
+		Self::new(RPC_PROBE_TIMEOUT)
+	}
+}
+
+#[async_trait]
+impl NetworkProbe for RpcNetworkProbe {
+	async fn probe(&self, rpc_url: &str, blockchain: &str) -> Result<(), ServiceError> {
+		probe_rpc_url(rpc_url, blockchain, self.timeout).await
+	}
+}
+
+/// URL schemes accepted for a network's `rpc_url`
+const RPC_URL_SCHEMES: &[&str] = &["http", "https", "ws", "wss"];
+
+/// Validate a network configuration and return its canonical form.
+///
+/// Top-level string values are trimmed. `rpc_url` is required; it defaults to
+/// `https://` when no scheme is given, has its scheme lowercased and loses any
+/// trailing slashes. Stellar networks also need a `network_passphrase`, and EVM
+/// networks a numeric `chain_id`.
+pub fn normalize_network_configuration(
+	configuration: &JsonValue,
+	blockchain: &str,
+) -> Result<JsonValue, ServiceError> {
+	let mut config = configuration.as_object().cloned().ok_or_else(|| {
+		ServiceError::ValidationError("Network configuration must be an object".to_string())
+	})?;
+
+	for value in config.values_mut() {
+		if let JsonValue::String(s) = value {
+			*s = s.trim().to_string();
+		}
+	}
+
+	let rpc_url = match config.get("rpc_url") {
+		Some(JsonValue::String(url)) if !url.is_empty() => url.clone(),
+		Some(JsonValue::String(_)) | None => {
+			return Err(ServiceError::ValidationError(
+				"Network configuration requires an rpc_url".to_string(),
+			));
+		}
+		Some(_) => {
+			return Err(ServiceError::ValidationError(
+				"rpc_url must be a string".to_string(),
+			));
+		}
+	};
+
+	config.insert(
+		"rpc_url".to_string(),
+		JsonValue::String(normalize_rpc_url(&rpc_url)?),
+	);
+
+	match blockchain {
+		"stellar" => match config.get("network_passphrase") {
+			Some(JsonValue::String(passphrase)) if !passphrase.is_empty() => {}
+			_ => {
+				return Err(ServiceError::ValidationError(
+					"Stellar network configuration requires a network_passphrase".to_string(),
+				));
+			}
+		},
+		"evm" => {
+			if config.get("chain_id").and_then(JsonValue::as_u64).is_none() {
+				return Err(ServiceError::ValidationError(
+					"EVM network configuration requires a numeric chain_id".to_string(),
+				));
+			}
+		}
+		other => {
+			return Err(ServiceError::ValidationError(format!(
+				"Invalid blockchain type: {}. Must be 'stellar' or 'evm'",
+				other
+			)));
+		}
+	}
+
+	Ok(JsonValue::Object(config))
+}
+
+fn normalize_rpc_url(url: &str) -> Result<String, ServiceError> {
+	let (scheme, rest) = match url.split_once("://") {
+		Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+		None => ("https".to_string(), url),
+	};
+
+	if !RPC_URL_SCHEMES.contains(&scheme.as_str()) {
+		return Err(ServiceError::ValidationError(format!(
+			"Unsupported rpc_url scheme: {}",
+			scheme
+		)));
+	}
+
+	let rest = rest.trim_end_matches('/');
+	let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+	if host.is_empty() || host.chars().any(char::is_whitespace) {
+		return Err(ServiceError::ValidationError(format!(
+			"Invalid rpc_url: {}",
+			url
+		)));
+	}
+
+	Ok(format!("{}://{}", scheme, rest))
 }
 
 #[derive(Clone)]
@@ -47,6 +261,8 @@ where
 	network_repo: N,
 	tenant_repo: T,
 	audit_service: A,
+	probe: Option<Arc<dyn NetworkProbe>>,
+	storage: StorageAccounting,
 }
 
 impl<N, T, A> NetworkService<N, T, A>
@@ -60,8 +276,22 @@ where
 			network_repo,
 			tenant_repo,
 			audit_service,
+			probe: Some(Arc::new(RpcNetworkProbe::default())),
+			storage: StorageAccounting::default(),
 		}
 	}
+
+	/// Probe used by `validate_connectivity`; `None` disables connectivity checks
+	pub fn with_probe(mut self, probe: Option<Arc<dyn NetworkProbe>>) -> Self {
+		self.probe = probe;
+		self
+	}
+
+	/// Storage checks and recording for network configurations
+	pub fn with_storage_accounting(mut self, storage: StorageAccounting) -> Self {
+		self.storage = storage;
+		self
+	}
 }
 
 #[async_trait]
@@ -102,8 +332,29 @@ where
 			)));
 		}
 
+		// Check per-blockchain sub-quota
+		if !quota_status.can_create_network_on(&request.blockchain) {
+			let limit = quota_status.blockchain(&request.blockchain);
+			return Err(ServiceError::QuotaExceeded(format!(
+				"{} network quota exceeded: {}/{}",
+				request.blockchain,
+				limit.map(|b| b.networks_count).unwrap_or_default(),
+				limit.and_then(|b| b.max_networks).unwrap_or_default()
+			)));
+		}
+
+		// Store the configuration in canonical form
+		let mut request = request;
+		request.configuration =
+			normalize_network_configuration(&request.configuration, &request.blockchain)?;
+
+		let storage_mb = config_storage_mb(&request.configuration);
+		self.storage.ensure_available(&quota_status, storage_mb)?;
+
 		// Create network
 		let network = self.network_repo.create(request.clone()).await?;
+		self.storage.record(context.tenant_id, storage_mb).await;
+		metrics::record_networks_added(1);
 
 		// Audit log
 		self.audit_service
@@ -117,6 +368,7 @@ where
 				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
@@ -145,11 +397,27 @@ where
 		// Get existing network
 		let existing = self.network_repo.get(network_id).await?;
 
+		let mut request = request;
+		if let Some(configuration) = &request.configuration {
+			request.configuration = Some(normalize_network_configuration(
+				configuration,
+				&existing.blockchain,
+			)?);
+		}
+
+		let storage_delta = request.configuration.as_ref().map_or(0, |configuration| {
+			config_storage_mb(configuration) - config_storage_mb(&existing.configuration)
+		});
+		self.storage
+			.check_quota(&self.tenant_repo, context.tenant_id, storage_delta)
+			.await?;
+
 		// Update network
 		let network = self
 			.network_repo
 			.update(network_id, request.clone())
 			.await?;
+		self.storage.record(context.tenant_id, storage_delta).await;
 
 		// Audit log
 		self.audit_service
@@ -163,6 +431,7 @@ where
 				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
@@ -176,8 +445,8 @@ where
 	) -> Result<(), ServiceError> {
 		let context = current_tenant_context();
 
-		// Check write permissions
-		if !context.can_write() {
+		// Deleting is reserved for admins and owners
+		if !context.can_delete() {
 			return Err(ServiceError::AccessDenied(
 				"Insufficient permissions to delete networks".to_string(),
 			));
@@ -188,6 +457,7 @@ where
 
 		// Delete network (repository will check for dependent monitors)
 		self.network_repo.delete(network_id).await?;
+		metrics::record_networks_removed(1);
 
 		// Audit log
 		self.audit_service
@@ -201,12 +471,93 @@ where
 				changes: None,
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
 		Ok(())
 	}
 
+	async fn delete_network_with_monitors(
+		&self,
+		network_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<NetworkDeletion, ServiceError> {
+		let context = current_tenant_context();
+
+		// Deleting is reserved for admins and owners
+		if !context.can_delete() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to delete networks".to_string(),
+			));
+		}
+
+		// Network, monitors and triggers are deleted atomically by the repository
+		let deletion = self.network_repo.delete_with_monitors(network_id).await?;
+		let live_monitors = deletion
+			.monitors
+			.iter()
+			.filter(|monitor| monitor.deleted_at.is_none())
+			.count();
+		metrics::record_triggers_removed(deletion.triggers.len() as i64);
+		metrics::record_monitors_removed(live_monitors as i64);
+		metrics::record_networks_removed(1);
+
+		for trigger in &deletion.triggers {
+			self.audit_service
+				.log(CreateAuditLogRequest {
+					tenant_id: context.tenant_id,
+					user_id: context.user.as_ref().map(|u| u.id),
+					api_key_id: context.api_key_id,
+					action: AuditAction::TriggerDeleted,
+					resource_type: Some(AuditResourceType::Trigger),
+					resource_id: Some(trigger.id),
+					changes: Some(json!({ "monitor_id": trigger.monitor_id })),
+					ip_address: metadata.ip_address,
+					user_agent: metadata.user_agent.clone(),
+					request_id: metadata.request_id.clone(),
+				})
+				.await?;
+		}
+
+		for monitor in &deletion.monitors {
+			self.audit_service
+				.log(CreateAuditLogRequest {
+					tenant_id: context.tenant_id,
+					user_id: context.user.as_ref().map(|u| u.id),
+					api_key_id: context.api_key_id,
+					action: AuditAction::MonitorDeleted,
+					resource_type: Some(AuditResourceType::Monitor),
+					resource_id: Some(monitor.id),
+					changes: Some(json!({ "network_id": deletion.network.id })),
+					ip_address: metadata.ip_address,
+					user_agent: metadata.user_agent.clone(),
+					request_id: metadata.request_id.clone(),
+				})
+				.await?;
+		}
+
+		self.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|u| u.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::NetworkDeleted,
+				resource_type: Some(AuditResourceType::Network),
+				resource_id: Some(deletion.network.id),
+				changes: Some(json!({
+					"monitors_deleted": deletion.monitors.len(),
+					"triggers_deleted": deletion.triggers.len(),
+				})),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+
+		Ok(deletion)
+	}
+
 	async fn list_networks(
 		&self,
 		limit: i64,
@@ -215,8 +566,130 @@ where
 		Ok(self.network_repo.list(limit, offset).await?)
 	}
 
+	async fn list_networks_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError> {
+		Ok(self.network_repo.list_after(cursor, limit).await?)
+	}
+
+	async fn list_networks_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError> {
+		Ok(self
+			.network_repo
+			.list_by_source(source, limit, offset)
+			.await?)
+	}
+
 	async fn get_network_count(&self) -> Result<i64, ServiceError> {
 		let networks = self.network_repo.get_all().await?;
 		Ok(networks.len() as i64)
 	}
+
+	async fn validate_connectivity(
+		&self,
+		config: &JsonValue,
+		blockchain: &str,
+	) -> Result<(), ServiceError> {
+		let config = normalize_network_configuration(config, blockchain)?;
+		let rpc_url = config["rpc_url"].as_str().unwrap_or_default();
+
+		let Some(probe) = &self.probe else {
+			tracing::debug!(rpc_url, "Network connectivity probe disabled; skipping");
+			return Ok(());
+		};
+
+		// The probe is a plain HTTP POST; WebSocket endpoints are not checked
+		if rpc_url.starts_with("ws://") || rpc_url.starts_with("wss://") {
+			return Ok(());
+		}
+
+		probe.probe(rpc_url, blockchain).await
+	}
+}
+
+#[async_trait]
+impl<T: NetworkServiceTrait> NetworkServiceTrait for Arc<T> {
+	async fn create_network(
+		&self,
+		request: CreateNetworkRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantNetwork, ServiceError> {
+		(**self).create_network(request, metadata).await
+	}
+
+	async fn get_network(&self, network_id: &str) -> Result<TenantNetwork, ServiceError> {
+		(**self).get_network(network_id).await
+	}
+
+	async fn update_network(
+		&self,
+		network_id: &str,
+		request: UpdateNetworkRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantNetwork, ServiceError> {
+		(**self).update_network(network_id, request, metadata).await
+	}
+
+	async fn delete_network(
+		&self,
+		network_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<(), ServiceError> {
+		(**self).delete_network(network_id, metadata).await
+	}
+
+	async fn delete_network_with_monitors(
+		&self,
+		network_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<NetworkDeletion, ServiceError> {
+		(**self)
+			.delete_network_with_monitors(network_id, metadata)
+			.await
+	}
+
+	async fn list_networks(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError> {
+		(**self).list_networks(limit, offset).await
+	}
+
+	async fn list_networks_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError> {
+		(**self).list_networks_after(cursor, limit).await
+	}
+
+	async fn list_networks_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantNetwork>, ServiceError> {
+		(**self)
+			.list_networks_by_source(source, limit, offset)
+			.await
+	}
+
+	async fn get_network_count(&self) -> Result<i64, ServiceError> {
+		(**self).get_network_count().await
+	}
+
+	async fn validate_connectivity(
+		&self,
+		config: &JsonValue,
+		blockchain: &str,
+	) -> Result<(), ServiceError> {
+		(**self).validate_connectivity(config, blockchain).await
+	}
 }
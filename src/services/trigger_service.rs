@@ -1,17 +1,215 @@
 use async_trait::async_trait;
-use serde_json::Value as JsonValue;
+use serde_json::{json, Value as JsonValue};
+use std::collections::{hash_map::Entry, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use super::monitor_service::{AuditServiceTrait, ServiceError};
+use super::usage_service::StorageAccounting;
 use crate::models::audit::ResourceType as AuditResourceType;
 use crate::models::{
-	AuditAction, CreateAuditLogRequest, CreateTriggerRequest, RequestMetadata, TenantTrigger,
-	UpdateTriggerRequest,
+	config_storage_mb, AuditAction, CreateAuditLogRequest, CreateTriggerRequest, CreationSource,
+	ListCursor, RequestMetadata, TenantTrigger, TestDeliveryResult, TestDeliveryStatus,
+	TriggerFilter, UpdateTriggerRequest,
 };
 use crate::repositories::{
-	TenantMonitorRepositoryTrait, TenantRepositoryTrait, TenantTriggerRepositoryTrait,
+	TenantMonitorRepositoryTrait, TenantRepositoryError, TenantRepositoryTrait,
+	TenantTriggerRepositoryTrait,
 };
-use crate::utils::current_tenant_context;
+use crate::utils::{current_tenant_context, metrics};
+
+/// Trigger types accepted on create
+pub const VALID_TRIGGER_TYPES: [&str; 6] =
+	["webhook", "email", "slack", "discord", "telegram", "script"];
+
+/// HTTP methods webhook triggers may use unless the deployment configures others
+pub const DEFAULT_WEBHOOK_METHODS: [&str; 2] = ["POST", "PUT"];
+
+fn validate_trigger_type(trigger_type: &str) -> Result<(), ServiceError> {
+	if !VALID_TRIGGER_TYPES.contains(&trigger_type) {
+		return Err(ServiceError::ValidationError(format!(
+			"Invalid trigger type: {}. Must be one of: {:?}",
+			trigger_type, VALID_TRIGGER_TYPES
+		)));
+	}
+
+	Ok(())
+}
+
+/// Configuration fields a trigger of the given type needs for delivery
+fn required_trigger_fields(trigger_type: &str) -> &'static [&'static str] {
+	match trigger_type {
+		"webhook" => &["url", "method"],
+		"email" => &["to"],
+		"slack" | "discord" => &["webhook_url"],
+		"telegram" => &["chat_id", "token"],
+		_ => &[],
+	}
+}
+
+/// Reject unknown trigger types and configurations missing a field the type
+/// requires; null, empty strings and empty lists count as missing
+pub fn validate_trigger_config(trigger_type: &str, config: &JsonValue) -> Result<(), ServiceError> {
+	validate_trigger_type(trigger_type)?;
+
+	for field in required_trigger_fields(trigger_type) {
+		let missing = match config.get(field) {
+			None | Some(JsonValue::Null) => true,
+			Some(JsonValue::String(value)) => value.trim().is_empty(),
+			Some(JsonValue::Array(values)) => values.is_empty(),
+			Some(_) => false,
+		};
+		if missing {
+			return Err(ServiceError::ValidationError(format!(
+				"{} trigger configuration is missing required field '{}'",
+				trigger_type, field
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+/// Reject a webhook configuration whose `method` is not one of `allowed_methods`,
+/// compared case-insensitively; other trigger types have no method to check
+pub fn validate_webhook_method(
+	trigger_type: &str,
+	config: &JsonValue,
+	allowed_methods: &[String],
+) -> Result<(), ServiceError> {
+	if trigger_type != "webhook" {
+		return Ok(());
+	}
+
+	// A missing method is reported by validate_trigger_config
+	let method = match config.get("method") {
+		None | Some(JsonValue::Null) => return Ok(()),
+		Some(method) => method.as_str().unwrap_or_default().trim(),
+	};
+	if !allowed_methods
+		.iter()
+		.any(|allowed| allowed.eq_ignore_ascii_case(method))
+	{
+		return Err(ServiceError::ValidationError(format!(
+			"Webhook method '{}' is not allowed. Must be one of: {:?}",
+			method, allowed_methods
+		)));
+	}
+
+	Ok(())
+}
+
+/// Longest a test delivery waits for the channel; a shorter `timeout` in the
+/// trigger configuration is respected
+pub const TEST_DELIVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Canned event sent by a test delivery, marked with `"test": true`
+fn sample_event(trigger: &TenantTrigger) -> JsonValue {
+	json!({
+		"test": true,
+		"trigger_id": trigger.trigger_id,
+		"monitor": {
+			"id": trigger.monitor_id,
+			"name": "Test monitor",
+		},
+		"event": {
+			"network": "stellar_testnet",
+			"ledger": 0,
+			"transaction_hash": "0".repeat(64),
+		},
+		"message": format!("Test notification for trigger '{}'", trigger.name),
+	})
+}
+
+/// Trigger types `send_test_payload` delivers; tests of other types are skipped
+pub const TEST_DELIVERED_TRIGGER_TYPES: &[&str] = &["webhook", "slack", "discord"];
+
+/// Send a sample event to the trigger's channel: webhooks get the event with
+/// the configured method and headers, Slack and Discord a chat message. Other
+/// trigger types are skipped. Failures are reported in the result.
+pub async fn send_test_payload(trigger: &TenantTrigger, timeout: Duration) -> TestDeliveryResult {
+	let config = &trigger.configuration;
+	let event = sample_event(trigger);
+	let message = event["message"].clone();
+	let target = match trigger.trigger_type.as_str() {
+		"webhook" => config["url"].as_str().map(|url| {
+			let method = config["method"].as_str().unwrap_or("POST");
+			(url, method, event)
+		}),
+		"slack" => config["webhook_url"]
+			.as_str()
+			.map(|url| (url, "POST", json!({ "text": message }))),
+		"discord" => config["webhook_url"]
+			.as_str()
+			.map(|url| (url, "POST", json!({ "content": message }))),
+		_ => None,
+	};
+
+	let mut result = TestDeliveryResult {
+		trigger_id: trigger.trigger_id.clone(),
+		trigger_type: trigger.trigger_type.clone(),
+		status: TestDeliveryStatus::Skipped,
+		status_code: None,
+		latency_ms: None,
+		error: None,
+	};
+	let Some((url, method, body)) = target else {
+		result.error = Some(format!(
+			"{} triggers are not delivered by a test",
+			trigger.trigger_type
+		));
+		return result;
+	};
+
+	let timeout = config["timeout"]
+		.as_u64()
+		.map(Duration::from_secs)
+		.map_or(timeout, |configured| configured.min(timeout));
+	let client = match reqwest::Client::builder().timeout(timeout).build() {
+		Ok(client) => client,
+		Err(e) => {
+			result.status = TestDeliveryStatus::Failed;
+			result.error = Some(e.to_string());
+			return result;
+		}
+	};
+	let method = reqwest::Method::from_bytes(method.to_ascii_uppercase().as_bytes())
+		.unwrap_or(reqwest::Method::POST);
+	// Configured headers replace the JSON content type rather than repeat it
+	let headers: reqwest::header::HeaderMap = config["headers"]
+		.as_object()
+		.into_iter()
+		.flatten()
+		.filter_map(|(name, value)| {
+			Some((
+				reqwest::header::HeaderName::from_bytes(name.as_bytes()).ok()?,
+				reqwest::header::HeaderValue::from_str(value.as_str()?).ok()?,
+			))
+		})
+		.collect();
+	let request = client.request(method, url).json(&body).headers(headers);
+
+	let started = Instant::now();
+	let response = request.send().await;
+	result.latency_ms = Some(started.elapsed().as_millis() as u64);
+	match response {
+		Ok(response) => {
+			result.status_code = Some(response.status().as_u16());
+			result.status = if response.status().is_success() {
+				TestDeliveryStatus::Delivered
+			} else {
+				TestDeliveryStatus::Failed
+			};
+		}
+		Err(e) => {
+			result.status = TestDeliveryStatus::Failed;
+			result.error = Some(e.to_string());
+		}
+	}
+
+	result
+}
 
 #[async_trait]
 pub trait TriggerServiceTrait: Send + Sync {
@@ -20,7 +218,13 @@ pub trait TriggerServiceTrait: Send + Sync {
 		request: CreateTriggerRequest,
 		metadata: RequestMetadata,
 	) -> Result<TenantTrigger, ServiceError>;
+	async fn create_triggers_batch(
+		&self,
+		requests: Vec<CreateTriggerRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError>;
 	async fn get_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError>;
+	async fn get_effective_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError>;
 	async fn update_trigger(
 		&self,
 		trigger_id: &str,
@@ -37,11 +241,43 @@ pub trait TriggerServiceTrait: Send + Sync {
 		limit: i64,
 		offset: i64,
 	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	/// Keyset pagination; `cursor` is the position of the last item already seen
+	async fn list_triggers_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	async fn list_triggers_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	async fn list_triggers_filtered(
+		&self,
+		filter: TriggerFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	async fn count_triggers_filtered(&self, filter: TriggerFilter) -> Result<i64, ServiceError>;
 	async fn list_triggers_by_monitor(
 		&self,
 		monitor_id: Uuid,
+		limit: i64,
+		offset: i64,
 	) -> Result<Vec<TenantTrigger>, ServiceError>;
+	/// Number of the monitor's active triggers, the total behind `list_triggers_by_monitor`
+	async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, ServiceError>;
 	async fn get_trigger_count(&self) -> Result<i64, ServiceError>;
+	/// Number of the tenant's triggers per trigger type
+	async fn get_trigger_type_stats(&self) -> Result<HashMap<String, i64>, ServiceError>;
+	/// Triggers still configured with the tenant's pre-rotation signing secret
+	async fn list_triggers_using_old_secret(&self) -> Result<Vec<TenantTrigger>, ServiceError>;
+	/// Send a sample event to the trigger's channel with its effective configuration
+	async fn send_test_notification(
+		&self,
+		trigger_id: &str,
+	) -> Result<TestDeliveryResult, ServiceError>;
 }
 
 #[derive(Clone)]
@@ -56,6 +292,9 @@ where
 	monitor_repo: M,
 	tenant_repo: T,
 	audit_service: A,
+	storage: StorageAccounting,
+	webhook_methods: Vec<String>,
+	max_per_type: Option<i64>,
 }
 
 impl<Tr, M, T, A> TriggerService<Tr, M, T, A>
@@ -71,7 +310,69 @@ where
 			monitor_repo,
 			tenant_repo,
 			audit_service,
+			storage: StorageAccounting::default(),
+			webhook_methods: DEFAULT_WEBHOOK_METHODS
+				.iter()
+				.map(|method| method.to_string())
+				.collect(),
+			max_per_type: None,
+		}
+	}
+
+	/// Storage checks and recording for trigger configurations
+	pub fn with_storage_accounting(mut self, storage: StorageAccounting) -> Self {
+		self.storage = storage;
+		self
+	}
+
+	/// HTTP methods webhook triggers may be created or updated with
+	pub fn with_webhook_methods(mut self, methods: Vec<String>) -> Self {
+		self.webhook_methods = methods;
+		self
+	}
+
+	/// Most triggers of the same type a monitor may have; unlimited when `None`
+	pub fn with_max_triggers_per_type(mut self, max: Option<i64>) -> Self {
+		self.max_per_type = max;
+		self
+	}
+
+	fn validate_config(&self, trigger_type: &str, config: &JsonValue) -> Result<(), ServiceError> {
+		validate_trigger_config(trigger_type, config)?;
+		validate_webhook_method(trigger_type, config, &self.webhook_methods)
+	}
+
+	/// Rejects requests that would take a monitor past the per-type cap,
+	/// counting the monitor's existing triggers and the requests themselves
+	async fn check_type_cap(&self, requests: &[CreateTriggerRequest]) -> Result<(), ServiceError> {
+		let Some(max) = self.max_per_type else {
+			return Ok(());
+		};
+
+		let mut counts: HashMap<(Uuid, &str), i64> = HashMap::new();
+		for request in requests {
+			let count = match counts.entry((request.monitor_id, request.trigger_type.as_str())) {
+				Entry::Occupied(entry) => entry.into_mut(),
+				Entry::Vacant(entry) => {
+					let existing = self
+						.trigger_repo
+						.get_by_monitor(request.monitor_id)
+						.await?
+						.iter()
+						.filter(|trigger| trigger.trigger_type == request.trigger_type)
+						.count() as i64;
+					entry.insert(existing)
+				}
+			};
+			if *count >= max {
+				return Err(ServiceError::ValidationError(format!(
+					"Monitor {} already has the maximum of {} {} trigger(s)",
+					request.monitor_id, max, request.trigger_type
+				)));
+			}
+			*count += 1;
 		}
+		Ok(())
 	}
 }
 
@@ -109,17 +410,17 @@ where
 			)));
 		}
 
-		// Validate trigger type
-		let valid_types = ["webhook", "email", "slack", "discord", "telegram", "script"];
-		if !valid_types.contains(&request.trigger_type.as_str()) {
-			return Err(ServiceError::ValidationError(format!(
-				"Invalid trigger type: {}. Must be one of: {:?}",
-				request.trigger_type, valid_types
-			)));
-		}
+		// Validate trigger type and configuration
+		self.validate_config(&request.trigger_type, &request.configuration)?;
+		self.check_type_cap(std::slice::from_ref(&request)).await?;
+
+		let storage_mb = config_storage_mb(&request.configuration);
+		self.storage.ensure_available(&quota_status, storage_mb)?;
 
 		// Create trigger
 		let trigger = self.trigger_repo.create(request.clone()).await?;
+		metrics::record_triggers_added(1);
+		self.storage.record(context.tenant_id, storage_mb).await;
 
 		// Audit log
 		self.audit_service
@@ -133,16 +434,95 @@ where
 				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
 		Ok(trigger)
 	}
 
+	async fn create_triggers_batch(
+		&self,
+		requests: Vec<CreateTriggerRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		let context = current_tenant_context();
+
+		// Check write permissions
+		if !context.can_write() {
+			return Err(ServiceError::AccessDenied(
+				"Insufficient permissions to create triggers".to_string(),
+			));
+		}
+
+		// Validate every trigger before anything is inserted
+		for request in &requests {
+			self.validate_config(&request.trigger_type, &request.configuration)?;
+		}
+		self.check_type_cap(&requests).await?;
+
+		let storage_mb: i64 = requests
+			.iter()
+			.map(|request| config_storage_mb(&request.configuration))
+			.sum();
+		self.storage
+			.check_quota(&self.tenant_repo, context.tenant_id, storage_mb)
+			.await?;
+
+		// Per-monitor quotas are enforced inside the batch transaction
+		let triggers = self
+			.trigger_repo
+			.create_batch(requests)
+			.await
+			.map_err(|e| match e {
+				TenantRepositoryError::QuotaExceeded(message) => {
+					ServiceError::QuotaExceeded(message)
+				}
+				e => ServiceError::Repository(e),
+			})?;
+		metrics::record_triggers_added(triggers.len() as i64);
+		self.storage.record(context.tenant_id, storage_mb).await;
+
+		// One audit entry for the whole batch
+		self.audit_service
+			.log(CreateAuditLogRequest {
+				tenant_id: context.tenant_id,
+				user_id: context.user.as_ref().map(|u| u.id),
+				api_key_id: context.api_key_id,
+				action: AuditAction::TriggerCreated,
+				resource_type: Some(AuditResourceType::Trigger),
+				resource_id: None,
+				changes: Some(serde_json::json!({
+					"batch_size": triggers.len(),
+					"trigger_ids": triggers.iter().map(|t| &t.trigger_id).collect::<Vec<_>>(),
+				})),
+				ip_address: metadata.ip_address,
+				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
+			})
+			.await?;
+
+		Ok(triggers)
+	}
+
 	async fn get_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError> {
 		Ok(self.trigger_repo.get(trigger_id).await?)
 	}
 
+	async fn get_effective_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError> {
+		let context = current_tenant_context();
+		let mut trigger = self.trigger_repo.get(trigger_id).await?;
+
+		// Merge tenant defaults the same way delivery does
+		let defaults = self
+			.tenant_repo
+			.get_trigger_defaults(context.tenant_id)
+			.await?;
+		trigger.configuration = defaults.apply(&trigger.configuration);
+
+		Ok(trigger)
+	}
+
 	async fn update_trigger(
 		&self,
 		trigger_id: &str,
@@ -161,11 +541,24 @@ where
 		// Get existing trigger
 		let existing = self.trigger_repo.get(trigger_id).await?;
 
+		// A replacement configuration must still suit the trigger's type
+		if let Some(configuration) = &request.configuration {
+			self.validate_config(&existing.trigger_type, configuration)?;
+		}
+
+		let storage_delta = request.configuration.as_ref().map_or(0, |configuration| {
+			config_storage_mb(configuration) - config_storage_mb(&existing.configuration)
+		});
+		self.storage
+			.check_quota(&self.tenant_repo, context.tenant_id, storage_delta)
+			.await?;
+
 		// Update trigger
 		let trigger = self
 			.trigger_repo
 			.update(trigger_id, request.clone())
 			.await?;
+		self.storage.record(context.tenant_id, storage_delta).await;
 
 		// Audit log
 		self.audit_service
@@ -179,6 +572,7 @@ where
 				changes: Some(serde_json::to_value(&request).unwrap_or(JsonValue::Null)),
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
@@ -192,8 +586,8 @@ where
 	) -> Result<(), ServiceError> {
 		let context = current_tenant_context();
 
-		// Check write permissions
-		if !context.can_write() {
+		// Deleting is reserved for admins and owners
+		if !context.can_delete() {
 			return Err(ServiceError::AccessDenied(
 				"Insufficient permissions to delete triggers".to_string(),
 			));
@@ -204,6 +598,7 @@ where
 
 		// Delete trigger
 		self.trigger_repo.delete(trigger_id).await?;
+		metrics::record_triggers_removed(1);
 
 		// Audit log
 		self.audit_service
@@ -217,6 +612,7 @@ where
 				changes: None,
 				ip_address: metadata.ip_address,
 				user_agent: metadata.user_agent.clone(),
+				request_id: metadata.request_id.clone(),
 			})
 			.await?;
 
@@ -231,17 +627,212 @@ where
 		Ok(self.trigger_repo.list(limit, offset).await?)
 	}
 
+	async fn list_triggers_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		Ok(self.trigger_repo.list_after(cursor, limit).await?)
+	}
+
+	async fn list_triggers_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		Ok(self
+			.trigger_repo
+			.list_by_source(source, limit, offset)
+			.await?)
+	}
+
+	async fn list_triggers_filtered(
+		&self,
+		filter: TriggerFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		Ok(self
+			.trigger_repo
+			.list_filtered(filter, limit, offset)
+			.await?)
+	}
+
+	async fn count_triggers_filtered(&self, filter: TriggerFilter) -> Result<i64, ServiceError> {
+		Ok(self.trigger_repo.count_filtered(filter).await?)
+	}
+
 	async fn list_triggers_by_monitor(
 		&self,
 		monitor_id: Uuid,
+		limit: i64,
+		offset: i64,
 	) -> Result<Vec<TenantTrigger>, ServiceError> {
 		// Verify monitor belongs to tenant
 		let _ = self.monitor_repo.get_by_uuid(monitor_id).await?;
 
-		Ok(self.trigger_repo.get_by_monitor(monitor_id).await?)
+		Ok(self
+			.trigger_repo
+			.list_by_monitor(monitor_id, limit, offset)
+			.await?)
+	}
+
+	async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, ServiceError> {
+		Ok(self.trigger_repo.count_by_monitor(monitor_id).await?)
 	}
 
 	async fn get_trigger_count(&self) -> Result<i64, ServiceError> {
 		Ok(self.trigger_repo.count().await?)
 	}
+
+	async fn get_trigger_type_stats(&self) -> Result<HashMap<String, i64>, ServiceError> {
+		Ok(self.trigger_repo.count_by_type().await?)
+	}
+
+	async fn list_triggers_using_old_secret(&self) -> Result<Vec<TenantTrigger>, ServiceError> {
+		let context = current_tenant_context();
+		let defaults = self
+			.tenant_repo
+			.get_trigger_defaults(context.tenant_id)
+			.await?;
+
+		// Nothing can reference an old secret until the tenant has rotated one
+		match defaults.previous_signing_secret {
+			Some(previous) => Ok(self.trigger_repo.list_by_signing_secret(&previous).await?),
+			None => Ok(Vec::new()),
+		}
+	}
+
+	async fn send_test_notification(
+		&self,
+		trigger_id: &str,
+	) -> Result<TestDeliveryResult, ServiceError> {
+		let trigger = self.get_effective_trigger(trigger_id).await?;
+
+		let result = send_test_payload(&trigger, TEST_DELIVERY_TIMEOUT).await;
+		if result.status == TestDeliveryStatus::Delivered {
+			self.trigger_repo.record_successful_test(trigger_id).await?;
+		}
+
+		Ok(result)
+	}
+}
+
+#[async_trait]
+impl<T: TriggerServiceTrait> TriggerServiceTrait for Arc<T> {
+	async fn create_trigger(
+		&self,
+		request: CreateTriggerRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantTrigger, ServiceError> {
+		(**self).create_trigger(request, metadata).await
+	}
+
+	async fn create_triggers_batch(
+		&self,
+		requests: Vec<CreateTriggerRequest>,
+		metadata: RequestMetadata,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self).create_triggers_batch(requests, metadata).await
+	}
+
+	async fn get_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError> {
+		(**self).get_trigger(trigger_id).await
+	}
+
+	async fn get_effective_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError> {
+		(**self).get_effective_trigger(trigger_id).await
+	}
+
+	async fn update_trigger(
+		&self,
+		trigger_id: &str,
+		request: UpdateTriggerRequest,
+		metadata: RequestMetadata,
+	) -> Result<TenantTrigger, ServiceError> {
+		(**self).update_trigger(trigger_id, request, metadata).await
+	}
+
+	async fn delete_trigger(
+		&self,
+		trigger_id: &str,
+		metadata: RequestMetadata,
+	) -> Result<(), ServiceError> {
+		(**self).delete_trigger(trigger_id, metadata).await
+	}
+
+	async fn list_triggers(
+		&self,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self).list_triggers(limit, offset).await
+	}
+
+	async fn list_triggers_after(
+		&self,
+		cursor: Option<ListCursor>,
+		limit: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self).list_triggers_after(cursor, limit).await
+	}
+
+	async fn list_triggers_by_source(
+		&self,
+		source: CreationSource,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self)
+			.list_triggers_by_source(source, limit, offset)
+			.await
+	}
+
+	async fn list_triggers_filtered(
+		&self,
+		filter: TriggerFilter,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self).list_triggers_filtered(filter, limit, offset).await
+	}
+
+	async fn count_triggers_filtered(&self, filter: TriggerFilter) -> Result<i64, ServiceError> {
+		(**self).count_triggers_filtered(filter).await
+	}
+
+	async fn list_triggers_by_monitor(
+		&self,
+		monitor_id: Uuid,
+		limit: i64,
+		offset: i64,
+	) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self)
+			.list_triggers_by_monitor(monitor_id, limit, offset)
+			.await
+	}
+
+	async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, ServiceError> {
+		(**self).count_by_monitor(monitor_id).await
+	}
+
+	async fn get_trigger_count(&self) -> Result<i64, ServiceError> {
+		(**self).get_trigger_count().await
+	}
+
+	async fn get_trigger_type_stats(&self) -> Result<HashMap<String, i64>, ServiceError> {
+		(**self).get_trigger_type_stats().await
+	}
+
+	async fn list_triggers_using_old_secret(&self) -> Result<Vec<TenantTrigger>, ServiceError> {
+		(**self).list_triggers_using_old_secret().await
+	}
+
+	async fn send_test_notification(
+		&self,
+		trigger_id: &str,
+	) -> Result<TestDeliveryResult, ServiceError> {
+		(**self).send_test_notification(trigger_id).await
+	}
 }
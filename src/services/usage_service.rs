@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::monitor_service::ServiceError;
+use crate::models::{ReportUsageRequest, ResourceQuotaStatus, StorageEnforcement};
+use crate::repositories::{TenantRepositoryTrait, UsageRepositoryTrait};
+
+/// Adds the storage estimated for API writes to the tenant's usage
+#[async_trait]
+pub trait StorageRecorder: Send + Sync {
+	async fn add_storage(&self, tenant_id: Uuid, delta_mb: i64) -> Result<(), ServiceError>;
+}
+
+/// Records resource consumption reported by monitor runners so quota checks
+/// for `rpc_requests` and `storage_mb` reflect actual usage
+#[derive(Clone)]
+pub struct UsageService<U>
+where
+	U: UsageRepositoryTrait,
+{
+	usage_repo: U,
+}
+
+impl<U> UsageService<U>
+where
+	U: UsageRepositoryTrait,
+{
+	pub fn new(usage_repo: U) -> Self {
+		Self { usage_repo }
+	}
+
+	/// Add `count` RPC requests to the tenant's current one-minute window
+	pub async fn record_rpc_requests(
+		&self,
+		tenant_id: Uuid,
+		count: i64,
+	) -> Result<(), ServiceError> {
+		if count < 1 {
+			return Err(ServiceError::ValidationError(
+				"rpc_requests must be at least 1".to_string(),
+			));
+		}
+
+		self.usage_repo
+			.record_rpc_requests(tenant_id, count)
+			.await?;
+		Ok(())
+	}
+
+	/// Replace the tenant's reported storage for today
+	pub async fn record_storage(&self, tenant_id: Uuid, mb: i64) -> Result<(), ServiceError> {
+		if mb < 0 {
+			return Err(ServiceError::ValidationError(
+				"storage_mb must not be negative".to_string(),
+			));
+		}
+
+		self.usage_repo.record_storage(tenant_id, mb).await?;
+		Ok(())
+	}
+
+	/// Adjust today's storage by `delta_mb`, on top of the last reported level
+	pub async fn add_storage(&self, tenant_id: Uuid, delta_mb: i64) -> Result<(), ServiceError> {
+		self.usage_repo.add_storage(tenant_id, delta_mb).await?;
+		Ok(())
+	}
+
+	/// Record every value present in a runner's report
+	pub async fn report(&self, request: ReportUsageRequest) -> Result<(), ServiceError> {
+		if request.rpc_requests.is_none() && request.storage_mb.is_none() {
+			return Err(ServiceError::ValidationError(
+				"Report must include rpc_requests or storage_mb".to_string(),
+			));
+		}
+
+		if let Some(count) = request.rpc_requests {
+			self.record_rpc_requests(request.tenant_id, count).await?;
+		}
+		if let Some(mb) = request.storage_mb {
+			self.record_storage(request.tenant_id, mb).await?;
+		}
+
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<U> StorageRecorder for UsageService<U>
+where
+	U: UsageRepositoryTrait,
+{
+	async fn add_storage(&self, tenant_id: Uuid, delta_mb: i64) -> Result<(), ServiceError> {
+		UsageService::add_storage(self, tenant_id, delta_mb).await
+	}
+}
+
+/// Storage quota checks and bookkeeping for services that write configurations.
+/// Without a recorder only the checks run, against whatever runners last reported.
+#[derive(Clone, Default)]
+pub struct StorageAccounting {
+	recorder: Option<Arc<dyn StorageRecorder>>,
+	enforcement: StorageEnforcement,
+}
+
+impl StorageAccounting {
+	pub fn new(
+		recorder: Option<Arc<dyn StorageRecorder>>,
+		enforcement: StorageEnforcement,
+	) -> Self {
+		Self {
+			recorder,
+			enforcement,
+		}
+	}
+
+	pub fn set_enforcement(&mut self, enforcement: StorageEnforcement) {
+		self.enforcement = enforcement;
+	}
+
+	pub fn set_recorder(&mut self, recorder: Option<Arc<dyn StorageRecorder>>) {
+		self.recorder = recorder;
+	}
+
+	/// Check that `delta_mb` more storage fits, using an already loaded quota status
+	pub fn ensure_available(
+		&self,
+		status: &ResourceQuotaStatus,
+		delta_mb: i64,
+	) -> Result<(), ServiceError> {
+		if delta_mb <= 0 || status.can_store(delta_mb as i32) {
+			return Ok(());
+		}
+
+		self.enforce(
+			status.tenant_id,
+			delta_mb,
+			format!(
+				"Storage quota exceeded: {}/{} MB",
+				status.usage.storage_mb_used, status.quotas.max_storage_mb
+			),
+		)
+	}
+
+	/// Check that `delta_mb` more storage fits through the tenant repository's quota check
+	pub async fn check_quota<T>(
+		&self,
+		tenant_repo: &T,
+		tenant_id: Uuid,
+		delta_mb: i64,
+	) -> Result<(), ServiceError>
+	where
+		T: TenantRepositoryTrait + Send + Sync,
+	{
+		if delta_mb <= 0
+			|| tenant_repo
+				.check_quota(tenant_id, "storage_mb", delta_mb as i32)
+				.await?
+		{
+			return Ok(());
+		}
+
+		self.enforce(
+			tenant_id,
+			delta_mb,
+			format!("Storage quota exceeded: {} MB more does not fit", delta_mb),
+		)
+	}
+
+	fn enforce(&self, tenant_id: Uuid, delta_mb: i64, message: String) -> Result<(), ServiceError> {
+		match self.enforcement {
+			StorageEnforcement::Hard => Err(ServiceError::QuotaExceeded(message)),
+			StorageEnforcement::Soft => {
+				tracing::warn!(
+					tenant_id = %tenant_id,
+					delta_mb,
+					"Storage quota exceeded; writing configuration under soft enforcement"
+				);
+				Ok(())
+			}
+		}
+	}
+
+	/// Add a committed write's estimated storage; failures are logged since the
+	/// write itself already succeeded and the next runner report corrects the level
+	pub async fn record(&self, tenant_id: Uuid, delta_mb: i64) {
+		let Some(recorder) = &self.recorder else {
+			return;
+		};
+		if delta_mb == 0 {
+			return;
+		}
+
+		if let Err(e) = recorder.add_storage(tenant_id, delta_mb).await {
+			tracing::warn!(
+				tenant_id = %tenant_id,
+				delta_mb,
+				error = %e,
+				"Failed to record storage usage"
+			);
+		}
+	}
+}
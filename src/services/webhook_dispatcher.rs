@@ -0,0 +1,222 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use super::audit_service::AuditEvents;
+use crate::models::{webhook_payload, AuditLog, TenantWebhook, WebhookDeliveryStatus};
+use crate::repositories::{TenantRepositoryError, WebhookRepository};
+
+/// Header carrying `sha256=<hex HMAC-SHA256 of the body>` keyed by the webhook's secret
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Signature";
+/// Header naming the audit action a delivery reports
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Webhook-Event";
+/// Header with the delivery id, the same across retries of one delivery
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// `X-Signature` value for `body`
+pub fn sign_webhook_payload(secret: &str, body: &[u8]) -> String {
+	let mut mac =
+		Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+	mac.update(body);
+	let digest: String = mac
+		.finalize()
+		.into_bytes()
+		.iter()
+		.map(|b| format!("{:02x}", b))
+		.collect();
+	format!("sha256={}", digest)
+}
+
+/// A random signing secret for a webhook created without one
+pub fn generate_webhook_secret() -> String {
+	use base64::{engine::general_purpose, Engine as _};
+	use rand::Rng;
+	let secret: [u8; 32] = rand::thread_rng().gen();
+	general_purpose::URL_SAFE_NO_PAD.encode(secret)
+}
+
+/// How often and how patiently a delivery is attempted
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookRetryPolicy {
+	/// Attempts in total, including the first
+	pub max_attempts: u32,
+	/// Wait before the second attempt; each later wait doubles
+	pub initial_backoff: Duration,
+	/// Longest a single attempt may take
+	pub timeout: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 5,
+			initial_backoff: Duration::from_secs(1),
+			timeout: Duration::from_secs(10),
+		}
+	}
+}
+
+impl WebhookRetryPolicy {
+	/// Wait after failed attempt number `attempt` (1-based)
+	pub fn backoff(&self, attempt: u32) -> Duration {
+		self.initial_backoff
+			.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+	}
+}
+
+/// Result of a single POST to a webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookAttempt {
+	pub delivered: bool,
+	pub response_status: Option<u16>,
+	pub error: Option<String>,
+}
+
+/// POSTs one signed payload; any 2xx response counts as delivered
+pub async fn send_webhook(
+	client: &reqwest::Client,
+	webhook: &TenantWebhook,
+	event: &str,
+	delivery_id: Uuid,
+	body: &[u8],
+) -> WebhookAttempt {
+	let response = client
+		.post(&webhook.url)
+		.header(reqwest::header::CONTENT_TYPE, "application/json")
+		.header(WEBHOOK_EVENT_HEADER, event)
+		.header(WEBHOOK_DELIVERY_HEADER, delivery_id.to_string())
+		.header(
+			WEBHOOK_SIGNATURE_HEADER,
+			sign_webhook_payload(&webhook.secret, body),
+		)
+		.body(body.to_vec())
+		.send()
+		.await;
+
+	match response {
+		Ok(response) => {
+			let status = response.status();
+			WebhookAttempt {
+				delivered: status.is_success(),
+				response_status: Some(status.as_u16()),
+				error: (!status.is_success())
+					.then(|| format!("Endpoint responded with {}", status)),
+			}
+		}
+		Err(e) => WebhookAttempt {
+			delivered: false,
+			response_status: None,
+			error: Some(e.to_string()),
+		},
+	}
+}
+
+/// Sends every audit entry to the tenant's matching webhooks, recording each
+/// delivery and retrying failed ones with exponential backoff
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+	repo: WebhookRepository,
+	client: reqwest::Client,
+	policy: WebhookRetryPolicy,
+}
+
+impl WebhookDispatcher {
+	pub fn new(repo: WebhookRepository, policy: WebhookRetryPolicy) -> Self {
+		let client = reqwest::Client::builder()
+			.timeout(policy.timeout)
+			.build()
+			.unwrap_or_default();
+		Self {
+			repo,
+			client,
+			policy,
+		}
+	}
+
+	/// Deliver `log` to each interested webhook, one task per webhook so a
+	/// slow endpoint does not hold up the others
+	pub async fn dispatch(
+		&self,
+		log: &AuditLog,
+	) -> Result<Vec<JoinHandle<()>>, TenantRepositoryError> {
+		let webhooks = self.repo.list_active_for_tenant(log.tenant_id).await?;
+		let payload = webhook_payload(log);
+
+		let mut tasks = Vec::new();
+		for webhook in webhooks.into_iter().filter(|w| w.wants(&log.action)) {
+			let delivery = self
+				.repo
+				.create_delivery(&webhook, Some(log.id), &log.action, &payload)
+				.await?;
+			let dispatcher = self.clone();
+			let body = payload.to_string().into_bytes();
+			tasks.push(tokio::spawn(async move {
+				dispatcher
+					.deliver(&webhook, delivery.id, &delivery.event, &body)
+					.await;
+			}));
+		}
+
+		Ok(tasks)
+	}
+
+	/// Attempt one delivery until it succeeds or the attempts run out,
+	/// recording the outcome after every attempt
+	async fn deliver(&self, webhook: &TenantWebhook, delivery_id: Uuid, event: &str, body: &[u8]) {
+		for attempt in 1..=self.policy.max_attempts {
+			let result = send_webhook(&self.client, webhook, event, delivery_id, body).await;
+			let status = if result.delivered {
+				WebhookDeliveryStatus::Delivered
+			} else if attempt == self.policy.max_attempts {
+				WebhookDeliveryStatus::Failed
+			} else {
+				WebhookDeliveryStatus::Pending
+			};
+
+			if let Err(e) = self
+				.repo
+				.record_attempt(
+					delivery_id,
+					status,
+					attempt as i32,
+					result.response_status.map(i32::from),
+					result.error.as_deref(),
+				)
+				.await
+			{
+				tracing::warn!(%delivery_id, error = %e, "Failed to record webhook delivery attempt");
+			}
+
+			if status != WebhookDeliveryStatus::Pending {
+				return;
+			}
+			tokio::time::sleep(self.policy.backoff(attempt)).await;
+		}
+	}
+
+	/// Deliver audit entries as they are published until the task is aborted
+	pub fn spawn(self, events: &AuditEvents) -> JoinHandle<()> {
+		let mut receiver = events.subscribe();
+		tokio::spawn(async move {
+			loop {
+				match receiver.recv().await {
+					Ok(log) => {
+						if let Err(e) = self.dispatch(&log).await {
+							tracing::error!(tenant_id = %log.tenant_id, error = %e, "Webhook dispatch failed");
+						}
+					}
+					Err(RecvError::Lagged(missed)) => {
+						tracing::warn!(
+							"Webhook dispatcher fell behind and skipped {} audit events",
+							missed
+						);
+					}
+					Err(RecvError::Closed) => return,
+				}
+			}
+		})
+	}
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Minimum age of a stored `last_used_at` before it is written again
+pub const API_KEY_USAGE_DEBOUNCE_SECS: i64 = 60;
+
+/// Whether a key last used at `last_used_at` is due another usage write
+pub fn should_record_key_use(last_used_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+	last_used_at
+		.map(|last_used_at| now - last_used_at >= Duration::seconds(API_KEY_USAGE_DEBOUNCE_SECS))
+		.unwrap_or(true)
+}
+
+/// Counts API key requests in memory so `last_used_at` and `request_count`
+/// are written at most once per debounce window per key
+#[derive(Default)]
+pub struct ApiKeyUsageTracker {
+	pending: Mutex<HashMap<Uuid, i64>>,
+}
+
+impl ApiKeyUsageTracker {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Count one request; returns the number of requests to flush when the
+	/// stored `last_used_at` is stale, or `None` when the write can be skipped
+	pub fn record(
+		&self,
+		key_id: Uuid,
+		last_used_at: Option<DateTime<Utc>>,
+		now: DateTime<Utc>,
+	) -> Option<i64> {
+		let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+		let count = pending.entry(key_id).or_insert(0);
+		*count += 1;
+
+		if should_record_key_use(last_used_at, now) {
+			pending.remove(&key_id)
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_first_use_is_recorded() {
+		let tracker = ApiKeyUsageTracker::new();
+		assert_eq!(tracker.record(Uuid::new_v4(), None, Utc::now()), Some(1));
+	}
+
+	#[test]
+	fn test_recent_use_skips_write_and_accumulates() {
+		let tracker = ApiKeyUsageTracker::new();
+		let key_id = Uuid::new_v4();
+		let now = Utc::now();
+		let used_seconds_ago = Some(now - Duration::seconds(5));
+
+		assert_eq!(tracker.record(key_id, used_seconds_ago, now), None);
+		assert_eq!(tracker.record(key_id, used_seconds_ago, now), None);
+
+		// Once the stored timestamp is stale every pending request is flushed
+		let stale = Some(now - Duration::seconds(API_KEY_USAGE_DEBOUNCE_SECS));
+		assert_eq!(tracker.record(key_id, stale, now), Some(3));
+		assert_eq!(
+			tracker.record(key_id, Some(now), now + Duration::seconds(1)),
+			None
+		);
+	}
+
+	#[test]
+	fn test_keys_are_tracked_separately() {
+		let tracker = ApiKeyUsageTracker::new();
+		let now = Utc::now();
+		let recent = Some(now - Duration::seconds(1));
+		let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+
+		assert_eq!(tracker.record(first, recent, now), None);
+		assert_eq!(tracker.record(second, None, now), Some(1));
+	}
+}
@@ -1,11 +1,14 @@
 use argon2::password_hash::{rand_core::OsRng, SaltString};
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::models::User;
+use crate::config::SsoConfig;
+use crate::models::{SsoIdentity, User};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -15,14 +18,164 @@ pub struct Claims {
 	pub iat: i64, // issued at timestamp
 }
 
+/// Claims read from an SSO provider's ID token; `aud` is checked by validation
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SsoClaims {
+	pub iss: String,
+	pub sub: String,
+	pub email: String,
+	#[serde(default)]
+	pub email_verified: Option<bool>,
+	#[serde(default)]
+	pub name: Option<String>,
+	pub exp: i64,
+}
+
+/// Verifies ID tokens issued by the configured SSO provider
+#[derive(Clone)]
+pub struct SsoVerifier {
+	decoding_key: DecodingKey,
+	validation: Validation,
+	provision_tenants: bool,
+}
+
+impl SsoVerifier {
+	pub fn new(config: &SsoConfig) -> Result<Self, String> {
+		let algorithm = Algorithm::from_str(&config.algorithm)
+			.map_err(|_| format!("Unsupported SSO algorithm '{}'", config.algorithm))?;
+		let key = config.verification_key.as_bytes();
+		let decoding_key = match algorithm {
+			Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+				Ok(DecodingKey::from_secret(key))
+			}
+			Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(key),
+			Algorithm::EdDSA => DecodingKey::from_ed_pem(key),
+			_ => DecodingKey::from_rsa_pem(key),
+		}
+		.map_err(|e| format!("Invalid SSO verification key: {}", e))?;
+
+		let mut validation = Validation::new(algorithm);
+		validation.set_issuer(&[&config.issuer]);
+		validation.set_audience(&[&config.audience]);
+		validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+		Ok(Self {
+			decoding_key,
+			validation,
+			provision_tenants: config.provision_tenants,
+		})
+	}
+
+	/// Whether users without a tenant get one provisioned
+	pub fn provisions_tenants(&self) -> bool {
+		self.provision_tenants
+	}
+
+	/// Verify an ID token and return the identity it asserts; tokens whose
+	/// email the provider marks as unverified are rejected
+	pub fn verify(&self, token: &str) -> Result<SsoIdentity, jsonwebtoken::errors::Error> {
+		let claims = decode::<SsoClaims>(token, &self.decoding_key, &self.validation)?.claims;
+
+		if claims.email_verified == Some(false) {
+			return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+		}
+
+		Ok(SsoIdentity {
+			issuer: claims.iss,
+			subject: claims.sub,
+			email: claims.email,
+			name: claims.name,
+		})
+	}
+}
+
+/// How long an email verification token stays valid
+pub const EMAIL_VERIFICATION_TTL_HOURS: i64 = 24;
+
+/// Leading characters of an API key body stored in clear to find its row
+pub const API_KEY_LOOKUP_LEN: usize = 8;
+
+/// Compare two byte strings without short-circuiting on the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+	a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[derive(Clone)]
 pub struct AuthService {
 	jwt_secret: String,
+	admin_token: Option<String>,
+	service_token: Option<String>,
+	sso: Option<SsoVerifier>,
+	default_full_access: bool,
+	require_email_verification: bool,
 }
 
 impl AuthService {
 	pub fn new(jwt_secret: String) -> Self {
-		Self { jwt_secret }
+		Self {
+			jwt_secret,
+			admin_token: None,
+			service_token: None,
+			sso: None,
+			default_full_access: true,
+			require_email_verification: false,
+		}
+	}
+
+	pub fn with_admin_token(mut self, admin_token: Option<String>) -> Self {
+		self.admin_token = admin_token.filter(|t| !t.is_empty());
+		self
+	}
+
+	/// Check a bearer token against the configured admin token
+	pub fn verify_admin_token(&self, token: &str) -> bool {
+		match &self.admin_token {
+			Some(expected) => constant_time_eq(expected.as_bytes(), token.as_bytes()),
+			None => false,
+		}
+	}
+
+	pub fn with_service_token(mut self, service_token: Option<String>) -> Self {
+		self.service_token = service_token.filter(|t| !t.is_empty());
+		self
+	}
+
+	/// Check a bearer token against the configured internal service token
+	pub fn verify_service_token(&self, token: &str) -> bool {
+		match &self.service_token {
+			Some(expected) => constant_time_eq(expected.as_bytes(), token.as_bytes()),
+			None => false,
+		}
+	}
+
+	pub fn with_sso(mut self, sso: Option<SsoVerifier>) -> Self {
+		self.sso = sso;
+		self
+	}
+
+	/// Whether API keys with no recorded permissions get full access
+	pub fn with_default_full_access(mut self, enabled: bool) -> Self {
+		self.default_full_access = enabled;
+		self
+	}
+
+	pub fn default_full_access(&self) -> bool {
+		self.default_full_access
+	}
+
+	/// Whether users must verify their email address before they can log in
+	pub fn with_require_email_verification(mut self, required: bool) -> Self {
+		self.require_email_verification = required;
+		self
+	}
+
+	pub fn requires_email_verification(&self) -> bool {
+		self.require_email_verification
+	}
+
+	/// The SSO verifier, if an identity provider is configured
+	pub fn sso(&self) -> Option<&SsoVerifier> {
+		self.sso.as_ref()
 	}
 
 	pub fn hash_password(&self, password: &str) -> Result<String, argon2::password_hash::Error> {
@@ -88,12 +241,272 @@ impl AuthService {
 		.map(|data| data.claims)
 	}
 
+	/// Signed token confirming that `user` controls their email address. It is
+	/// signed with a key derived from the JWT secret, so it cannot be used as
+	/// an access token and an access token cannot verify an email.
+	pub fn generate_verification_token(
+		&self,
+		user: &User,
+	) -> Result<String, jsonwebtoken::errors::Error> {
+		self.generate_verification_token_with_expiry(
+			user,
+			Duration::hours(EMAIL_VERIFICATION_TTL_HOURS),
+		)
+	}
+
+	fn generate_verification_token_with_expiry(
+		&self,
+		user: &User,
+		expiry_duration: Duration,
+	) -> Result<String, jsonwebtoken::errors::Error> {
+		let now = Utc::now();
+		let claims = Claims {
+			sub: user.id,
+			email: user.email.clone(),
+			exp: (now + expiry_duration).timestamp(),
+			iat: now.timestamp(),
+		};
+
+		encode(
+			&Header::default(),
+			&claims,
+			&EncodingKey::from_secret(&self.verification_secret()),
+		)
+	}
+
+	/// The user id and email a verification token was issued for
+	pub fn verify_verification_token(
+		&self,
+		token: &str,
+	) -> Result<Claims, jsonwebtoken::errors::Error> {
+		decode::<Claims>(
+			token,
+			&DecodingKey::from_secret(&self.verification_secret()),
+			&Validation::default(),
+		)
+		.map(|data| data.claims)
+	}
+
+	fn verification_secret(&self) -> Vec<u8> {
+		format!("{}:email-verification", self.jwt_secret).into_bytes()
+	}
+
+	/// Generate the body of a new API key (without the configured prefix):
+	/// a short lookup id followed by a random secret
 	pub fn generate_api_key(&self) -> String {
-		// Generate a secure random API key
 		use base64::{engine::general_purpose, Engine as _};
-		use rand::Rng;
+		use rand::{distributions::Alphanumeric, Rng};
 		let mut rng = rand::thread_rng();
-		let key: [u8; 32] = rng.gen();
-		general_purpose::STANDARD.encode(key)
+		let lookup: String = (&mut rng)
+			.sample_iter(&Alphanumeric)
+			.take(API_KEY_LOOKUP_LEN)
+			.map(char::from)
+			.collect();
+		let secret: [u8; 32] = rng.gen();
+		format!(
+			"{}_{}",
+			lookup,
+			general_purpose::URL_SAFE_NO_PAD.encode(secret)
+		)
+	}
+
+	/// Random, URL-safe token sent with a tenant invitation
+	pub fn generate_invitation_token(&self) -> String {
+		use base64::{engine::general_purpose, Engine as _};
+		use rand::Rng;
+		let secret: [u8; 32] = rand::thread_rng().gen();
+		general_purpose::URL_SAFE_NO_PAD.encode(secret)
+	}
+
+	/// The part of an API key body used to look up its row
+	pub fn api_key_lookup(key: &str) -> Option<&str> {
+		key.get(..API_KEY_LOOKUP_LEN)
+			.filter(|_| key.len() > API_KEY_LOOKUP_LEN)
+	}
+
+	/// Deterministic hash stored for an API key body. Keys carry 256 bits of
+	/// randomness, so a fast unsalted digest is enough and lets rows be found
+	/// by lookup id instead of verifying every key of the tenant
+	pub fn hash_api_key(key: &str) -> String {
+		Sha256::digest(key.as_bytes())
+			.iter()
+			.map(|b| format!("{:02x}", b))
+			.collect()
+	}
+
+	pub fn verify_api_key(key: &str, key_hash: &str) -> bool {
+		constant_time_eq(Self::hash_api_key(key).as_bytes(), key_hash.as_bytes())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn test_user() -> User {
+		User {
+			id: Uuid::new_v4(),
+			email: "jane@example.com".to_string(),
+			password_hash: String::new(),
+			is_active: true,
+			email_verified: false,
+			created_at: None,
+			updated_at: None,
+		}
+	}
+
+	#[test]
+	fn test_verification_token_round_trip() {
+		let auth = AuthService::new("secret".to_string());
+		let user = test_user();
+
+		let token = auth.generate_verification_token(&user).unwrap();
+		let claims = auth.verify_verification_token(&token).unwrap();
+
+		assert_eq!(claims.sub, user.id);
+		assert_eq!(claims.email, user.email);
+	}
+
+	#[test]
+	fn test_expired_verification_token_is_rejected() {
+		let auth = AuthService::new("secret".to_string());
+		// Past the default 60 second leeway
+		let token = auth
+			.generate_verification_token_with_expiry(&test_user(), Duration::minutes(-5))
+			.unwrap();
+
+		let err = auth.verify_verification_token(&token).unwrap_err();
+		assert_eq!(
+			*err.kind(),
+			jsonwebtoken::errors::ErrorKind::ExpiredSignature
+		);
+	}
+
+	#[test]
+	fn test_tampered_verification_token_is_rejected() {
+		let auth = AuthService::new("secret".to_string());
+		let token = auth.generate_verification_token(&test_user()).unwrap();
+		let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+		let forged = AuthService::new("secret".to_string())
+			.generate_verification_token(&User {
+				email: "attacker@example.com".to_string(),
+				..test_user()
+			})
+			.unwrap();
+		// Swap in another token's claims but keep the original signature
+		parts[1] = forged.split('.').nth(1).unwrap().to_string();
+
+		assert!(auth.verify_verification_token(&parts.join(".")).is_err());
+		assert!(AuthService::new("other-secret".to_string())
+			.verify_verification_token(&token)
+			.is_err());
+	}
+
+	#[test]
+	fn test_verification_and_access_tokens_are_not_interchangeable() {
+		let auth = AuthService::new("secret".to_string());
+		let user = test_user();
+
+		let verification = auth.generate_verification_token(&user).unwrap();
+		let access = auth.generate_jwt(&user).unwrap();
+
+		assert!(auth.verify_jwt(&verification).is_err());
+		assert!(auth.verify_verification_token(&access).is_err());
+	}
+
+	#[test]
+	fn test_generated_api_key_round_trip() {
+		let auth = AuthService::new("secret".to_string());
+		let key = auth.generate_api_key();
+		let key_hash = AuthService::hash_api_key(&key);
+
+		assert_eq!(
+			AuthService::api_key_lookup(&key).map(str::len),
+			Some(API_KEY_LOOKUP_LEN)
+		);
+		assert!(AuthService::verify_api_key(&key, &key_hash));
+		assert!(!AuthService::verify_api_key(
+			&auth.generate_api_key(),
+			&key_hash
+		));
+	}
+
+	#[test]
+	fn test_api_key_hash_is_deterministic_and_not_plaintext() {
+		let key = "abcdefgh_secret";
+
+		assert_eq!(
+			AuthService::hash_api_key(key),
+			AuthService::hash_api_key(key)
+		);
+		assert_eq!(AuthService::hash_api_key(key).len(), 64);
+		assert!(!AuthService::hash_api_key(key).contains("secret"));
+	}
+
+	#[test]
+	fn test_api_key_lookup_requires_secret() {
+		assert_eq!(AuthService::api_key_lookup("abcdefgh"), None);
+		assert_eq!(AuthService::api_key_lookup("short"), None);
+		assert_eq!(
+			AuthService::api_key_lookup("abcdefgh_rest"),
+			Some("abcdefgh")
+		);
+	}
+
+	#[test]
+	fn test_verify_admin_token() {
+		let auth = AuthService::new("secret".to_string())
+			.with_admin_token(Some("admin-token".to_string()));
+
+		assert!(auth.verify_admin_token("admin-token"));
+		assert!(!auth.verify_admin_token("admin-tokem"));
+		assert!(!auth.verify_admin_token("admin"));
+	}
+
+	#[test]
+	fn test_verify_service_token() {
+		let auth = AuthService::new("secret".to_string())
+			.with_admin_token(Some("admin-token".to_string()))
+			.with_service_token(Some("service-token".to_string()));
+
+		assert!(auth.verify_service_token("service-token"));
+		assert!(!auth.verify_service_token("admin-token"));
+		assert!(!AuthService::new("secret".to_string())
+			.with_service_token(Some(String::new()))
+			.verify_service_token(""));
+	}
+
+	#[test]
+	fn test_sso_verifier_checks_issuer_audience_and_email() {
+		let config = SsoConfig {
+			issuer: "https://idp.example.com".to_string(),
+			audience: "smt".to_string(),
+			algorithm: "HS256".to_string(),
+			verification_key: "idp-secret".to_string(),
+			provision_tenants: true,
+		};
+		let verifier = SsoVerifier::new(&config).unwrap();
+		let token = |aud: &str, email_verified: bool| {
+			encode(
+				&Header::default(),
+				&serde_json::json!({
+					"iss": "https://idp.example.com",
+					"aud": aud,
+					"sub": "idp-user-1",
+					"email": "jane@example.com",
+					"email_verified": email_verified,
+					"exp": (Utc::now() + Duration::minutes(5)).timestamp(),
+				}),
+				&EncodingKey::from_secret(b"idp-secret"),
+			)
+			.unwrap()
+		};
+
+		let identity = verifier.verify(&token("smt", true)).unwrap();
+		assert_eq!(identity.subject, "idp-user-1");
+		assert_eq!(identity.email, "jane@example.com");
+
+		assert!(verifier.verify(&token("other-app", true)).is_err());
+		assert!(verifier.verify(&token("smt", false)).is_err());
 	}
 }
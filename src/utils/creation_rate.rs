@@ -0,0 +1,86 @@
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Span of the sliding window creations are counted over
+pub const CREATION_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Sliding-window count of each tenant's recent creations, so bursts beyond
+/// `max_per_minute` are refused while other tenants are unaffected
+#[derive(Clone)]
+pub struct CreationRateLimiter {
+	max_per_minute: u32,
+	windows: Arc<DashMap<Uuid, VecDeque<Instant>>>,
+}
+
+impl CreationRateLimiter {
+	pub fn new(max_per_minute: u32) -> Self {
+		Self {
+			max_per_minute,
+			windows: Arc::new(DashMap::new()),
+		}
+	}
+
+	pub fn max_per_minute(&self) -> u32 {
+		self.max_per_minute
+	}
+
+	/// Count one creation for the tenant at `now`, or return how long until
+	/// the oldest creation in the window expires
+	pub fn try_acquire(&self, tenant_id: Uuid, now: Instant) -> Result<(), Duration> {
+		// The entry guard locks the tenant's shard, so concurrent creates
+		// cannot both take the last slot
+		let mut window = self.windows.entry(tenant_id).or_default();
+		while window
+			.front()
+			.is_some_and(|created| now.duration_since(*created) >= CREATION_RATE_WINDOW)
+		{
+			window.pop_front();
+		}
+
+		if window.len() >= self.max_per_minute as usize {
+			let oldest = window.front().copied().unwrap_or(now);
+			return Err(CREATION_RATE_WINDOW.saturating_sub(now.duration_since(oldest)));
+		}
+
+		window.push_back(now);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_refuses_beyond_limit_until_window_slides() {
+		let limiter = CreationRateLimiter::new(2);
+		let tenant = Uuid::new_v4();
+		let start = Instant::now();
+
+		assert!(limiter.try_acquire(tenant, start).is_ok());
+		assert!(limiter
+			.try_acquire(tenant, start + Duration::from_secs(10))
+			.is_ok());
+		assert_eq!(
+			limiter.try_acquire(tenant, start + Duration::from_secs(20)),
+			Err(Duration::from_secs(40))
+		);
+
+		// The first creation leaves the window after a minute
+		assert!(limiter
+			.try_acquire(tenant, start + CREATION_RATE_WINDOW)
+			.is_ok());
+	}
+
+	#[test]
+	fn test_tenants_have_separate_windows() {
+		let limiter = CreationRateLimiter::new(1);
+		let now = Instant::now();
+
+		assert!(limiter.try_acquire(Uuid::new_v4(), now).is_ok());
+		assert!(limiter.try_acquire(Uuid::new_v4(), now).is_ok());
+	}
+}
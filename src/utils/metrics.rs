@@ -0,0 +1,267 @@
+use prometheus::{
+	register_histogram, register_histogram_vec, register_int_counter_vec, register_int_gauge_vec,
+	Encoder, Histogram, HistogramVec, IntCounterVec, IntGaugeVec, TextEncoder,
+};
+use std::time::{Duration, Instant};
+
+use super::current_tenant_context_option;
+use crate::models::ResourceCounts;
+
+/// `tenant_slug` label of requests that never authenticated against a tenant
+pub const NO_TENANT_LABEL: &str = "none";
+
+// Per-tenant metrics, registered in the default registry served by the metrics
+// server. They are labelled by slug only, so cardinality grows with the number
+// of tenants rather than with resource or key ids.
+lazy_static::lazy_static! {
+	static ref TENANT_MONITORS: IntGaugeVec = register_int_gauge_vec!(
+		"tenant_monitors_total",
+		"Monitors currently owned by the tenant",
+		&["tenant_slug"]
+	)
+	.expect("tenant monitor gauge registers once");
+	static ref TENANT_NETWORKS: IntGaugeVec = register_int_gauge_vec!(
+		"tenant_networks_total",
+		"Networks currently owned by the tenant",
+		&["tenant_slug"]
+	)
+	.expect("tenant network gauge registers once");
+	static ref TENANT_TRIGGERS: IntGaugeVec = register_int_gauge_vec!(
+		"tenant_triggers_total",
+		"Triggers currently owned by the tenant",
+		&["tenant_slug"]
+	)
+	.expect("tenant trigger gauge registers once");
+	static ref TENANT_RPC_REQUESTS: IntCounterVec = register_int_counter_vec!(
+		"tenant_rpc_requests_total",
+		"Requests counted against the tenant's RPC quota",
+		&["tenant_slug"]
+	)
+	.expect("tenant RPC request counter registers once");
+	static ref TENANT_QUOTA_EXCEEDED: IntCounterVec = register_int_counter_vec!(
+		"tenant_quota_exceeded_total",
+		"Requests rejected because the tenant's RPC quota was spent",
+		&["tenant_slug"]
+	)
+	.expect("tenant quota counter registers once");
+	static ref TENANT_RATE_LIMITED: IntCounterVec = register_int_counter_vec!(
+		"tenant_rate_limited_requests_total",
+		"Requests rejected by the per-tenant rate limiter",
+		&["tenant_slug"]
+	)
+	.expect("tenant rate limit counter registers once");
+	static ref HTTP_REQUESTS: IntCounterVec = register_int_counter_vec!(
+		"http_requests_total",
+		"API requests by route, response status and tenant",
+		&["method", "route", "status", "tenant_slug"]
+	)
+	.expect("HTTP request counter registers once");
+	static ref HTTP_REQUEST_DURATION: HistogramVec = register_histogram_vec!(
+		"http_request_duration_seconds",
+		"API request latency by route and tenant",
+		&["method", "route", "tenant_slug"]
+	)
+	.expect("HTTP latency histogram registers once");
+	static ref DB_POOL_CONNECTIONS: IntGaugeVec = register_int_gauge_vec!(
+		"db_pool_connections",
+		"Database pool connections, by whether they are idle or in use",
+		&["state"]
+	)
+	.expect("database pool gauge registers once");
+	static ref DB_POOL_ACQUIRE_DURATION: Histogram = register_histogram!(
+		"db_pool_acquire_duration_seconds",
+		"Time spent waiting for a pooled database connection"
+	)
+	.expect("database acquire histogram registers once");
+}
+
+/// Register every metric up front, so `/metrics` lists them before they are
+/// first touched
+pub fn register_metrics() {
+	lazy_static::initialize(&TENANT_MONITORS);
+	lazy_static::initialize(&TENANT_NETWORKS);
+	lazy_static::initialize(&TENANT_TRIGGERS);
+	lazy_static::initialize(&TENANT_RPC_REQUESTS);
+	lazy_static::initialize(&TENANT_QUOTA_EXCEEDED);
+	lazy_static::initialize(&TENANT_RATE_LIMITED);
+	lazy_static::initialize(&HTTP_REQUESTS);
+	lazy_static::initialize(&HTTP_REQUEST_DURATION);
+	lazy_static::initialize(&DB_POOL_CONNECTIONS);
+	lazy_static::initialize(&DB_POOL_ACQUIRE_DURATION);
+}
+
+/// The default registry in the Prometheus text format, as served at `/metrics`
+pub fn render_metrics() -> String {
+	let mut buffer = Vec::new();
+	TextEncoder::new()
+		.encode(&prometheus::gather(), &mut buffer)
+		.expect("metrics encode as text");
+	String::from_utf8(buffer).expect("metrics text is UTF-8")
+}
+
+/// Inserted into a response's extensions by tenant authentication, so request
+/// metrics are labelled with the tenant the request actually ran as. A slug
+/// taken from an unauthenticated URL would let callers mint label values.
+#[derive(Debug, Clone)]
+pub struct TenantSlugLabel(pub String);
+
+/// Slug of the tenant the current request runs as, if it was resolved from the URL
+fn current_tenant_slug() -> Option<String> {
+	current_tenant_context_option().and_then(|context| context.tenant_slug.clone())
+}
+
+fn add_to_gauge(gauge: &IntGaugeVec, delta: i64) {
+	if let Some(slug) = current_tenant_slug() {
+		gauge.with_label_values(&[&slug]).add(delta);
+	}
+}
+
+fn inc_counter(counter: &IntCounterVec) {
+	if let Some(slug) = current_tenant_slug() {
+		counter.with_label_values(&[&slug]).inc();
+	}
+}
+
+/// Set a tenant's resource gauges outright, e.g. from the periodic refresh
+pub fn set_tenant_resource_counts(tenant_slug: &str, counts: &ResourceCounts) {
+	TENANT_MONITORS
+		.with_label_values(&[tenant_slug])
+		.set(counts.monitors);
+	TENANT_NETWORKS
+		.with_label_values(&[tenant_slug])
+		.set(counts.networks);
+	TENANT_TRIGGERS
+		.with_label_values(&[tenant_slug])
+		.set(counts.triggers);
+}
+
+/// Monitor and trigger counts currently reported for a tenant
+pub fn tenant_resource_counts(tenant_slug: &str) -> (i64, i64) {
+	(
+		TENANT_MONITORS.with_label_values(&[tenant_slug]).get(),
+		TENANT_TRIGGERS.with_label_values(&[tenant_slug]).get(),
+	)
+}
+
+pub fn record_monitors_added(count: i64) {
+	add_to_gauge(&TENANT_MONITORS, count);
+}
+
+pub fn record_monitors_removed(count: i64) {
+	add_to_gauge(&TENANT_MONITORS, -count);
+}
+
+pub fn record_networks_added(count: i64) {
+	add_to_gauge(&TENANT_NETWORKS, count);
+}
+
+pub fn record_networks_removed(count: i64) {
+	add_to_gauge(&TENANT_NETWORKS, -count);
+}
+
+pub fn record_triggers_added(count: i64) {
+	add_to_gauge(&TENANT_TRIGGERS, count);
+}
+
+pub fn record_triggers_removed(count: i64) {
+	add_to_gauge(&TENANT_TRIGGERS, -count);
+}
+
+pub fn record_rpc_request() {
+	inc_counter(&TENANT_RPC_REQUESTS);
+}
+
+pub fn record_quota_exceeded() {
+	inc_counter(&TENANT_QUOTA_EXCEEDED);
+}
+
+pub fn record_rate_limited() {
+	inc_counter(&TENANT_RATE_LIMITED);
+}
+
+/// Count one API request; `route` is the matched route template, not the raw path
+pub fn record_http_request(
+	method: &str,
+	route: &str,
+	status: u16,
+	tenant_slug: Option<&str>,
+	elapsed: Duration,
+) {
+	let tenant_slug = tenant_slug.unwrap_or(NO_TENANT_LABEL);
+	HTTP_REQUESTS
+		.with_label_values(&[method, route, &status.to_string(), tenant_slug])
+		.inc();
+	HTTP_REQUEST_DURATION
+		.with_label_values(&[method, route, tenant_slug])
+		.observe(elapsed.as_secs_f64());
+}
+
+/// Sample the pool: how long a connection takes to acquire right now, and how
+/// many connections are idle or in use
+pub async fn record_pool_metrics(pool: &sqlx::PgPool) {
+	let start = Instant::now();
+	match pool.acquire().await {
+		Ok(connection) => {
+			DB_POOL_ACQUIRE_DURATION.observe(start.elapsed().as_secs_f64());
+			drop(connection);
+		}
+		Err(e) => tracing::warn!(error = %e, "Failed to acquire a connection for pool metrics"),
+	}
+
+	let size = i64::from(pool.size());
+	let idle = pool.num_idle() as i64;
+	DB_POOL_CONNECTIONS.with_label_values(&["idle"]).set(idle);
+	DB_POOL_CONNECTIONS
+		.with_label_values(&["active"])
+		.set(size - idle);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::TenantQuotas;
+	use crate::utils::{with_tenant_context, TenantContext};
+	use uuid::Uuid;
+
+	#[tokio::test]
+	async fn test_gauges_follow_creates_and_deletes_for_the_current_slug() {
+		let context =
+			TenantContext::new(Uuid::new_v4(), TenantQuotas::default()).with_slug("metrics-acme");
+		set_tenant_resource_counts(
+			"metrics-acme",
+			&ResourceCounts {
+				monitors: 2,
+				networks: 1,
+				triggers: 1,
+			},
+		);
+
+		with_tenant_context(context, async {
+			record_monitors_added(1);
+			record_triggers_added(3);
+			record_triggers_removed(1);
+			record_networks_removed(1);
+			record_quota_exceeded();
+		})
+		.await;
+
+		assert_eq!(
+			TENANT_MONITORS.with_label_values(&["metrics-acme"]).get(),
+			3
+		);
+		assert_eq!(
+			TENANT_TRIGGERS.with_label_values(&["metrics-acme"]).get(),
+			3
+		);
+		assert_eq!(
+			TENANT_NETWORKS.with_label_values(&["metrics-acme"]).get(),
+			0
+		);
+		assert_eq!(
+			TENANT_QUOTA_EXCEEDED
+				.with_label_values(&["metrics-acme"])
+				.get(),
+			1
+		);
+	}
+}
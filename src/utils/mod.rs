@@ -1,5 +1,16 @@
+pub mod api_key_usage;
 pub mod auth;
+pub mod creation_rate;
+pub mod metrics;
+pub mod quota_cache;
+pub mod request_id;
 pub mod tenant_context;
+pub mod tenant_locks;
 
+pub use api_key_usage::*;
 pub use auth::*;
+pub use creation_rate::*;
+pub use quota_cache::*;
+pub use request_id::*;
 pub use tenant_context::*;
+pub use tenant_locks::*;
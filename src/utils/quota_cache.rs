@@ -0,0 +1,128 @@
+use dashmap::DashMap;
+use std::sync::{
+	atomic::{AtomicU64, Ordering},
+	Arc,
+};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::models::ResourceQuotaStatus;
+
+/// Short-lived per-tenant copy of the quota status, so hot tenants are not
+/// re-counted on every write. Repositories that add or remove counted
+/// resources invalidate the tenant's entry; anything else, such as RPC usage,
+/// is at most `ttl` stale. Disabled (every lookup misses) unless built with a TTL.
+#[derive(Clone, Default)]
+pub struct QuotaStatusCache {
+	ttl: Option<Duration>,
+	entries: Arc<DashMap<Uuid, (Instant, ResourceQuotaStatus)>>,
+	/// Bumped on every invalidation so a status loaded before a write cannot
+	/// be cached after it
+	generation: Arc<AtomicU64>,
+}
+
+impl QuotaStatusCache {
+	pub fn new(ttl: Duration) -> Self {
+		Self {
+			ttl: Some(ttl),
+			..Self::default()
+		}
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		self.ttl.is_some()
+	}
+
+	/// The cached status, if it is younger than the TTL
+	pub fn get(&self, tenant_id: Uuid) -> Option<ResourceQuotaStatus> {
+		let ttl = self.ttl?;
+		let entry = self.entries.get(&tenant_id)?;
+		let (cached_at, status) = entry.value();
+		(cached_at.elapsed() < ttl).then(|| status.clone())
+	}
+
+	/// Generation to pass to [`insert`](Self::insert) for a status about to be loaded
+	pub fn generation(&self) -> u64 {
+		self.generation.load(Ordering::Acquire)
+	}
+
+	/// Cache `status` unless something was invalidated since `generation` was read
+	pub fn insert(&self, tenant_id: Uuid, status: ResourceQuotaStatus, generation: u64) {
+		if self.ttl.is_none() {
+			return;
+		}
+		self.entries.insert(tenant_id, (Instant::now(), status));
+		// A concurrent invalidation may have landed between the load and the insert
+		if self.generation() != generation {
+			self.entries.remove(&tenant_id);
+		}
+	}
+
+	pub fn invalidate(&self, tenant_id: Uuid) {
+		if self.ttl.is_none() {
+			return;
+		}
+		self.generation.fetch_add(1, Ordering::AcqRel);
+		self.entries.remove(&tenant_id);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::{CurrentUsage, TenantQuotas};
+
+	fn status(tenant_id: Uuid, monitors_count: i32) -> ResourceQuotaStatus {
+		ResourceQuotaStatus::from_usage(
+			tenant_id,
+			TenantQuotas::default(),
+			CurrentUsage {
+				monitors_count,
+				networks_count: 0,
+				triggers_count: 0,
+				rpc_requests_last_minute: 0,
+				storage_mb_used: 0,
+			},
+			vec![],
+		)
+	}
+
+	#[test]
+	fn test_hit_until_invalidated() {
+		let cache = QuotaStatusCache::new(Duration::from_secs(60));
+		let (tenant, other) = (Uuid::new_v4(), Uuid::new_v4());
+
+		cache.insert(tenant, status(tenant, 2), cache.generation());
+		cache.insert(other, status(other, 5), cache.generation());
+		assert_eq!(cache.get(tenant).unwrap().usage.monitors_count, 2);
+
+		cache.invalidate(tenant);
+		assert!(cache.get(tenant).is_none());
+		assert_eq!(cache.get(other).unwrap().usage.monitors_count, 5);
+	}
+
+	#[test]
+	fn test_expired_and_stale_loads_are_not_served() {
+		let cache = QuotaStatusCache::new(Duration::ZERO);
+		let tenant = Uuid::new_v4();
+		cache.insert(tenant, status(tenant, 1), cache.generation());
+		assert!(cache.get(tenant).is_none());
+
+		let cache = QuotaStatusCache::new(Duration::from_secs(60));
+		let loaded_at = cache.generation();
+		cache.invalidate(tenant);
+		cache.insert(tenant, status(tenant, 1), loaded_at);
+		assert!(cache.get(tenant).is_none());
+	}
+
+	#[test]
+	fn test_disabled_by_default() {
+		let cache = QuotaStatusCache::default();
+		let tenant = Uuid::new_v4();
+
+		cache.insert(tenant, status(tenant, 1), cache.generation());
+
+		assert!(!cache.is_enabled());
+		assert!(cache.get(tenant).is_none());
+	}
+}
@@ -0,0 +1,67 @@
+use tokio::task_local;
+
+/// Header a request id is read from and echoed back in
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest caller-supplied request id that is kept; longer ones are replaced
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+// Task-local storage for the id of the request being served
+task_local! {
+	static REQUEST_ID: String;
+}
+
+/// The caller's `X-Request-Id` if it is a plausible id, otherwise a new one.
+/// Only letters, digits and `-_.:` are accepted so the id is safe to log and
+/// to echo back in a header.
+pub fn request_id_or_generate(supplied: Option<&str>) -> String {
+	supplied
+		.filter(|id| {
+			!id.is_empty()
+				&& id.len() <= MAX_REQUEST_ID_LEN
+				&& id
+					.chars()
+					.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+		})
+		.map(str::to_string)
+		.unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+}
+
+// Run a future with a request id
+pub async fn with_request_id<F, R>(request_id: String, f: F) -> R
+where
+	F: std::future::Future<Output = R>,
+{
+	REQUEST_ID.scope(request_id, f).await
+}
+
+/// Id of the request being served, if any
+pub fn current_request_id() -> Option<String> {
+	REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_supplied_request_id_is_kept_only_when_well_formed() {
+		assert_eq!(
+			request_id_or_generate(Some("abc-123.x:y_z")),
+			"abc-123.x:y_z"
+		);
+
+		for bad in ["", "has space", "new\nline", &"a".repeat(129)] {
+			let generated = request_id_or_generate(Some(bad));
+			assert_ne!(generated, bad);
+			assert!(uuid::Uuid::parse_str(&generated).is_ok());
+		}
+	}
+
+	#[tokio::test]
+	async fn test_request_id_is_scoped_to_the_request() {
+		assert_eq!(current_request_id(), None);
+		let inside = with_request_id("req-1".to_string(), async { current_request_id() }).await;
+		assert_eq!(inside.as_deref(), Some("req-1"));
+	}
+}
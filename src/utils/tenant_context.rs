@@ -2,7 +2,7 @@ use std::sync::Arc;
 use tokio::task_local;
 use uuid::Uuid;
 
-use crate::models::{TenantQuotas, TenantRole};
+use crate::models::{ApiPermission, TenantQuotas, TenantRole, REVEAL_SECRETS_ACTION};
 
 // Task-local storage for tenant context
 task_local! {
@@ -12,9 +12,23 @@ task_local! {
 #[derive(Debug, Clone)]
 pub struct TenantContext {
 	pub tenant_id: Uuid,
+	/// Slug the tenant was addressed by; used to label per-tenant metrics
+	pub tenant_slug: Option<String>,
 	pub user: Option<AuthenticatedUser>,
 	pub api_key_id: Option<Uuid>,
+	/// Permissions granted to the API key; `None` when not restricted
+	pub permissions: Option<Vec<ApiPermission>>,
 	pub quotas: TenantQuotas,
+	/// The tenant has been deactivated; only the suspension allowlist is served
+	pub suspended: bool,
+}
+
+/// The caller's API key or tenant role does not grant `action` on `resource`
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Caller lacks {action} permission on {resource}")]
+pub struct InsufficientPermissions {
+	pub resource: String,
+	pub action: String,
 }
 
 #[derive(Debug, Clone)]
@@ -28,27 +42,36 @@ impl TenantContext {
 	pub fn new(tenant_id: Uuid, quotas: TenantQuotas) -> Self {
 		Self {
 			tenant_id,
+			tenant_slug: None,
 			user: None,
 			api_key_id: None,
+			permissions: None,
 			quotas,
+			suspended: false,
 		}
 	}
 
 	pub fn with_user(tenant_id: Uuid, user: AuthenticatedUser, quotas: TenantQuotas) -> Self {
 		Self {
 			tenant_id,
+			tenant_slug: None,
 			user: Some(user),
 			api_key_id: None,
+			permissions: None,
 			quotas,
+			suspended: false,
 		}
 	}
 
 	pub fn with_api_key(tenant_id: Uuid, api_key_id: Uuid, quotas: TenantQuotas) -> Self {
 		Self {
 			tenant_id,
+			tenant_slug: None,
 			user: None,
 			api_key_id: Some(api_key_id),
+			permissions: None,
 			quotas,
+			suspended: false,
 		}
 	}
 
@@ -59,12 +82,71 @@ impl TenantContext {
 			.unwrap_or(true) // API keys can write by default
 	}
 
+	pub fn can_delete(&self) -> bool {
+		self.user
+			.as_ref()
+			.map(|u| u.role.can_delete())
+			.unwrap_or(true) // API keys are limited by their permissions instead
+	}
+
 	pub fn can_manage(&self) -> bool {
 		self.user
 			.as_ref()
 			.map(|u| u.role.can_manage_tenant())
 			.unwrap_or(false)
 	}
+
+	pub fn with_slug(mut self, tenant_slug: impl Into<String>) -> Self {
+		self.tenant_slug = Some(tenant_slug.into());
+		self
+	}
+
+	pub fn with_suspended(mut self, suspended: bool) -> Self {
+		self.suspended = suspended;
+		self
+	}
+
+	/// Restrict an API key context to the permissions stored on the key
+	pub fn with_permissions(mut self, permissions: Vec<ApiPermission>) -> Self {
+		self.permissions = Some(permissions);
+		self
+	}
+
+	/// Whether the caller may perform `action` (read, write, delete or
+	/// reveal_secrets) on `resource`; JWT sessions are bounded by the member's
+	/// role in this tenant: viewers read, members also write, and only admins
+	/// and owners delete
+	pub fn has_permission(&self, resource: &str, action: &str) -> bool {
+		if let Some(user) = &self.user {
+			return match action {
+				"read" => user.role.can_read(),
+				"delete" => user.role.can_delete(),
+				REVEAL_SECRETS_ACTION => user.role.can_manage_tenant(),
+				_ => user.role.can_write(),
+			};
+		}
+
+		match &self.permissions {
+			Some(permissions) => permissions.iter().any(|p| p.allows(resource, action)),
+			None => true,
+		}
+	}
+
+	/// Every tenant-scoped handler calls this before touching a resource
+	pub fn require_permission(
+		&self,
+		resource: &str,
+		action: &str,
+	) -> Result<(), InsufficientPermissions> {
+		if self.has_permission(resource, action) {
+			Ok(())
+		} else {
+			Err(InsufficientPermissions {
+				resource: resource.to_string(),
+				action: action.to_string(),
+			})
+		}
+	}
 }
 
 // Execute a future with a tenant context
@@ -98,6 +180,11 @@ pub fn can_write() -> bool {
 	current_tenant_context().can_write()
 }
 
+// Check the current API key grants `action` on `resource`
+pub fn require_permission(resource: &str, action: &str) -> Result<(), InsufficientPermissions> {
+	current_tenant_context().require_permission(resource, action)
+}
+
 // Check if we have management permissions in the current context
 pub fn can_manage() -> bool {
 	current_tenant_context().can_manage()
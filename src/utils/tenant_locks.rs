@@ -0,0 +1,43 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use uuid::Uuid;
+
+/// One async mutex per tenant, so work that must not interleave within a
+/// tenant runs one at a time while other tenants proceed in parallel
+#[derive(Clone, Default)]
+pub struct TenantLocks {
+	locks: Arc<DashMap<Uuid, Arc<Mutex<()>>>>,
+}
+
+impl TenantLocks {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Wait for the tenant's lock; it is released when the guard is dropped
+	pub async fn lock(&self, tenant_id: Uuid) -> OwnedMutexGuard<()> {
+		// Clone the mutex out so the map shard is not held across the await
+		let lock = self.locks.entry(tenant_id).or_default().clone();
+		lock.lock_owned().await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_same_tenant_waits_other_tenant_does_not() {
+		let locks = TenantLocks::new();
+		let (tenant, other) = (Uuid::new_v4(), Uuid::new_v4());
+
+		let _held = locks.lock(tenant).await;
+
+		let same = tokio::time::timeout(std::time::Duration::from_millis(20), locks.lock(tenant));
+		assert!(same.await.is_err());
+		let different =
+			tokio::time::timeout(std::time::Duration::from_millis(20), locks.lock(other));
+		assert!(different.await.is_ok());
+	}
+}
@@ -0,0 +1,109 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use axum_extra::{
+	headers::{authorization::Bearer, Authorization},
+	TypedHeader,
+};
+use stellar_monitor_tenant_isolation::{
+	api::{auth, routes::AppState},
+	models::*,
+	utils::AuthService,
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{TenantBuilder, UserBuilder},
+};
+
+fn state(
+	tenant_repo: MockTenantRepository,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+#[tokio::test]
+async fn test_user_lists_every_tenant_with_role() {
+	// Arrange
+	let user = UserBuilder::new().build();
+	let token = AuthService::new("test-secret".to_string())
+		.generate_jwt(&user)
+		.unwrap();
+	let memberships = vec![
+		(
+			TenantBuilder::new().with_slug("acme").build(),
+			TenantRole::Owner,
+		),
+		(
+			TenantBuilder::new().with_slug("globex").build(),
+			TenantRole::Viewer,
+		),
+	];
+	let mut tenant_repo = MockTenantRepository::new();
+	let user_id = user.id;
+	tenant_repo
+		.expect_get_user_tenants()
+		.withf(move |id| *id == user_id)
+		.returning(move |_| Ok(memberships.clone()));
+
+	// Act
+	let response = auth::list_accessible_tenants(
+		State(state(tenant_repo)),
+		Some(TypedHeader(Authorization::bearer(&token).unwrap())),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let tenants = body["data"].as_array().unwrap();
+	assert_eq!(tenants.len(), 2);
+	assert_eq!(tenants[0]["slug"], "acme");
+	assert_eq!(tenants[0]["role"], "owner");
+	assert_eq!(tenants[1]["slug"], "globex");
+	assert_eq!(tenants[1]["role"], "viewer");
+}
+
+#[tokio::test]
+async fn test_accessible_tenants_requires_valid_token() {
+	// Act
+	let missing = auth::list_accessible_tenants(State(state(MockTenantRepository::new())), None)
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap_or_else(IntoResponse::into_response);
+	let invalid = auth::list_accessible_tenants(
+		State(state(MockTenantRepository::new())),
+		Some(TypedHeader(
+			Authorization::<Bearer>::bearer("not-a-jwt").unwrap(),
+		)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+	assert_eq!(invalid.status(), StatusCode::UNAUTHORIZED);
+}
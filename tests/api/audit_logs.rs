@@ -0,0 +1,323 @@
+use axum::{
+	extract::{Path, Query, State},
+	http::StatusCode,
+	response::IntoResponse,
+};
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	utils::{current_tenant_id, with_tenant_context, AuthService},
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::MonitorBuilder,
+		context::{owner_context, user_context},
+		fixtures::TestIds,
+	},
+};
+
+fn app_state(
+	audit_service: MockAuditService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	monitor_audit_state(MockMonitorService::new(), audit_service)
+}
+
+fn monitor_audit_state(
+	monitor_service: MockMonitorService,
+	audit_service: MockAuditService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		audit_service,
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn audit_log(tenant_id: Uuid, action: &str) -> AuditLog {
+	AuditLog {
+		id: Uuid::new_v4(),
+		tenant_id,
+		user_id: None,
+		api_key_id: None,
+		action: action.to_string(),
+		resource_type: Some("monitor".to_string()),
+		resource_id: Some(Uuid::new_v4()),
+		changes: None,
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+		created_at: Some(chrono::Utc::now()),
+	}
+}
+
+#[tokio::test]
+async fn test_audit_logs_scoped_to_current_tenant() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let logs: Arc<Vec<AuditLog>> = Arc::new(vec![
+		audit_log(test_ids.tenant_1, "monitor_created"),
+		audit_log(test_ids.tenant_1, "monitor_deleted"),
+		audit_log(test_ids.tenant_2, "monitor_created"),
+	]);
+
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_query_logs()
+		.withf(|filter| filter.action.as_deref() == Some("monitor_created"))
+		.times(1)
+		.returning(move |filter| {
+			let tenant_id = current_tenant_id();
+			Ok(logs
+				.iter()
+				.filter(|log| log.tenant_id == tenant_id)
+				.filter(|log| filter.action.as_deref().is_none_or(|a| log.action == a))
+				.cloned()
+				.collect())
+		});
+	audit_service
+		.expect_count_logs()
+		.times(1)
+		.returning(|_| Ok(1));
+
+	let state = app_state(audit_service);
+	let filter = AuditLogFilter {
+		action: Some("monitor_created".to_string()),
+		..Default::default()
+	};
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_audit_logs(State(state), Query(filter))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let data = body["data"].as_array().unwrap();
+	assert_eq!(data.len(), 1);
+	assert_eq!(data[0]["tenant_id"], test_ids.tenant_1.to_string());
+	assert_eq!(data[0]["action"], "monitor_created");
+	assert_eq!(body["meta"]["total"], 1);
+	assert_eq!(body["meta"]["limit"], 50);
+}
+
+#[tokio::test]
+async fn test_audit_logs_require_admin_or_owner() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_query_logs().times(0);
+	let state = app_state(audit_service);
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Member),
+		async move {
+			handlers::list_audit_logs(State(state), Query(AuditLogFilter::default()))
+				.await
+				.map(IntoResponse::into_response)
+		},
+	)
+	.await
+	.unwrap_err()
+	.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_audit_logs_range_within_limit_is_accepted() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_query_logs()
+		.withf(|filter| filter.limit == Some(500))
+		.times(1)
+		.returning(|_| Ok(vec![]));
+	audit_service
+		.expect_count_logs()
+		.times(1)
+		.returning(|_| Ok(0));
+	let state = app_state(audit_service);
+	let now = chrono::Utc::now();
+	let filter = AuditLogFilter {
+		from: Some(now - chrono::Duration::days(30)),
+		to: Some(now),
+		limit: Some(10_000),
+		..Default::default()
+	};
+
+	// Act
+	let result = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_audit_logs(State(state), Query(filter))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(result.unwrap().status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_audit_logs_range_exceeding_limit_is_rejected() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_query_logs().times(0);
+	let state = app_state(audit_service);
+	let filter = AuditLogFilter {
+		from: Some(chrono::Utc::now() - chrono::Duration::days(365)),
+		..Default::default()
+	};
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_audit_logs(State(state), Query(filter))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap_err()
+	.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert!(body["error"]
+		.as_str()
+		.unwrap()
+		.contains("exceeds the maximum of 90 days"));
+}
+
+#[tokio::test]
+async fn test_monitor_audit_returns_latest_entries_for_monitor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let monitor = MonitorBuilder::new()
+		.with_id(test_ids.monitor_1)
+		.with_monitor_id("payments")
+		.build();
+
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_get_monitor()
+		.withf(|monitor_id| monitor_id == "payments")
+		.times(1)
+		.returning(move |_| Ok(monitor.clone()));
+
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_query_logs()
+		.withf(move |filter| {
+			filter.resource_type.as_deref() == Some("monitor")
+				&& filter.resource_id == Some(test_ids.monitor_1)
+				&& filter.limit == Some(2)
+		})
+		.times(1)
+		.returning(move |filter| {
+			let now = chrono::Utc::now();
+			Ok((0..filter.page_limit())
+				.map(|age| AuditLog {
+					resource_id: filter.resource_id,
+					created_at: Some(now - chrono::Duration::minutes(age)),
+					..audit_log(test_ids.tenant_1, "monitor_updated")
+				})
+				.collect())
+		});
+	audit_service.expect_count_logs().times(0);
+
+	let state = monitor_audit_state(monitor_service, audit_service);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::get_monitor_audit(
+			State(state),
+			Path(("acme".to_string(), "payments".to_string())),
+			Query(ResourceAuditQuery { limit: Some(2) }),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let data = body["data"].as_array().unwrap();
+	assert_eq!(data.len(), 2);
+	assert!(data
+		.iter()
+		.all(|log| log["resource_id"] == test_ids.monitor_1.to_string()));
+	assert_eq!(body["meta"]["limit"], 2);
+}
+
+#[tokio::test]
+async fn test_monitor_audit_requires_admin_or_owner() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_get_monitor().times(0);
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_query_logs().times(0);
+	let state = monitor_audit_state(monitor_service, audit_service);
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Viewer),
+		async move {
+			handlers::get_monitor_audit(
+				State(state),
+				Path(("acme".to_string(), "payments".to_string())),
+				Query(ResourceAuditQuery::default()),
+			)
+			.await
+			.map(IntoResponse::into_response)
+		},
+	)
+	.await
+	.unwrap_err()
+	.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,259 @@
+use axum::{
+	extract::{ConnectInfo, State},
+	http::{HeaderMap, StatusCode},
+	response::{IntoResponse, Response},
+	Json,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	services::{AuditEvents, AuditServiceTrait, MonitorService, MonitorServiceTrait},
+	utils::{with_tenant_context, AuthService},
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantMonitorRepository,
+		MockTenantNetworkRepository, MockTenantRepository, MockTriggerService,
+	},
+	utils::{
+		builders::{CreateMonitorRequestBuilder, MonitorBuilder, NetworkBuilder},
+		context::{owner_context, user_context},
+		fixtures::{stellar_monitor_config, TestIds},
+	},
+};
+
+type StreamMonitorService = MonitorService<
+	MockTenantMonitorRepository,
+	MockTenantNetworkRepository,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn state_with<M: MonitorServiceTrait>(
+	monitor_service: M,
+	events: &AuditEvents,
+) -> AppState<M, MockNetworkService, MockTriggerService, MockTenantRepository, MockAuditService> {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+	.with_audit_events(events.clone())
+}
+
+/// Publishes each entry the way `AuditService` does once it has been written
+fn publishing_audit_service(events: &AuditEvents) -> MockAuditService {
+	let events = events.clone();
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().returning(move |request| {
+		events.publish(AuditLog {
+			id: Uuid::new_v4(),
+			tenant_id: request.tenant_id,
+			user_id: request.user_id,
+			api_key_id: request.api_key_id,
+			action: request.action.as_str().to_string(),
+			resource_type: request.resource_type.map(|r| r.as_str().to_string()),
+			resource_id: request.resource_id,
+			changes: request.changes,
+			ip_address: request.ip_address,
+			user_agent: request.user_agent,
+			request_id: None,
+			created_at: Some(chrono::Utc::now()),
+		});
+		Ok(())
+	});
+	audit_service
+}
+
+fn monitor_service(tenant_id: Uuid, events: &AuditEvents) -> StreamMonitorService {
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	monitor_repo.expect_create().returning(move |request| {
+		Ok(MonitorBuilder::new()
+			.with_tenant_id(tenant_id)
+			.with_monitor_id(request.monitor_id)
+			.with_network_id(request.network_id)
+			.build())
+	});
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		Ok(NetworkBuilder::new()
+			.with_id(id)
+			.with_tenant_id(tenant_id)
+			.with_blockchain("stellar")
+			.build())
+	});
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 10,
+					max_triggers_per_monitor: 10,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: 0,
+					networks_count: 1,
+					triggers_count: 0,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+
+	MonitorService::new(
+		monitor_repo,
+		network_repo,
+		tenant_repo,
+		publishing_audit_service(events),
+	)
+}
+
+/// The next `audit` event on the stream, as JSON
+async fn next_audit_event(response: Response) -> serde_json::Value {
+	let mut body = response.into_body().into_data_stream();
+	let mut buffer = String::new();
+	tokio::time::timeout(Duration::from_secs(5), async move {
+		loop {
+			let chunk = body.next().await.expect("stream ended").unwrap();
+			buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+			if let Some((event, _)) = buffer.split_once("\n\n") {
+				if event.lines().any(|line| line == "event: audit") {
+					let data = event
+						.lines()
+						.find_map(|line| line.strip_prefix("data: "))
+						.unwrap();
+					return serde_json::from_str(data).unwrap();
+				}
+				buffer = buffer.split_off(event.len() + 2);
+			}
+		}
+	})
+	.await
+	.expect("no audit event arrived")
+}
+
+#[tokio::test]
+async fn test_audit_stream_delivers_monitor_created() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let events = AuditEvents::default();
+	let stream_state = state_with(MockMonitorService::new(), &events);
+	let create_state = state_with(monitor_service(test_ids.tenant_1, &events), &events);
+	let request = CreateMonitorRequestBuilder::new()
+		.with_monitor_id("payments")
+		.with_network_id(test_ids.network_1)
+		.with_configuration(stellar_monitor_config())
+		.build();
+
+	// Act
+	let (stream, created) = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		let stream = handlers::stream_audit_logs(State(stream_state))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap();
+		let created = handlers::create_monitor(
+			State(create_state),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Json(request),
+		)
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap();
+		(stream, created)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(created.status(), StatusCode::CREATED);
+	assert_eq!(stream.status(), StatusCode::OK);
+	let event = next_audit_event(stream).await;
+	assert_eq!(event["action"], "monitor_created");
+	assert_eq!(event["tenant_id"], test_ids.tenant_1.to_string());
+	assert_eq!(event["changes"]["monitor_id"], "payments");
+}
+
+#[tokio::test]
+async fn test_audit_stream_skips_other_tenants() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let events = AuditEvents::default();
+	let state = state_with(MockMonitorService::new(), &events);
+	let audit_service = publishing_audit_service(&events);
+	let entry = |tenant_id, action| CreateAuditLogRequest {
+		tenant_id,
+		user_id: None,
+		api_key_id: None,
+		action,
+		resource_type: Some(ResourceType::Monitor),
+		resource_id: None,
+		changes: None,
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+	};
+
+	// Act
+	let stream = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::stream_audit_logs(State(state))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap()
+	})
+	.await;
+	audit_service
+		.log(entry(test_ids.tenant_2, AuditAction::MonitorCreated))
+		.await
+		.unwrap();
+	audit_service
+		.log(entry(test_ids.tenant_1, AuditAction::MonitorDeleted))
+		.await
+		.unwrap();
+
+	// Assert
+	let event = next_audit_event(stream).await;
+	assert_eq!(event["action"], "monitor_deleted");
+	assert_eq!(event["tenant_id"], test_ids.tenant_1.to_string());
+}
+
+#[tokio::test]
+async fn test_audit_stream_requires_admin_or_owner() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let events = AuditEvents::default();
+	let state = state_with(MockMonitorService::new(), &events);
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Member),
+		async move {
+			handlers::stream_audit_logs(State(state))
+				.await
+				.map(IntoResponse::into_response)
+		},
+	)
+	.await
+	.unwrap_err()
+	.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
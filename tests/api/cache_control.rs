@@ -0,0 +1,144 @@
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header, Request, StatusCode},
+	middleware,
+	routing::get,
+	Router,
+};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use stellar_monitor_tenant_isolation::{
+	api::{
+		auth, handlers,
+		middleware::{cache_control_middleware, no_store_middleware, tenant_auth_middleware},
+		routes::AppState,
+	},
+	config::ApiConfig,
+	models::*,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{MonitorBuilder, TenantBuilder, UserBuilder},
+};
+
+/// Monitor and API key listings wired as in the tenant router, for an owner
+/// of `acme-corp`; returns the app and the owner's token
+fn app(monitor_service: MockMonitorService) -> (Router, String) {
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: uuid::Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role: TenantRole::Owner,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+
+	// The API key listing fails fast against the unreachable pool
+	let state = AppState::new(
+		Arc::new(monitor_service),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.acquire_timeout(Duration::from_millis(100))
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+	.with_api_config(ApiConfig {
+		cache_max_age_secs: 120,
+		..Default::default()
+	});
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = Router::new()
+		.route("/monitors", get(handlers::list_monitors))
+		.route(
+			"/api-keys",
+			get(auth::list_api_keys).layer(middleware::from_fn(no_store_middleware)),
+		)
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			cache_control_middleware,
+		))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state);
+
+	(app, token)
+}
+
+fn get_request(uri: &str, token: &str) -> Request<Body> {
+	let mut request = Request::builder()
+		.uri(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.body(Body::empty())
+		.unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+	request
+}
+
+#[tokio::test]
+async fn test_monitor_list_carries_configured_cache_control() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_list_monitors_by_source()
+		.times(1)
+		.returning(|_, _, _| Ok(vec![MonitorBuilder::new().build()]));
+	let (app, token) = app(monitor_service);
+
+	// Act
+	let response = app
+		.oneshot(get_request(
+			"/api/v1/tenants/acme-corp/monitors?source=api",
+			&token,
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(
+		response.headers()[header::CACHE_CONTROL],
+		"private, max-age=120"
+	);
+}
+
+#[tokio::test]
+async fn test_api_key_list_is_never_cached() {
+	// Arrange
+	let (app, token) = app(MockMonitorService::new());
+
+	// Act
+	let response = app
+		.oneshot(get_request("/api/v1/tenants/acme-corp/api-keys", &token))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+}
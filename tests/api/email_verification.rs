@@ -0,0 +1,82 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use stellar_monitor_tenant_isolation::{
+	api::{auth, routes::AppState},
+	utils::AuthService,
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::UserBuilder,
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+// Tokens are rejected before the users table is touched, so the lazy pool is
+// never connected
+fn state() -> TestState {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()).with_require_email_verification(true),
+	)
+}
+
+async fn verify(state: TestState, token: String) -> axum::response::Response {
+	auth::verify_email(State(state), Json(auth::VerifyEmailRequest { token }))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap_or_else(IntoResponse::into_response)
+}
+
+#[tokio::test]
+async fn test_verify_email_rejects_tampered_token() {
+	// Arrange
+	let state = state();
+	let token = state
+		.auth_service
+		.generate_verification_token(&UserBuilder::new().with_email_verified(false).build())
+		.unwrap();
+	let (unsigned, signature) = token.rsplit_once('.').unwrap();
+	let tampered = format!(
+		"{}.{}",
+		unsigned,
+		signature.chars().rev().collect::<String>()
+	);
+
+	// Act
+	let response = verify(state, tampered).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_verify_email_rejects_access_token() {
+	// Arrange
+	let state = state();
+	let access_token = state
+		.auth_service
+		.generate_jwt(&UserBuilder::new().with_email_verified(false).build())
+		.unwrap();
+
+	// Act
+	let response = verify(state, access_token).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,69 @@
+use axum::{
+	http::{header, StatusCode},
+	response::{IntoResponse, Response},
+};
+use stellar_monitor_tenant_isolation::{
+	api::handlers::{ApiError, UNAVAILABLE_RETRY_AFTER_SECS},
+	repositories::TenantRepositoryError,
+	services::ServiceError,
+};
+
+async fn json_body(response: Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_quota_exceeded_is_not_retryable() {
+	// Arrange
+	let error = ApiError::Service(ServiceError::QuotaExceeded(
+		"Monitor quota exceeded: 10/10".to_string(),
+	));
+
+	// Act
+	let response = error.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+	assert!(response.headers().get(header::RETRY_AFTER).is_none());
+	let body = json_body(response).await;
+	assert_eq!(body["code"], "QUOTA_EXCEEDED");
+	assert_eq!(body["retryable"], false);
+}
+
+#[tokio::test]
+async fn test_unavailable_database_is_retryable_after_a_delay() {
+	// Arrange
+	let error = ApiError::Service(ServiceError::Repository(TenantRepositoryError::Database(
+		sqlx::Error::PoolTimedOut,
+	)));
+
+	// Act
+	let response = error.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+	assert_eq!(
+		response.headers()[header::RETRY_AFTER],
+		UNAVAILABLE_RETRY_AFTER_SECS.to_string().as_str()
+	);
+	let body = json_body(response).await;
+	assert_eq!(body["code"], "SERVICE_UNAVAILABLE");
+	assert_eq!(body["retryable"], true);
+}
+
+#[tokio::test]
+async fn test_rate_limited_is_retryable_and_client_errors_are_not() {
+	// Act
+	let rate_limited = ApiError::RateLimited(7).into_response();
+	let bad_request = json_body(ApiError::BadRequest("nope".to_string()).into_response()).await;
+	let not_found = json_body(ApiError::NotFound.into_response()).await;
+
+	// Assert
+	assert_eq!(rate_limited.headers()[header::RETRY_AFTER], "7");
+	assert_eq!(json_body(rate_limited).await["retryable"], true);
+	assert_eq!(bad_request["retryable"], false);
+	assert_eq!(not_found["retryable"], false);
+}
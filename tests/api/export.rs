@@ -0,0 +1,412 @@
+use axum::{
+	extract::{Query, State},
+	http::{header, StatusCode},
+	response::IntoResponse,
+};
+use std::io::Read;
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers::{self, ExportQuery},
+		routes::AppState,
+	},
+	models::*,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::{MonitorBuilder, NetworkBuilder, TriggerBuilder},
+		context::{api_key_context, owner_context},
+		fixtures::{stellar_network_config, webhook_trigger_config, TestIds},
+	},
+};
+
+fn read_entry<D: serde::de::DeserializeOwned>(
+	archive: &mut zip::ZipArchive<std::io::Cursor<Vec<u8>>>,
+	name: &str,
+) -> D {
+	let mut contents = String::new();
+	archive
+		.by_name(name)
+		.unwrap()
+		.read_to_string(&mut contents)
+		.unwrap();
+	serde_json::from_str(&contents).unwrap()
+}
+
+#[tokio::test]
+async fn test_export_zip_uses_monitor_directory_layout() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let network = NetworkBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_network_id("stellar_testnet")
+		.with_name("Stellar Testnet")
+		.with_blockchain("stellar")
+		.with_configuration(stellar_network_config())
+		.build();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("usdc-transfers")
+		.with_name("USDC Transfers")
+		.with_network_id(network.id)
+		.with_active(false)
+		.with_configuration(serde_json::json!({
+			"addresses": [{ "address": "CA6PUJLBYKZKUEKLZJMKBZLEKP2OTHANDEOWSFF44FTSYLKQPIICCJBE" }],
+			"match_conditions": { "functions": [], "events": [], "transactions": [] }
+		}))
+		.build();
+	let trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("usdc-webhook")
+		.with_name("USDC Webhook")
+		.with_trigger_type("webhook")
+		.with_monitor_id(monitor.id)
+		.with_configuration(webhook_trigger_config())
+		.build();
+
+	let mut network_service = MockNetworkService::new();
+	let networks = vec![network.clone()];
+	network_service
+		.expect_list_networks_after()
+		.times(1)
+		.returning(move |_, _| Ok(networks.clone()));
+	let mut monitor_service = MockMonitorService::new();
+	let monitors = vec![monitor.clone()];
+	monitor_service
+		.expect_list_monitors_after()
+		.times(1)
+		.returning(move |_, _| Ok(monitors.clone()));
+	let mut trigger_service = MockTriggerService::new();
+	let triggers = vec![trigger.clone()];
+	trigger_service
+		.expect_list_triggers_after()
+		.times(1)
+		.returning(move |_, _| Ok(triggers.clone()));
+
+	let state = AppState::new(
+		monitor_service,
+		network_service,
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let context = owner_context(test_ids.tenant_1).with_slug("acme-corp");
+
+	// Act
+	let response = with_tenant_context(context, async move {
+		handlers::export_tenant(
+			State(state),
+			Query(ExportQuery {
+				format: ExportFormat::Zip,
+			}),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(response.headers()[header::CONTENT_TYPE], "application/zip");
+	assert_eq!(
+		response.headers()[header::CONTENT_DISPOSITION],
+		"attachment; filename=\"acme-corp-config.zip\""
+	);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let mut archive = zip::ZipArchive::new(std::io::Cursor::new(body.to_vec())).unwrap();
+
+	let mut names: Vec<&str> = archive.file_names().collect();
+	names.sort();
+	assert_eq!(
+		names,
+		vec![
+			"monitors/usdc-transfers.json",
+			"networks/stellar_testnet.json",
+			"triggers/usdc-webhook.json",
+		]
+	);
+
+	let exported_network: ExportedNetwork =
+		read_entry(&mut archive, "networks/stellar_testnet.json");
+	assert_eq!(exported_network.network_type, "stellar");
+	assert_eq!(exported_network.slug, "stellar_testnet");
+	assert_eq!(
+		exported_network.config["network_passphrase"],
+		"Test SDF Network ; September 2015"
+	);
+
+	let exported_monitor: ExportedMonitor =
+		read_entry(&mut archive, "monitors/usdc-transfers.json");
+	assert_eq!(exported_monitor.name, "USDC Transfers");
+	assert_eq!(exported_monitor.networks, vec!["stellar_testnet"]);
+	assert_eq!(exported_monitor.triggers, vec!["usdc-webhook"]);
+	assert!(exported_monitor.paused);
+	assert!(exported_monitor.config.contains_key("match_conditions"));
+
+	let exported_triggers: ExportedTriggerFile =
+		read_entry(&mut archive, "triggers/usdc-webhook.json");
+	let exported_trigger = &exported_triggers["usdc-webhook"];
+	assert_eq!(exported_trigger.trigger_type, "webhook");
+	assert_eq!(exported_trigger.config, webhook_trigger_config());
+}
+
+#[test]
+fn test_export_file_stem_cannot_escape_its_directory() {
+	assert_eq!(export_file_stem("usdc-transfers"), "usdc-transfers");
+	assert_eq!(export_file_stem("../../etc/passwd"), "_.._etc_passwd");
+	assert_eq!(export_file_stem(".hidden"), "hidden");
+}
+
+#[tokio::test]
+async fn test_json_export_references_resources_by_external_id() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let network = NetworkBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_network_id("stellar_mainnet")
+		.build();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("large-payments")
+		.with_network_id(network.id)
+		.build();
+	let trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("payments-webhook")
+		.with_monitor_id(monitor.id)
+		.build();
+	// Belongs to a deleted monitor, which the monitor listing leaves out
+	let orphan = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("orphan-webhook")
+		.build();
+
+	let mut network_service = MockNetworkService::new();
+	let networks = vec![network];
+	network_service
+		.expect_list_networks_after()
+		.returning(move |_, _| Ok(networks.clone()));
+	let mut monitor_service = MockMonitorService::new();
+	let monitors = vec![monitor];
+	monitor_service
+		.expect_list_monitors_after()
+		.returning(move |_, _| Ok(monitors.clone()));
+	let mut trigger_service = MockTriggerService::new();
+	let triggers = vec![trigger, orphan];
+	trigger_service
+		.expect_list_triggers_after()
+		.returning(move |_, _| Ok(triggers.clone()));
+
+	let state = AppState::new(
+		monitor_service,
+		network_service,
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::export_current_tenant(State(state))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let document: TenantExportDocument = serde_json::from_slice(&body).unwrap();
+
+	assert_eq!(document.networks.len(), 1);
+	assert_eq!(document.networks[0].network_id, "stellar_mainnet");
+	assert_eq!(document.monitors.len(), 1);
+	assert_eq!(document.monitors[0].monitor_id, "large-payments");
+	assert_eq!(document.monitors[0].network_id, "stellar_mainnet");
+	assert_eq!(document.triggers.len(), 1);
+	assert_eq!(document.triggers[0].trigger_id, "payments-webhook");
+	assert_eq!(document.triggers[0].monitor_id, "large-payments");
+}
+
+#[tokio::test]
+async fn test_export_masks_trigger_secrets_for_a_normal_key() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let network = NetworkBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_network_id("stellar_mainnet")
+		.build();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("large-payments")
+		.with_network_id(network.id)
+		.build();
+	let trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("payments-webhook")
+		.with_monitor_id(monitor.id)
+		.with_configuration(serde_json::json!({
+			"url": "https://example.com/webhook",
+			"method": "POST",
+			"headers": { "Authorization": "Bearer hook-token" },
+			"signing_secret": "whsec_123"
+		}))
+		.build();
+
+	let mut network_service = MockNetworkService::new();
+	network_service
+		.expect_list_networks_after()
+		.returning(move |_, _| Ok(vec![network.clone()]));
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_list_monitors_after()
+		.returning(move |_, _| Ok(vec![monitor.clone()]));
+	let mut trigger_service = MockTriggerService::new();
+	trigger_service
+		.expect_list_triggers_after()
+		.returning(move |_, _| Ok(vec![trigger.clone()]));
+
+	let state = AppState::new(
+		monitor_service,
+		network_service,
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	// Reads everything, but without reveal_secrets on triggers
+	let context = api_key_context(test_ids.tenant_1, uuid::Uuid::new_v4()).with_permissions(vec![
+		ApiPermission::all_networks(),
+		ApiPermission::all_monitors(),
+		ApiPermission::all_triggers(),
+	]);
+
+	// Act
+	let response = with_tenant_context(context, async move {
+		handlers::export_current_tenant(State(state))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let document: TenantExportDocument = serde_json::from_slice(&body).unwrap();
+	let configuration = &document.triggers[0].configuration;
+	assert_eq!(configuration["signing_secret"], REDACTED_SECRET);
+	assert_eq!(configuration["headers"]["Authorization"], REDACTED_SECRET);
+	assert_eq!(configuration["url"], "https://example.com/webhook");
+}
+
+#[tokio::test]
+async fn test_streamed_json_export_matches_the_document() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let networks = vec![
+		NetworkBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_network_id("stellar_mainnet")
+			.build(),
+		NetworkBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_network_id("stellar_testnet")
+			.build(),
+	];
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("large-payments")
+		.with_network_id(networks[0].id)
+		.build();
+	let trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("payments-webhook")
+		.with_monitor_id(monitor.id)
+		.build();
+	let expected = serde_json::to_vec(
+		&TenantExport {
+			networks: networks.clone(),
+			monitors: vec![monitor.clone()],
+			triggers: vec![trigger.clone()],
+		}
+		.document(),
+	)
+	.unwrap();
+
+	let mut network_service = MockNetworkService::new();
+	network_service
+		.expect_list_networks_after()
+		.returning(move |_, _| Ok(networks.clone()));
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_list_monitors_after()
+		.returning(move |_, _| Ok(vec![monitor.clone()]));
+	let mut trigger_service = MockTriggerService::new();
+	trigger_service
+		.expect_list_triggers_after()
+		.returning(move |_, _| Ok(vec![trigger.clone()]));
+
+	let state = AppState::new(
+		monitor_service,
+		network_service,
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let context = owner_context(test_ids.tenant_1).with_slug("acme-corp");
+
+	// Act
+	let response = with_tenant_context(context, async move {
+		handlers::export_tenant(
+			State(state),
+			Query(ExportQuery {
+				format: ExportFormat::Json,
+			}),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+	assert_eq!(
+		response.headers()[header::CONTENT_DISPOSITION],
+		"attachment; filename=\"acme-corp-config.json\""
+	);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	assert_eq!(body.to_vec(), expected);
+}
@@ -0,0 +1,188 @@
+use async_trait::async_trait;
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use std::{collections::HashSet, sync::Arc, time::Duration};
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	repositories::MIGRATOR,
+	services::{pending_migrations, HealthCheck, MigrationsHealthCheck},
+	utils::AuthService,
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::database::TestDatabase,
+};
+
+fn state_with(
+	pool: sqlx::PgPool,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		pool,
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+fn unreachable_pool() -> sqlx::PgPool {
+	sqlx::postgres::PgPoolOptions::new()
+		.acquire_timeout(Duration::from_millis(500))
+		.connect_lazy("postgres://127.0.0.1:1/unused")
+		.unwrap()
+}
+
+struct StubCheck {
+	name: &'static str,
+	critical: bool,
+	healthy: bool,
+}
+
+#[async_trait]
+impl HealthCheck for StubCheck {
+	fn name(&self) -> &'static str {
+		self.name
+	}
+
+	fn is_critical(&self) -> bool {
+		self.critical
+	}
+
+	async fn check(&self) -> Result<(), String> {
+		if self.healthy {
+			Ok(())
+		} else {
+			Err(format!("{} is down", self.name))
+		}
+	}
+}
+
+#[tokio::test]
+async fn test_liveness_is_ok_without_database() {
+	// Act
+	let response = handlers::liveness_check().await.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(json_body(response).await["status"], "healthy");
+}
+
+#[tokio::test]
+async fn test_readiness_reports_unavailable_database() {
+	// Arrange
+	let state = state_with(unreachable_pool());
+
+	// Act
+	let response = handlers::readiness_check(State(state)).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+	let body = json_body(response).await;
+	assert_eq!(body["status"], "unavailable");
+	assert_eq!(body["pool"]["size"], 0);
+	assert_eq!(body["checks"][0]["name"], "database");
+	assert_eq!(body["checks"][0]["status"], "down");
+	assert!(body["checks"][0]["latency_ms"].is_u64());
+	assert!(body["checks"][0]["error"].is_string());
+}
+
+#[tokio::test]
+async fn test_readiness_runs_added_checks() {
+	// Arrange: replace the database check with stubs
+	let stub_state = || {
+		let mut state = state_with(unreachable_pool());
+		state.health_checks = Vec::new();
+		state
+			.with_health_check(Arc::new(StubCheck {
+				name: "cache",
+				critical: true,
+				healthy: true,
+			}))
+			.with_health_check(Arc::new(StubCheck {
+				name: "rpc",
+				critical: false,
+				healthy: false,
+			}))
+	};
+
+	// Act
+	let response = handlers::readiness_check(State(stub_state())).await;
+	let failing =
+		handlers::readiness_check(State(stub_state().with_health_check(Arc::new(StubCheck {
+			name: "queue",
+			critical: true,
+			healthy: false,
+		}))))
+		.await;
+
+	// Assert: a failing non-critical check is reported without failing readiness
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = json_body(response).await;
+	assert_eq!(body["status"], "ready");
+	assert_eq!(body["checks"][0]["status"], "up");
+	assert_eq!(body["checks"][1]["status"], "down");
+	assert_eq!(body["checks"][1]["critical"], false);
+	assert_eq!(failing.status(), StatusCode::SERVICE_UNAVAILABLE);
+	assert_eq!(
+		json_body(failing).await["checks"][2]["error"],
+		"queue is down"
+	);
+}
+
+#[test]
+fn test_pending_migrations_lists_unapplied_versions() {
+	// Arrange
+	let versions: Vec<i64> = MIGRATOR.iter().map(|migration| migration.version).collect();
+	let all: HashSet<i64> = versions.iter().copied().collect();
+	let mut all_but_last = all.clone();
+	all_but_last.remove(versions.last().unwrap());
+
+	// Act / Assert
+	assert!(pending_migrations(&MIGRATOR, &all).is_empty());
+	assert_eq!(
+		pending_migrations(&MIGRATOR, &all_but_last),
+		[*versions.last().unwrap()]
+	);
+	assert_eq!(pending_migrations(&MIGRATOR, &HashSet::new()), versions);
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_readiness_reports_ready_with_pool_stats() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let state = state_with(db.pool.clone()).with_health_check(Arc::new(
+		MigrationsHealthCheck::new(db.pool.clone(), &MIGRATOR),
+	));
+
+	// Act
+	let response = handlers::readiness_check(State(state)).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = json_body(response).await;
+	assert_eq!(body["status"], "ready");
+	assert_eq!(body["checks"][0]["name"], "database");
+	assert_eq!(body["checks"][1]["name"], "migrations");
+	assert_eq!(body["checks"][1]["status"], "up");
+	assert!(body["pool"]["size"].as_u64().unwrap() >= 1);
+	assert!(body["pool"]["idle"].is_u64());
+}
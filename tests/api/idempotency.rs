@@ -0,0 +1,145 @@
+use axum::{
+	body::Body,
+	http::{Method, Request, StatusCode},
+	middleware,
+	response::IntoResponse,
+	routing::post,
+	Router,
+};
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers::ApiError,
+		middleware::{
+			idempotency_middleware, replay_response, request_fingerprint, IDEMPOTENCY_KEY_HEADER,
+			IDEMPOTENT_REPLAYED_HEADER, MAX_IDEMPOTENCY_KEY_LENGTH,
+		},
+		routes::AppState,
+	},
+	repositories::StoredResponse,
+	utils::{with_tenant_context, AuthService},
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::owner_context, fixtures::TestIds},
+};
+
+fn app() -> Router {
+	// The lazy pool is never connected; requests that reach the database would fail
+	let state = AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(MockTenantRepository::new()),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	Router::new()
+		.route(
+			"/monitors",
+			post(|| async { (StatusCode::CREATED, "created") }).route_layer(
+				middleware::from_fn_with_state(state.clone(), idempotency_middleware),
+			),
+		)
+		.with_state(state)
+}
+
+#[tokio::test]
+async fn test_request_without_key_skips_idempotency() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let request = Request::post("/monitors").body(Body::empty()).unwrap();
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), app().oneshot(request))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CREATED);
+	assert!(response.headers().get(IDEMPOTENT_REPLAYED_HEADER).is_none());
+}
+
+#[tokio::test]
+async fn test_overlong_key_is_rejected() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let request = Request::post("/monitors")
+		.header(
+			IDEMPOTENCY_KEY_HEADER,
+			"k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1),
+		)
+		.body(Body::empty())
+		.unwrap();
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), app().oneshot(request))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_replayed_response_keeps_status_and_body() {
+	// Arrange
+	let stored = StoredResponse {
+		status: 201,
+		body: br#"{"success":true,"data":{"id":"m-1"}}"#.to_vec(),
+	};
+
+	// Act
+	let response = replay_response(stored.clone());
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CREATED);
+	assert_eq!(response.headers()[IDEMPOTENT_REPLAYED_HEADER], "true");
+	assert_eq!(response.headers()["content-type"], "application/json");
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	assert_eq!(body.to_vec(), stored.body);
+}
+
+#[test]
+fn test_request_fingerprint_covers_method_path_and_body() {
+	// Arrange
+	let body = br#"{"name":"Payments"}"#;
+	let fingerprint = request_fingerprint(&Method::POST, "/monitors", body);
+
+	// Act
+	let same = request_fingerprint(&Method::POST, "/monitors", body);
+	let other_body = request_fingerprint(&Method::POST, "/monitors", br#"{"name":"Refunds"}"#);
+	let other_path = request_fingerprint(&Method::POST, "/networks", body);
+	let other_method = request_fingerprint(&Method::PUT, "/monitors", body);
+
+	// Assert
+	assert_eq!(fingerprint, same);
+	assert_eq!(fingerprint.len(), 64);
+	assert_ne!(fingerprint, other_body);
+	assert_ne!(fingerprint, other_path);
+	assert_ne!(fingerprint, other_method);
+}
+
+#[tokio::test]
+async fn test_reused_key_is_unprocessable() {
+	// Act
+	let response = ApiError::IdempotencyKeyReused.into_response();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["code"], "IDEMPOTENCY_KEY_REUSED");
+}
@@ -0,0 +1,138 @@
+use axum::{
+	extract::{ConnectInfo, Query, State},
+	http::{HeaderMap, StatusCode},
+	response::IntoResponse,
+	Json,
+};
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::user_context, fixtures::TestIds},
+};
+
+fn state() -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().never();
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		audit_service,
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn document(trigger_configuration: serde_json::Value) -> TenantExportDocument {
+	TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![NetworkExportEntry {
+			network_id: "stellar-mainnet".to_string(),
+			name: "Stellar Mainnet".to_string(),
+			blockchain: "stellar".to_string(),
+			configuration: json!({}),
+			is_active: true,
+		}],
+		monitors: vec![MonitorExportEntry {
+			monitor_id: "payments".to_string(),
+			name: "Payments".to_string(),
+			network_id: "stellar-mainnet".to_string(),
+			configuration: json!({}),
+			is_active: true,
+		}],
+		triggers: vec![TriggerExportEntry {
+			trigger_id: "payments-webhook".to_string(),
+			name: "Payments webhook".to_string(),
+			monitor_id: "payments".to_string(),
+			trigger_type: "webhook".to_string(),
+			configuration: trigger_configuration,
+			is_active: true,
+		}],
+	}
+}
+
+async fn import_as(role: TenantRole, document: TenantExportDocument) -> axum::response::Response {
+	let test_ids = TestIds::default();
+	with_tenant_context(
+		user_context(test_ids.tenant_1, role),
+		handlers::import_tenant(
+			State(state()),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			Query(handlers::ImportQuery::default()),
+			HeaderMap::new(),
+			Json(document),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response)
+}
+
+#[tokio::test]
+async fn test_import_requires_write_permission() {
+	// Act
+	let response = import_as(
+		TenantRole::Viewer,
+		document(json!({ "url": "https://example.com/hook", "method": "POST" })),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_import_rejects_invalid_trigger_before_writing() {
+	// Act
+	let response = import_as(TenantRole::Admin, document(json!({ "method": "POST" }))).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_import_rejects_unknown_version() {
+	// Arrange
+	let mut document = document(json!({ "url": "https://example.com/hook", "method": "POST" }));
+	document.version = EXPORT_FORMAT_VERSION + 1;
+
+	// Act
+	let response = import_as(TenantRole::Admin, document).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_unversioned_document_reads_as_current_version() {
+	let document: TenantExportDocument =
+		serde_json::from_value(json!({ "networks": [], "monitors": [], "triggers": [] })).unwrap();
+	assert_eq!(document.version, EXPORT_FORMAT_VERSION);
+}
+
+#[test]
+fn test_import_mode_defaults_to_fail() {
+	assert_eq!(ImportMode::default(), ImportMode::Fail);
+	assert_eq!(
+		serde_json::from_value::<ImportMode>(json!("skip_existing")).unwrap(),
+		ImportMode::SkipExisting
+	);
+}
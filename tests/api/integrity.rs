@@ -0,0 +1,174 @@
+use axum::{
+	extract::{Path, State},
+	http::StatusCode,
+	response::IntoResponse,
+};
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	utils::{metrics, AuthService},
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::TenantBuilder,
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn state_with(tenant_repo: MockTenantRepository) -> TestState {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+/// A repository holding `tenant` with the given violations and stored counts
+fn tenant_repo_with(
+	tenant: Tenant,
+	violations: Vec<IntegrityViolation>,
+	counts: ResourceCounts,
+) -> MockTenantRepository {
+	let tenant_id = tenant.id;
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get()
+		.with(eq(tenant_id))
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo
+		.expect_find_integrity_violations()
+		.with(eq(tenant_id))
+		.times(1)
+		.returning(move |_| Ok(violations.clone()));
+	tenant_repo
+		.expect_get_resource_counts()
+		.with(eq(tenant_id))
+		.returning(move |_| Ok(counts));
+	tenant_repo
+}
+
+async fn into_json(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_integrity_check_on_healthy_tenant_reports_nothing() {
+	// Arrange
+	let tenant = TenantBuilder::new().with_slug("integrity-healthy").build();
+	let tenant_id = tenant.id;
+	let counts = ResourceCounts {
+		monitors: 2,
+		networks: 1,
+		triggers: 3,
+	};
+	metrics::set_tenant_resource_counts("integrity-healthy", &counts);
+	let tenant_repo = tenant_repo_with(tenant, vec![], counts);
+
+	// Act
+	let response =
+		handlers::tenant_integrity_check(State(state_with(tenant_repo)), Path(tenant_id))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = into_json(response).await;
+	assert_eq!(body["data"]["tenant_id"], tenant_id.to_string());
+	assert_eq!(body["data"]["healthy"], true);
+	assert_eq!(body["data"]["violations"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_integrity_check_on_corrupted_tenant_reports_violations() {
+	// Arrange
+	let tenant = TenantBuilder::new()
+		.with_slug("integrity-corrupted")
+		.build();
+	let tenant_id = tenant.id;
+	let monitor_id = Uuid::new_v4();
+	// The gauges lag the database by one monitor
+	metrics::set_tenant_resource_counts(
+		"integrity-corrupted",
+		&ResourceCounts {
+			monitors: 1,
+			networks: 1,
+			triggers: 0,
+		},
+	);
+	let tenant_repo = tenant_repo_with(
+		tenant,
+		vec![IntegrityViolation {
+			kind: IntegrityViolationKind::ForeignNetwork,
+			resource_type: "monitor".to_string(),
+			resource_id: Some(monitor_id),
+			detail: "monitor watcher uses network owned by another tenant".to_string(),
+		}],
+		ResourceCounts {
+			monitors: 2,
+			networks: 1,
+			triggers: 0,
+		},
+	);
+
+	// Act
+	let response =
+		handlers::tenant_integrity_check(State(state_with(tenant_repo)), Path(tenant_id))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = into_json(response).await;
+	assert_eq!(body["data"]["healthy"], false);
+	let violations = body["data"]["violations"].as_array().unwrap();
+	assert_eq!(violations.len(), 2);
+	assert_eq!(violations[0]["kind"], "foreign_network");
+	assert_eq!(violations[0]["resource_id"], monitor_id.to_string());
+	assert_eq!(violations[1]["kind"], "count_mismatch");
+	assert_eq!(violations[1]["resource_type"], "monitor");
+	assert_eq!(violations[1]["resource_id"], serde_json::Value::Null);
+}
+
+#[tokio::test]
+async fn test_integrity_check_for_unknown_tenant_is_not_found() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get()
+		.returning(|tenant_id| Err(TenantRepositoryError::TenantNotFound(tenant_id)));
+	tenant_repo.expect_find_integrity_violations().times(0);
+
+	// Act
+	let response =
+		handlers::tenant_integrity_check(State(state_with(tenant_repo)), Path(Uuid::new_v4()))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,185 @@
+use axum::{
+	extract::{ConnectInfo, Path, State},
+	http::{HeaderMap, StatusCode},
+	response::IntoResponse,
+	Json,
+};
+use axum_extra::{
+	headers::{authorization::Bearer, Authorization},
+	TypedHeader,
+};
+use std::net::SocketAddr;
+use stellar_monitor_tenant_isolation::{
+	api::{auth, handlers, routes::AppState},
+	models::*,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::user_context, fixtures::TestIds},
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+// Every case here is rejected before the invitation repository touches the
+// database, so the lazy pool is never connected
+fn state() -> TestState {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn addr() -> ConnectInfo<SocketAddr> {
+	ConnectInfo("127.0.0.1:8080".parse().unwrap())
+}
+
+fn invite(email: &str, role: TenantRole) -> Json<CreateInvitationRequest> {
+	Json(CreateInvitationRequest {
+		email: email.to_string(),
+		role,
+	})
+}
+
+#[tokio::test]
+async fn test_member_cannot_invite() {
+	// Arrange
+	let test_ids = TestIds::default();
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Member),
+		handlers::create_invitation(
+			State(state()),
+			addr(),
+			HeaderMap::new(),
+			invite("new@example.com", TenantRole::Member),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_invite_owner() {
+	// Arrange
+	let test_ids = TestIds::default();
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		handlers::create_invitation(
+			State(state()),
+			addr(),
+			HeaderMap::new(),
+			invite("new@example.com", TenantRole::Owner),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_invite_rejects_invalid_email() {
+	// Arrange
+	let test_ids = TestIds::default();
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		handlers::create_invitation(
+			State(state()),
+			addr(),
+			HeaderMap::new(),
+			invite("not-an-email", TenantRole::Member),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_viewer_cannot_list_invitations() {
+	// Arrange
+	let test_ids = TestIds::default();
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Viewer),
+		handlers::list_invitations(State(state())),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_accept_requires_authentication() {
+	// Act
+	let response = auth::accept_invitation(
+		State(state()),
+		addr(),
+		HeaderMap::new(),
+		Path("invitation-token".to_string()),
+		None,
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_accept_rejects_invalid_token() {
+	// Arrange
+	let bearer = Authorization::bearer("not-a-jwt").unwrap();
+
+	// Act
+	let response = auth::accept_invitation(
+		State(state()),
+		addr(),
+		HeaderMap::new(),
+		Path("invitation-token".to_string()),
+		Some(TypedHeader::<Authorization<Bearer>>(bearer)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
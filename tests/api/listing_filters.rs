@@ -0,0 +1,164 @@
+use axum::{
+	extract::{Query, State},
+	http::{StatusCode, Uri},
+	response::IntoResponse,
+};
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::{MonitorBuilder, TriggerBuilder},
+		context::owner_context,
+		fixtures::TestIds,
+	},
+};
+
+fn state(
+	monitor_service: MockMonitorService,
+	trigger_service: MockTriggerService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn query<Q: serde::de::DeserializeOwned>(uri: &str) -> Query<Q> {
+	Query::try_from_uri(&uri.parse::<Uri>().unwrap()).unwrap()
+}
+
+async fn body_json(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_list_monitors_applies_filters_and_reports_filtered_total() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let expected = MonitorFilter {
+		is_active: Some(false),
+		network: Some("stellar-mainnet".to_string()),
+		search: Some("pay".to_string()),
+		sort: MonitorSort::Name,
+	};
+	let mut monitor_service = MockMonitorService::new();
+	let counted = expected.clone();
+	monitor_service
+		.expect_count_monitors_filtered()
+		.withf(move |filter| *filter == counted)
+		.times(1)
+		.returning(|_| Ok(3));
+	let listed = expected.clone();
+	monitor_service
+		.expect_list_monitors_filtered()
+		.withf(move |filter, limit, offset| *filter == listed && *limit == 1 && *offset == 0)
+		.times(1)
+		.returning(|_, _, _| Ok(vec![MonitorBuilder::new().with_name("Payments").build()]));
+	monitor_service.expect_list_monitors().never();
+	let state = state(monitor_service, MockTriggerService::new());
+	let uri = "/monitors?limit=1&is_active=false&network_id=stellar-mainnet&q=pay&sort=name";
+
+	// Act
+	let response = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		handlers::list_monitors(State(state), query(uri), query(uri)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = body_json(response).await;
+	assert_eq!(body["data"].as_array().unwrap().len(), 1);
+	assert_eq!(body["meta"]["total"], 3);
+	assert!(body["meta"]["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn test_list_monitors_rejects_filters_with_include_deleted() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_count_monitors_filtered().never();
+	let state = state(monitor_service, MockTriggerService::new());
+	let uri = "/monitors?include_deleted=true&sort=-created_at";
+
+	// Act
+	let response = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		handlers::list_monitors(State(state), query(uri), query(uri)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_triggers_filters_by_type_and_monitor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let expected = TriggerFilter {
+		trigger_type: Some("webhook".to_string()),
+		monitor: Some("payments".to_string()),
+		is_active: None,
+	};
+	let mut trigger_service = MockTriggerService::new();
+	let counted = expected.clone();
+	trigger_service
+		.expect_count_triggers_filtered()
+		.withf(move |filter| *filter == counted)
+		.returning(|_| Ok(1));
+	trigger_service
+		.expect_list_triggers_filtered()
+		.withf(move |filter, _, _| *filter == expected)
+		.returning(|_, _, _| {
+			Ok(vec![TriggerBuilder::new()
+				.with_trigger_type("webhook")
+				.build()])
+		});
+	trigger_service.expect_list_triggers().never();
+	let state = state(MockMonitorService::new(), trigger_service);
+	let uri = "/triggers?trigger_type=webhook&monitor_id=payments";
+
+	// Act
+	let response = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		handlers::list_triggers(State(state), query(uri), query(uri)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap();
+
+	// Assert
+	let body = body_json(response).await;
+	assert_eq!(body["data"][0]["trigger_type"], "webhook");
+	assert_eq!(body["meta"]["total"], 1);
+}
@@ -0,0 +1,453 @@
+use axum::{
+	extract::{ConnectInfo, State},
+	http::{HeaderMap, StatusCode},
+	response::{IntoResponse, Response},
+	Json,
+};
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::user_context, fixtures::TestIds},
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn state_with(tenant_repo: MockTenantRepository) -> TestState {
+	state_with_audit(tenant_repo, MockAuditService::new())
+}
+
+fn state_with_audit(
+	tenant_repo: MockTenantRepository,
+	audit_service: MockAuditService,
+) -> TestState {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		audit_service,
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn error_code(response: Response) -> String {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	body["code"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_owner_transfers_ownership() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let context = user_context(test_ids.tenant_1, TenantRole::Owner);
+	let owner_id = context.user.as_ref().unwrap().id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_transfer_ownership()
+		.with(eq(test_ids.tenant_1), eq(owner_id), eq(test_ids.user_2))
+		.times(1)
+		.returning(|_, _, _| Ok(()));
+	let request = TransferOwnershipRequest {
+		to_user_id: test_ids.user_2,
+	};
+
+	// Act
+	let response = with_tenant_context(
+		context,
+		handlers::transfer_ownership(State(state_with(tenant_repo)), Json(request)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_transfer_ownership() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo.expect_transfer_ownership().times(0);
+	let request = TransferOwnershipRequest {
+		to_user_id: test_ids.user_2,
+	};
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		handlers::transfer_ownership(State(state_with(tenant_repo)), Json(request)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_transfer_to_non_member_is_not_found() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_transfer_ownership()
+		.times(1)
+		.returning(|tenant_id, _, to_user| {
+			Err(TenantRepositoryError::ResourceNotFound {
+				resource_type: "membership".to_string(),
+				resource_id: format!("{}/{}", tenant_id, to_user),
+			})
+		});
+	let request = TransferOwnershipRequest {
+		to_user_id: test_ids.user_2,
+	};
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Owner),
+		handlers::transfer_ownership(State(state_with(tenant_repo)), Json(request)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_last_owner_guard_surfaces_as_conflict() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_transfer_ownership()
+		.returning(|_, from_user, _| Err(TenantRepositoryError::CannotRemoveLastOwner(from_user)));
+	let request = TransferOwnershipRequest {
+		to_user_id: test_ids.user_2,
+	};
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Owner),
+		handlers::transfer_ownership(State(state_with(tenant_repo)), Json(request)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	assert_eq!(error_code(response).await, "LAST_OWNER");
+}
+
+fn batch_entry(email: &str, role: TenantRole) -> BatchMemberEntry {
+	BatchMemberEntry {
+		user_id: None,
+		email: Some(email.to_string()),
+		role,
+	}
+}
+
+async fn add_members(
+	state: TestState,
+	role: TenantRole,
+	entries: Vec<BatchMemberEntry>,
+) -> Response {
+	let test_ids = TestIds::default();
+
+	with_tenant_context(
+		user_context(test_ids.tenant_1, role),
+		handlers::add_members_batch(
+			State(state),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Json(entries),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response)
+}
+
+#[tokio::test]
+async fn test_admin_adds_members_batch_within_quota() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_add_members_batch()
+		.withf(move |tenant_id, entries| *tenant_id == test_ids.tenant_1 && entries.len() == 3)
+		.times(1)
+		.returning(|_, entries| {
+			Ok(entries
+				.into_iter()
+				.enumerate()
+				.map(|(index, entry)| BatchMemberResult {
+					index,
+					user_id: Some(uuid::Uuid::new_v4()),
+					email: entry.email,
+					role: entry.role,
+					// The last user already belongs to the tenant
+					status: if index < 2 {
+						BatchMemberStatus::Added
+					} else {
+						BatchMemberStatus::AlreadyMember
+					},
+				})
+				.collect())
+		});
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_log()
+		.withf(|request| matches!(request.action, AuditAction::MemberAdded))
+		.times(2)
+		.returning(|_| Ok(()));
+	let entries = vec![
+		batch_entry("jane@example.com", TenantRole::Member),
+		batch_entry("john@example.com", TenantRole::Viewer),
+		batch_entry("mary@example.com", TenantRole::Admin),
+	];
+
+	// Act
+	let response = add_members(
+		state_with_audit(tenant_repo, audit_service),
+		TenantRole::Admin,
+		entries,
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let statuses: Vec<_> = body["data"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|result| result["status"].as_str().unwrap().to_string())
+		.collect();
+	assert_eq!(statuses, ["added", "added", "already_member"]);
+}
+
+#[tokio::test]
+async fn test_members_batch_over_quota_adds_nobody() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_add_members_batch()
+		.times(1)
+		.returning(|_, _| {
+			Err(TenantRepositoryError::QuotaExceeded(
+				"Adding 2 members would exceed the limit of 3 (currently 2)".to_string(),
+			))
+		});
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().times(0);
+	let entries = vec![
+		batch_entry("jane@example.com", TenantRole::Member),
+		batch_entry("john@example.com", TenantRole::Member),
+	];
+
+	// Act
+	let response = add_members(
+		state_with_audit(tenant_repo, audit_service),
+		TenantRole::Owner,
+		entries,
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+	assert_eq!(error_code(response).await, "QUOTA_EXCEEDED");
+}
+
+#[tokio::test]
+async fn test_only_owner_can_batch_add_owner() {
+	for role in [TenantRole::Admin, TenantRole::Member] {
+		// Arrange
+		let mut tenant_repo = MockTenantRepository::new();
+		tenant_repo.expect_add_members_batch().times(0);
+		let entries = vec![batch_entry("jane@example.com", TenantRole::Owner)];
+
+		// Act
+		let response = add_members(state_with(tenant_repo), role, entries).await;
+
+		// Assert
+		assert_eq!(response.status(), StatusCode::FORBIDDEN, "{:?}", role);
+	}
+}
+
+async fn sync_members(
+	state: TestState,
+	role: TenantRole,
+	members: Vec<BatchMemberEntry>,
+) -> Response {
+	let test_ids = TestIds::default();
+
+	with_tenant_context(
+		user_context(test_ids.tenant_1, role),
+		handlers::sync_members(
+			State(state),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Json(SyncMembersRequest { members }),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response)
+}
+
+#[tokio::test]
+async fn test_sync_members_audits_each_change() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_sync_members()
+		.withf(move |tenant_id, members, change_owners| {
+			*tenant_id == test_ids.tenant_1 && members.len() == 3 && *change_owners
+		})
+		.times(1)
+		.returning(move |_, _, _| {
+			Ok(MemberSyncResult {
+				changes: vec![
+					MemberSyncChange {
+						user_id: test_ids.user_2,
+						action: MemberSyncAction::Removed,
+						previous_role: Some(TenantRole::Member),
+						role: None,
+					},
+					MemberSyncChange {
+						user_id: test_ids.user_1,
+						action: MemberSyncAction::RoleChanged,
+						previous_role: Some(TenantRole::Member),
+						role: Some(TenantRole::Admin),
+					},
+					MemberSyncChange {
+						user_id: uuid::Uuid::new_v4(),
+						action: MemberSyncAction::Added,
+						previous_role: None,
+						role: Some(TenantRole::Viewer),
+					},
+				],
+				members: Vec::new(),
+			})
+		});
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_log()
+		.withf(|request| matches!(request.action, AuditAction::UserRemoved))
+		.times(1)
+		.returning(|_| Ok(()));
+	audit_service
+		.expect_log()
+		.withf(|request| matches!(request.action, AuditAction::UserRoleChanged))
+		.times(1)
+		.returning(|_| Ok(()));
+	audit_service
+		.expect_log()
+		.withf(|request| matches!(request.action, AuditAction::MemberAdded))
+		.times(1)
+		.returning(|_| Ok(()));
+	let members = vec![
+		batch_entry("owner@example.com", TenantRole::Owner),
+		batch_entry("jane@example.com", TenantRole::Admin),
+		batch_entry("john@example.com", TenantRole::Viewer),
+	];
+
+	// Act
+	let response = sync_members(
+		state_with_audit(tenant_repo, audit_service),
+		TenantRole::Owner,
+		members,
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let actions: Vec<_> = body["data"]["changes"]
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|change| change["action"].as_str().unwrap().to_string())
+		.collect();
+	assert_eq!(actions, ["removed", "role_changed", "added"]);
+}
+
+#[tokio::test]
+async fn test_admin_sync_changing_owners_is_forbidden() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_sync_members()
+		.withf(|_, _, change_owners| !*change_owners)
+		.times(1)
+		.returning(|_, _, _| {
+			Err(TenantRepositoryError::AccessDenied(
+				"Only an owner can add or remove owners".to_string(),
+			))
+		});
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().times(0);
+	let members = vec![batch_entry("jane@example.com", TenantRole::Owner)];
+
+	// Act
+	let response = sync_members(
+		state_with_audit(tenant_repo, audit_service),
+		TenantRole::Admin,
+		members,
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+	assert_eq!(error_code(response).await, "ACCESS_DENIED");
+}
+
+#[tokio::test]
+async fn test_member_cannot_sync_members() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo.expect_sync_members().times(0);
+	let members = vec![batch_entry("jane@example.com", TenantRole::Owner)];
+
+	// Act
+	let response = sync_members(state_with(tenant_repo), TenantRole::Member, members).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,159 @@
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header, Method, Request, StatusCode},
+	middleware,
+	routing::post,
+	Router,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers,
+		middleware::{http_metrics_middleware, tenant_auth_middleware},
+		routes::AppState,
+	},
+	models::*,
+	utils::{metrics, AuthService},
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{CreateMonitorRequestBuilder, MonitorBuilder, TenantBuilder, UserBuilder},
+};
+
+/// The monitor create route behind the real JWT middleware and the metrics
+/// layer, for a member of `metrics-acme`; returns the app and the user's token
+fn app() -> (Router, String) {
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("metrics-acme").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: uuid::Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role: TenantRole::Member,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_create_monitor()
+		.returning(|request, _| {
+			Ok(MonitorBuilder::new()
+				.with_monitor_id(request.monitor_id)
+				.build())
+		});
+
+	// The lazy pool is never connected; background usage recording only logs its failure
+	let state = AppState::new(
+		Arc::new(monitor_service),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = Router::new()
+		.route("/monitors", post(handlers::create_monitor))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.route_layer(middleware::from_fn(http_metrics_middleware))
+		.with_state(state);
+
+	(app, token)
+}
+
+fn create_monitor(uri: &str, token: &str) -> Request<Body> {
+	let body = serde_json::to_value(CreateMonitorRequestBuilder::new().build()).unwrap();
+	let mut request = Request::builder()
+		.method(Method::POST)
+		.uri(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.header(header::CONTENT_TYPE, "application/json")
+		.body(Body::from(body.to_string()))
+		.unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+	request
+}
+
+/// The sample line of `name` carrying every label in `labels`
+fn sample<'a>(output: &'a str, name: &str, labels: &[&str]) -> Option<&'a str> {
+	output.lines().find(|line| {
+		line.starts_with(&format!("{}{{", name)) && labels.iter().all(|l| line.contains(l))
+	})
+}
+
+#[tokio::test]
+async fn test_requests_are_counted_by_route_status_and_tenant() {
+	// Arrange
+	metrics::register_metrics();
+	let (app, token) = app();
+
+	// Act
+	let created = app
+		.clone()
+		.oneshot(create_monitor(
+			"/api/v1/tenants/metrics-acme/monitors",
+			&token,
+		))
+		.await
+		.unwrap();
+	let unauthorized = app
+		.oneshot(create_monitor(
+			"/api/v1/tenants/metrics-acme/monitors",
+			"not-a-token",
+		))
+		.await
+		.unwrap();
+	let output = metrics::render_metrics();
+
+	// Assert
+	assert_eq!(created.status(), StatusCode::CREATED);
+	assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+	let route = r#"route="/api/v1/tenants/:tenant_slug/monitors""#;
+	let counted = sample(
+		&output,
+		"http_requests_total",
+		&[route, r#"status="201""#, r#"tenant_slug="metrics-acme""#],
+	)
+	.expect("authenticated request is counted");
+	assert!(counted.ends_with(" 1"), "{}", counted);
+	// A slug from an unauthenticated URL never becomes a label value
+	assert!(sample(
+		&output,
+		"http_requests_total",
+		&[route, r#"status="401""#, r#"tenant_slug="none""#],
+	)
+	.is_some());
+	assert!(sample(
+		&output,
+		"http_request_duration_seconds_count",
+		&[route, r#"tenant_slug="metrics-acme""#],
+	)
+	.is_some());
+	assert!(output.contains("db_pool_acquire_duration_seconds"));
+}
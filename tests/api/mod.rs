@@ -0,0 +1,35 @@
+pub mod accessible_tenants;
+pub mod audit_logs;
+pub mod audit_stream;
+pub mod cache_control;
+pub mod email_verification;
+pub mod errors;
+pub mod export;
+pub mod health;
+pub mod idempotency;
+pub mod import;
+pub mod integrity;
+pub mod invitations;
+pub mod listing_filters;
+pub mod memberships;
+pub mod metrics;
+pub mod monitor_batch_plan;
+pub mod monitor_delete;
+pub mod network_delete;
+pub mod pagination;
+pub mod permissions;
+pub mod projection;
+pub mod quota_status;
+pub mod rate_limit;
+pub mod request_id;
+pub mod roles;
+pub mod rpc_quota;
+pub mod sso;
+pub mod stats;
+pub mod suspension;
+pub mod tenant_list;
+pub mod tenant_routes;
+pub mod tenant_selection;
+pub mod trigger_secrets;
+pub mod triggers;
+pub mod webhooks;
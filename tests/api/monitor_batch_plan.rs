@@ -0,0 +1,92 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	services::ServiceError,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{builders::NetworkBuilder, context::user_context, fixtures::TestIds},
+};
+
+fn planned(monitor_id: &str, networks: &[&str]) -> PlannedMonitor {
+	PlannedMonitor {
+		monitor_id: monitor_id.to_string(),
+		networks: networks.iter().map(|n| n.to_string()).collect(),
+	}
+}
+
+#[tokio::test]
+async fn test_plan_classifies_existing_and_missing_networks() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let existing = NetworkBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_network_id("stellar-mainnet")
+		.build();
+	let existing_id = existing.id;
+
+	let mut network_service = MockNetworkService::new();
+	network_service
+		.expect_get_network()
+		.times(2)
+		.returning(move |network_id| match network_id {
+			"stellar-mainnet" => Ok(existing.clone()),
+			other => Err(ServiceError::Repository(
+				TenantRepositoryError::ResourceNotFound {
+					resource_type: "network".to_string(),
+					resource_id: other.to_string(),
+				},
+			)),
+		});
+	let state = AppState::new(
+		MockMonitorService::new(),
+		network_service,
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let request = MonitorBatchPlanRequest {
+		monitors: vec![
+			planned("payments", &["stellar-mainnet"]),
+			planned("swaps", &["stellar-mainnet", "stellar-futurenet"]),
+		],
+	};
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Viewer),
+		handlers::plan_monitor_batch(State(state), Json(request)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let plan: MonitorBatchPlan = serde_json::from_value(body["data"].clone()).unwrap();
+
+	assert_eq!(plan.existing_networks.len(), 1);
+	assert_eq!(plan.existing_networks[0].network_id, "stellar-mainnet");
+	assert_eq!(plan.existing_networks[0].id, Some(existing_id));
+	assert_eq!(plan.existing_networks[0].monitor_ids, ["payments", "swaps"]);
+
+	assert_eq!(plan.missing_networks.len(), 1);
+	assert_eq!(plan.missing_networks[0].network_id, "stellar-futurenet");
+	assert_eq!(plan.missing_networks[0].id, None);
+	assert_eq!(plan.missing_networks[0].monitor_ids, ["swaps"]);
+}
@@ -0,0 +1,113 @@
+use axum::{
+	extract::{ConnectInfo, Path, Query, State},
+	http::{HeaderMap, StatusCode},
+	response::IntoResponse,
+};
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	repositories::TenantRepositoryError,
+	services::ServiceError,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::owner_context, fixtures::TestIds},
+};
+
+fn state_with(
+	monitor_service: MockMonitorService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn delete(monitor_service: MockMonitorService, force: bool) -> axum::response::Response {
+	let test_ids = TestIds::default();
+	with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::delete_monitor(
+			State(state_with(monitor_service)),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Path(("acme-corp".to_string(), "monitor-123".to_string())),
+			Query(handlers::DeleteQuery { force }),
+		)
+		.await
+		.into_response()
+	})
+	.await
+}
+
+#[tokio::test]
+async fn test_delete_monitor_with_triggers_conflicts_and_lists_them() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_delete_monitor()
+		.with(eq("monitor-123"), always())
+		.times(1)
+		.returning(|monitor_id, _| {
+			Err(ServiceError::Repository(
+				TenantRepositoryError::MonitorHasTriggers {
+					monitor_id: monitor_id.to_string(),
+					trigger_ids: vec!["trigger-1".to_string(), "trigger-2".to_string()],
+				},
+			))
+		});
+	monitor_service
+		.expect_delete_monitor_with_triggers()
+		.times(0);
+
+	// Act
+	let response = delete(monitor_service, false).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["code"], "MONITOR_HAS_TRIGGERS");
+	assert_eq!(body["details"]["trigger_count"], 2);
+	assert_eq!(
+		body["details"]["trigger_ids"],
+		serde_json::json!(["trigger-1", "trigger-2"])
+	);
+}
+
+#[tokio::test]
+async fn test_forced_delete_removes_triggers_with_monitor() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_delete_monitor().times(0);
+	monitor_service
+		.expect_delete_monitor_with_triggers()
+		.with(eq("monitor-123"), always())
+		.times(1)
+		.returning(|_, _| Ok(vec![]));
+
+	// Act
+	let response = delete(monitor_service, true).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
@@ -0,0 +1,127 @@
+use axum::{
+	extract::{ConnectInfo, Path, Query, State},
+	http::{HeaderMap, StatusCode},
+	response::IntoResponse,
+};
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::{DependentMonitor, NetworkDeletion},
+	repositories::TenantRepositoryError,
+	services::ServiceError,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{builders::NetworkBuilder, context::owner_context, fixtures::TestIds},
+};
+
+fn state_with(
+	network_service: MockNetworkService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		MockMonitorService::new(),
+		network_service,
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn delete(network_service: MockNetworkService, force: bool) -> axum::response::Response {
+	let test_ids = TestIds::default();
+	with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::delete_network(
+			State(state_with(network_service)),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Path(("acme-corp".to_string(), "stellar-mainnet".to_string())),
+			Query(handlers::DeleteQuery { force }),
+		)
+		.await
+		.into_response()
+	})
+	.await
+}
+
+#[tokio::test]
+async fn test_delete_network_with_monitors_conflicts_and_lists_them() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let monitor_uuid = test_ids.monitor_1;
+	let mut network_service = MockNetworkService::new();
+	network_service
+		.expect_delete_network()
+		.with(eq("stellar-mainnet"), always())
+		.times(1)
+		.returning(move |network_id, _| {
+			Err(ServiceError::Repository(
+				TenantRepositoryError::NetworkHasMonitors {
+					network_id: network_id.to_string(),
+					monitors: vec![DependentMonitor {
+						id: monitor_uuid,
+						monitor_id: "payments".to_string(),
+						name: "Payments".to_string(),
+						deleted: false,
+					}],
+				},
+			))
+		});
+	network_service
+		.expect_delete_network_with_monitors()
+		.times(0);
+
+	// Act
+	let response = delete(network_service, false).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["code"], "CONFLICT");
+	let monitors = body["details"]["monitors"].as_array().unwrap();
+	assert_eq!(monitors.len(), 1);
+	assert_eq!(monitors[0]["id"], monitor_uuid.to_string());
+	assert_eq!(monitors[0]["monitor_id"], "payments");
+	assert_eq!(monitors[0]["name"], "Payments");
+}
+
+#[tokio::test]
+async fn test_forced_network_delete_cascades() {
+	// Arrange
+	let mut network_service = MockNetworkService::new();
+	network_service.expect_delete_network().times(0);
+	network_service
+		.expect_delete_network_with_monitors()
+		.with(eq("stellar-mainnet"), always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(NetworkDeletion {
+				network: NetworkBuilder::new().build(),
+				monitors: vec![],
+				triggers: vec![],
+			})
+		});
+
+	// Act
+	let response = delete(network_service, true).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
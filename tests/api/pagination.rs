@@ -0,0 +1,184 @@
+use axum::{
+	extract::{Query, State},
+	http::StatusCode,
+	response::IntoResponse,
+};
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	services::TriggerService,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantMonitorRepository,
+		MockTenantRepository, MockTenantTriggerRepository,
+	},
+	utils::{builders::TriggerBuilder, context::owner_context, fixtures::TestIds},
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	TriggerService<
+		MockTenantTriggerRepository,
+		MockTenantMonitorRepository,
+		MockTenantRepository,
+		MockAuditService,
+	>,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+/// Triggers ordered newest first, the order both pagination styles use
+fn newest_first(triggers: &[TenantTrigger]) -> Vec<TenantTrigger> {
+	let mut sorted = triggers.to_vec();
+	sorted.sort_by_key(|trigger| std::cmp::Reverse((trigger.created_at, trigger.id)));
+	sorted
+}
+
+fn state_over(triggers: Arc<Vec<TenantTrigger>>) -> TestState {
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let listed = triggers.clone();
+	trigger_repo.expect_list().returning(move |limit, offset| {
+		Ok(newest_first(&listed)
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit as usize)
+			.collect())
+	});
+	let counted = triggers.clone();
+	trigger_repo
+		.expect_count()
+		.returning(move || Ok(counted.len() as i64));
+	trigger_repo
+		.expect_list_after()
+		.returning(move |cursor, limit| {
+			Ok(newest_first(&triggers)
+				.into_iter()
+				.filter(|t| match cursor {
+					Some(cursor) => (t.created_at.unwrap(), t.id) < (cursor.created_at, cursor.id),
+					None => true,
+				})
+				.take(limit as usize)
+				.collect())
+		});
+
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		TriggerService::new(
+			trigger_repo,
+			MockTenantMonitorRepository::new(),
+			MockTenantRepository::new(),
+			MockAuditService::new(),
+		),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn page_query(cursor: Option<String>) -> handlers::PaginationQuery {
+	handlers::PaginationQuery {
+		limit: Some(2),
+		offset: Some(0),
+		source: None,
+		include_deleted: None,
+		cursor,
+	}
+}
+
+#[tokio::test]
+async fn test_list_triggers_follows_cursor_to_last_page() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let now = chrono::Utc::now();
+	let triggers: Arc<Vec<TenantTrigger>> = Arc::new(
+		(0..5)
+			.map(|i| {
+				TriggerBuilder::new()
+					.with_tenant_id(test_ids.tenant_1)
+					.with_trigger_id(format!("trigger-{}", i))
+					.with_created_at(now - chrono::Duration::minutes(i))
+					.build()
+			})
+			.collect(),
+	);
+
+	// Act: the first page uses offset pagination, later pages the returned cursor
+	let pages = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		let mut pages = Vec::new();
+		let mut cursor = None;
+		loop {
+			let response = handlers::list_triggers(
+				State(state_over(triggers.clone())),
+				Query(page_query(cursor)),
+				Query(handlers::TriggerListQuery::default()),
+			)
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap();
+			let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+				.await
+				.unwrap();
+			let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+			cursor = body["meta"]["next_cursor"].as_str().map(str::to_string);
+			pages.push(body);
+			if cursor.is_none() {
+				break pages;
+			}
+		}
+	})
+	.await;
+
+	// Assert
+	let ids: Vec<Vec<&str>> = pages
+		.iter()
+		.map(|page| {
+			page["data"]
+				.as_array()
+				.unwrap()
+				.iter()
+				.map(|t| t["trigger_id"].as_str().unwrap())
+				.collect()
+		})
+		.collect();
+	assert_eq!(
+		ids,
+		vec![
+			vec!["trigger-0", "trigger-1"],
+			vec!["trigger-2", "trigger-3"],
+			vec!["trigger-4"],
+		]
+	);
+	assert_eq!(pages[0]["meta"]["total"], 5);
+	assert!(pages[1]["meta"]["total"].is_null());
+}
+
+#[tokio::test]
+async fn test_list_triggers_rejects_invalid_cursor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let state = state_over(Arc::new(Vec::new()));
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_triggers(
+			State(state),
+			Query(page_query(Some("not-a-cursor".to_string()))),
+			Query(handlers::TriggerListQuery::default()),
+		)
+		.await
+		.into_response()
+	})
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,268 @@
+use axum::{
+	extract::{ConnectInfo, Path, Query, State},
+	http::{HeaderMap, StatusCode},
+	response::{IntoResponse, Response},
+	Json,
+};
+use std::net::SocketAddr;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, middleware::api_key_permissions, routes::AppState},
+	models::*,
+	utils::{with_tenant_context, AuthService, TenantContext},
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::{CreateMonitorRequestBuilder, MonitorBuilder, UpdateMonitorRequestBuilder},
+		context::{api_key_context, user_context},
+		fixtures::TestIds,
+	},
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn state_with(monitor_service: MockMonitorService) -> TestState {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn read_only_context(tenant_id: Uuid) -> TenantContext {
+	api_key_context(tenant_id, Uuid::new_v4())
+		.with_permissions(vec![ApiPermission::read_only_monitors()])
+}
+
+fn addr() -> ConnectInfo<SocketAddr> {
+	ConnectInfo("127.0.0.1:8080".parse().unwrap())
+}
+
+async fn error_code(response: Response) -> String {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	body["code"].as_str().unwrap_or_default().to_string()
+}
+
+#[tokio::test]
+async fn test_read_only_key_can_get_monitor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_service = MockMonitorService::new();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.build();
+	monitor_service
+		.expect_get_monitor()
+		.times(1)
+		.returning(move |_| Ok(monitor.clone()));
+	let state = state_with(monitor_service);
+
+	// Act
+	let response = with_tenant_context(read_only_context(test_ids.tenant_1), async move {
+		handlers::get_monitor(
+			State(state),
+			Path(("acme-corp".to_string(), "monitor-123".to_string())),
+		)
+		.await
+		.into_response()
+	})
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_read_only_key_cannot_write_or_delete_monitors() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let context = read_only_context(test_ids.tenant_1);
+	let create = CreateMonitorRequestBuilder::new()
+		.with_network_id(test_ids.network_1)
+		.build();
+	let update = UpdateMonitorRequestBuilder::new().build();
+
+	// Act: the services have no expectations, so reaching them would panic
+	let responses = with_tenant_context(context, async move {
+		vec![
+			handlers::create_monitor(
+				State(state_with(MockMonitorService::new())),
+				addr(),
+				HeaderMap::new(),
+				Json(create),
+			)
+			.await
+			.into_response(),
+			handlers::update_monitor(
+				State(state_with(MockMonitorService::new())),
+				addr(),
+				HeaderMap::new(),
+				Path(("acme-corp".to_string(), "monitor-123".to_string())),
+				Json(update),
+			)
+			.await
+			.into_response(),
+			handlers::delete_monitor(
+				State(state_with(MockMonitorService::new())),
+				addr(),
+				HeaderMap::new(),
+				Path(("acme-corp".to_string(), "monitor-123".to_string())),
+				Query(handlers::DeleteQuery::default()),
+			)
+			.await
+			.into_response(),
+		]
+	})
+	.await;
+
+	// Assert
+	for response in responses {
+		assert_eq!(response.status(), StatusCode::FORBIDDEN);
+		assert_eq!(error_code(response).await, "INSUFFICIENT_PERMISSIONS");
+	}
+}
+
+#[test]
+fn test_permission_allows_only_listed_resource_and_actions() {
+	let permission = ApiPermission::read_only_monitors();
+
+	assert!(permission.allows("monitors", "read"));
+	assert!(!permission.allows("monitors", "write"));
+	assert!(!permission.allows("triggers", "read"));
+}
+
+#[test]
+fn test_keys_without_permissions_follow_default_full_access() {
+	let empty = Some(serde_json::json!([]));
+
+	assert!(api_key_permissions(empty.clone(), true).is_none());
+	assert!(api_key_permissions(None, true).is_none());
+	assert_eq!(api_key_permissions(empty, false).unwrap().len(), 0);
+}
+
+#[test]
+fn test_unreadable_permissions_grant_nothing() {
+	let unreadable = Some(serde_json::json!({ "monitors": "everything" }));
+
+	assert_eq!(api_key_permissions(unreadable, true).unwrap().len(), 0);
+
+	let scoped = api_key_permissions(
+		Some(serde_json::to_value(vec![ApiPermission::read_only_monitors()]).unwrap()),
+		true,
+	)
+	.unwrap();
+	assert_eq!(scoped.len(), 1);
+	assert!(scoped[0].allows("monitors", "read"));
+}
+
+#[tokio::test]
+async fn test_viewer_can_get_but_not_create_or_delete_monitors() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let context = user_context(test_ids.tenant_1, TenantRole::Viewer);
+	let mut monitor_service = MockMonitorService::new();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.build();
+	monitor_service
+		.expect_get_monitor()
+		.times(1)
+		.returning(move |_| Ok(monitor.clone()));
+	let create = CreateMonitorRequestBuilder::new()
+		.with_network_id(test_ids.network_1)
+		.build();
+
+	// Act
+	let (get, writes) = with_tenant_context(context, async move {
+		let get = handlers::get_monitor(
+			State(state_with(monitor_service)),
+			Path(("acme-corp".to_string(), "monitor-123".to_string())),
+		)
+		.await
+		.into_response();
+		let writes = vec![
+			handlers::create_monitor(
+				State(state_with(MockMonitorService::new())),
+				addr(),
+				HeaderMap::new(),
+				Json(create),
+			)
+			.await
+			.into_response(),
+			handlers::delete_monitor(
+				State(state_with(MockMonitorService::new())),
+				addr(),
+				HeaderMap::new(),
+				Path(("acme-corp".to_string(), "monitor-123".to_string())),
+				Query(handlers::DeleteQuery::default()),
+			)
+			.await
+			.into_response(),
+		];
+		(get, writes)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(get.status(), StatusCode::OK);
+	for response in writes {
+		assert_eq!(response.status(), StatusCode::FORBIDDEN);
+	}
+}
+
+#[tokio::test]
+async fn test_only_admins_and_owners_delete_monitors() {
+	for (role, expected) in [
+		(TenantRole::Owner, StatusCode::NO_CONTENT),
+		(TenantRole::Admin, StatusCode::NO_CONTENT),
+		(TenantRole::Member, StatusCode::FORBIDDEN),
+		(TenantRole::Viewer, StatusCode::FORBIDDEN),
+	] {
+		// Arrange
+		let test_ids = TestIds::default();
+		let mut monitor_service = MockMonitorService::new();
+		monitor_service
+			.expect_delete_monitor()
+			.times(usize::from(expected == StatusCode::NO_CONTENT))
+			.returning(|_, _| Ok(()));
+
+		// Act
+		let response = with_tenant_context(user_context(test_ids.tenant_1, role), async move {
+			handlers::delete_monitor(
+				State(state_with(monitor_service)),
+				addr(),
+				HeaderMap::new(),
+				Path(("acme-corp".to_string(), "monitor-123".to_string())),
+				Query(handlers::DeleteQuery::default()),
+			)
+			.await
+			.into_response()
+		})
+		.await;
+
+		// Assert
+		assert_eq!(response.status(), expected, "{:?}", role);
+	}
+}
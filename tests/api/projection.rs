@@ -0,0 +1,119 @@
+use axum::{
+	extract::{Query, State},
+	http::{StatusCode, Uri},
+	response::IntoResponse,
+};
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	config::ApiConfig,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{builders::MonitorBuilder, context::owner_context, fixtures::TestIds},
+};
+
+fn state(
+	monitor_service: MockMonitorService,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+	.with_api_config(ApiConfig {
+		max_projection_fields: 3,
+		..Default::default()
+	})
+}
+
+fn query<Q: serde::de::DeserializeOwned>(uri: &str) -> Query<Q> {
+	Query::try_from_uri(&uri.parse::<Uri>().unwrap()).unwrap()
+}
+
+async fn list_monitors(monitor_service: MockMonitorService, uri: &str) -> axum::response::Response {
+	let test_ids = TestIds::default();
+	with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		handlers::list_monitors(State(state(monitor_service)), query(uri), query(uri)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response)
+}
+
+#[tokio::test]
+async fn test_list_monitors_returns_only_projected_fields() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_list_monitors()
+		.times(1)
+		.returning(|_, _| Ok(vec![MonitorBuilder::new().with_name("Payments").build()]));
+	monitor_service
+		.expect_get_monitor_count()
+		.times(1)
+		.returning(|| Ok(1));
+
+	// Act
+	let response = list_monitors(monitor_service, "/monitors?fields=monitor_id,name").await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let monitor = body["data"][0].as_object().unwrap();
+	let mut keys: Vec<_> = monitor.keys().map(String::as_str).collect();
+	keys.sort();
+	assert_eq!(keys, vec!["monitor_id", "name"]);
+	assert_eq!(monitor["name"], "Payments");
+	assert_eq!(body["meta"]["total"], 1);
+}
+
+#[tokio::test]
+async fn test_list_monitors_rejects_projection_over_the_limit() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_list_monitors().never();
+
+	// Act
+	let response = list_monitors(
+		monitor_service,
+		"/monitors?fields=id,monitor_id,name,is_active",
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_monitors_rejects_unknown_projected_field() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_list_monitors().never();
+
+	// Act
+	let response = list_monitors(monitor_service, "/monitors?fields=name,password_hash").await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,193 @@
+use axum::{
+	body::Body,
+	extract::{ConnectInfo, Path, State},
+	http::{header, Method, Request, StatusCode},
+	middleware,
+	response::IntoResponse,
+	routing::get,
+	Router,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, middleware::current_tenant_auth_middleware, routes::AppState},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{TenantBuilder, UserBuilder},
+};
+
+/// Two monitors sharing three triggers unevenly: `busy` is at its limit of
+/// three while `idle` has none, yet the flat headroom still reports three
+fn mixed_usage_status(tenant_id: Uuid) -> ResourceQuotaStatus {
+	ResourceQuotaStatus::from_usage(
+		tenant_id,
+		TenantQuotas {
+			max_monitors: 10,
+			max_networks: 5,
+			max_triggers_per_monitor: 3,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		CurrentUsage {
+			monitors_count: 2,
+			networks_count: 1,
+			triggers_count: 3,
+			rpc_requests_last_minute: 0,
+			storage_mb_used: 0,
+		},
+		vec![],
+	)
+	.with_monitor_triggers([("busy".to_string(), 3), ("idle".to_string(), 0)])
+}
+
+type TestState = AppState<
+	Arc<MockMonitorService>,
+	Arc<MockNetworkService>,
+	Arc<MockTriggerService>,
+	Arc<MockTenantRepository>,
+	Arc<MockAuditService>,
+>;
+
+fn state(tenant_repo: MockTenantRepository) -> TestState {
+	// The lazy pool is never connected; background usage recording only logs its failure
+	AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_current_quota_reports_triggers_per_monitor() {
+	// Arrange
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	let membership = tenant.clone();
+	tenant_repo
+		.expect_get_user_tenants()
+		.returning(move |_| Ok(vec![(membership.clone(), TenantRole::Viewer)]));
+	tenant_repo
+		.expect_get_by_slug()
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role: TenantRole::Viewer,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+	tenant_repo
+		.expect_get_quota_status()
+		.withf(move |id| *id == tenant_id)
+		.returning(|id| Ok(mixed_usage_status(id)));
+
+	let state = state(tenant_repo);
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let current_tenant_routes = Router::new()
+		.route("/quota", get(handlers::get_quota_status))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			current_tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1", current_tenant_routes)
+		.with_state(state);
+	let mut request = Request::builder()
+		.method(Method::GET)
+		.uri("/api/v1/quota")
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.body(Body::empty())
+		.unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+
+	// Act
+	let response = app.oneshot(request).await.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = json_body(response).await;
+	assert_eq!(body["data"]["tenant_id"], tenant_id.to_string());
+	assert_eq!(body["data"]["available"]["triggers"], 3);
+	assert_eq!(
+		body["data"]["monitor_triggers"],
+		serde_json::json!({
+			"busy": { "triggers_count": 3, "max_triggers": 3, "available": 0 },
+			"idle": { "triggers_count": 0, "max_triggers": 3, "available": 3 },
+		})
+	);
+}
+
+#[tokio::test]
+async fn test_admin_quota_status_by_tenant_id() {
+	// Arrange
+	let tenant_id = Uuid::new_v4();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_quota_status()
+		.withf(move |id| *id == tenant_id)
+		.returning(|id| Ok(mixed_usage_status(id)));
+
+	// Act
+	let response = handlers::tenant_quota_status(State(state(tenant_repo)), Path(tenant_id))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = json_body(response).await;
+	assert_eq!(body["data"]["usage"]["triggers_count"], 3);
+	assert_eq!(body["data"]["monitor_triggers"]["busy"]["available"], 0);
+}
+
+#[tokio::test]
+async fn test_admin_quota_status_unknown_tenant_is_not_found() {
+	// Arrange
+	let tenant_id = Uuid::new_v4();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|id| Err(TenantRepositoryError::TenantNotFound(id)));
+
+	// Act
+	let response = handlers::tenant_quota_status(State(state(tenant_repo)), Path(tenant_id))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,178 @@
+use axum::{
+	body::Body,
+	http::{header, Request, StatusCode},
+	middleware,
+	routing::get,
+	Router,
+};
+use mockall::predicate::*;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use stellar_monitor_tenant_isolation::{
+	api::{
+		middleware::{rate_limit_middleware, TokenBucket},
+		routes::AppState,
+	},
+	utils::{with_tenant_context, AuthService},
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{builders::TenantBuilder, context::owner_context, fixtures::TestIds},
+};
+
+#[test]
+fn test_token_bucket_refills_over_time() {
+	// Arrange
+	let start = Instant::now();
+	let mut bucket = TokenBucket::new(60, start);
+	for _ in 0..60 {
+		bucket.try_acquire(60, start).unwrap();
+	}
+
+	// Act
+	let exhausted = bucket.try_acquire(60, start);
+	let refilled = bucket.try_acquire(60, start + Duration::from_secs(1));
+
+	// Assert
+	assert_eq!(exhausted, Err(Duration::from_secs(1)));
+	assert!(refilled.is_ok());
+}
+
+#[tokio::test]
+async fn test_rate_limited_tenant_gets_429_with_retry_after() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	// The limit is cached, so the tenant row is read once for all requests
+	tenant_repo
+		.expect_get()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(move |id| {
+			Ok(TenantBuilder::new()
+				.with_id(id)
+				.with_max_rpc_requests_per_minute(2)
+				.build())
+		});
+
+	let state = AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let app = Router::new()
+		.route("/monitors", get(|| async { "ok" }))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			rate_limit_middleware,
+		))
+		.with_state(state);
+
+	// Act
+	let mut statuses = Vec::new();
+	let mut last_response = None;
+	for _ in 0..3 {
+		let response = with_tenant_context(
+			owner_context(test_ids.tenant_1),
+			app.clone()
+				.oneshot(Request::get("/monitors").body(Body::empty()).unwrap()),
+		)
+		.await
+		.unwrap();
+		statuses.push(response.status());
+		last_response = Some(response);
+	}
+
+	// Assert
+	assert_eq!(
+		statuses,
+		vec![
+			StatusCode::OK,
+			StatusCode::OK,
+			StatusCode::TOO_MANY_REQUESTS
+		]
+	);
+	let response = last_response.unwrap();
+	assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["code"], "RATE_LIMITED");
+}
+
+#[tokio::test]
+async fn test_each_tenant_is_throttled_at_its_own_limit() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let limits = [(test_ids.tenant_1, 1), (test_ids.tenant_2, 3)];
+	let mut tenant_repo = MockTenantRepository::new();
+	for (tenant_id, limit) in limits {
+		tenant_repo
+			.expect_get()
+			.with(eq(tenant_id))
+			.times(1)
+			.returning(move |id| {
+				Ok(TenantBuilder::new()
+					.with_id(id)
+					.with_max_rpc_requests_per_minute(limit)
+					.build())
+			});
+	}
+
+	let state = AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let app = Router::new()
+		.route("/monitors", get(|| async { "ok" }))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			rate_limit_middleware,
+		))
+		.with_state(state);
+
+	// Act
+	let mut statuses = Vec::new();
+	for (tenant_id, _) in limits {
+		let mut tenant_statuses = Vec::new();
+		for _ in 0..2 {
+			let response = with_tenant_context(
+				owner_context(tenant_id),
+				app.clone()
+					.oneshot(Request::get("/monitors").body(Body::empty()).unwrap()),
+			)
+			.await
+			.unwrap();
+			tenant_statuses.push(response.status());
+		}
+		statuses.push(tenant_statuses);
+	}
+
+	// Assert
+	assert_eq!(
+		statuses,
+		[
+			vec![StatusCode::OK, StatusCode::TOO_MANY_REQUESTS],
+			vec![StatusCode::OK, StatusCode::OK],
+		]
+	);
+}
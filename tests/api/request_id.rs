@@ -0,0 +1,132 @@
+use axum::{
+	body::Body,
+	extract::{ConnectInfo, State},
+	http::{HeaderMap, Request, StatusCode},
+	middleware,
+	response::IntoResponse,
+	routing::get,
+	Json, Router,
+};
+use std::net::SocketAddr;
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers::{self, ApiError},
+		middleware::request_id_middleware,
+		routes::AppState,
+	},
+	utils::{with_request_id, with_tenant_context, AuthService, REQUEST_ID_HEADER},
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::{CreateMonitorRequestBuilder, MonitorBuilder},
+		context::owner_context,
+		fixtures::TestIds,
+	},
+};
+
+fn app() -> Router {
+	Router::new()
+		.route(
+			"/missing",
+			get(|| async { ApiError::NotFound.into_response() }),
+		)
+		.layer(middleware::from_fn(request_id_middleware))
+}
+
+async fn get_missing(request_id: Option<&str>) -> axum::response::Response {
+	let mut request = Request::builder().uri("/missing");
+	if let Some(request_id) = request_id {
+		request = request.header(REQUEST_ID_HEADER, request_id);
+	}
+	app()
+		.oneshot(request.body(Body::empty()).unwrap())
+		.await
+		.unwrap()
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_supplied_request_id_is_echoed_in_header_and_error_body() {
+	// Act
+	let response = get_missing(Some("support-42")).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+	assert_eq!(response.headers()[REQUEST_ID_HEADER], "support-42");
+	assert_eq!(json_body(response).await["request_id"], "support-42");
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_when_missing_or_malformed() {
+	for supplied in [None, Some("not a usable id")] {
+		// Act
+		let response = get_missing(supplied).await;
+
+		// Assert
+		let header = response.headers()[REQUEST_ID_HEADER]
+			.to_str()
+			.unwrap()
+			.to_string();
+		assert!(uuid::Uuid::parse_str(&header).is_ok(), "{:?}", supplied);
+		assert_eq!(json_body(response).await["request_id"], header);
+	}
+}
+
+#[tokio::test]
+async fn test_request_id_reaches_service_metadata() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_create_monitor()
+		.withf(|_, metadata| metadata.request_id.as_deref() == Some("req-7"))
+		.times(1)
+		.returning(|request, _| {
+			Ok(MonitorBuilder::new()
+				.with_monitor_id(request.monitor_id)
+				.build())
+		});
+	let state = AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_request_id(
+		"req-7".to_string(),
+		with_tenant_context(owner_context(test_ids.tenant_1), async move {
+			handlers::create_monitor(
+				State(state),
+				ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()),
+				HeaderMap::new(),
+				Json(CreateMonitorRequestBuilder::new().build()),
+			)
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap_or_else(IntoResponse::into_response)
+		}),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CREATED);
+}
@@ -0,0 +1,200 @@
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header, Method, Request, StatusCode},
+	middleware,
+	routing::post,
+	Router,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{auth, handlers, middleware::tenant_auth_middleware, routes::AppState},
+	models::*,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{CreateMonitorRequestBuilder, MonitorBuilder, TenantBuilder, UserBuilder},
+};
+
+/// Monitor and API key routes behind the real JWT middleware, for a user who
+/// holds `role` in the `acme-corp` tenant; returns the app and the user's token
+fn app_for(role: TenantRole, monitor_service: MockMonitorService) -> (Router, String) {
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: uuid::Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+
+	// The lazy pool is never connected; background usage recording only logs its failure
+	let state = AppState::new(
+		Arc::new(monitor_service),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = Router::new()
+		.route("/monitors", post(handlers::create_monitor))
+		.route(
+			"/api-keys",
+			post(auth::create_api_key).get(auth::list_api_keys),
+		)
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state);
+
+	(app, token)
+}
+
+fn request(
+	method: Method,
+	uri: &str,
+	token: &str,
+	body: Option<serde_json::Value>,
+) -> Request<Body> {
+	let mut request = Request::builder()
+		.method(method)
+		.uri(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.header(header::CONTENT_TYPE, "application/json")
+		.body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+		.unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+	request
+}
+
+async fn error_code(response: axum::response::Response) -> String {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	body["code"].as_str().unwrap_or_default().to_string()
+}
+
+#[tokio::test]
+async fn test_create_monitor_follows_jwt_role() {
+	for (role, expected) in [
+		(TenantRole::Owner, StatusCode::CREATED),
+		(TenantRole::Admin, StatusCode::CREATED),
+		(TenantRole::Member, StatusCode::CREATED),
+		(TenantRole::Viewer, StatusCode::FORBIDDEN),
+	] {
+		// Arrange
+		let mut monitor_service = MockMonitorService::new();
+		monitor_service
+			.expect_create_monitor()
+			.times(usize::from(expected == StatusCode::CREATED))
+			.returning(|request, _| {
+				Ok(MonitorBuilder::new()
+					.with_monitor_id(request.monitor_id)
+					.build())
+			});
+		let (app, token) = app_for(role, monitor_service);
+		let body = serde_json::to_value(CreateMonitorRequestBuilder::new().build()).unwrap();
+
+		// Act
+		let response = app
+			.oneshot(request(
+				Method::POST,
+				"/api/v1/tenants/acme-corp/monitors",
+				&token,
+				Some(body),
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status(), expected, "{:?}", role);
+		if expected == StatusCode::FORBIDDEN {
+			assert_eq!(error_code(response).await, "INSUFFICIENT_PERMISSIONS");
+		}
+	}
+}
+
+#[tokio::test]
+async fn test_create_api_key_requires_admin_or_owner() {
+	// max_uses of 0 is rejected right after the role check, before any key is stored
+	for (role, expected) in [
+		(TenantRole::Owner, StatusCode::BAD_REQUEST),
+		(TenantRole::Admin, StatusCode::BAD_REQUEST),
+		(TenantRole::Member, StatusCode::FORBIDDEN),
+		(TenantRole::Viewer, StatusCode::FORBIDDEN),
+	] {
+		// Arrange
+		let (app, token) = app_for(role, MockMonitorService::new());
+		let body = serde_json::json!({
+			"name": "ci",
+			"permissions": [],
+			"expires_at": null,
+			"max_uses": 0,
+		});
+
+		// Act
+		let response = app
+			.oneshot(request(
+				Method::POST,
+				"/api/v1/tenants/acme-corp/api-keys",
+				&token,
+				Some(body),
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status(), expected, "{:?}", role);
+	}
+}
+
+#[tokio::test]
+async fn test_list_api_keys_is_forbidden_below_admin() {
+	for role in [TenantRole::Member, TenantRole::Viewer] {
+		// Arrange
+		let (app, token) = app_for(role, MockMonitorService::new());
+
+		// Act
+		let response = app
+			.oneshot(request(
+				Method::GET,
+				"/api/v1/tenants/acme-corp/api-keys",
+				&token,
+				None,
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status(), StatusCode::FORBIDDEN, "{:?}", role);
+	}
+}
@@ -0,0 +1,69 @@
+use axum::{
+	body::Body,
+	http::{Request, StatusCode},
+	middleware,
+	routing::get,
+	Router,
+};
+use mockall::predicate::*;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{middleware::rpc_quota_middleware, routes::AppState},
+	utils::{with_tenant_context, AuthService},
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::owner_context, fixtures::TestIds},
+};
+
+#[tokio::test]
+async fn test_rpc_quota_exhausted_returns_429() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_check_quota()
+		.with(eq(test_ids.tenant_1), eq("rpc_requests"), eq(1))
+		.times(1)
+		.returning(|_, _, _| Ok(false));
+
+	let state = AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let app = Router::new()
+		.route("/monitors", get(|| async { "ok" }))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			rpc_quota_middleware,
+		))
+		.with_state(state);
+
+	// Act
+	let response = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		app.oneshot(Request::get("/monitors").body(Body::empty()).unwrap()),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["code"], "QUOTA_EXCEEDED");
+}
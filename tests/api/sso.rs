@@ -0,0 +1,211 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{
+	api::{auth, routes::AppState},
+	config::SsoConfig,
+	models::*,
+	utils::{AuthService, SsoVerifier},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{TenantBuilder, UserBuilder},
+};
+
+const ISSUER: &str = "https://idp.example.com";
+const IDP_SECRET: &str = "idp-secret";
+
+fn id_token(subject: &str, email: &str) -> String {
+	encode(
+		&Header::default(),
+		&serde_json::json!({
+			"iss": ISSUER,
+			"aud": "stellar-monitor",
+			"sub": subject,
+			"email": email,
+			"email_verified": true,
+			"name": "Jane Doe",
+			"exp": (Utc::now() + Duration::minutes(5)).timestamp(),
+		}),
+		&EncodingKey::from_secret(IDP_SECRET.as_bytes()),
+	)
+	.unwrap()
+}
+
+fn sso_state(
+	tenant_repo: MockTenantRepository,
+) -> AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let sso = SsoVerifier::new(&SsoConfig {
+		issuer: ISSUER.to_string(),
+		audience: "stellar-monitor".to_string(),
+		algorithm: "HS256".to_string(),
+		verification_key: IDP_SECRET.to_string(),
+		provision_tenants: true,
+	})
+	.unwrap();
+
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()).with_sso(Some(sso)),
+	)
+}
+
+async fn into_json(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_sso_provision_creates_user_and_tenant() {
+	// Arrange
+	let user = UserBuilder::new()
+		.with_email("jane@example.com")
+		.with_password_hash("")
+		.build();
+	let user_id = user.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_find_user_by_identity()
+		.with(eq(ISSUER), eq("idp-user-1"))
+		.times(1)
+		.returning(|_, _| Ok(None));
+	tenant_repo
+		.expect_link_identity()
+		.withf(|identity| identity.subject == "idp-user-1" && identity.email == "jane@example.com")
+		.times(1)
+		.returning(move |_| Ok(user.clone()));
+	tenant_repo
+		.expect_get_user_tenants()
+		.with(eq(user_id))
+		.times(1)
+		.returning(|_| Ok(vec![]));
+	tenant_repo
+		.expect_create_with_owner()
+		.withf(move |request, owner_id| {
+			request.name == "Jane Doe" && request.slug.is_empty() && *owner_id == user_id
+		})
+		.times(1)
+		.returning(|request, _| {
+			Ok(TenantBuilder::new()
+				.with_name(request.name)
+				.with_slug("jane-doe")
+				.build())
+		});
+
+	let request = SsoProvisionRequest {
+		id_token: id_token("idp-user-1", "jane@example.com"),
+		tenant_name: None,
+		tenant_slug: None,
+	};
+
+	// Act
+	let response = auth::sso_provision(State(sso_state(tenant_repo)), Json(request))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CREATED);
+	let body = into_json(response).await;
+	assert_eq!(body["data"]["tenant_created"], true);
+	assert_eq!(body["data"]["user"]["email"], "jane@example.com");
+	assert_eq!(body["data"]["tenants"][0]["tenant_slug"], "jane-doe");
+	assert_eq!(body["data"]["tenants"][0]["role"], "owner");
+	assert!(body["data"]["access_token"].is_string());
+}
+
+#[tokio::test]
+async fn test_sso_provision_links_existing_user_without_new_tenant() {
+	// Arrange
+	let user = UserBuilder::new().with_email("jane@example.com").build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_find_user_by_identity()
+		.times(1)
+		.returning(move |_, _| Ok(Some(user.clone())));
+	tenant_repo.expect_link_identity().times(0);
+	tenant_repo
+		.expect_get_user_tenants()
+		.with(eq(user_id))
+		.times(1)
+		.returning(move |_| Ok(vec![(tenant.clone(), TenantRole::Admin)]));
+	tenant_repo.expect_create_with_owner().times(0);
+
+	let request = SsoProvisionRequest {
+		id_token: id_token("idp-user-1", "jane@example.com"),
+		tenant_name: Some("Another Tenant".to_string()),
+		tenant_slug: None,
+	};
+
+	// Act
+	let response = auth::sso_provision(State(sso_state(tenant_repo)), Json(request))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = into_json(response).await;
+	assert_eq!(body["data"]["tenant_created"], false);
+	assert_eq!(body["data"]["tenants"].as_array().unwrap().len(), 1);
+	assert_eq!(body["data"]["tenants"][0]["tenant_slug"], "acme-corp");
+}
+
+#[tokio::test]
+async fn test_sso_provision_rejects_token_from_other_issuer() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo.expect_find_user_by_identity().times(0);
+
+	let token = encode(
+		&Header::default(),
+		&serde_json::json!({
+			"iss": "https://evil.example.com",
+			"aud": "stellar-monitor",
+			"sub": "idp-user-1",
+			"email": "jane@example.com",
+			"exp": (Utc::now() + Duration::minutes(5)).timestamp(),
+		}),
+		&EncodingKey::from_secret(IDP_SECRET.as_bytes()),
+	)
+	.unwrap();
+	let request = SsoProvisionRequest {
+		id_token: token,
+		tenant_name: None,
+		tenant_slug: None,
+	};
+
+	// Act
+	let result = auth::sso_provision(State(sso_state(tenant_repo)), Json(request)).await;
+
+	// Assert
+	assert!(result.is_err());
+	assert_eq!(
+		result.err().unwrap().into_response().status(),
+		StatusCode::UNAUTHORIZED
+	);
+}
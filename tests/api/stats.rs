@@ -0,0 +1,381 @@
+use axum::{
+	extract::{Query, State},
+	http::StatusCode,
+	response::IntoResponse,
+};
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	utils::{with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::{MonitorBuilder, NetworkBuilder, TriggerBuilder},
+		context::owner_context,
+		fixtures::TestIds,
+	},
+};
+
+#[tokio::test]
+async fn test_resource_stats_count_tenant_resources() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let monitors: Arc<Vec<TenantMonitor>> = Arc::new(vec![
+		MonitorBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		MonitorBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		MonitorBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_deleted_at(chrono::Utc::now())
+			.build(),
+		MonitorBuilder::new()
+			.with_tenant_id(test_ids.tenant_2)
+			.build(),
+	]);
+	let networks: Arc<Vec<TenantNetwork>> = Arc::new(vec![
+		NetworkBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		NetworkBuilder::new()
+			.with_tenant_id(test_ids.tenant_2)
+			.build(),
+	]);
+	let triggers: Arc<Vec<TenantTrigger>> = Arc::new(vec![
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_2)
+			.build(),
+	]);
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_resource_counts()
+		.times(1)
+		.returning(move |tenant_id| {
+			Ok(ResourceCounts {
+				monitors: monitors
+					.iter()
+					.filter(|m| m.tenant_id == tenant_id && m.deleted_at.is_none())
+					.count() as i64,
+				networks: networks.iter().filter(|n| n.tenant_id == tenant_id).count() as i64,
+				triggers: triggers.iter().filter(|t| t.tenant_id == tenant_id).count() as i64,
+			})
+		});
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::get_resource_stats(State(state))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(
+		body["data"],
+		serde_json::json!({ "monitors": 2, "networks": 1, "triggers": 3 })
+	);
+}
+
+#[tokio::test]
+async fn test_quota_status_reports_warnings() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_quota_status()
+		.times(1)
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 5,
+					max_triggers_per_monitor: 10,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: 9,
+					networks_count: 1,
+					triggers_count: 2,
+					rpc_requests_last_minute: 10,
+					storage_mb_used: 100,
+				},
+				vec![],
+			))
+		});
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::get_quota_status(State(state))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["data"]["available"]["monitors"], 1);
+	assert_eq!(
+		body["data"]["warnings"],
+		serde_json::json!([
+			{ "resource": "monitors", "usage": 9, "limit": 10, "percent_used": 90 }
+		])
+	);
+}
+
+#[tokio::test]
+async fn test_quota_check_batch_reports_each_resource() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_check_quota_batch()
+		.times(1)
+		.returning(|tenant_id, checks| {
+			// Near the limit: one monitor left, no networks, two triggers
+			let status = ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 5,
+					max_triggers_per_monitor: 3,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: 9,
+					networks_count: 5,
+					triggers_count: 25,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			);
+			Ok(status.check_all(checks).unwrap())
+		});
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let request = BatchQuotaCheckRequest {
+		checks: vec![
+			QuotaCheck {
+				resource: "monitors".to_string(),
+				amount: 1,
+			},
+			QuotaCheck {
+				resource: "networks".to_string(),
+				amount: 1,
+			},
+			QuotaCheck {
+				resource: "triggers".to_string(),
+				amount: 3,
+			},
+		],
+	};
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::check_quota_batch(State(state), axum::Json(request))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(
+		body["data"],
+		serde_json::json!([
+			{ "resource": "monitors", "requested": 1, "available": 1, "allowed": true },
+			{ "resource": "networks", "requested": 1, "available": 0, "allowed": false },
+			{ "resource": "triggers", "requested": 3, "available": 2, "allowed": false }
+		])
+	);
+}
+
+fn near_limit_status(tenant_id: uuid::Uuid) -> ResourceQuotaStatus {
+	ResourceQuotaStatus::from_usage(
+		tenant_id,
+		TenantQuotas {
+			max_monitors: 10,
+			max_networks: 5,
+			max_triggers_per_monitor: 3,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		CurrentUsage {
+			monitors_count: 9,
+			networks_count: 5,
+			triggers_count: 25,
+			rpc_requests_last_minute: 0,
+			storage_mb_used: 0,
+		},
+		vec![],
+	)
+}
+
+fn quota_check_state() -> TestState {
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_check_quota()
+		.returning(|tenant_id, resource, amount| {
+			near_limit_status(tenant_id)
+				.available_for(resource)
+				.map(|available| available >= amount)
+				.ok_or_else(|| {
+					TenantRepositoryError::InvalidConfiguration(format!(
+						"Unknown resource type: {}",
+						resource
+					))
+				})
+		});
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(near_limit_status(tenant_id)));
+
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+async fn check_quota(resource: &str, amount: i32) -> axum::response::Response {
+	let test_ids = TestIds::default();
+	let query = QuotaCheckQuery {
+		resource: resource.to_string(),
+		amount,
+	};
+
+	with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::check_quota(State(quota_check_state()), Query(query))
+			.await
+			.map(IntoResponse::into_response)
+			.unwrap_or_else(IntoResponse::into_response)
+	})
+	.await
+}
+
+#[tokio::test]
+async fn test_quota_check_reports_allowed_available_and_limit() {
+	for (resource, amount, expected) in [
+		(
+			"monitors",
+			1,
+			serde_json::json!({ "allowed": true, "available": 1, "limit": 10 }),
+		),
+		(
+			"networks",
+			1,
+			serde_json::json!({ "allowed": false, "available": 0, "limit": 5 }),
+		),
+		(
+			"triggers",
+			3,
+			serde_json::json!({ "allowed": false, "available": 2, "limit": 27 }),
+		),
+	] {
+		// Act
+		let response = check_quota(resource, amount).await;
+
+		// Assert
+		assert_eq!(response.status(), StatusCode::OK, "{}", resource);
+		let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+			.await
+			.unwrap();
+		let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+		assert_eq!(body["data"], expected, "{}", resource);
+	}
+}
+
+#[tokio::test]
+async fn test_quota_check_rejects_unknown_resource() {
+	// Act
+	let response = check_quota("compute_minutes", 1).await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
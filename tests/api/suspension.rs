@@ -0,0 +1,204 @@
+use axum::{
+	body::Body,
+	extract::{ConnectInfo, Path, State},
+	http::{header, HeaderMap, Request, StatusCode},
+	middleware,
+	response::IntoResponse,
+	routing::get,
+	Router,
+};
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, middleware::tenant_auth_middleware, routes::AppState},
+	models::*,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{TenantBuilder, UserBuilder},
+};
+
+type TestState = AppState<
+	Arc<MockMonitorService>,
+	Arc<MockNetworkService>,
+	Arc<MockTriggerService>,
+	Arc<MockTenantRepository>,
+	Arc<MockAuditService>,
+>;
+
+fn state_with(tenant_repo: MockTenantRepository, audit_service: MockAuditService) -> TestState {
+	// The lazy pool is never connected; background usage recording only logs its failure
+	AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(audit_service),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn get_request(uri: &str, token: &str) -> Request<Body> {
+	Request::get(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.body(Body::empty())
+		.unwrap()
+}
+
+async fn error_code(response: axum::response::Response) -> String {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	body["code"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_suspension_mid_session_blocks_monitor_requests() {
+	// Arrange
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+	let active = Arc::new(AtomicBool::new(true));
+
+	let mut tenant_repo = MockTenantRepository::new();
+	let tenant_active = active.clone();
+	tenant_repo.expect_get_by_slug().returning(move |_| {
+		let mut tenant = tenant.clone();
+		tenant.is_active = tenant_active.load(Ordering::SeqCst);
+		Ok(tenant)
+	});
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: uuid::Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role: TenantRole::Owner,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+
+	let state = state_with(tenant_repo, MockAuditService::new());
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = Router::new()
+		.route("/monitors", get(|| async { "monitors" }))
+		.route("/quota", get(|| async { "quota" }))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state);
+
+	// Act
+	let before = app
+		.clone()
+		.oneshot(get_request("/api/v1/tenants/acme-corp/monitors", &token))
+		.await
+		.unwrap();
+	active.store(false, Ordering::SeqCst);
+	let after = app
+		.clone()
+		.oneshot(get_request("/api/v1/tenants/acme-corp/monitors", &token))
+		.await
+		.unwrap();
+	let quota = app
+		.oneshot(get_request("/api/v1/tenants/acme-corp/quota", &token))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(before.status(), StatusCode::OK);
+	assert_eq!(after.status(), StatusCode::FORBIDDEN);
+	assert_eq!(error_code(after).await, "TENANT_SUSPENDED");
+	assert_eq!(quota.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_suspend_tenant_logs_audit_entry() {
+	// Arrange
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.times(1)
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo
+		.expect_update()
+		.withf(move |id, request| *id == tenant_id && request.is_active == Some(false))
+		.times(1)
+		.returning(move |_, _| {
+			Ok(TenantBuilder::new()
+				.with_id(tenant_id)
+				.with_active(false)
+				.build())
+		});
+
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_log()
+		.withf(move |request| {
+			matches!(request.action, AuditAction::TenantSuspended)
+				&& request.resource_id == Some(tenant_id)
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+
+	// Act
+	let response = handlers::suspend_tenant(
+		State(state_with(tenant_repo, audit_service)),
+		Path("acme-corp".to_string()),
+		ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+		HeaderMap::new(),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_reactivating_active_tenant_is_not_audited() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.times(1)
+		.returning(|_| Ok(TenantBuilder::new().with_slug("acme-corp").build()));
+	tenant_repo.expect_update().never();
+
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().never();
+
+	// Act
+	let response = handlers::reactivate_tenant(
+		State(state_with(tenant_repo, audit_service)),
+		Path("acme-corp".to_string()),
+		ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+		HeaderMap::new(),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
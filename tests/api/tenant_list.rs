@@ -0,0 +1,125 @@
+use axum::{
+	extract::{Query, State},
+	response::IntoResponse,
+};
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers::{self, TenantListQuery},
+		routes::AppState,
+	},
+	models::*,
+	utils::AuthService,
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::TenantBuilder,
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn state_with(tenant_repo: MockTenantRepository) -> TestState {
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+async fn into_json(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_list_tenants_passes_filters_and_reports_total() {
+	// Arrange
+	let expected = TenantFilter {
+		name_contains: Some("acme".to_string()),
+		is_active: Some(true),
+		slug_prefix: None,
+	};
+	let mut tenant_repo = MockTenantRepository::new();
+	let count_filter = expected.clone();
+	tenant_repo
+		.expect_count_filtered()
+		.withf(move |filter| *filter == count_filter)
+		.times(1)
+		.returning(|_| Ok(7));
+	let list_filter = expected.clone();
+	tenant_repo
+		.expect_list_filtered()
+		.withf(move |filter, limit, offset| *filter == list_filter && *limit == 2 && *offset == 4)
+		.times(1)
+		.returning(|_, _, _| {
+			Ok(vec![
+				TenantBuilder::new().with_name("Acme Corp").build(),
+				TenantBuilder::new().with_name("ACME Labs").build(),
+			])
+		});
+	let query = TenantListQuery {
+		search: Some("acme".to_string()),
+		active: Some(true),
+		limit: Some(2),
+		offset: Some(4),
+		..Default::default()
+	};
+
+	// Act
+	let response = handlers::list_tenants(State(state_with(tenant_repo)), Query(query))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap();
+
+	// Assert
+	let body = into_json(response).await;
+	assert_eq!(body["data"].as_array().unwrap().len(), 2);
+	assert_eq!(body["meta"]["total"], 7);
+	assert_eq!(body["meta"]["limit"], 2);
+	assert_eq!(body["meta"]["offset"], 4);
+}
+
+#[tokio::test]
+async fn test_list_tenants_clamps_page_size() {
+	// Arrange
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo.expect_count_filtered().returning(|_| Ok(0));
+	tenant_repo
+		.expect_list_filtered()
+		.withf(|filter, limit, offset| {
+			*filter == TenantFilter::default() && *limit == 100 && *offset == 0
+		})
+		.times(1)
+		.returning(|_, _, _| Ok(vec![]));
+	let query = TenantListQuery {
+		limit: Some(10_000),
+		offset: Some(-3),
+		..Default::default()
+	};
+
+	// Act
+	let response = handlers::list_tenants(State(state_with(tenant_repo)), Query(query))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap();
+
+	// Assert
+	assert_eq!(into_json(response).await["meta"]["limit"], 100);
+}
@@ -0,0 +1,357 @@
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header, Method, Request, StatusCode},
+	middleware, Router,
+};
+use mockall::predicate::*;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use stellar_monitor_tenant_isolation::{
+	api::{
+		middleware::tenant_auth_middleware,
+		routes::{tenant_resource_routes, AppState},
+	},
+	models::*,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{MonitorBuilder, NetworkBuilder, TenantBuilder, TriggerBuilder, UserBuilder},
+};
+
+/// The tenant resource routes of the real router behind tenant authentication,
+/// for an owner of `acme-corp`; returns the app and the owner's token
+fn app(
+	monitor_service: MockMonitorService,
+	network_service: MockNetworkService,
+	trigger_service: MockTriggerService,
+) -> (Router, String) {
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenant = TenantBuilder::new().with_slug("acme-corp").build();
+	let tenant_id = tenant.id;
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_by_slug()
+		.returning(move |_| Ok(tenant.clone()));
+	tenant_repo.expect_get_members().returning(move |_| {
+		Ok(vec![TenantMembership {
+			id: uuid::Uuid::new_v4(),
+			tenant_id,
+			user_id,
+			role: TenantRole::Owner,
+			created_at: None,
+			updated_at: None,
+		}])
+	});
+
+	// Only the background usage count touches the pool, and it fails fast
+	let state = AppState::new(
+		Arc::new(monitor_service),
+		Arc::new(network_service),
+		Arc::new(trigger_service),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.acquire_timeout(Duration::from_millis(100))
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = tenant_resource_routes(&state).layer(middleware::from_fn_with_state(
+		state.clone(),
+		tenant_auth_middleware,
+	));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state);
+
+	(app, token)
+}
+
+fn request(method: Method, uri: &str, token: &str, body: Body) -> Request<Body> {
+	let mut request = Request::builder()
+		.method(method)
+		.uri(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.header(header::CONTENT_TYPE, "application/json")
+		.body(body)
+		.unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+	request
+}
+
+#[tokio::test]
+async fn test_restore_monitor_route_extracts_monitor_id() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_restore_monitor()
+		.with(eq("monitor-123"), always())
+		.times(1)
+		.returning(|_, _| Ok(MonitorBuilder::new().build()));
+	let (app, token) = app(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+	);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::POST,
+			"/api/v1/tenants/acme-corp/monitors/monitor-123/restore",
+			&token,
+			Body::empty(),
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_deactivate_monitor_route_extracts_monitor_id() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_deactivate_with_triggers()
+		.with(eq("monitor-123"), always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(MonitorDeactivation {
+				monitor: MonitorBuilder::new().with_active(false).build(),
+				triggers: vec![],
+			})
+		});
+	let (app, token) = app(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+	);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::POST,
+			"/api/v1/tenants/acme-corp/monitors/monitor-123/deactivate",
+			&token,
+			Body::empty(),
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_list_triggers_by_monitor_route_extracts_monitor_id() {
+	// Arrange
+	let monitor_id = uuid::Uuid::new_v4();
+	let mut trigger_service = MockTriggerService::new();
+	trigger_service
+		.expect_list_triggers_by_monitor()
+		.with(eq(monitor_id), eq(20), eq(0))
+		.times(1)
+		.returning(|_, _, _| Ok(vec![]));
+	trigger_service
+		.expect_count_by_monitor()
+		.with(eq(monitor_id))
+		.times(1)
+		.returning(|_| Ok(0));
+	let (app, token) = app(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		trigger_service,
+	);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::GET,
+			&format!("/api/v1/tenants/acme-corp/monitors/{}/triggers", monitor_id),
+			&token,
+			Body::empty(),
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_monitor_routes_extract_monitor_id() {
+	// Arrange
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_get_monitor()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(|_| Ok(MonitorBuilder::new().build()));
+	monitor_service
+		.expect_update_monitor()
+		.withf(|monitor_id, request, _| {
+			monitor_id == "monitor-123" && request.name.as_deref() == Some("Renamed")
+		})
+		.times(1)
+		.returning(|_, _, _| Ok(MonitorBuilder::new().with_name("Renamed").build()));
+	monitor_service
+		.expect_delete_monitor()
+		.with(eq("monitor-123"), always())
+		.times(1)
+		.returning(|_, _| Ok(()));
+	let (app, token) = app(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+	);
+	let uri = "/api/v1/tenants/acme-corp/monitors/monitor-123";
+
+	// Act
+	let get = app
+		.clone()
+		.oneshot(request(Method::GET, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+	let update = app
+		.clone()
+		.oneshot(request(
+			Method::PUT,
+			uri,
+			&token,
+			Body::from(r#"{"name":"Renamed"}"#),
+		))
+		.await
+		.unwrap();
+	let delete = app
+		.oneshot(request(Method::DELETE, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(get.status(), StatusCode::OK);
+	assert_eq!(update.status(), StatusCode::OK);
+	assert_eq!(delete.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_network_routes_extract_network_id() {
+	// Arrange
+	let mut network_service = MockNetworkService::new();
+	network_service
+		.expect_get_network()
+		.with(eq("stellar-mainnet"))
+		.times(1)
+		.returning(|_| Ok(NetworkBuilder::new().build()));
+	network_service
+		.expect_update_network()
+		.withf(|network_id, request, _| {
+			network_id == "stellar-mainnet" && request.name.as_deref() == Some("Renamed")
+		})
+		.times(1)
+		.returning(|_, _, _| Ok(NetworkBuilder::new().with_name("Renamed").build()));
+	network_service
+		.expect_delete_network()
+		.with(eq("stellar-mainnet"), always())
+		.times(1)
+		.returning(|_, _| Ok(()));
+	let (app, token) = app(
+		MockMonitorService::new(),
+		network_service,
+		MockTriggerService::new(),
+	);
+	let uri = "/api/v1/tenants/acme-corp/networks/stellar-mainnet";
+
+	// Act
+	let get = app
+		.clone()
+		.oneshot(request(Method::GET, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+	let update = app
+		.clone()
+		.oneshot(request(
+			Method::PUT,
+			uri,
+			&token,
+			Body::from(r#"{"name":"Renamed"}"#),
+		))
+		.await
+		.unwrap();
+	let delete = app
+		.oneshot(request(Method::DELETE, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(get.status(), StatusCode::OK);
+	assert_eq!(update.status(), StatusCode::OK);
+	assert_eq!(delete.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_trigger_routes_extract_trigger_id() {
+	// Arrange
+	let mut trigger_service = MockTriggerService::new();
+	trigger_service
+		.expect_get_trigger()
+		.with(eq("trigger-123"))
+		.times(1)
+		.returning(|_| Ok(TriggerBuilder::new().build()));
+	trigger_service
+		.expect_update_trigger()
+		.withf(|trigger_id, request, _| {
+			trigger_id == "trigger-123" && request.name.as_deref() == Some("Renamed")
+		})
+		.times(1)
+		.returning(|_, _, _| Ok(TriggerBuilder::new().with_name("Renamed").build()));
+	trigger_service
+		.expect_delete_trigger()
+		.with(eq("trigger-123"), always())
+		.times(1)
+		.returning(|_, _| Ok(()));
+	let (app, token) = app(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		trigger_service,
+	);
+	let uri = "/api/v1/tenants/acme-corp/triggers/trigger-123";
+
+	// Act
+	let get = app
+		.clone()
+		.oneshot(request(Method::GET, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+	let update = app
+		.clone()
+		.oneshot(request(
+			Method::PUT,
+			uri,
+			&token,
+			Body::from(r#"{"name":"Renamed"}"#),
+		))
+		.await
+		.unwrap();
+	let delete = app
+		.oneshot(request(Method::DELETE, uri, &token, Body::empty()))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(get.status(), StatusCode::OK);
+	assert_eq!(update.status(), StatusCode::OK);
+	assert_eq!(delete.status(), StatusCode::NO_CONTENT);
+}
@@ -0,0 +1,262 @@
+use axum::{
+	body::Body,
+	extract::ConnectInfo,
+	http::{header, Method, Request, StatusCode},
+	middleware,
+	routing::{get, post},
+	Router,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{
+		handlers,
+		middleware::{tenant_auth_middleware, TENANT_ID_HEADER, TENANT_SLUG_HEADER},
+		routes::AppState,
+	},
+	models::*,
+	repositories::error::TenantRepositoryError,
+	utils::AuthService,
+};
+use tower::ServiceExt;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::builders::{CreateMonitorRequestBuilder, MonitorBuilder, TenantBuilder, UserBuilder},
+};
+
+/// Monitor routes behind the real JWT middleware, for a user who holds each
+/// listed role; `initech` exists but the user is not a member of it
+fn app_for(memberships: &[(&str, TenantRole)]) -> (Router, String, Vec<Tenant>) {
+	let user = UserBuilder::new().build();
+	let user_id = user.id;
+	let tenants: Vec<Tenant> = memberships
+		.iter()
+		.map(|(slug, _)| *slug)
+		.chain(["initech"])
+		.map(|slug| TenantBuilder::new().with_slug(slug).build())
+		.collect();
+	let user_tenants: Vec<(Tenant, TenantRole)> = tenants
+		.iter()
+		.cloned()
+		.zip(memberships.iter().map(|(_, role)| *role))
+		.collect();
+
+	let mut tenant_repo = MockTenantRepository::new();
+	let known = tenants.clone();
+	tenant_repo.expect_get_by_slug().returning(move |slug| {
+		known
+			.iter()
+			.find(|tenant| tenant.slug == slug)
+			.cloned()
+			.ok_or_else(|| TenantRepositoryError::ResourceNotFound {
+				resource_type: "tenant".to_string(),
+				resource_id: slug.to_string(),
+			})
+	});
+	let known = tenants.clone();
+	tenant_repo.expect_get().returning(move |id| {
+		known
+			.iter()
+			.find(|tenant| tenant.id == id)
+			.cloned()
+			.ok_or(TenantRepositoryError::TenantNotFound(id))
+	});
+	let members = user_tenants.clone();
+	tenant_repo
+		.expect_get_members()
+		.returning(move |tenant_id| {
+			Ok(members
+				.iter()
+				.filter(|(tenant, _)| tenant.id == tenant_id)
+				.map(|(tenant, role)| TenantMembership {
+					id: uuid::Uuid::new_v4(),
+					tenant_id: tenant.id,
+					user_id,
+					role: *role,
+					created_at: None,
+					updated_at: None,
+				})
+				.collect())
+		});
+	tenant_repo
+		.expect_get_user_tenants()
+		.returning(move |_| Ok(user_tenants.clone()));
+
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service
+		.expect_list_monitors_by_source()
+		.returning(|_, _, _| Ok(vec![]));
+	monitor_service
+		.expect_create_monitor()
+		.returning(|request, _| {
+			Ok(MonitorBuilder::new()
+				.with_monitor_id(request.monitor_id)
+				.build())
+		});
+
+	// The lazy pool is never connected; background usage recording only logs its failure
+	let state = AppState::new(
+		Arc::new(monitor_service),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(tenant_repo),
+		Arc::new(MockAuditService::new()),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let token = state.auth_service.generate_jwt(&user).unwrap();
+	let tenant_routes = Router::new()
+		.route("/monitors", get(handlers::list_monitors))
+		.route("/monitors", post(handlers::create_monitor))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	let app = Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state);
+
+	(app, token, tenants)
+}
+
+fn request(
+	method: Method,
+	uri: &str,
+	token: &str,
+	selection: Option<(&str, &str)>,
+) -> Request<Body> {
+	let mut builder = Request::builder()
+		.method(method.clone())
+		.uri(uri)
+		.header(header::AUTHORIZATION, format!("Bearer {}", token))
+		.header(header::CONTENT_TYPE, "application/json");
+	if let Some((name, value)) = selection {
+		builder = builder.header(name, value);
+	}
+	let body = if method == Method::POST {
+		Body::from(serde_json::to_string(&CreateMonitorRequestBuilder::new().build()).unwrap())
+	} else {
+		Body::empty()
+	};
+	let mut request = builder.body(body).unwrap();
+	request
+		.extensions_mut()
+		.insert(ConnectInfo("127.0.0.1:8080".parse::<SocketAddr>().unwrap()));
+	request
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_current_tenant_defaults_to_only_membership() {
+	// Arrange
+	let (app, token, _) = app_for(&[("acme-corp", TenantRole::Viewer)]);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::GET,
+			"/api/v1/tenants/current/monitors?source=api",
+			&token,
+			None,
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_current_tenant_requires_selection_for_several_memberships() {
+	// Arrange
+	let (app, token, _) = app_for(&[
+		("acme-corp", TenantRole::Owner),
+		("globex", TenantRole::Viewer),
+	]);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::GET,
+			"/api/v1/tenants/current/monitors?source=api",
+			&token,
+			None,
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+	let message = json_body(response).await["error"]
+		.as_str()
+		.unwrap_or_default()
+		.to_string();
+	assert!(message.contains("acme-corp, globex"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_selected_tenant_applies_its_role() {
+	for (header_name, tenant_index, expected) in [
+		(TENANT_SLUG_HEADER, 1, StatusCode::FORBIDDEN),
+		(TENANT_SLUG_HEADER, 0, StatusCode::CREATED),
+		(TENANT_ID_HEADER, 0, StatusCode::CREATED),
+	] {
+		// Arrange
+		let (app, token, tenants) = app_for(&[
+			("acme-corp", TenantRole::Owner),
+			("globex", TenantRole::Viewer),
+		]);
+		let tenant = &tenants[tenant_index];
+		let value = if header_name == TENANT_ID_HEADER {
+			tenant.id.to_string()
+		} else {
+			tenant.slug.clone()
+		};
+
+		// Act
+		let response = app
+			.oneshot(request(
+				Method::POST,
+				"/api/v1/tenants/current/monitors",
+				&token,
+				Some((header_name, &value)),
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status(), expected, "{}: {}", header_name, value);
+	}
+}
+
+#[tokio::test]
+async fn test_selecting_foreign_tenant_is_forbidden() {
+	// Arrange
+	let (app, token, _) = app_for(&[("acme-corp", TenantRole::Owner)]);
+
+	// Act
+	let response = app
+		.oneshot(request(
+			Method::GET,
+			"/api/v1/tenants/current/monitors?source=api",
+			&token,
+			Some((TENANT_SLUG_HEADER, "initech")),
+		))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
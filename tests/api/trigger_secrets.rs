@@ -0,0 +1,261 @@
+use axum::{
+	extract::{ConnectInfo, Path, Query, State},
+	http::HeaderMap,
+	response::IntoResponse,
+	Json,
+};
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	services::TriggerService,
+	utils::{with_tenant_context, AuthService, TenantContext},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantMonitorRepository,
+		MockTenantRepository, MockTenantTriggerRepository, MockTriggerService,
+	},
+	utils::{builders::TriggerBuilder, context::api_key_context, fixtures::TestIds},
+};
+
+type SecretsState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	TriggerService<
+		MockTenantTriggerRepository,
+		MockTenantMonitorRepository,
+		MockTenantRepository,
+		MockAuditService,
+	>,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+fn secret_triggers(tenant_id: uuid::Uuid) -> Vec<TenantTrigger> {
+	vec![
+		TriggerBuilder::new()
+			.with_tenant_id(tenant_id)
+			.with_trigger_id("webhook")
+			.with_configuration(json!({
+				"url": "https://example.com/webhook",
+				"method": "POST",
+				"headers": {
+					"Authorization": "Bearer hook-token",
+					"Content-Type": "application/json"
+				},
+				"signing_secret": "whsec_123"
+			}))
+			.build(),
+		TriggerBuilder::new()
+			.with_tenant_id(tenant_id)
+			.with_trigger_id("telegram")
+			.with_trigger_type("telegram")
+			.with_configuration(json!({
+				"token": "123456:bot-token",
+				"chat_id": "42"
+			}))
+			.build(),
+	]
+}
+
+fn state_with(triggers: Vec<TenantTrigger>) -> SecretsState {
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let listed = triggers.clone();
+	trigger_repo
+		.expect_list()
+		.returning(move |_, _| Ok(listed.clone()));
+	let count = triggers.len() as i64;
+	trigger_repo.expect_count().returning(move || Ok(count));
+	trigger_repo.expect_get().returning(move |trigger_id| {
+		Ok(triggers
+			.iter()
+			.find(|t| t.trigger_id == trigger_id)
+			.cloned()
+			.unwrap())
+	});
+
+	AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		TriggerService::new(
+			trigger_repo,
+			MockTenantMonitorRepository::new(),
+			MockTenantRepository::new(),
+			MockAuditService::new(),
+		),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn key_context(tenant_id: uuid::Uuid, reveal: bool) -> TenantContext {
+	let mut permission = ApiPermission::all_triggers();
+	if reveal {
+		permission.actions.push(REVEAL_SECRETS_ACTION.to_string());
+	}
+	api_key_context(tenant_id, uuid::Uuid::new_v4()).with_permissions(vec![permission])
+}
+
+async fn list_body(state: SecretsState, context: TenantContext) -> serde_json::Value {
+	let response = with_tenant_context(context, async move {
+		handlers::list_triggers(
+			State(state),
+			Query(handlers::PaginationQuery {
+				limit: None,
+				offset: None,
+				source: None,
+				include_deleted: None,
+				cursor: None,
+			}),
+			Query(handlers::TriggerListQuery::default()),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+async fn get_body(
+	state: SecretsState,
+	context: TenantContext,
+	trigger_id: &str,
+) -> serde_json::Value {
+	let trigger_id = trigger_id.to_string();
+	let response = with_tenant_context(context, async move {
+		handlers::get_trigger(
+			State(state),
+			Path(("acme-corp".to_string(), trigger_id)),
+			Query(handlers::TriggerQuery::default()),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_trigger_secrets_are_masked_for_a_normal_key() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let triggers = secret_triggers(test_ids.tenant_1);
+
+	// Act
+	let listed = list_body(
+		state_with(triggers.clone()),
+		key_context(test_ids.tenant_1, false),
+	)
+	.await;
+	let fetched = get_body(
+		state_with(triggers),
+		key_context(test_ids.tenant_1, false),
+		"webhook",
+	)
+	.await;
+
+	// Assert
+	let webhook = &listed["data"][0]["configuration"];
+	assert_eq!(webhook["signing_secret"], REDACTED_SECRET);
+	assert_eq!(webhook["headers"]["Authorization"], REDACTED_SECRET);
+	assert_eq!(webhook["headers"]["Content-Type"], "application/json");
+	assert_eq!(webhook["url"], "https://example.com/webhook");
+	let telegram = &listed["data"][1]["configuration"];
+	assert_eq!(telegram["token"], REDACTED_SECRET);
+	assert_eq!(telegram["chat_id"], "42");
+	assert_eq!(fetched["data"]["configuration"], *webhook);
+}
+
+#[tokio::test]
+async fn test_trigger_secrets_are_revealed_with_permission() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let triggers = secret_triggers(test_ids.tenant_1);
+
+	// Act
+	let listed = list_body(
+		state_with(triggers.clone()),
+		key_context(test_ids.tenant_1, true),
+	)
+	.await;
+	let fetched = get_body(
+		state_with(triggers),
+		key_context(test_ids.tenant_1, true),
+		"telegram",
+	)
+	.await;
+
+	// Assert
+	let webhook = &listed["data"][0]["configuration"];
+	assert_eq!(webhook["signing_secret"], "whsec_123");
+	assert_eq!(webhook["headers"]["Authorization"], "Bearer hook-token");
+	assert_eq!(
+		fetched["data"]["configuration"]["token"],
+		"123456:bot-token"
+	);
+}
+
+#[tokio::test]
+async fn test_updated_trigger_secrets_are_masked_for_a_normal_key() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let updated = secret_triggers(test_ids.tenant_1).remove(0);
+	let mut trigger_service = MockTriggerService::new();
+	trigger_service
+		.expect_update_trigger()
+		.times(1)
+		.returning(move |_, _, _| Ok(updated.clone()));
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		trigger_service,
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(key_context(test_ids.tenant_1, false), async move {
+		handlers::update_trigger(
+			State(state),
+			ConnectInfo("127.0.0.1:8080".parse().unwrap()),
+			HeaderMap::new(),
+			Path(("acme-corp".to_string(), "webhook".to_string())),
+			Json(UpdateTriggerRequest {
+				name: Some("Renamed".to_string()),
+				configuration: None,
+				is_active: None,
+			}),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let webhook = &body["data"]["configuration"];
+	assert_eq!(webhook["signing_secret"], REDACTED_SECRET);
+	assert_eq!(webhook["headers"]["Authorization"], REDACTED_SECRET);
+}
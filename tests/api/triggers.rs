@@ -0,0 +1,258 @@
+use axum::{
+	extract::{Path, Query, State},
+	response::IntoResponse,
+};
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	services::TriggerService,
+	utils::{current_tenant_id, with_tenant_context, AuthService},
+};
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantMonitorRepository,
+		MockTenantRepository, MockTenantTriggerRepository,
+	},
+	utils::{
+		builders::{MonitorBuilder, TriggerBuilder},
+		context::owner_context,
+		fixtures::TestIds,
+	},
+};
+
+#[tokio::test]
+async fn test_list_triggers_reports_tenant_total() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let triggers: Arc<Vec<TenantTrigger>> = Arc::new(
+		(0..3)
+			.map(|i| {
+				TriggerBuilder::new()
+					.with_tenant_id(test_ids.tenant_1)
+					.with_trigger_id(format!("trigger-{}", i))
+					.build()
+			})
+			.chain((0..2).map(|i| {
+				TriggerBuilder::new()
+					.with_tenant_id(test_ids.tenant_2)
+					.with_trigger_id(format!("other-{}", i))
+					.build()
+			}))
+			.collect(),
+	);
+
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let listed = triggers.clone();
+	trigger_repo.expect_list().returning(move |limit, offset| {
+		let tenant_id = current_tenant_id();
+		Ok(listed
+			.iter()
+			.filter(|t| t.tenant_id == tenant_id)
+			.skip(offset as usize)
+			.take(limit as usize)
+			.cloned()
+			.collect())
+	});
+	let counted = triggers.clone();
+	trigger_repo.expect_count().times(1).returning(move || {
+		let tenant_id = current_tenant_id();
+		Ok(counted.iter().filter(|t| t.tenant_id == tenant_id).count() as i64)
+	});
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		TriggerService::new(
+			trigger_repo,
+			MockTenantMonitorRepository::new(),
+			MockTenantRepository::new(),
+			MockAuditService::new(),
+		),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+	let query = handlers::PaginationQuery {
+		limit: Some(2),
+		offset: Some(0),
+		source: None,
+		include_deleted: None,
+		cursor: None,
+	};
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_triggers(
+			State(state),
+			Query(query),
+			Query(handlers::TriggerListQuery::default()),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["data"].as_array().unwrap().len(), 2);
+	assert_eq!(body["meta"]["total"], 3);
+}
+
+#[tokio::test]
+async fn test_trigger_type_stats_count_per_type() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let triggers: Arc<Vec<TenantTrigger>> = Arc::new(
+		["webhook", "webhook", "email", "slack", "webhook", "email"]
+			.iter()
+			.map(|trigger_type| {
+				TriggerBuilder::new()
+					.with_tenant_id(test_ids.tenant_1)
+					.with_trigger_type(*trigger_type)
+					.build()
+			})
+			.chain(std::iter::once(
+				TriggerBuilder::new()
+					.with_tenant_id(test_ids.tenant_2)
+					.with_trigger_type("discord")
+					.build(),
+			))
+			.collect(),
+	);
+
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	trigger_repo
+		.expect_count_by_type()
+		.times(1)
+		.returning(move || {
+			let tenant_id = current_tenant_id();
+			let mut counts = std::collections::HashMap::new();
+			for trigger in triggers.iter().filter(|t| t.tenant_id == tenant_id) {
+				*counts.entry(trigger.trigger_type.clone()).or_insert(0) += 1;
+			}
+			Ok(counts)
+		});
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		TriggerService::new(
+			trigger_repo,
+			MockTenantMonitorRepository::new(),
+			MockTenantRepository::new(),
+			MockAuditService::new(),
+		),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::get_trigger_type_stats(State(state))
+			.await
+			.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(
+		body["data"],
+		serde_json::json!({ "webhook": 3, "email": 2, "slack": 1 })
+	);
+}
+
+#[tokio::test]
+async fn test_list_triggers_by_monitor_pages_and_reports_total() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.build();
+	let monitor_id = monitor.id;
+
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo
+		.expect_get_by_uuid()
+		.returning(move |_| Ok(monitor.clone()));
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	trigger_repo
+		.expect_list_by_monitor()
+		.withf(move |id, limit, offset| *id == monitor_id && *limit == 2 && *offset == 2)
+		.times(1)
+		.returning(move |_, limit, _| {
+			Ok((0..limit)
+				.map(|_| {
+					TriggerBuilder::new()
+						.with_tenant_id(test_ids.tenant_1)
+						.with_monitor_id(monitor_id)
+						.build()
+				})
+				.collect())
+		});
+	trigger_repo
+		.expect_count_by_monitor()
+		.times(1)
+		.returning(|_| Ok(5));
+
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		TriggerService::new(
+			trigger_repo,
+			monitor_repo,
+			MockTenantRepository::new(),
+			MockAuditService::new(),
+		),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = with_tenant_context(owner_context(test_ids.tenant_1), async move {
+		handlers::list_triggers_by_monitor(
+			State(state),
+			Path(("acme-corp".to_string(), monitor_id)),
+			Query(handlers::PaginationQuery {
+				limit: Some(2),
+				offset: Some(2),
+				..Default::default()
+			}),
+		)
+		.await
+		.map(IntoResponse::into_response)
+	})
+	.await
+	.unwrap();
+
+	// Assert
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	assert_eq!(body["data"].as_array().unwrap().len(), 2);
+	assert_eq!(body["meta"]["total"], 5);
+	assert_eq!(body["meta"]["limit"], 2);
+	assert_eq!(body["meta"]["offset"], 2);
+}
@@ -0,0 +1,161 @@
+use axum::{
+	extract::{ConnectInfo, Path, Query, State},
+	http::{HeaderMap, StatusCode},
+	response::IntoResponse,
+	Json,
+};
+use std::net::SocketAddr;
+use stellar_monitor_tenant_isolation::{
+	api::{handlers, routes::AppState},
+	models::*,
+	services::ServiceError,
+	utils::{with_tenant_context, AuthService},
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{context::user_context, fixtures::TestIds},
+};
+
+type TestState = AppState<
+	MockMonitorService,
+	MockNetworkService,
+	MockTriggerService,
+	MockTenantRepository,
+	MockAuditService,
+>;
+
+// Every webhook case here is rejected before the repository touches the
+// database, so the lazy pool is never connected
+fn state(monitor_service: MockMonitorService, audit_service: MockAuditService) -> TestState {
+	AppState::new(
+		monitor_service,
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		MockTenantRepository::new(),
+		audit_service,
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+		AuthService::new("test-secret".to_string()),
+	)
+}
+
+fn addr() -> ConnectInfo<SocketAddr> {
+	ConnectInfo("127.0.0.1:8080".parse().unwrap())
+}
+
+fn webhook_request(events: &[&str]) -> Json<CreateWebhookRequest> {
+	Json(CreateWebhookRequest {
+		url: "https://example.com/hook".to_string(),
+		secret: None,
+		events: events.iter().map(|event| event.to_string()).collect(),
+		is_active: None,
+	})
+}
+
+#[tokio::test]
+async fn test_member_cannot_manage_webhooks() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let context = user_context(test_ids.tenant_1, TenantRole::Member);
+
+	// Act
+	let create = with_tenant_context(
+		context.clone(),
+		handlers::create_webhook(
+			State(state(MockMonitorService::new(), MockAuditService::new())),
+			addr(),
+			HeaderMap::new(),
+			webhook_request(&[]),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+	let deliveries = with_tenant_context(
+		context,
+		handlers::list_webhook_deliveries(
+			State(state(MockMonitorService::new(), MockAuditService::new())),
+			Path(Uuid::new_v4()),
+			Query(WebhookDeliveryQuery::default()),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(create.status(), StatusCode::FORBIDDEN);
+	assert_eq!(deliveries.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_create_webhook_rejects_unknown_event() {
+	// Arrange
+	let test_ids = TestIds::default();
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		handlers::create_webhook(
+			State(state(MockMonitorService::new(), MockAuditService::new())),
+			addr(),
+			HeaderMap::new(),
+			webhook_request(&["monitor_created", "monitor_exploded"]),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_quota_rejected_monitor_create_is_audited() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_service = MockMonitorService::new();
+	monitor_service.expect_create_monitor().returning(|_, _| {
+		Err(ServiceError::QuotaExceeded(
+			"Monitor quota exceeded: 10/10".into(),
+		))
+	});
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_log()
+		.withf(|request| {
+			matches!(request.action, AuditAction::QuotaExceeded)
+				&& matches!(request.resource_type, Some(ResourceType::Monitor))
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+
+	// Act
+	let response = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		handlers::create_monitor(
+			State(state(monitor_service, audit_service)),
+			addr(),
+			HeaderMap::new(),
+			Json(CreateMonitorRequest {
+				monitor_id: "payments".to_string(),
+				name: "Payments".to_string(),
+				network_id: test_ids.network_1,
+				configuration: serde_json::json!({}),
+			}),
+		),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
@@ -20,18 +20,39 @@ mod tests {
 		#[async_trait::async_trait]
 		impl TenantRepositoryTrait for TenantRepo {
 			async fn create(&self, request: CreateTenantRequest) -> Result<Tenant, TenantRepositoryError>;
+			async fn create_with_owner(&self, request: CreateTenantRequest, owner_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
+			fn validate_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
+			async fn check_deleted_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
 			async fn get(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
 			async fn get_by_slug(&self, slug: &str) -> Result<Tenant, TenantRepositoryError>;
 			async fn update(&self, tenant_id: Uuid, request: UpdateTenantRequest) -> Result<Tenant, TenantRepositoryError>;
-			async fn delete(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
+			async fn rename_slug(&self, tenant_id: Uuid, slug: &str) -> Result<Tenant, TenantRepositoryError>;
+			async fn delete(&self, tenant_id: Uuid, force: bool) -> Result<(), TenantRepositoryError>;
+			async fn restore(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
+			async fn purge(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
 			async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError>;
+			async fn list_filtered(&self, filter: TenantFilter, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError>;
+			async fn count_filtered(&self, filter: TenantFilter) -> Result<i64, TenantRepositoryError>;
 			async fn add_member(&self, tenant_id: Uuid, user_id: Uuid, role: TenantRole) -> Result<TenantMembership, TenantRepositoryError>;
 			async fn remove_member(&self, tenant_id: Uuid, user_id: Uuid) -> Result<(), TenantRepositoryError>;
 			async fn update_member_role(&self, tenant_id: Uuid, user_id: Uuid, role: TenantRole) -> Result<TenantMembership, TenantRepositoryError>;
+			async fn transfer_ownership(&self, tenant_id: Uuid, from_user: Uuid, to_user: Uuid) -> Result<(), TenantRepositoryError>;
 			async fn get_members(&self, tenant_id: Uuid) -> Result<Vec<TenantMembership>, TenantRepositoryError>;
 			async fn get_user_tenants(&self, user_id: Uuid) -> Result<Vec<(Tenant, TenantRole)>, TenantRepositoryError>;
+			async fn add_members_batch(&self, tenant_id: Uuid, entries: Vec<BatchMemberEntry>) -> Result<Vec<BatchMemberResult>, TenantRepositoryError>;
+			async fn sync_members(&self, tenant_id: Uuid, entries: Vec<BatchMemberEntry>, change_owners: bool) -> Result<MemberSyncResult, TenantRepositoryError>;
+			async fn find_duplicate_memberships(&self) -> Result<Vec<DuplicateMembership>, TenantRepositoryError>;
+			async fn find_integrity_violations(&self, tenant_id: Uuid) -> Result<Vec<IntegrityViolation>, TenantRepositoryError>;
+			async fn find_user_by_identity(&self, issuer: &str, subject: &str) -> Result<Option<User>, TenantRepositoryError>;
+			async fn link_identity(&self, identity: &SsoIdentity) -> Result<User, TenantRepositoryError>;
 			async fn get_quota_status(&self, tenant_id: Uuid) -> Result<ResourceQuotaStatus, TenantRepositoryError>;
+			async fn get_quota_statuses(&self, tenant_ids: &[Uuid]) -> Result<Vec<ResourceQuotaStatus>, TenantRepositoryError>;
+			async fn get_resource_counts(&self, tenant_id: Uuid) -> Result<ResourceCounts, TenantRepositoryError>;
 			async fn check_quota(&self, tenant_id: Uuid, resource: &str, amount: i32) -> Result<bool, TenantRepositoryError>;
+			async fn check_quota_batch(&self, tenant_id: Uuid, checks: &[QuotaCheck]) -> Result<Vec<QuotaCheckResult>, TenantRepositoryError>;
+			async fn set_blockchain_quota(&self, tenant_id: Uuid, quota: BlockchainQuota) -> Result<BlockchainQuota, TenantRepositoryError>;
+			async fn get_trigger_defaults(&self, tenant_id: Uuid) -> Result<TriggerDefaults, TenantRepositoryError>;
+			async fn rotate_signing_secret(&self, tenant_id: Uuid, signing_secret: String) -> Result<TriggerDefaults, TenantRepositoryError>;
 		}
 	}
 
@@ -202,11 +223,22 @@ mod tests {
 		#[async_trait::async_trait]
 		impl MonitorServiceTrait for MonitorSvc {
 			async fn create_monitor(&self, request: CreateMonitorRequest, metadata: RequestMetadata) -> Result<TenantMonitor, ServiceError>;
+			async fn import_monitors(&self, requests: Vec<CreateMonitorRequest>, metadata: RequestMetadata) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn clone_monitor(&self, source_monitor_id: &str, new_monitor_id: String, target_network_id: Option<Uuid>, metadata: RequestMetadata) -> Result<TenantMonitor, ServiceError>;
 			async fn get_monitor(&self, monitor_id: &str) -> Result<TenantMonitor, ServiceError>;
 			async fn update_monitor(&self, monitor_id: &str, request: UpdateMonitorRequest, metadata: RequestMetadata) -> Result<TenantMonitor, ServiceError>;
 			async fn delete_monitor(&self, monitor_id: &str, metadata: RequestMetadata) -> Result<(), ServiceError>;
+			async fn delete_monitor_with_triggers(&self, monitor_id: &str, metadata: RequestMetadata) -> Result<Vec<TenantTrigger>, ServiceError>;
+			async fn restore_monitor(&self, monitor_id: &str, metadata: RequestMetadata) -> Result<TenantMonitor, ServiceError>;
+			async fn rollback_monitor(&self, monitor_id: &str, version: i32, metadata: RequestMetadata) -> Result<MonitorRollback, ServiceError>;
 			async fn list_monitors(&self, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn list_monitors_after(&self, cursor: Option<ListCursor>, limit: i64) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn list_monitors_including_deleted(&self, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn list_monitors_by_source(&self, source: CreationSource, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn list_monitors_filtered(&self, filter: MonitorFilter, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, ServiceError>;
+			async fn count_monitors_filtered(&self, filter: MonitorFilter) -> Result<i64, ServiceError>;
 			async fn get_monitor_count(&self) -> Result<i64, ServiceError>;
+			async fn deactivate_with_triggers(&self, monitor_id: &str, metadata: RequestMetadata) -> Result<MonitorDeactivation, ServiceError>;
 		}
 	}
 
@@ -221,8 +253,10 @@ mod tests {
 			network_id: Uuid::new_v4(),
 			configuration: serde_json::json!({"type": "test"}),
 			is_active: Some(true),
+			creation_source: "api".to_string(),
 			created_at: Some(chrono::Utc::now()),
 			updated_at: Some(chrono::Utc::now()),
+			deleted_at: None,
 		};
 		let expected_clone = expected_monitor.clone();
 
@@ -241,6 +275,7 @@ mod tests {
 		let metadata = RequestMetadata {
 			ip_address: None,
 			user_agent: None,
+			request_id: None,
 		};
 
 		let result = mock_service.create_monitor(request, metadata).await;
@@ -271,6 +306,7 @@ mod tests {
 		let metadata = RequestMetadata {
 			ip_address: None,
 			user_agent: None,
+			request_id: None,
 		};
 
 		let result = mock_service.create_monitor(request, metadata).await;
@@ -324,7 +360,7 @@ mod tests {
 			.returning(|_, _, _| Ok(true));
 
 		// Delete tenant
-		mock_repo.expect_delete().times(1).returning(|_| Ok(()));
+		mock_repo.expect_delete().times(1).returning(|_, _| Ok(()));
 
 		// Execute lifecycle
 		let create_request = CreateTenantRequest {
@@ -352,7 +388,7 @@ mod tests {
 			.unwrap();
 		assert!(quota_ok);
 
-		let delete_result = mock_repo.delete(tenant_id).await;
+		let delete_result = mock_repo.delete(tenant_id, false).await;
 		assert!(delete_result.is_ok());
 	}
 
@@ -4,9 +4,10 @@ use std::collections::HashMap;
 use stellar_monitor_tenant_isolation::{
 	models::*,
 	repositories::{
-		error::TenantRepositoryError, monitor::TenantMonitorRepositoryTrait,
+		api_key::ApiKeyRepositoryTrait, audit::AuditRepositoryTrait, error::TenantRepositoryError,
+		feed::ResourceFeedRepositoryTrait, monitor::TenantMonitorRepositoryTrait,
 		network::TenantNetworkRepositoryTrait, tenant::TenantRepositoryTrait,
-		trigger::TenantTriggerRepositoryTrait,
+		trigger::TenantTriggerRepositoryTrait, usage::UsageRepositoryTrait,
 	},
 };
 use uuid::Uuid;
@@ -22,22 +23,46 @@ mock! {
 	#[async_trait]
 	impl TenantRepositoryTrait for TenantRepository {
 		async fn create(&self, request: CreateTenantRequest) -> Result<Tenant, TenantRepositoryError>;
+		async fn create_with_owner(&self, request: CreateTenantRequest, owner_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
+		fn validate_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
+		async fn check_deleted_slug(&self, slug: &str) -> Result<(), TenantRepositoryError>;
 		async fn get(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
 		async fn get_by_slug(&self, slug: &str) -> Result<Tenant, TenantRepositoryError>;
 		async fn update(&self, tenant_id: Uuid, request: UpdateTenantRequest) -> Result<Tenant, TenantRepositoryError>;
-		async fn delete(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
+		async fn rename_slug(&self, tenant_id: Uuid, slug: &str) -> Result<Tenant, TenantRepositoryError>;
+		async fn delete(&self, tenant_id: Uuid, force: bool) -> Result<(), TenantRepositoryError>;
+		async fn restore(&self, tenant_id: Uuid) -> Result<Tenant, TenantRepositoryError>;
+		async fn purge(&self, tenant_id: Uuid) -> Result<(), TenantRepositoryError>;
 		async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError>;
+		async fn list_filtered(&self, filter: TenantFilter, limit: i64, offset: i64) -> Result<Vec<Tenant>, TenantRepositoryError>;
+		async fn count_filtered(&self, filter: TenantFilter) -> Result<i64, TenantRepositoryError>;
 
 		// Membership management
 		async fn add_member(&self, tenant_id: Uuid, user_id: Uuid, role: TenantRole) -> Result<TenantMembership, TenantRepositoryError>;
 		async fn remove_member(&self, tenant_id: Uuid, user_id: Uuid) -> Result<(), TenantRepositoryError>;
 		async fn update_member_role(&self, tenant_id: Uuid, user_id: Uuid, role: TenantRole) -> Result<TenantMembership, TenantRepositoryError>;
+		async fn transfer_ownership(&self, tenant_id: Uuid, from_user: Uuid, to_user: Uuid) -> Result<(), TenantRepositoryError>;
 		async fn get_members(&self, tenant_id: Uuid) -> Result<Vec<TenantMembership>, TenantRepositoryError>;
 		async fn get_user_tenants(&self, user_id: Uuid) -> Result<Vec<(Tenant, TenantRole)>, TenantRepositoryError>;
+		async fn add_members_batch(&self, tenant_id: Uuid, entries: Vec<BatchMemberEntry>) -> Result<Vec<BatchMemberResult>, TenantRepositoryError>;
+		async fn sync_members(&self, tenant_id: Uuid, entries: Vec<BatchMemberEntry>, change_owners: bool) -> Result<MemberSyncResult, TenantRepositoryError>;
+		async fn find_duplicate_memberships(&self) -> Result<Vec<DuplicateMembership>, TenantRepositoryError>;
+		async fn find_integrity_violations(&self, tenant_id: Uuid) -> Result<Vec<IntegrityViolation>, TenantRepositoryError>;
+		async fn find_user_by_identity(&self, issuer: &str, subject: &str) -> Result<Option<User>, TenantRepositoryError>;
+		async fn link_identity(&self, identity: &SsoIdentity) -> Result<User, TenantRepositoryError>;
 
 		// Resource quota management
 		async fn get_quota_status(&self, tenant_id: Uuid) -> Result<ResourceQuotaStatus, TenantRepositoryError>;
+		async fn get_quota_statuses(&self, tenant_ids: &[Uuid]) -> Result<Vec<ResourceQuotaStatus>, TenantRepositoryError>;
+		async fn get_resource_counts(&self, tenant_id: Uuid) -> Result<ResourceCounts, TenantRepositoryError>;
 		async fn check_quota(&self, tenant_id: Uuid, resource: &str, amount: i32) -> Result<bool, TenantRepositoryError>;
+		async fn check_quota_batch(&self, tenant_id: Uuid, checks: &[QuotaCheck]) -> Result<Vec<QuotaCheckResult>, TenantRepositoryError>;
+
+		async fn set_blockchain_quota(&self, tenant_id: Uuid, quota: BlockchainQuota) -> Result<BlockchainQuota, TenantRepositoryError>;
+
+		// Tenant-level trigger defaults
+		async fn get_trigger_defaults(&self, tenant_id: Uuid) -> Result<TriggerDefaults, TenantRepositoryError>;
+		async fn rotate_signing_secret(&self, tenant_id: Uuid, signing_secret: String) -> Result<TriggerDefaults, TenantRepositoryError>;
 	}
 }
 
@@ -52,13 +77,26 @@ mock! {
 	#[async_trait]
 	impl TenantMonitorRepositoryTrait for TenantMonitorRepository {
 		async fn create(&self, request: CreateMonitorRequest) -> Result<TenantMonitor, TenantRepositoryError>;
+		async fn create_with_source(&self, request: CreateMonitorRequest, source: CreationSource) -> Result<TenantMonitor, TenantRepositoryError>;
 		async fn get(&self, monitor_id: &str) -> Result<TenantMonitor, TenantRepositoryError>;
 		async fn get_by_uuid(&self, id: Uuid) -> Result<TenantMonitor, TenantRepositoryError>;
 		async fn get_all(&self) -> Result<HashMap<String, TenantMonitor>, TenantRepositoryError>;
 		async fn update(&self, monitor_id: &str, request: UpdateMonitorRequest) -> Result<TenantMonitor, TenantRepositoryError>;
+		async fn rollback(&self, monitor_id: &str, version: i32) -> Result<MonitorRollback, TenantRepositoryError>;
 		async fn delete(&self, monitor_id: &str) -> Result<(), TenantRepositoryError>;
+		async fn delete_with_triggers(&self, monitor_id: &str) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError>;
+		async fn restore(&self, monitor_id: &str) -> Result<TenantMonitor, TenantRepositoryError>;
 		async fn list(&self, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn list_after(&self, cursor: Option<ListCursor>, limit: i64) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn list_including_deleted(&self, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn list_by_source(&self, source: CreationSource, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn list_filtered(&self, filter: MonitorFilter, limit: i64, offset: i64) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn count_filtered(&self, filter: MonitorFilter) -> Result<i64, TenantRepositoryError>;
+		async fn deactivate_with_triggers(&self, monitor_id: &str) -> Result<(TenantMonitor, Vec<TenantTrigger>), TenantRepositoryError>;
 		async fn check_quota(&self) -> Result<bool, TenantRepositoryError>;
+		async fn contract_address_in_use(&self, network_id: Uuid, contract_address: &str) -> Result<bool, TenantRepositoryError>;
+		async fn purge_deleted_before(&self, cutoff: chrono::DateTime<chrono::Utc>) -> Result<Vec<TenantMonitor>, TenantRepositoryError>;
+		async fn list_untested_triggers(&self, monitor_id: Uuid) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
 	}
 }
 
@@ -78,7 +116,10 @@ mock! {
 		async fn get_all(&self) -> Result<HashMap<String, TenantNetwork>, TenantRepositoryError>;
 		async fn update(&self, network_id: &str, request: UpdateNetworkRequest) -> Result<TenantNetwork, TenantRepositoryError>;
 		async fn delete(&self, network_id: &str) -> Result<(), TenantRepositoryError>;
+		async fn delete_with_monitors(&self, network_id: &str) -> Result<NetworkDeletion, TenantRepositoryError>;
 		async fn list(&self, limit: i64, offset: i64) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
+		async fn list_after(&self, cursor: Option<ListCursor>, limit: i64) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
+		async fn list_by_source(&self, source: CreationSource, limit: i64, offset: i64) -> Result<Vec<TenantNetwork>, TenantRepositoryError>;
 		async fn check_quota(&self) -> Result<bool, TenantRepositoryError>;
 	}
 }
@@ -94,14 +135,87 @@ mock! {
 	#[async_trait]
 	impl TenantTriggerRepositoryTrait for TenantTriggerRepository {
 		async fn create(&self, request: CreateTriggerRequest) -> Result<TenantTrigger, TenantRepositoryError>;
+		async fn create_batch(&self, requests: Vec<CreateTriggerRequest>) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
 		async fn get(&self, trigger_id: &str) -> Result<TenantTrigger, TenantRepositoryError>;
 		async fn get_by_uuid(&self, id: Uuid) -> Result<TenantTrigger, TenantRepositoryError>;
 		async fn get_all(&self) -> Result<HashMap<String, TenantTrigger>, TenantRepositoryError>;
 		async fn get_by_monitor(&self, monitor_id: Uuid) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn list_by_monitor(&self, monitor_id: Uuid, limit: i64, offset: i64) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, TenantRepositoryError>;
 		async fn update(&self, trigger_id: &str, request: UpdateTriggerRequest) -> Result<TenantTrigger, TenantRepositoryError>;
 		async fn delete(&self, trigger_id: &str) -> Result<(), TenantRepositoryError>;
 		async fn list(&self, limit: i64, offset: i64) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn list_after(&self, cursor: Option<ListCursor>, limit: i64) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn list_by_source(&self, source: CreationSource, limit: i64, offset: i64) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn list_filtered(&self, filter: TriggerFilter, limit: i64, offset: i64) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn count_filtered(&self, filter: TriggerFilter) -> Result<i64, TenantRepositoryError>;
 		async fn count(&self) -> Result<i64, TenantRepositoryError>;
+		async fn count_by_type(&self) -> Result<HashMap<String, i64>, TenantRepositoryError>;
+		async fn list_by_signing_secret(&self, signing_secret: &str) -> Result<Vec<TenantTrigger>, TenantRepositoryError>;
+		async fn record_successful_test(&self, trigger_id: &str) -> Result<(), TenantRepositoryError>;
 		async fn check_quota(&self, monitor_id: Uuid) -> Result<bool, TenantRepositoryError>;
 	}
 }
+
+// Mock for AuditRepository
+mock! {
+	pub AuditRepository {}
+
+	impl Clone for AuditRepository {
+		fn clone(&self) -> Self;
+	}
+
+	#[async_trait]
+	impl AuditRepositoryTrait for AuditRepository {
+		async fn query(&self, tenant_id: Uuid, filter: &AuditLogFilter, limit: i64, offset: i64) -> Result<Vec<AuditLog>, TenantRepositoryError>;
+		async fn count(&self, tenant_id: Uuid, filter: &AuditLogFilter) -> Result<i64, TenantRepositoryError>;
+	}
+}
+
+// Mock for ResourceFeedRepository
+mock! {
+	pub ResourceFeedRepository {}
+
+	impl Clone for ResourceFeedRepository {
+		fn clone(&self) -> Self;
+	}
+
+	#[async_trait]
+	impl ResourceFeedRepositoryTrait for ResourceFeedRepository {
+		async fn list_since(&self, since: i64, limit: i64) -> Result<Vec<ResourceChangeEvent>, TenantRepositoryError>;
+	}
+}
+
+// Mock for UsageRepository
+mock! {
+	pub UsageRepository {}
+
+	impl Clone for UsageRepository {
+		fn clone(&self) -> Self;
+	}
+
+	#[async_trait]
+	impl UsageRepositoryTrait for UsageRepository {
+		async fn record_api_request(&self, tenant_id: Uuid, date: chrono::NaiveDate) -> Result<(), TenantRepositoryError>;
+		async fn record_rpc_requests(&self, tenant_id: Uuid, count: i64) -> Result<(), TenantRepositoryError>;
+		async fn record_storage(&self, tenant_id: Uuid, mb: i64) -> Result<(), TenantRepositoryError>;
+		async fn add_storage(&self, tenant_id: Uuid, delta_mb: i64) -> Result<(), TenantRepositoryError>;
+		async fn api_request_counts(&self, tenant_id: Uuid, from: chrono::NaiveDate, to: chrono::NaiveDate) -> Result<Vec<UsagePoint>, TenantRepositoryError>;
+	}
+}
+
+// Mock for ApiKeyRepository
+mock! {
+	pub ApiKeyRepository {}
+
+	impl Clone for ApiKeyRepository {
+		fn clone(&self) -> Self;
+	}
+
+	#[async_trait]
+	impl ApiKeyRepositoryTrait for ApiKeyRepository {
+		async fn list_unused_since(&self, cutoff: chrono::DateTime<chrono::Utc>) -> Result<Vec<ApiKey>, TenantRepositoryError>;
+		async fn deactivate(&self, key_id: Uuid) -> Result<ApiKey, TenantRepositoryError>;
+		async fn update_last_used(&self, key_id: Uuid, requests: i64) -> Result<(), TenantRepositoryError>;
+	}
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use mockall::mock;
+use std::collections::HashMap;
 use stellar_monitor_tenant_isolation::{
 	models::*,
 	services::{
 		monitor_service::{AuditServiceTrait, MonitorServiceTrait, ServiceError},
-		network_service::NetworkServiceTrait,
+		network_service::{NetworkProbe, NetworkServiceTrait},
 		trigger_service::TriggerServiceTrait,
+		usage_service::StorageRecorder,
 	},
 };
 use uuid::Uuid;
@@ -17,6 +19,10 @@ mock! {
 	#[async_trait]
 	impl AuditServiceTrait for AuditService {
 		async fn log(&self, request: CreateAuditLogRequest) -> Result<(), ServiceError>;
+
+		async fn query_logs(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, ServiceError>;
+
+		async fn count_logs(&self, filter: AuditLogFilter) -> Result<i64, ServiceError>;
 	}
 }
 
@@ -32,6 +38,20 @@ mock! {
 			metadata: RequestMetadata,
 		) -> Result<TenantMonitor, ServiceError>;
 
+		async fn import_monitors(
+			&self,
+			requests: Vec<CreateMonitorRequest>,
+			metadata: RequestMetadata,
+		) -> Result<Vec<TenantMonitor>, ServiceError>;
+
+		async fn clone_monitor(
+			&self,
+			source_monitor_id: &str,
+			new_monitor_id: String,
+			target_network_id: Option<Uuid>,
+			metadata: RequestMetadata,
+		) -> Result<TenantMonitor, ServiceError>;
+
 		async fn get_monitor(&self, monitor_id: &str) -> Result<TenantMonitor, ServiceError>;
 
 		async fn update_monitor(
@@ -47,13 +67,66 @@ mock! {
 			metadata: RequestMetadata,
 		) -> Result<(), ServiceError>;
 
+		async fn delete_monitor_with_triggers(
+			&self,
+			monitor_id: &str,
+			metadata: RequestMetadata,
+		) -> Result<Vec<TenantTrigger>, ServiceError>;
+
+		async fn restore_monitor(
+			&self,
+			monitor_id: &str,
+			metadata: RequestMetadata,
+		) -> Result<TenantMonitor, ServiceError>;
+
+		async fn rollback_monitor(
+			&self,
+			monitor_id: &str,
+			version: i32,
+			metadata: RequestMetadata,
+		) -> Result<MonitorRollback, ServiceError>;
+
 		async fn list_monitors(
 			&self,
 			limit: i64,
 			offset: i64,
 		) -> Result<Vec<TenantMonitor>, ServiceError>;
 
+		async fn list_monitors_after(
+			&self,
+			cursor: Option<ListCursor>,
+			limit: i64,
+		) -> Result<Vec<TenantMonitor>, ServiceError>;
+
+		async fn list_monitors_including_deleted(
+			&self,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantMonitor>, ServiceError>;
+
+		async fn list_monitors_by_source(
+			&self,
+			source: CreationSource,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantMonitor>, ServiceError>;
+
+		async fn list_monitors_filtered(
+			&self,
+			filter: MonitorFilter,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantMonitor>, ServiceError>;
+
+		async fn count_monitors_filtered(&self, filter: MonitorFilter) -> Result<i64, ServiceError>;
+
 		async fn get_monitor_count(&self) -> Result<i64, ServiceError>;
+
+		async fn deactivate_with_triggers(
+			&self,
+			monitor_id: &str,
+			metadata: RequestMetadata,
+		) -> Result<MonitorDeactivation, ServiceError>;
 	}
 }
 
@@ -84,13 +157,58 @@ mock! {
 			metadata: RequestMetadata,
 		) -> Result<(), ServiceError>;
 
+		async fn delete_network_with_monitors(
+			&self,
+			network_id: &str,
+			metadata: RequestMetadata,
+		) -> Result<NetworkDeletion, ServiceError>;
+
 		async fn list_networks(
 			&self,
 			limit: i64,
 			offset: i64,
 		) -> Result<Vec<TenantNetwork>, ServiceError>;
 
+		async fn list_networks_after(
+			&self,
+			cursor: Option<ListCursor>,
+			limit: i64,
+		) -> Result<Vec<TenantNetwork>, ServiceError>;
+
+		async fn list_networks_by_source(
+			&self,
+			source: CreationSource,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantNetwork>, ServiceError>;
+
 		async fn get_network_count(&self) -> Result<i64, ServiceError>;
+
+		async fn validate_connectivity(
+			&self,
+			config: &serde_json::Value,
+			blockchain: &str,
+		) -> Result<(), ServiceError>;
+	}
+}
+
+// Mock for NetworkProbe
+mock! {
+	pub NetworkProbe {}
+
+	#[async_trait]
+	impl NetworkProbe for NetworkProbe {
+		async fn probe(&self, rpc_url: &str, blockchain: &str) -> Result<(), ServiceError>;
+	}
+}
+
+// Mock for StorageRecorder
+mock! {
+	pub StorageRecorder {}
+
+	#[async_trait]
+	impl StorageRecorder for StorageRecorder {
+		async fn add_storage(&self, tenant_id: Uuid, delta_mb: i64) -> Result<(), ServiceError>;
 	}
 }
 
@@ -106,8 +224,16 @@ mock! {
 			metadata: RequestMetadata,
 		) -> Result<TenantTrigger, ServiceError>;
 
+		async fn create_triggers_batch(
+			&self,
+			requests: Vec<CreateTriggerRequest>,
+			metadata: RequestMetadata,
+		) -> Result<Vec<TenantTrigger>, ServiceError>;
+
 		async fn get_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError>;
 
+		async fn get_effective_trigger(&self, trigger_id: &str) -> Result<TenantTrigger, ServiceError>;
+
 		async fn update_trigger(
 			&self,
 			trigger_id: &str,
@@ -127,11 +253,46 @@ mock! {
 			offset: i64,
 		) -> Result<Vec<TenantTrigger>, ServiceError>;
 
+		async fn list_triggers_after(
+			&self,
+			cursor: Option<ListCursor>,
+			limit: i64,
+		) -> Result<Vec<TenantTrigger>, ServiceError>;
+
+		async fn list_triggers_by_source(
+			&self,
+			source: CreationSource,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantTrigger>, ServiceError>;
+
+		async fn list_triggers_filtered(
+			&self,
+			filter: TriggerFilter,
+			limit: i64,
+			offset: i64,
+		) -> Result<Vec<TenantTrigger>, ServiceError>;
+
+		async fn count_triggers_filtered(&self, filter: TriggerFilter) -> Result<i64, ServiceError>;
+
 		async fn list_triggers_by_monitor(
 			&self,
 			monitor_id: Uuid,
+			limit: i64,
+			offset: i64,
 		) -> Result<Vec<TenantTrigger>, ServiceError>;
 
+		async fn count_by_monitor(&self, monitor_id: Uuid) -> Result<i64, ServiceError>;
+
 		async fn get_trigger_count(&self) -> Result<i64, ServiceError>;
+
+		async fn get_trigger_type_stats(&self) -> Result<HashMap<String, i64>, ServiceError>;
+
+		async fn list_triggers_using_old_secret(&self) -> Result<Vec<TenantTrigger>, ServiceError>;
+
+		async fn send_test_notification(
+			&self,
+			trigger_id: &str,
+		) -> Result<TestDeliveryResult, ServiceError>;
 	}
 }
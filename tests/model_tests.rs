@@ -19,6 +19,7 @@ mod tests {
 
 		assert!(!TenantRole::Member.can_manage_tenant());
 		assert!(TenantRole::Member.can_write());
+		assert!(!TenantRole::Member.can_delete());
 		assert!(TenantRole::Member.can_read());
 
 		assert!(!TenantRole::Viewer.can_manage_tenant());
@@ -52,6 +53,9 @@ mod tests {
 				rpc_requests_per_minute: 500,
 				storage_mb: 800,
 			},
+			blockchains: vec![],
+			warnings: vec![],
+			monitor_triggers: Default::default(),
 		};
 
 		assert!(status.can_create_monitor());
@@ -63,6 +67,106 @@ mod tests {
 		assert!(!status.has_storage_capacity(900));
 	}
 
+	#[test]
+	fn test_quota_status_warns_past_threshold() {
+		let quotas = TenantQuotas {
+			max_monitors: 10,
+			max_networks: 5,
+			max_triggers_per_monitor: 10,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		};
+		let usage = CurrentUsage {
+			monitors_count: 8,
+			networks_count: 3,
+			triggers_count: 20,
+			rpc_requests_last_minute: 500,
+			storage_mb_used: 1200,
+		};
+
+		let status = ResourceQuotaStatus::from_usage(Uuid::new_v4(), quotas, usage, vec![]);
+
+		assert_eq!(
+			status.warnings,
+			vec![
+				QuotaWarning {
+					resource: "monitors".to_string(),
+					usage: 8,
+					limit: 10,
+					percent_used: 80,
+				},
+				QuotaWarning {
+					resource: "storage_mb".to_string(),
+					usage: 1200,
+					limit: 1000,
+					percent_used: 120,
+				},
+			]
+		);
+
+		let status = status.with_warn_threshold(50);
+		let resources: Vec<_> = status
+			.warnings
+			.iter()
+			.map(|w| w.resource.as_str())
+			.collect();
+		assert_eq!(
+			resources,
+			vec![
+				"monitors",
+				"networks",
+				"rpc_requests_per_minute",
+				"storage_mb"
+			]
+		);
+	}
+
+	#[test]
+	fn test_quota_check_all_rejects_unknown_resource() {
+		let status = ResourceQuotaStatus::from_usage(
+			Uuid::new_v4(),
+			TenantQuotas {
+				max_monitors: 10,
+				max_networks: 5,
+				max_triggers_per_monitor: 10,
+				max_rpc_requests_per_minute: 1000,
+				max_storage_mb: 1000,
+				api_rate_limits: Default::default(),
+			},
+			CurrentUsage {
+				monitors_count: 0,
+				networks_count: 0,
+				triggers_count: 0,
+				rpc_requests_last_minute: 0,
+				storage_mb_used: 0,
+			},
+			vec![],
+		);
+
+		let checks = vec![
+			QuotaCheck {
+				resource: "storage_mb".to_string(),
+				amount: 1000,
+			},
+			QuotaCheck {
+				resource: "dashboards".to_string(),
+				amount: 1,
+			},
+		];
+
+		assert_eq!(
+			status.check_all(&checks[..1]).unwrap()[0],
+			QuotaCheckResult {
+				resource: "storage_mb".to_string(),
+				requested: 1000,
+				available: 1000,
+				allowed: true,
+			}
+		);
+		assert!(status.check_all(&checks).is_err());
+	}
+
 	#[test]
 	fn test_api_permissions() {
 		let perm = ApiPermission::all_monitors();
@@ -130,9 +234,181 @@ mod tests {
 		];
 
 		for slug in valid_slugs {
-			// Just validate the slug format matches expected patterns
-			assert!(slug.chars().all(|c| c.is_alphanumeric() || c == '-'));
+			assert!(
+				validate_slug_format(slug).is_ok(),
+				"{} should be valid",
+				slug
+			);
+		}
+
+		let max_length = "a".repeat(SLUG_MAX_LENGTH);
+		assert!(validate_slug_format(&max_length).is_ok());
+
+		// Invalid slugs
+		let over_length = "a".repeat(SLUG_MAX_LENGTH + 1);
+		let invalid_slugs = vec![
+			"ab",
+			over_length.as_str(),
+			"Company",
+			"HELLO",
+			"hello world",
+			"Hello World!",
+			"../../etc",
+			"café",
+			"日本語テナント",
+			"-company",
+			"company-",
+			"company_name",
+		];
+
+		for slug in invalid_slugs {
+			assert!(
+				validate_slug_format(slug).is_err(),
+				"{} should be invalid",
+				slug
+			);
 		}
+
+		assert!(validate_slug_format("Company")
+			.unwrap_err()
+			.contains("lowercase"));
+		assert!(validate_slug_format(&over_length)
+			.unwrap_err()
+			.contains("between 3 and 63"));
+	}
+
+	#[test]
+	fn test_tenant_validate_slug() {
+		let reserved: Vec<String> = DEFAULT_RESERVED_SLUGS
+			.iter()
+			.map(|slug| slug.to_string())
+			.collect();
+		assert!(Tenant::validate_slug("acme-corp", &reserved).is_ok());
+
+		for (slug, reason) in [
+			("ab", "between 3 and 63"),
+			("Acme", "lowercase"),
+			("acme corp", "lowercase"),
+			("acme/corp", "lowercase"),
+			("-acme", "hyphen"),
+			("acme-", "hyphen"),
+			("api", "reserved"),
+			("health", "reserved"),
+			("admin", "reserved"),
+		] {
+			let error = Tenant::validate_slug(slug, &reserved).unwrap_err();
+			assert!(error.contains(reason), "{}: {}", slug, error);
+		}
+
+		// A configured list replaces the defaults
+		let configured = vec!["acme-corp".to_string()];
+		assert!(Tenant::validate_slug("api", &configured).is_ok());
+		assert!(Tenant::validate_slug("Acme-Corp", &configured).is_err());
+	}
+
+	#[test]
+	fn test_slugify() {
+		assert_eq!(slugify("Acme Corp"), "acme-corp");
+		assert_eq!(slugify("  Hello, World!  "), "hello-world");
+		assert_eq!(slugify("../../etc"), "etc");
+		assert_eq!(slugify("Café Zürich"), "caf-z-rich");
+		assert_eq!(slugify("日本語"), "");
+		assert_eq!(slugify("ACME--Corp__2024"), "acme-corp-2024");
+
+		let long = slugify(&format!("{} tail", "a".repeat(62)));
+		assert_eq!(long, "a".repeat(62));
+		assert!(validate_slug_format(&long).is_ok());
+
+		// Too-short results are left to validation to reject
+		assert!(validate_slug_format(&slugify("A")).is_err());
+	}
+
+	#[test]
+	fn test_reserved_slugs() {
+		let reserved: Vec<String> = DEFAULT_RESERVED_SLUGS
+			.iter()
+			.map(|s| s.to_string())
+			.collect();
+
+		assert!(is_reserved_slug("admin", &reserved));
+		assert!(is_reserved_slug("API", &reserved));
+		assert!(!is_reserved_slug("acme-corp", &reserved));
+
+		// Custom lists replace the defaults
+		let custom = vec!["billing".to_string()];
+		assert!(is_reserved_slug("billing", &custom));
+		assert!(!is_reserved_slug("admin", &custom));
+	}
+
+	#[test]
+	fn test_slug_rename_cooldown() {
+		let cooldown = Duration::hours(1);
+		let renamed_at = Utc::now();
+
+		// Never renamed: allowed
+		assert!(slug_rename_blocked_until(None, cooldown, renamed_at).is_none());
+
+		// Immediately after a rename: rejected until the cooldown elapses
+		let blocked = slug_rename_blocked_until(Some(renamed_at), cooldown, renamed_at);
+		assert_eq!(blocked, Some(renamed_at + cooldown));
+
+		// Once the cooldown has passed: allowed again
+		assert!(
+			slug_rename_blocked_until(Some(renamed_at), cooldown, renamed_at + cooldown).is_none()
+		);
+	}
+
+	#[test]
+	fn test_slug_rename_cooldown_maps_to_429() {
+		use axum::{http::StatusCode, response::IntoResponse};
+		use stellar_monitor_tenant_isolation::{
+			api::handlers::ApiError, repositories::TenantRepositoryError, services::ServiceError,
+		};
+
+		let error = ApiError::Service(ServiceError::Repository(
+			TenantRepositoryError::RateLimited("Slug was renamed recently".to_string()),
+		));
+
+		assert_eq!(
+			error.into_response().status(),
+			StatusCode::TOO_MANY_REQUESTS
+		);
+	}
+
+	#[test]
+	fn test_expired_api_key_maps_to_401() {
+		use axum::{http::StatusCode, response::IntoResponse};
+		use stellar_monitor_tenant_isolation::api::handlers::ApiError;
+
+		assert_eq!(
+			ApiError::ApiKeyExpired.into_response().status(),
+			StatusCode::UNAUTHORIZED
+		);
+	}
+
+	#[test]
+	fn test_exhausted_api_key_maps_to_401() {
+		use axum::{http::StatusCode, response::IntoResponse};
+		use stellar_monitor_tenant_isolation::api::handlers::ApiError;
+
+		assert_eq!(
+			ApiError::ApiKeyExhausted.into_response().status(),
+			StatusCode::UNAUTHORIZED
+		);
+	}
+
+	#[test]
+	fn test_network_unreachable_maps_to_422() {
+		use axum::{http::StatusCode, response::IntoResponse};
+		use stellar_monitor_tenant_isolation::{api::handlers::ApiError, services::ServiceError};
+
+		let error = ApiError::Service(ServiceError::NetworkUnreachable(
+			"connection refused".to_string(),
+		));
+		assert_eq!(
+			error.into_response().status(),
+			StatusCode::UNPROCESSABLE_ENTITY
+		);
 	}
 
 	#[test]
@@ -213,6 +489,7 @@ mod tests {
 			})),
 			ip_address: Some(IpAddr::from_str("192.168.1.1").unwrap()),
 			user_agent: Some("Mozilla/5.0".to_string()),
+			request_id: None,
 			created_at: Some(Utc::now()),
 		};
 
@@ -229,12 +506,22 @@ mod tests {
 			AuditAction::TenantCreated,
 			AuditAction::TenantUpdated,
 			AuditAction::TenantDeleted,
+			AuditAction::TenantSuspended,
+			AuditAction::TenantReactivated,
+			AuditAction::ConfigurationImported,
+			AuditAction::QuotaExceeded,
 			AuditAction::UserInvited,
 			AuditAction::UserRemoved,
 			AuditAction::UserRoleChanged,
+			AuditAction::MemberAdded,
+			AuditAction::InvitationCreated,
+			AuditAction::InvitationRevoked,
+			AuditAction::InvitationAccepted,
 			AuditAction::MonitorCreated,
 			AuditAction::MonitorUpdated,
 			AuditAction::MonitorDeleted,
+			AuditAction::MonitorRestored,
+			AuditAction::MonitorPurged,
 			AuditAction::MonitorEnabled,
 			AuditAction::MonitorDisabled,
 			AuditAction::NetworkCreated,
@@ -246,7 +533,12 @@ mod tests {
 			AuditAction::TriggerEnabled,
 			AuditAction::TriggerDisabled,
 			AuditAction::ApiKeyCreated,
+			AuditAction::ApiKeyRotated,
 			AuditAction::ApiKeyDeleted,
+			AuditAction::ApiKeyDisabled,
+			AuditAction::WebhookCreated,
+			AuditAction::WebhookUpdated,
+			AuditAction::WebhookDeleted,
 		];
 
 		for action in actions {
@@ -255,6 +547,17 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_resource_counts_is_empty() {
+		assert!(ResourceCounts::default().is_empty());
+		assert!(!ResourceCounts {
+			monitors: 0,
+			networks: 0,
+			triggers: 1,
+		}
+		.is_empty());
+	}
+
 	#[test]
 	fn test_quota_edge_cases() {
 		let status = ResourceQuotaStatus {
@@ -281,6 +584,9 @@ mod tests {
 				rpc_requests_per_minute: 0,
 				storage_mb: 0,
 			},
+			blockchains: vec![],
+			warnings: vec![],
+			monitor_triggers: Default::default(),
 		};
 
 		assert!(!status.can_create_monitor());
@@ -0,0 +1,85 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use axum_extra::{headers::Authorization, TypedHeader};
+use stellar_monitor_tenant_isolation::{
+	api::{auth, routes::AppState},
+	repositories::{tenant::TenantRepositoryTrait, TenantRepository},
+	utils::AuthService,
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{MockAuditService, MockMonitorService, MockNetworkService, MockTriggerService},
+	utils::{
+		builders::CreateTenantRequestBuilder,
+		database::{cleanup_database, TestDatabase},
+	},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_api_key_lists_only_its_own_tenant() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("acme-accessible")
+				.build(),
+		)
+		.await
+		.unwrap();
+	tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("globex-accessible")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let auth_service = AuthService::new("test-secret".to_string());
+	let api_key = auth_service.generate_api_key();
+	let _key_id: Uuid = sqlx::query_scalar(
+		"INSERT INTO api_keys (tenant_id, name, key_prefix, key_hash) VALUES ($1, $2, $3, $4) RETURNING id",
+	)
+	.bind(tenant.id)
+	.bind("ci")
+	.bind(AuthService::api_key_lookup(&api_key).unwrap())
+	.bind(AuthService::hash_api_key(&api_key))
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		tenant_repo,
+		MockAuditService::new(),
+		db.pool.clone(),
+		auth_service,
+	);
+
+	// Act
+	let response = auth::list_accessible_tenants(
+		State(state),
+		Some(TypedHeader(
+			Authorization::bearer(&format!("smt_{}", api_key)).unwrap(),
+		)),
+	)
+	.await
+	.map(IntoResponse::into_response)
+	.unwrap_or_else(IntoResponse::into_response);
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::OK);
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+	let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+	let tenants = body["data"].as_array().unwrap();
+	assert_eq!(tenants.len(), 1);
+	assert_eq!(tenants[0]["id"], tenant.id.to_string());
+	assert!(tenants[0].get("role").is_none());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,159 @@
+use axum::{
+	body::Body,
+	http::{header, Request, StatusCode},
+	middleware,
+	routing::get,
+	Router,
+};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	api::{middleware::tenant_auth_middleware, routes::AppState},
+	repositories::{tenant::TenantRepositoryTrait, TenantRepository},
+	utils::AuthService,
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+	mocks::{
+		MockAuditService, MockMonitorService, MockNetworkService, MockTenantRepository,
+		MockTriggerService,
+	},
+	utils::{
+		builders::CreateTenantRequestBuilder,
+		database::{cleanup_database, TestDatabase},
+	},
+};
+
+async fn last_used_at(pool: &PgPool, key_id: Uuid) -> Option<DateTime<Utc>> {
+	sqlx::query_scalar("SELECT last_used_at FROM api_keys WHERE id = $1")
+		.bind(key_id)
+		.fetch_one(pool)
+		.await
+		.unwrap()
+}
+
+async fn insert_api_key(pool: &PgPool, tenant_id: Uuid, api_key: &str) -> Uuid {
+	sqlx::query_scalar(
+		"INSERT INTO api_keys (tenant_id, name, key_prefix, key_hash) VALUES ($1, $2, $3, $4) RETURNING id",
+	)
+	.bind(tenant_id)
+	.bind("ci")
+	.bind(AuthService::api_key_lookup(api_key).unwrap())
+	.bind(AuthService::hash_api_key(api_key))
+	.fetch_one(pool)
+	.await
+	.unwrap()
+}
+
+/// A tenant route behind the real authentication middleware and database
+fn app(pool: PgPool, auth_service: AuthService) -> Router {
+	let state = AppState::new(
+		Arc::new(MockMonitorService::new()),
+		Arc::new(MockNetworkService::new()),
+		Arc::new(MockTriggerService::new()),
+		Arc::new(MockTenantRepository::new()),
+		Arc::new(MockAuditService::new()),
+		pool,
+		auth_service,
+	);
+	let tenant_routes = Router::new()
+		.route("/monitors", get(|| async { "monitors" }))
+		.layer(middleware::from_fn_with_state(
+			state.clone(),
+			tenant_auth_middleware,
+		));
+	Router::new()
+		.nest("/api/v1/tenants/:tenant_slug", tenant_routes)
+		.with_state(state)
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_last_used_at_advances_after_a_request() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_name("Acme Corp")
+				.with_slug("acme-key-usage")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let auth_service = AuthService::new("test-secret".to_string());
+	let api_key = auth_service.generate_api_key();
+	let key_id = insert_api_key(&db.pool, tenant.id, &api_key).await;
+	let app = app(db.pool.clone(), auth_service);
+	let request = || {
+		Request::get("/api/v1/tenants/acme-key-usage/monitors")
+			.header(header::AUTHORIZATION, format!("Bearer smt_{}", api_key))
+			.body(Body::empty())
+			.unwrap()
+	};
+
+	// Act
+	let first = app.clone().oneshot(request()).await.unwrap();
+	let first_used = last_used_at(&db.pool, key_id).await;
+	let stale = sqlx::query_scalar::<_, DateTime<Utc>>(
+		"UPDATE api_keys SET last_used_at = NOW() - INTERVAL '2 minutes' WHERE id = $1 RETURNING last_used_at",
+	)
+	.bind(key_id)
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	let second = app.clone().oneshot(request()).await.unwrap();
+	let second_used = last_used_at(&db.pool, key_id).await.unwrap();
+	let third = app.oneshot(request()).await.unwrap();
+	let third_used = last_used_at(&db.pool, key_id).await.unwrap();
+
+	// Assert
+	assert_eq!(first.status(), StatusCode::OK);
+	assert_eq!(second.status(), StatusCode::OK);
+	assert_eq!(third.status(), StatusCode::OK);
+	assert!(first_used.is_some());
+	assert!(second_used > stale);
+	// A request within a minute of the last write leaves the timestamp alone
+	assert_eq!(third_used, second_used);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_api_key_of_deleted_tenant_is_rejected() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("acme-deleted-key")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let auth_service = AuthService::new("test-secret".to_string());
+	let api_key = auth_service.generate_api_key();
+	insert_api_key(&db.pool, tenant.id, &api_key).await;
+	tenant_repo.delete(tenant.id, true).await.unwrap();
+
+	// Act
+	let response = app(db.pool.clone(), auth_service)
+		.oneshot(
+			Request::get("/api/v1/tenants/acme-deleted-key/monitors")
+				.header(header::AUTHORIZATION, format!("Bearer smt_{}", api_key))
+				.body(Body::empty())
+				.unwrap(),
+		)
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,58 @@
+use mockall::predicate::*;
+use stellar_monitor_tenant_isolation::{models::*, repositories::audit::AuditRepositoryTrait};
+use uuid::Uuid;
+
+use crate::{mocks::MockAuditRepository, utils::fixtures::TestIds};
+
+#[tokio::test]
+async fn test_query_uses_filter_page_bounds() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let resource_id = Uuid::new_v4();
+	let filter = AuditLogFilter {
+		resource_id: Some(resource_id),
+		limit: Some(1_000),
+		offset: Some(-5),
+		..Default::default()
+	};
+
+	let mut mock_repo = MockAuditRepository::new();
+	mock_repo
+		.expect_query()
+		.withf(move |tenant_id, filter, limit, offset| {
+			*tenant_id == test_ids.tenant_1
+				&& filter.resource_id == Some(resource_id)
+				&& *limit == 500
+				&& *offset == 0
+		})
+		.times(1)
+		.returning(|_, _, _, _| Ok(vec![]));
+	mock_repo
+		.expect_count()
+		.with(eq(test_ids.tenant_1), always())
+		.times(1)
+		.returning(|_, _| Ok(42));
+
+	// Act
+	let logs = mock_repo
+		.query(
+			test_ids.tenant_1,
+			&filter,
+			filter.page_limit(),
+			filter.page_offset(),
+		)
+		.await
+		.unwrap();
+	let total = mock_repo.count(test_ids.tenant_1, &filter).await.unwrap();
+
+	// Assert
+	assert!(logs.is_empty());
+	assert_eq!(total, 42);
+}
+
+#[test]
+fn test_filter_page_defaults() {
+	let filter = AuditLogFilter::default();
+	assert_eq!(filter.page_limit(), 50);
+	assert_eq!(filter.page_offset(), 0);
+}
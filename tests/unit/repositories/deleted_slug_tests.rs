@@ -0,0 +1,111 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use stellar_monitor_tenant_isolation::{
+	api::{auth, routes::AppState},
+	repositories::{error::TenantRepositoryError, tenant::TenantRepositoryTrait, TenantRepository},
+	utils::AuthService,
+};
+
+use crate::{
+	mocks::{MockAuditService, MockMonitorService, MockNetworkService, MockTriggerService},
+	utils::{
+		builders::CreateTenantRequestBuilder,
+		database::{cleanup_database, TestDatabase},
+	},
+};
+
+fn register_request(slug: &str) -> Json<auth::RegisterRequest> {
+	Json(auth::RegisterRequest {
+		email: format!("owner@{}.test", slug),
+		password: "correct horse battery staple".to_string(),
+		tenant_name: "Returning Corp".to_string(),
+		tenant_slug: Some(slug.to_string()),
+	})
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_registration_rejects_slug_of_recently_deleted_tenant() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone())
+		.with_deleted_slug_retention(chrono::Duration::days(30));
+	let deleted = repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("held-slug")
+				.build(),
+		)
+		.await
+		.unwrap();
+	repo.delete(deleted.id, false).await.unwrap();
+	let state = AppState::new(
+		MockMonitorService::new(),
+		MockNetworkService::new(),
+		MockTriggerService::new(),
+		repo.clone(),
+		MockAuditService::new(),
+		db.pool.clone(),
+		AuthService::new("test-secret".to_string()),
+	);
+
+	// Act
+	let response = auth::register(State(state), register_request("held-slug"))
+		.await
+		.map(IntoResponse::into_response)
+		.unwrap_or_else(IntoResponse::into_response);
+	let created = repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("held-slug")
+				.build(),
+		)
+		.await;
+
+	// Assert
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	assert!(matches!(
+		created,
+		Err(TenantRepositoryError::DeletedTenantSlug(_))
+	));
+	// The deleted tenant can still come back under its slug
+	assert_eq!(repo.restore(deleted.id).await.unwrap().slug, "held-slug");
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_deleted_slug_is_reusable_without_retention() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let deleted = repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("free-slug")
+				.build(),
+		)
+		.await
+		.unwrap();
+	repo.delete(deleted.id, false).await.unwrap();
+
+	// Act
+	let reused = repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("free-slug")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let restored = repo.restore(deleted.id).await;
+
+	// Assert
+	assert_ne!(reused.id, deleted.id);
+	assert!(matches!(
+		restored,
+		Err(TenantRepositoryError::AlreadyExists { .. })
+	));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
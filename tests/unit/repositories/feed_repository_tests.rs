@@ -0,0 +1,78 @@
+use chrono::Utc;
+use std::sync::{Arc, Mutex};
+use stellar_monitor_tenant_isolation::{
+	models::*, repositories::feed::ResourceFeedRepositoryTrait,
+};
+use uuid::Uuid;
+
+use crate::mocks::MockResourceFeedRepository;
+
+fn record(log: &Mutex<Vec<ResourceChangeEvent>>, kind: &str, resource_id: Uuid, action: &str) {
+	let mut log = log.lock().unwrap();
+	let cursor = log.len() as i64 + 1;
+	log.push(ResourceChangeEvent {
+		cursor,
+		kind: kind.to_string(),
+		resource_id,
+		action: action.to_string(),
+		occurred_at: Utc::now(),
+	});
+}
+
+#[tokio::test]
+async fn test_feed_returns_changes_after_cursor_in_order() {
+	// Arrange
+	let log = Arc::new(Mutex::new(Vec::new()));
+	let network_id = Uuid::new_v4();
+	let monitor_id = Uuid::new_v4();
+	let trigger_id = Uuid::new_v4();
+
+	record(&log, "network", network_id, "created");
+	record(&log, "monitor", monitor_id, "created");
+	let since = log.lock().unwrap().last().unwrap().cursor;
+	record(&log, "trigger", trigger_id, "created");
+	record(&log, "monitor", monitor_id, "updated");
+	record(&log, "trigger", trigger_id, "deleted");
+
+	let mut mock_repo = MockResourceFeedRepository::new();
+	let feed_log = log.clone();
+	mock_repo
+		.expect_list_since()
+		.returning(move |since, limit| {
+			Ok(feed_log
+				.lock()
+				.unwrap()
+				.iter()
+				.filter(|e| e.cursor > since)
+				.take(limit as usize)
+				.cloned()
+				.collect())
+		});
+
+	// Act
+	let events = mock_repo.list_since(since, 100).await.unwrap();
+	let page = FeedPage::new(events, since);
+
+	// Assert
+	let changes: Vec<_> = page
+		.events
+		.iter()
+		.map(|e| (e.kind.as_str(), e.resource_id, e.action.as_str()))
+		.collect();
+	assert_eq!(
+		changes,
+		vec![
+			("trigger", trigger_id, "created"),
+			("monitor", monitor_id, "updated"),
+			("trigger", trigger_id, "deleted"),
+		]
+	);
+	assert!(page.events.windows(2).all(|w| w[0].cursor < w[1].cursor));
+	assert_eq!(page.next_cursor, 5);
+
+	// Resuming from the returned cursor yields nothing new
+	let events = mock_repo.list_since(page.next_cursor, 100).await.unwrap();
+	let resumed = FeedPage::new(events, page.next_cursor);
+	assert!(resumed.events.is_empty());
+	assert_eq!(resumed.next_cursor, page.next_cursor);
+}
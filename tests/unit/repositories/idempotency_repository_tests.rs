@@ -0,0 +1,224 @@
+use chrono::Duration;
+use stellar_monitor_tenant_isolation::repositories::{
+	tenant::TenantRepositoryTrait, IdempotencyClaim, IdempotencyRepository, StoredResponse,
+	TenantRepository,
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_concurrent_claim_waits_for_stored_response() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("idempotent-acme")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let other_tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("idempotent-other")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let repo = IdempotencyRepository::new(db.pool.clone());
+	let ttl = Duration::hours(24);
+	let response = StoredResponse {
+		status: 201,
+		body: b"{\"id\":\"m-1\"}".to_vec(),
+	};
+
+	// Act
+	let IdempotencyClaim::Claimed(lock) = repo
+		.claim(tenant.id, "retry-1", "fingerprint", ttl)
+		.await
+		.unwrap()
+	else {
+		panic!("expected the first request to claim the key");
+	};
+
+	let second = tokio::spawn({
+		let repo = repo.clone();
+		async move {
+			repo.claim(tenant.id, "retry-1", "fingerprint", ttl)
+				.await
+				.unwrap()
+		}
+	});
+	tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+	assert!(!second.is_finished());
+
+	// The same key under another tenant is independent
+	let other = repo
+		.claim(other_tenant.id, "retry-1", "fingerprint", ttl)
+		.await
+		.unwrap();
+
+	lock.store(&response).await.unwrap();
+	let second = second.await.unwrap();
+
+	// Assert
+	assert!(matches!(other, IdempotencyClaim::Claimed(_)));
+	assert!(matches!(second, IdempotencyClaim::Replay(replayed) if replayed == response));
+
+	drop(other);
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_released_claim_lets_a_retry_run_again() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("idempotent-retry")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let repo = IdempotencyRepository::new(db.pool.clone());
+	let ttl = Duration::hours(24);
+	let IdempotencyClaim::Claimed(lock) = repo
+		.claim(tenant.id, "retry-2", "fingerprint", ttl)
+		.await
+		.unwrap()
+	else {
+		panic!("expected the first request to claim the key");
+	};
+
+	// Act
+	lock.release().await.unwrap();
+	let retry = tokio::time::timeout(
+		std::time::Duration::from_secs(5),
+		repo.claim(tenant.id, "retry-2", "fingerprint", ttl),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+
+	// Assert
+	assert!(matches!(retry, IdempotencyClaim::Claimed(_)));
+
+	drop(retry);
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_key_reused_for_another_request_is_a_mismatch() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("idempotent-mismatch")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let repo = IdempotencyRepository::new(db.pool.clone());
+	let ttl = Duration::hours(24);
+	let IdempotencyClaim::Claimed(lock) = repo
+		.claim(tenant.id, "retry-3", "first-request", ttl)
+		.await
+		.unwrap()
+	else {
+		panic!("expected the first request to claim the key");
+	};
+
+	// Act
+	let while_pending = repo
+		.claim(tenant.id, "retry-3", "second-request", ttl)
+		.await
+		.unwrap();
+	lock.store(&StoredResponse {
+		status: 201,
+		body: b"{}".to_vec(),
+	})
+	.await
+	.unwrap();
+	let once_stored = repo
+		.claim(tenant.id, "retry-3", "second-request", ttl)
+		.await
+		.unwrap();
+
+	// Assert
+	assert!(matches!(while_pending, IdempotencyClaim::Mismatch));
+	assert!(matches!(once_stored, IdempotencyClaim::Mismatch));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_claim_is_abandoned_only_once_its_lease_lapses() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("idempotent-lease")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let repo = IdempotencyRepository::new(db.pool.clone());
+	let ttl = Duration::hours(24);
+	let IdempotencyClaim::Claimed(lock) = repo
+		.claim(tenant.id, "retry-4", "fingerprint", ttl)
+		.await
+		.unwrap()
+	else {
+		panic!("expected the first request to claim the key");
+	};
+	// Claimed longer ago than the claim timeout, but with a fresh lease
+	sqlx::query(
+		"UPDATE idempotency_keys SET created_at = NOW() - INTERVAL '2 minutes', lease_renewed_at = NOW() WHERE key = 'retry-4'",
+	)
+	.execute(&db.pool)
+	.await
+	.unwrap();
+
+	// Act
+	let while_renewed = tokio::time::timeout(
+		std::time::Duration::from_millis(300),
+		repo.claim(tenant.id, "retry-4", "fingerprint", ttl),
+	)
+	.await;
+	sqlx::query(
+		"UPDATE idempotency_keys SET lease_renewed_at = NOW() - INTERVAL '2 minutes' WHERE key = 'retry-4'",
+	)
+	.execute(&db.pool)
+	.await
+	.unwrap();
+	let once_lapsed = tokio::time::timeout(
+		std::time::Duration::from_secs(5),
+		repo.claim(tenant.id, "retry-4", "fingerprint", ttl),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+
+	// Assert
+	assert!(
+		while_renewed.is_err(),
+		"a renewed claim must not be taken over"
+	);
+	assert!(matches!(once_lapsed, IdempotencyClaim::Claimed(_)));
+
+	drop(lock);
+	drop(once_lapsed);
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,378 @@
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	models::{
+		ImportConflict, ImportMode, MonitorExportEntry, NetworkExportEntry, TenantExport,
+		TenantExportDocument, TriggerExportEntry, EXPORT_FORMAT_VERSION,
+	},
+	repositories::{
+		error::TenantRepositoryError, monitor::TenantMonitorRepositoryTrait,
+		network::TenantNetworkRepositoryTrait, tenant::TenantRepositoryTrait,
+		trigger::TenantTriggerRepositoryTrait, TenantImportRepository, TenantMonitorRepository,
+		TenantNetworkRepository, TenantRepository, TenantTriggerRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::{CreateNetworkRequestBuilder, CreateTenantRequestBuilder},
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+fn network(network_id: &str) -> NetworkExportEntry {
+	NetworkExportEntry {
+		network_id: network_id.to_string(),
+		name: network_id.to_string(),
+		blockchain: "stellar".to_string(),
+		configuration: json!({ "rpc_url": "https://example.com/rpc" }),
+		is_active: true,
+	}
+}
+
+fn monitor(monitor_id: &str, network_id: &str) -> MonitorExportEntry {
+	MonitorExportEntry {
+		monitor_id: monitor_id.to_string(),
+		name: monitor_id.to_string(),
+		network_id: network_id.to_string(),
+		configuration: json!({}),
+		is_active: true,
+	}
+}
+
+fn trigger(trigger_id: &str, monitor_id: &str) -> TriggerExportEntry {
+	TriggerExportEntry {
+		trigger_id: trigger_id.to_string(),
+		name: trigger_id.to_string(),
+		monitor_id: monitor_id.to_string(),
+		trigger_type: "email".to_string(),
+		configuration: json!({ "to": ["ops@example.com"] }),
+		is_active: true,
+	}
+}
+
+fn conflict(resource_type: &str, id: &str) -> ImportConflict {
+	ImportConflict {
+		resource_type: resource_type.to_string(),
+		id: id.to_string(),
+	}
+}
+
+fn document() -> TenantExportDocument {
+	TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![network("stellar-mainnet"), network("stellar-testnet")],
+		monitors: vec![
+			monitor("payments", "stellar-mainnet"),
+			monitor("swaps", "stellar-testnet"),
+		],
+		triggers: vec![
+			trigger("payments-email", "payments"),
+			trigger("swaps-email", "swaps"),
+		],
+	}
+}
+
+/// The current tenant's configuration as the export would write it, sorted by id
+async fn export_document(pool: &sqlx::PgPool) -> TenantExportDocument {
+	let export = TenantExport {
+		networks: TenantNetworkRepository::new(pool.clone())
+			.list(100, 0)
+			.await
+			.unwrap(),
+		monitors: TenantMonitorRepository::new(pool.clone())
+			.list(100, 0)
+			.await
+			.unwrap(),
+		triggers: TenantTriggerRepository::new(pool.clone())
+			.list(100, 0)
+			.await
+			.unwrap(),
+	};
+	let mut document = export.document();
+	document
+		.networks
+		.sort_by(|a, b| a.network_id.cmp(&b.network_id));
+	document
+		.monitors
+		.sort_by(|a, b| a.monitor_id.cmp(&b.monitor_id));
+	document
+		.triggers
+		.sort_by(|a, b| a.trigger_id.cmp(&b.trigger_id));
+	document
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_import_remaps_references_and_skips_existing() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("import-skip")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let existing = with_tenant_context(
+		context.clone(),
+		TenantNetworkRepository::new(db.pool.clone()).create(
+			CreateNetworkRequestBuilder::new()
+				.with_network_id("stellar-mainnet")
+				.build(),
+		),
+	)
+	.await
+	.unwrap();
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![network("stellar-mainnet"), network("stellar-testnet")],
+		monitors: vec![
+			monitor("payments", "stellar-mainnet"),
+			monitor("swaps", "stellar-testnet"),
+		],
+		triggers: vec![trigger("payments-email", "payments")],
+	};
+	let import = TenantImportRepository::new(db.pool.clone());
+
+	// Act
+	let failed =
+		with_tenant_context(context.clone(), import.import(&document, ImportMode::Fail)).await;
+	let summary = with_tenant_context(
+		context.clone(),
+		import.import(&document, ImportMode::SkipExisting),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	assert!(matches!(
+		failed,
+		Err(TenantRepositoryError::ImportConflicts(ref conflicts))
+			if conflicts == &vec![conflict("network", "stellar-mainnet")]
+	));
+	assert_eq!(
+		summary.conflicts,
+		vec![conflict("network", "stellar-mainnet")]
+	);
+	assert_eq!(summary.created.networks, 1);
+	assert_eq!(summary.skipped.networks, 1);
+	assert_eq!(summary.created.monitors, 2);
+	assert_eq!(summary.created.triggers, 1);
+
+	with_tenant_context(context, async {
+		let monitors = TenantMonitorRepository::new(db.pool.clone());
+		let testnet = TenantNetworkRepository::new(db.pool.clone())
+			.get("stellar-testnet")
+			.await
+			.unwrap();
+		let payments = monitors.get("payments").await.unwrap();
+		assert_eq!(payments.network_id, existing.id);
+		assert_eq!(monitors.get("swaps").await.unwrap().network_id, testnet.id);
+
+		let triggers = TenantTriggerRepository::new(db.pool.clone())
+			.get_by_monitor(payments.id)
+			.await
+			.unwrap();
+		assert_eq!(triggers.len(), 1);
+		assert_eq!(triggers[0].trigger_id, "payments-email");
+	})
+	.await;
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_import_rolls_back_when_quota_exceeded() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("import-quota")
+				.with_max_monitors(1)
+				.build(),
+		)
+		.await
+		.unwrap();
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![network("stellar-mainnet")],
+		monitors: vec![
+			monitor("payments", "stellar-mainnet"),
+			monitor("swaps", "stellar-mainnet"),
+		],
+		triggers: vec![],
+	};
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(tenant.id),
+		TenantImportRepository::new(db.pool.clone()).import(&document, ImportMode::Fail),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(TenantRepositoryError::QuotaExceeded(_))
+	));
+	let networks: i64 =
+		sqlx::query_scalar("SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1")
+			.bind(tenant.id)
+			.fetch_one(&db.pool)
+			.await
+			.unwrap();
+	assert_eq!(networks, 0);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_export_import_round_trip_into_fresh_tenant() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let staging = tenants
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("round-trip-staging")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let production = tenants
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("round-trip-production")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let import = TenantImportRepository::new(db.pool.clone());
+	let exported = with_tenant_context(owner_context(staging.id), async {
+		import.import(&document(), ImportMode::Fail).await.unwrap();
+		export_document(&db.pool).await
+	})
+	.await;
+
+	// Act
+	let (summary, reexported) = with_tenant_context(owner_context(production.id), async {
+		let summary = import.import(&exported, ImportMode::Fail).await.unwrap();
+		(summary, export_document(&db.pool).await)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(exported.version, EXPORT_FORMAT_VERSION);
+	assert_eq!(summary.created.networks, 2);
+	assert_eq!(summary.created.monitors, 2);
+	assert_eq!(summary.created.triggers, 2);
+	assert!(summary.conflicts.is_empty());
+	assert_eq!(reexported, exported);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_import_fail_reports_every_conflict() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("import-conflicts")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let import = TenantImportRepository::new(db.pool.clone());
+	let context = owner_context(tenant.id);
+	with_tenant_context(
+		context.clone(),
+		import.import(
+			&TenantExportDocument {
+				version: EXPORT_FORMAT_VERSION,
+				networks: vec![network("stellar-mainnet")],
+				monitors: vec![monitor("payments", "stellar-mainnet")],
+				triggers: vec![trigger("payments-email", "payments")],
+			},
+			ImportMode::Fail,
+		),
+	)
+	.await
+	.unwrap();
+
+	// Act
+	let result = with_tenant_context(
+		context.clone(),
+		import.import(&document(), ImportMode::Fail),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(TenantRepositoryError::ImportConflicts(conflicts)) => assert_eq!(
+			conflicts,
+			vec![
+				conflict("network", "stellar-mainnet"),
+				conflict("monitor", "payments"),
+				conflict("trigger", "payments-email"),
+			]
+		),
+		other => panic!("expected import conflicts, got {:?}", other),
+	}
+	let testnet = with_tenant_context(
+		context,
+		TenantNetworkRepository::new(db.pool.clone()).get("stellar-testnet"),
+	)
+	.await;
+	assert!(matches!(
+		testnet,
+		Err(TenantRepositoryError::ResourceNotFound { .. })
+	));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_import_dry_run_reports_without_writing() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("import-dry-run")
+				.build(),
+		)
+		.await
+		.unwrap();
+
+	// Act
+	let summary = with_tenant_context(
+		owner_context(tenant.id),
+		TenantImportRepository::new(db.pool.clone()).dry_run(&document(), ImportMode::Fail),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	assert!(summary.dry_run);
+	assert_eq!(summary.created.networks, 2);
+	assert_eq!(summary.created.monitors, 2);
+	assert_eq!(summary.created.triggers, 2);
+	let networks: i64 =
+		sqlx::query_scalar("SELECT COUNT(*) FROM tenant_networks WHERE tenant_id = $1")
+			.bind(tenant.id)
+			.fetch_one(&db.pool)
+			.await
+			.unwrap();
+	assert_eq!(networks, 0);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,97 @@
+use stellar_monitor_tenant_isolation::{
+	models::{IntegrityViolationKind, TenantMonitor, TenantNetwork},
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, network::TenantNetworkRepositoryTrait,
+		tenant::TenantRepositoryTrait, TenantMonitorRepository, TenantNetworkRepository,
+		TenantRepository,
+	},
+	utils::with_tenant_context,
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::{
+		CreateMonitorRequestBuilder, CreateNetworkRequestBuilder, CreateTenantRequestBuilder,
+	},
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+/// A tenant with one network and one monitor watching it
+async fn tenant_with_monitor(
+	db: &TestDatabase,
+	slug: &str,
+) -> (Uuid, TenantNetwork, TenantMonitor) {
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(CreateTenantRequestBuilder::new().with_slug(slug).build())
+		.await
+		.unwrap();
+
+	with_tenant_context(owner_context(tenant.id), async {
+		let network = TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build())
+			.await
+			.unwrap();
+		let monitor = TenantMonitorRepository::new(db.pool.clone())
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		(tenant.id, network, monitor)
+	})
+	.await
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_healthy_tenant_has_no_integrity_violations() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let (tenant_id, _, _) = tenant_with_monitor(&db, "integrity-healthy").await;
+
+	// Act
+	let violations = TenantRepository::new(db.pool.clone())
+		.find_integrity_violations(tenant_id)
+		.await
+		.unwrap();
+
+	// Assert
+	assert!(violations.is_empty());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_monitor_pointing_at_another_tenants_network_is_reported() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let (tenant_id, _, monitor) = tenant_with_monitor(&db, "integrity-victim").await;
+	let (other_tenant_id, other_network, _) = tenant_with_monitor(&db, "integrity-other").await;
+	sqlx::query("UPDATE tenant_monitors SET network_id = $1 WHERE id = $2")
+		.bind(other_network.id)
+		.bind(monitor.id)
+		.execute(&db.pool)
+		.await
+		.unwrap();
+
+	// Act
+	let repo = TenantRepository::new(db.pool.clone());
+	let violations = repo.find_integrity_violations(tenant_id).await.unwrap();
+	let other_violations = repo
+		.find_integrity_violations(other_tenant_id)
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(violations.len(), 1);
+	assert_eq!(violations[0].kind, IntegrityViolationKind::ForeignNetwork);
+	assert_eq!(violations[0].resource_id, Some(monitor.id));
+	assert!(violations[0].detail.contains(&other_tenant_id.to_string()));
+	assert!(other_violations.is_empty());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
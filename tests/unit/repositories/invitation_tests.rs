@@ -0,0 +1,234 @@
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use stellar_monitor_tenant_isolation::{
+	models::{Tenant, TenantRole},
+	repositories::{
+		accept_pending_invitations, error::TenantRepositoryError, tenant::TenantRepositoryTrait,
+		InvitationRepository, TenantRepository,
+	},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+async fn insert_user(pool: &PgPool, email: &str) -> Uuid {
+	sqlx::query_scalar("INSERT INTO users (email, password_hash) VALUES ($1, '') RETURNING id")
+		.bind(email)
+		.fetch_one(pool)
+		.await
+		.unwrap()
+}
+
+async fn create_tenant(repo: &TenantRepository, slug: &str) -> Tenant {
+	repo.create(CreateTenantRequestBuilder::new().with_slug(slug).build())
+		.await
+		.unwrap()
+}
+
+async fn role_of(repo: &TenantRepository, tenant_id: Uuid, user_id: Uuid) -> Option<TenantRole> {
+	repo.get_members(tenant_id)
+		.await
+		.unwrap()
+		.into_iter()
+		.find(|membership| membership.user_id == user_id)
+		.map(|membership| membership.role)
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_reinviting_pending_email_keeps_token() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let invitations = InvitationRepository::new(db.pool.clone());
+	let tenant = create_tenant(&tenants, "reinvite").await;
+	let expires_at = Utc::now() + Duration::hours(1);
+
+	// Act
+	let first = invitations
+		.create(
+			tenant.id,
+			"Jane@Example.com",
+			TenantRole::Member,
+			None,
+			"token-1",
+			expires_at,
+		)
+		.await
+		.unwrap();
+	let second = invitations
+		.create(
+			tenant.id,
+			"jane@example.com",
+			TenantRole::Admin,
+			None,
+			"token-2",
+			expires_at,
+		)
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(first.id, second.id);
+	assert_eq!(second.email, "jane@example.com");
+	assert_eq!(second.token, "token-1");
+	assert_eq!(second.role, TenantRole::Admin);
+	assert_eq!(invitations.list_open(tenant.id).await.unwrap().len(), 1);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_accept_adds_membership_once() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let invitations = InvitationRepository::new(db.pool.clone());
+	let tenant = create_tenant(&tenants, "accept-invite").await;
+	let user_id = insert_user(&db.pool, "jane@accept.test").await;
+	invitations
+		.create(
+			tenant.id,
+			"jane@accept.test",
+			TenantRole::Admin,
+			None,
+			"accept-token",
+			Utc::now() + Duration::hours(1),
+		)
+		.await
+		.unwrap();
+
+	// Act
+	let accepted = invitations
+		.accept("accept-token", user_id, "JANE@accept.test")
+		.await
+		.unwrap();
+	let again = invitations
+		.accept("accept-token", user_id, "jane@accept.test")
+		.await;
+
+	// Assert
+	assert_eq!(accepted.accepted_by, Some(user_id));
+	assert_eq!(
+		role_of(&tenants, tenant.id, user_id).await,
+		Some(TenantRole::Admin)
+	);
+	assert!(matches!(
+		again,
+		Err(TenantRepositoryError::AlreadyExists { .. })
+	));
+	assert!(invitations.list_open(tenant.id).await.unwrap().is_empty());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_accept_rejects_other_email_expired_and_revoked() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let invitations = InvitationRepository::new(db.pool.clone());
+	let tenant = create_tenant(&tenants, "reject-invite").await;
+	let user_id = insert_user(&db.pool, "jane@reject.test").await;
+	invitations
+		.create(
+			tenant.id,
+			"jane@reject.test",
+			TenantRole::Member,
+			None,
+			"expired-token",
+			Utc::now() - Duration::minutes(1),
+		)
+		.await
+		.unwrap();
+	let revoked = invitations
+		.create(
+			tenant.id,
+			"john@reject.test",
+			TenantRole::Member,
+			None,
+			"revoked-token",
+			Utc::now() + Duration::hours(1),
+		)
+		.await
+		.unwrap();
+	invitations.revoke(tenant.id, revoked.id).await.unwrap();
+
+	// Act
+	let wrong_email = invitations
+		.accept("expired-token", user_id, "someone@else.test")
+		.await;
+	let expired = invitations
+		.accept("expired-token", user_id, "jane@reject.test")
+		.await;
+	let after_revoke = invitations
+		.accept("revoked-token", user_id, "john@reject.test")
+		.await;
+
+	// Assert
+	assert!(matches!(
+		wrong_email,
+		Err(TenantRepositoryError::AccessDenied(_))
+	));
+	assert!(matches!(
+		expired,
+		Err(TenantRepositoryError::ValidationError(_))
+	));
+	assert!(matches!(
+		after_revoke,
+		Err(TenantRepositoryError::ResourceNotFound { .. })
+	));
+	assert_eq!(role_of(&tenants, tenant.id, user_id).await, None);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_registration_accepts_pending_invitations() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let invitations = InvitationRepository::new(db.pool.clone());
+	let first = create_tenant(&tenants, "register-invite-1").await;
+	let second = create_tenant(&tenants, "register-invite-2").await;
+	for (tenant, token) in [(&first, "register-1"), (&second, "register-2")] {
+		invitations
+			.create(
+				tenant.id,
+				"new@register.test",
+				TenantRole::Viewer,
+				None,
+				token,
+				Utc::now() + Duration::hours(1),
+			)
+			.await
+			.unwrap();
+	}
+	let user_id = insert_user(&db.pool, "new@register.test").await;
+
+	// Act
+	let mut tx = db.pool.begin().await.unwrap();
+	let accepted = accept_pending_invitations(&mut tx, user_id, "new@register.test")
+		.await
+		.unwrap();
+	tx.commit().await.unwrap();
+
+	// Assert
+	assert_eq!(accepted.len(), 2);
+	assert_eq!(
+		role_of(&tenants, first.id, user_id).await,
+		Some(TenantRole::Viewer)
+	);
+	assert_eq!(
+		role_of(&tenants, second.id, user_id).await,
+		Some(TenantRole::Viewer)
+	);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,186 @@
+use stellar_monitor_tenant_isolation::{
+	models::{MonitorFilter, MonitorSort, TriggerFilter},
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, network::TenantNetworkRepositoryTrait,
+		tenant::TenantRepositoryTrait, trigger::TenantTriggerRepositoryTrait,
+		TenantMonitorRepository, TenantNetworkRepository, TenantRepository,
+		TenantTriggerRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::{
+		CreateMonitorRequestBuilder, CreateNetworkRequestBuilder, CreateTenantRequestBuilder,
+		CreateTriggerRequestBuilder,
+	},
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_monitor_and_trigger_filters_compose() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("listing-filters")
+				.build(),
+		)
+		.await
+		.unwrap();
+
+	with_tenant_context(owner_context(tenant.id), async {
+		let networks = TenantNetworkRepository::new(db.pool.clone());
+		let mainnet = networks
+			.create(
+				CreateNetworkRequestBuilder::new()
+					.with_network_id("stellar-mainnet")
+					.build(),
+			)
+			.await
+			.unwrap();
+		let testnet = networks
+			.create(
+				CreateNetworkRequestBuilder::new()
+					.with_network_id("stellar-testnet")
+					.build(),
+			)
+			.await
+			.unwrap();
+		let monitors = TenantMonitorRepository::new(db.pool.clone());
+		let mut created = Vec::new();
+		for (monitor_id, name, network) in [
+			("payments-b", "Payments B", mainnet.id),
+			("payments-a", "Payments A", mainnet.id),
+			("swaps", "Swaps", mainnet.id),
+			("payments-test", "Payments Test", testnet.id),
+		] {
+			created.push(
+				monitors
+					.create(
+						CreateMonitorRequestBuilder::new()
+							.with_monitor_id(monitor_id)
+							.with_name(name)
+							.with_network_id(network)
+							.build(),
+					)
+					.await
+					.unwrap(),
+			);
+		}
+		let triggers = TenantTriggerRepository::new(db.pool.clone());
+		triggers
+			.create(
+				CreateTriggerRequestBuilder::new()
+					.with_monitor_id(created[0].id)
+					.with_trigger_type("email")
+					.build(),
+			)
+			.await
+			.unwrap();
+		triggers
+			.create(
+				CreateTriggerRequestBuilder::new()
+					.with_monitor_id(created[2].id)
+					.build(),
+			)
+			.await
+			.unwrap();
+
+		// Act
+		let filter = MonitorFilter {
+			network: Some("stellar-mainnet".to_string()),
+			search: Some("PAY".to_string()),
+			sort: MonitorSort::Name,
+			..Default::default()
+		};
+		let page = monitors.list_filtered(filter.clone(), 10, 0).await.unwrap();
+		let total = monitors.count_filtered(filter).await.unwrap();
+		let by_uuid = monitors
+			.count_filtered(MonitorFilter {
+				network: Some(testnet.id.to_string()),
+				..Default::default()
+			})
+			.await
+			.unwrap();
+		let email = TriggerFilter {
+			trigger_type: Some("email".to_string()),
+			monitor: Some("payments-b".to_string()),
+			..Default::default()
+		};
+		let email_triggers = triggers.list_filtered(email.clone(), 10, 0).await.unwrap();
+		let email_total = triggers.count_filtered(email).await.unwrap();
+
+		// Assert
+		let names: Vec<&str> = page.iter().map(|m| m.name.as_str()).collect();
+		assert_eq!(names, ["Payments A", "Payments B"]);
+		assert_eq!(total, 2);
+		assert_eq!(by_uuid, 1);
+		assert_eq!(email_triggers.len(), 1);
+		assert_eq!(email_triggers[0].monitor_id, created[0].id);
+		assert_eq!(email_total, 1);
+	})
+	.await;
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_triggers_by_monitor_are_paged_with_a_total() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("triggers-by-monitor")
+				.build(),
+		)
+		.await
+		.unwrap();
+
+	with_tenant_context(owner_context(tenant.id), async {
+		let network = TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build())
+			.await
+			.unwrap();
+		let monitor = TenantMonitorRepository::new(db.pool.clone())
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		let triggers = TenantTriggerRepository::new(db.pool.clone());
+		for trigger_id in ["first", "second", "third"] {
+			triggers
+				.create(
+					CreateTriggerRequestBuilder::new()
+						.with_trigger_id(trigger_id)
+						.with_monitor_id(monitor.id)
+						.build(),
+				)
+				.await
+				.unwrap();
+		}
+
+		// Act
+		let first_page = triggers.list_by_monitor(monitor.id, 2, 0).await.unwrap();
+		let last_page = triggers.list_by_monitor(monitor.id, 2, 2).await.unwrap();
+		let total = triggers.count_by_monitor(monitor.id).await.unwrap();
+
+		// Assert
+		let ids: Vec<&str> = first_page.iter().map(|t| t.trigger_id.as_str()).collect();
+		assert_eq!(ids, ["first", "second"]);
+		assert_eq!(last_page.len(), 1);
+		assert_eq!(last_page[0].trigger_id, "third");
+		assert_eq!(total, 3);
+	})
+	.await;
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
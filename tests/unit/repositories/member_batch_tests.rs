@@ -0,0 +1,237 @@
+use sqlx::PgPool;
+use stellar_monitor_tenant_isolation::{
+	models::{BatchMemberEntry, BatchMemberStatus, MemberSyncAction, Tenant, TenantRole},
+	repositories::{error::TenantRepositoryError, tenant::TenantRepositoryTrait, TenantRepository},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+async fn insert_user(pool: &PgPool, email: &str) -> Uuid {
+	sqlx::query_scalar("INSERT INTO users (email, password_hash) VALUES ($1, '') RETURNING id")
+		.bind(email)
+		.fetch_one(pool)
+		.await
+		.unwrap()
+}
+
+/// A tenant whose only member is a new owner
+async fn tenant_with_owner(repo: &TenantRepository, pool: &PgPool, slug: &str) -> Tenant {
+	let tenant = repo
+		.create(CreateTenantRequestBuilder::new().with_slug(slug).build())
+		.await
+		.unwrap();
+	let owner_id = insert_user(pool, &format!("owner@{}.test", slug)).await;
+	repo.add_member(tenant.id, owner_id, TenantRole::Owner)
+		.await
+		.unwrap();
+	tenant
+}
+
+fn by_email(email: &str) -> BatchMemberEntry {
+	BatchMemberEntry {
+		user_id: None,
+		email: Some(email.to_string()),
+		role: TenantRole::Member,
+	}
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_batch_adds_members_within_quota() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone()).with_max_members(3);
+	let tenant = tenant_with_owner(&repo, &db.pool, "batch-within").await;
+	let jane = insert_user(&db.pool, "jane@batch-within.test").await;
+	let john = insert_user(&db.pool, "john@batch-within.test").await;
+
+	// Act
+	let results = repo
+		.add_members_batch(
+			tenant.id,
+			vec![
+				by_email("JANE@batch-within.test"),
+				BatchMemberEntry {
+					user_id: Some(john),
+					email: None,
+					role: TenantRole::Viewer,
+				},
+				by_email("jane@batch-within.test"),
+				by_email("nobody@batch-within.test"),
+				BatchMemberEntry {
+					user_id: None,
+					email: None,
+					role: TenantRole::Member,
+				},
+			],
+		)
+		.await
+		.unwrap();
+
+	// Assert
+	let statuses: Vec<_> = results.iter().map(|result| result.status).collect();
+	assert_eq!(
+		statuses,
+		[
+			BatchMemberStatus::Added,
+			BatchMemberStatus::Added,
+			BatchMemberStatus::Duplicate,
+			BatchMemberStatus::UserNotFound,
+			BatchMemberStatus::Invalid,
+		]
+	);
+	assert_eq!(results[0].user_id, Some(jane));
+	let members = repo.get_members(tenant.id).await.unwrap();
+	assert_eq!(members.len(), 3);
+	assert!(members
+		.iter()
+		.any(|member| member.user_id == john && member.role == TenantRole::Viewer));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_batch_over_quota_adds_nobody() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone()).with_max_members(2);
+	let tenant = tenant_with_owner(&repo, &db.pool, "batch-over").await;
+	insert_user(&db.pool, "jane@batch-over.test").await;
+	insert_user(&db.pool, "john@batch-over.test").await;
+
+	// Act
+	let result = repo
+		.add_members_batch(
+			tenant.id,
+			vec![
+				by_email("jane@batch-over.test"),
+				by_email("john@batch-over.test"),
+			],
+		)
+		.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(TenantRepositoryError::QuotaExceeded(_))
+	));
+	assert_eq!(repo.get_members(tenant.id).await.unwrap().len(), 1);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_sync_adds_updates_and_removes_members() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_with_owner(&repo, &db.pool, "member-sync").await;
+	let owner = repo.get_members(tenant.id).await.unwrap()[0].user_id;
+	let jane = insert_user(&db.pool, "jane@member-sync.test").await;
+	let john = insert_user(&db.pool, "john@member-sync.test").await;
+	let mary = insert_user(&db.pool, "mary@member-sync.test").await;
+	repo.add_member(tenant.id, jane, TenantRole::Member)
+		.await
+		.unwrap();
+	repo.add_member(tenant.id, john, TenantRole::Member)
+		.await
+		.unwrap();
+	let desired = vec![
+		BatchMemberEntry {
+			user_id: Some(owner),
+			email: None,
+			role: TenantRole::Owner,
+		},
+		BatchMemberEntry {
+			role: TenantRole::Admin,
+			..by_email("jane@member-sync.test")
+		},
+		by_email("mary@member-sync.test"),
+	];
+
+	// Act
+	let result = repo.sync_members(tenant.id, desired, false).await.unwrap();
+
+	// Assert
+	let mut actions: Vec<_> = result
+		.changes
+		.iter()
+		.map(|change| (change.user_id, change.action))
+		.collect();
+	actions.sort_by_key(|(user_id, _)| *user_id);
+	let mut expected = vec![
+		(john, MemberSyncAction::Removed),
+		(jane, MemberSyncAction::RoleChanged),
+		(mary, MemberSyncAction::Added),
+	];
+	expected.sort_by_key(|(user_id, _)| *user_id);
+	assert_eq!(actions, expected);
+	let mut members: Vec<_> = repo
+		.get_members(tenant.id)
+		.await
+		.unwrap()
+		.into_iter()
+		.map(|member| (member.user_id, member.role))
+		.collect();
+	members.sort_by_key(|(user_id, _)| *user_id);
+	let mut expected = vec![
+		(owner, TenantRole::Owner),
+		(jane, TenantRole::Admin),
+		(mary, TenantRole::Member),
+	];
+	expected.sort_by_key(|(user_id, _)| *user_id);
+	assert_eq!(members, expected);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_sync_rejects_set_without_owner_or_changed_owners() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_with_owner(&repo, &db.pool, "member-sync-owner").await;
+	let jane = insert_user(&db.pool, "jane@member-sync-owner.test").await;
+
+	// Act
+	let without_owner = repo
+		.sync_members(
+			tenant.id,
+			vec![by_email("jane@member-sync-owner.test")],
+			true,
+		)
+		.await;
+	let new_owner = repo
+		.sync_members(
+			tenant.id,
+			vec![BatchMemberEntry {
+				user_id: Some(jane),
+				email: None,
+				role: TenantRole::Owner,
+			}],
+			false,
+		)
+		.await;
+
+	// Assert
+	assert!(matches!(
+		without_owner,
+		Err(TenantRepositoryError::ValidationError(_))
+	));
+	assert!(matches!(
+		new_owner,
+		Err(TenantRepositoryError::AccessDenied(_))
+	));
+	let members = repo.get_members(tenant.id).await.unwrap();
+	assert_eq!(members.len(), 1);
+	assert_eq!(members[0].role, TenantRole::Owner);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
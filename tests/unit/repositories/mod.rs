@@ -1,4 +1,28 @@
+mod accessible_tenants_tests;
+mod api_key_usage_tests;
+mod audit_repository_tests;
+mod deleted_slug_tests;
+mod feed_repository_tests;
+mod idempotency_repository_tests;
+mod import_tests;
+mod integrity_tests;
+mod invitation_tests;
+mod listing_filter_tests;
+mod member_batch_tests;
+mod monitor_audit_tests;
+mod monitor_deactivate_tests;
+mod monitor_delete_tests;
 mod monitor_repository_tests;
+mod network_delete_tests;
 mod network_repository_tests;
+mod owner_guard_tests;
+mod quota_concurrency_tests;
+mod quota_status_tests;
+mod storage_quota_tests;
+mod tenant_filter_tests;
 mod tenant_repository_tests;
+mod tenant_update_tests;
 mod trigger_repository_tests;
+mod trigger_test_record_tests;
+mod usage_repository_tests;
+mod webhook_tests;
@@ -0,0 +1,181 @@
+use stellar_monitor_tenant_isolation::{
+	models::{
+		AuditLogFilter, RequestMetadata, ResourceType, TenantQuotas, TenantRole,
+		UpdateMonitorRequest,
+	},
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, network::TenantNetworkRepositoryTrait,
+		tenant::TenantRepositoryTrait, TenantMonitorRepository, TenantNetworkRepository,
+		TenantRepository,
+	},
+	services::{AuditService, AuditServiceTrait, MonitorService, MonitorServiceTrait},
+	utils::{with_tenant_context, AuthenticatedUser, TenantContext},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::{
+		CreateMonitorRequestBuilder, CreateNetworkRequestBuilder, CreateTenantRequestBuilder,
+	},
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_monitor_audit_history_is_newest_first_and_limited() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("monitor-audit")
+				.build(),
+		)
+		.await
+		.unwrap();
+	// Audit entries reference the acting user, so the context needs a real one
+	let user_id: Uuid = sqlx::query_scalar(
+		"INSERT INTO users (email, password_hash) VALUES ('owner@monitor-audit.test', '') RETURNING id",
+	)
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	let context = TenantContext::with_user(
+		tenant.id,
+		AuthenticatedUser {
+			id: user_id,
+			email: "owner@monitor-audit.test".to_string(),
+			role: TenantRole::Owner,
+		},
+		TenantQuotas::default(),
+	);
+	let audit_service = AuditService::new(db.pool.clone());
+	let service = MonitorService::new(
+		TenantMonitorRepository::new(db.pool.clone()),
+		TenantNetworkRepository::new(db.pool.clone()),
+		tenant_repo,
+		audit_service.clone(),
+	);
+
+	// Act
+	let (monitor_id, history) = with_tenant_context(context, async {
+		let network = TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build())
+			.await
+			.unwrap();
+		let monitor = TenantMonitorRepository::new(db.pool.clone())
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		for revision in 1..=4 {
+			service
+				.update_monitor(
+					&monitor.monitor_id,
+					UpdateMonitorRequest {
+						name: Some(format!("Revision {}", revision)),
+						configuration: None,
+						is_active: None,
+					},
+					RequestMetadata::default(),
+				)
+				.await
+				.unwrap();
+		}
+
+		let history = audit_service
+			.query_logs(AuditLogFilter::for_resource(
+				ResourceType::Monitor,
+				monitor.id,
+				Some(3),
+			))
+			.await
+			.unwrap();
+		(monitor.id, history)
+	})
+	.await;
+
+	// Assert
+	let names: Vec<_> = history
+		.iter()
+		.map(|log| log.changes.as_ref().unwrap()["name"].clone())
+		.collect();
+	assert_eq!(names, ["Revision 4", "Revision 3", "Revision 2"]);
+	assert!(history
+		.iter()
+		.all(|log| log.resource_id == Some(monitor_id) && log.action == "monitor_updated"));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_audit_entry_keeps_the_request_id() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("audit-request-id")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = TenantContext::new(tenant.id, TenantQuotas::default());
+	let audit_service = AuditService::new(db.pool.clone());
+	let service = MonitorService::new(
+		TenantMonitorRepository::new(db.pool.clone()),
+		TenantNetworkRepository::new(db.pool.clone()),
+		tenant_repo,
+		audit_service.clone(),
+	);
+
+	// Act
+	let history = with_tenant_context(context, async {
+		let network = TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build())
+			.await
+			.unwrap();
+		let monitor = TenantMonitorRepository::new(db.pool.clone())
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		service
+			.update_monitor(
+				&monitor.monitor_id,
+				UpdateMonitorRequest {
+					name: Some("Renamed".to_string()),
+					configuration: None,
+					is_active: None,
+				},
+				RequestMetadata::new().with_request_id(Some("req-audit-1".to_string())),
+			)
+			.await
+			.unwrap();
+
+		audit_service
+			.query_logs(AuditLogFilter::for_resource(
+				ResourceType::Monitor,
+				monitor.id,
+				None,
+			))
+			.await
+			.unwrap()
+	})
+	.await;
+
+	// Assert
+	assert_eq!(history.len(), 1);
+	assert_eq!(history[0].request_id.as_deref(), Some("req-audit-1"));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
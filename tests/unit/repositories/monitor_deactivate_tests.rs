@@ -0,0 +1,110 @@
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	models::{
+		ImportMode, MonitorExportEntry, NetworkExportEntry, TenantExportDocument,
+		TriggerExportEntry, EXPORT_FORMAT_VERSION,
+	},
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, tenant::TenantRepositoryTrait,
+		trigger::TenantTriggerRepositoryTrait, TenantImportRepository, TenantMonitorRepository,
+		TenantRepository, TenantTriggerRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+fn trigger(trigger_id: &str) -> TriggerExportEntry {
+	TriggerExportEntry {
+		trigger_id: trigger_id.to_string(),
+		name: trigger_id.to_string(),
+		monitor_id: "payments".to_string(),
+		trigger_type: "email".to_string(),
+		configuration: json!({ "to": ["ops@example.com"] }),
+		is_active: true,
+	}
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_deactivate_rolls_back_monitor_when_trigger_update_fails() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("monitor-deactivate")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![NetworkExportEntry {
+			network_id: "stellar-mainnet".to_string(),
+			name: "Stellar Mainnet".to_string(),
+			blockchain: "stellar".to_string(),
+			configuration: json!({ "rpc_url": "https://example.com/rpc" }),
+			is_active: true,
+		}],
+		monitors: vec![MonitorExportEntry {
+			monitor_id: "payments".to_string(),
+			name: "Payments".to_string(),
+			network_id: "stellar-mainnet".to_string(),
+			configuration: json!({}),
+			is_active: true,
+		}],
+		triggers: vec![trigger("payments-email"), trigger("ops-email")],
+	};
+	with_tenant_context(
+		context.clone(),
+		TenantImportRepository::new(db.pool.clone()).import(&document, ImportMode::Fail),
+	)
+	.await
+	.unwrap();
+	// Each test has its own database, so failing every trigger update is contained
+	sqlx::raw_sql(
+		r#"
+		CREATE FUNCTION fail_trigger_update() RETURNS trigger AS $$
+		BEGIN
+			RAISE EXCEPTION 'trigger update failed';
+		END;
+		$$ LANGUAGE plpgsql;
+		CREATE TRIGGER fail_trigger_update BEFORE UPDATE ON tenant_triggers
+			FOR EACH ROW EXECUTE FUNCTION fail_trigger_update();
+		"#,
+	)
+	.execute(&db.pool)
+	.await
+	.unwrap();
+	let monitors = TenantMonitorRepository::new(db.pool.clone());
+
+	// Act
+	let result = with_tenant_context(
+		context.clone(),
+		monitors.deactivate_with_triggers("payments"),
+	)
+	.await;
+
+	// Assert
+	assert!(result.is_err());
+	let (monitor, triggers) = with_tenant_context(context, async {
+		let monitor = monitors.get("payments").await.unwrap();
+		let triggers = TenantTriggerRepository::new(db.pool.clone())
+			.get_by_monitor(monitor.id)
+			.await
+			.unwrap();
+		(monitor, triggers)
+	})
+	.await;
+	assert_eq!(monitor.is_active, Some(true));
+	assert_eq!(triggers.len(), 2);
+	assert!(triggers.iter().all(|t| t.is_active == Some(true)));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
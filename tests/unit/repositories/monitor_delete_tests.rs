@@ -0,0 +1,98 @@
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	models::{
+		ImportMode, MonitorExportEntry, NetworkExportEntry, TenantExportDocument,
+		TriggerExportEntry, EXPORT_FORMAT_VERSION,
+	},
+	repositories::{
+		error::TenantRepositoryError, monitor::TenantMonitorRepositoryTrait,
+		tenant::TenantRepositoryTrait, trigger::TenantTriggerRepositoryTrait,
+		TenantImportRepository, TenantMonitorRepository, TenantRepository, TenantTriggerRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+fn trigger(trigger_id: &str) -> TriggerExportEntry {
+	TriggerExportEntry {
+		trigger_id: trigger_id.to_string(),
+		name: trigger_id.to_string(),
+		monitor_id: "payments".to_string(),
+		trigger_type: "email".to_string(),
+		configuration: json!({ "to": ["ops@example.com"] }),
+		is_active: true,
+	}
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_delete_refuses_monitor_with_triggers_until_forced() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("monitor-delete")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![NetworkExportEntry {
+			network_id: "stellar-mainnet".to_string(),
+			name: "Stellar Mainnet".to_string(),
+			blockchain: "stellar".to_string(),
+			configuration: json!({ "rpc_url": "https://example.com/rpc" }),
+			is_active: true,
+		}],
+		monitors: vec![MonitorExportEntry {
+			monitor_id: "payments".to_string(),
+			name: "Payments".to_string(),
+			network_id: "stellar-mainnet".to_string(),
+			configuration: json!({}),
+			is_active: true,
+		}],
+		triggers: vec![trigger("payments-email"), trigger("ops-email")],
+	};
+	with_tenant_context(
+		context.clone(),
+		TenantImportRepository::new(db.pool.clone()).import(&document, ImportMode::Fail),
+	)
+	.await
+	.unwrap();
+	let monitors = TenantMonitorRepository::new(db.pool.clone());
+
+	// Act
+	let (refused, forced) = with_tenant_context(context.clone(), async {
+		let refused = monitors.delete("payments").await;
+		let forced = monitors.delete_with_triggers("payments").await;
+		(refused, forced)
+	})
+	.await;
+
+	// Assert
+	assert!(matches!(
+		refused,
+		Err(TenantRepositoryError::MonitorHasTriggers { ref trigger_ids, .. })
+			if trigger_ids == &["ops-email".to_string(), "payments-email".to_string()]
+	));
+	let (monitor, triggers) = forced.unwrap();
+	assert!(monitor.deleted_at.is_some());
+	assert_eq!(triggers.len(), 2);
+	let remaining = with_tenant_context(
+		context,
+		TenantTriggerRepository::new(db.pool.clone()).get_by_monitor(monitor.id),
+	)
+	.await
+	.unwrap();
+	assert!(remaining.is_empty());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,95 @@
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	models::{
+		ImportMode, MonitorExportEntry, NetworkExportEntry, TenantExportDocument,
+		TriggerExportEntry, EXPORT_FORMAT_VERSION,
+	},
+	repositories::{
+		error::TenantRepositoryError, network::TenantNetworkRepositoryTrait,
+		tenant::TenantRepositoryTrait, TenantImportRepository, TenantNetworkRepository,
+		TenantRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_delete_refuses_network_with_monitors_until_forced() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("network-delete")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![NetworkExportEntry {
+			network_id: "stellar-mainnet".to_string(),
+			name: "Stellar Mainnet".to_string(),
+			blockchain: "stellar".to_string(),
+			configuration: json!({ "rpc_url": "https://example.com/rpc" }),
+			is_active: true,
+		}],
+		monitors: vec![MonitorExportEntry {
+			monitor_id: "payments".to_string(),
+			name: "Payments".to_string(),
+			network_id: "stellar-mainnet".to_string(),
+			configuration: json!({}),
+			is_active: true,
+		}],
+		triggers: vec![TriggerExportEntry {
+			trigger_id: "payments-email".to_string(),
+			name: "Payments email".to_string(),
+			monitor_id: "payments".to_string(),
+			trigger_type: "email".to_string(),
+			configuration: json!({ "to": ["ops@example.com"] }),
+			is_active: true,
+		}],
+	};
+	with_tenant_context(
+		context.clone(),
+		TenantImportRepository::new(db.pool.clone()).import(&document, ImportMode::Fail),
+	)
+	.await
+	.unwrap();
+	let networks = TenantNetworkRepository::new(db.pool.clone());
+
+	// Act
+	let (refused, forced) = with_tenant_context(context, async {
+		let refused = networks.delete("stellar-mainnet").await;
+		let forced = networks.delete_with_monitors("stellar-mainnet").await;
+		(refused, forced)
+	})
+	.await;
+
+	// Assert
+	assert!(matches!(
+		refused,
+		Err(TenantRepositoryError::NetworkHasMonitors { ref monitors, .. })
+			if monitors.len() == 1 && monitors[0].name == "Payments" && !monitors[0].deleted
+	));
+	let deletion = forced.unwrap();
+	assert_eq!(deletion.network.network_id, "stellar-mainnet");
+	assert_eq!(deletion.monitors.len(), 1);
+	assert_eq!(deletion.triggers.len(), 1);
+	let monitors: i64 =
+		sqlx::query_scalar("SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1")
+			.bind(tenant.id)
+			.fetch_one(&db.pool)
+			.await
+			.unwrap();
+	assert_eq!(monitors, 0);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
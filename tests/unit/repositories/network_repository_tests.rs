@@ -4,6 +4,7 @@ use stellar_monitor_tenant_isolation::{
 	models::*,
 	repositories::{error::TenantRepositoryError, network::TenantNetworkRepositoryTrait},
 };
+use uuid::Uuid;
 
 use crate::{
 	mocks::MockTenantNetworkRepository,
@@ -277,22 +278,27 @@ async fn test_delete_network_with_monitors() {
 		.expect_delete()
 		.with(eq(network_id))
 		.times(1)
-		.returning(|_| {
-			Err(TenantRepositoryError::ValidationError(
-				"Cannot delete network with active monitors".to_string(),
-			))
+		.returning(|network_id| {
+			Err(TenantRepositoryError::NetworkHasMonitors {
+				network_id: network_id.to_string(),
+				monitors: vec![DependentMonitor {
+					id: Uuid::nil(),
+					monitor_id: "payments".to_string(),
+					name: "Payments".to_string(),
+					deleted: false,
+				}],
+			})
 		});
 
 	// Act
 	let result = mock_repo.delete(network_id).await;
 
 	// Assert
-	assert!(result.is_err());
 	match result.unwrap_err() {
-		TenantRepositoryError::ValidationError(msg) => {
-			assert_eq!(msg, "Cannot delete network with active monitors");
+		TenantRepositoryError::NetworkHasMonitors { monitors, .. } => {
+			assert_eq!(monitors[0].monitor_id, "payments");
 		}
-		_ => panic!("Expected ValidationError error"),
+		_ => panic!("Expected NetworkHasMonitors error"),
 	}
 }
 
@@ -0,0 +1,131 @@
+use sqlx::PgPool;
+use stellar_monitor_tenant_isolation::{
+	models::{Tenant, TenantRole},
+	repositories::{error::TenantRepositoryError, tenant::TenantRepositoryTrait, TenantRepository},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+async fn insert_user(pool: &PgPool, email: &str) -> Uuid {
+	sqlx::query_scalar("INSERT INTO users (email, password_hash) VALUES ($1, '') RETURNING id")
+		.bind(email)
+		.fetch_one(pool)
+		.await
+		.unwrap()
+}
+
+/// A tenant whose only member is `owner_id`, as its owner
+async fn tenant_owned_by(repo: &TenantRepository, slug: &str, owner_id: Uuid) -> Tenant {
+	let tenant = repo
+		.create(CreateTenantRequestBuilder::new().with_slug(slug).build())
+		.await
+		.unwrap();
+	repo.add_member(tenant.id, owner_id, TenantRole::Owner)
+		.await
+		.unwrap();
+	tenant
+}
+
+async fn role_of(repo: &TenantRepository, tenant_id: Uuid, user_id: Uuid) -> Option<TenantRole> {
+	repo.get_members(tenant_id)
+		.await
+		.unwrap()
+		.into_iter()
+		.find(|membership| membership.user_id == user_id)
+		.map(|membership| membership.role)
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_sole_owner_cannot_be_removed() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let owner_id = insert_user(&db.pool, "owner@sole-removal.test").await;
+	let tenant = tenant_owned_by(&repo, "sole-owner-removal", owner_id).await;
+
+	// Act
+	let result = repo.remove_member(tenant.id, owner_id).await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(TenantRepositoryError::CannotRemoveLastOwner(user_id)) if user_id == owner_id
+	));
+	assert_eq!(
+		role_of(&repo, tenant.id, owner_id).await,
+		Some(TenantRole::Owner)
+	);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_sole_owner_cannot_be_downgraded() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let owner_id = insert_user(&db.pool, "owner@sole-downgrade.test").await;
+	let tenant = tenant_owned_by(&repo, "sole-owner-downgrade", owner_id).await;
+
+	// Act
+	let result = repo
+		.update_member_role(tenant.id, owner_id, TenantRole::Viewer)
+		.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(TenantRepositoryError::CannotRemoveLastOwner(_))
+	));
+	assert_eq!(
+		role_of(&repo, tenant.id, owner_id).await,
+		Some(TenantRole::Owner)
+	);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_ownership_moves_by_promoting_then_demoting() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let owner_id = insert_user(&db.pool, "owner@two-step.test").await;
+	let successor_id = insert_user(&db.pool, "successor@two-step.test").await;
+	let tenant = tenant_owned_by(&repo, "two-step-transfer", owner_id).await;
+	repo.add_member(tenant.id, successor_id, TenantRole::Admin)
+		.await
+		.unwrap();
+
+	// Act
+	repo.update_member_role(tenant.id, successor_id, TenantRole::Owner)
+		.await
+		.unwrap();
+	let demoted = repo
+		.update_member_role(tenant.id, owner_id, TenantRole::Admin)
+		.await;
+
+	// Assert
+	assert!(demoted.is_ok());
+	assert_eq!(
+		role_of(&repo, tenant.id, owner_id).await,
+		Some(TenantRole::Admin)
+	);
+	assert_eq!(
+		role_of(&repo, tenant.id, successor_id).await,
+		Some(TenantRole::Owner)
+	);
+	assert!(matches!(
+		repo.remove_member(tenant.id, successor_id).await,
+		Err(TenantRepositoryError::CannotRemoveLastOwner(_))
+	));
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,78 @@
+use stellar_monitor_tenant_isolation::{
+	repositories::{
+		error::TenantRepositoryError, monitor::TenantMonitorRepositoryTrait,
+		network::TenantNetworkRepositoryTrait, tenant::TenantRepositoryTrait,
+		TenantMonitorRepository, TenantNetworkRepository, TenantRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::{
+		CreateMonitorRequestBuilder, CreateNetworkRequestBuilder, CreateTenantRequestBuilder,
+	},
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_concurrent_monitor_creates_respect_quota() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("quota-race")
+				.with_max_monitors(5)
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let network = with_tenant_context(
+		context.clone(),
+		TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build()),
+	)
+	.await
+	.unwrap();
+
+	// Act
+	let monitor_repo = TenantMonitorRepository::new(db.pool.clone());
+	let handles: Vec<_> = (0..10)
+		.map(|_| {
+			let repo = monitor_repo.clone();
+			let request = CreateMonitorRequestBuilder::new()
+				.with_network_id(network.id)
+				.build();
+			tokio::spawn(with_tenant_context(context.clone(), async move {
+				repo.create(request).await
+			}))
+		})
+		.collect();
+	let mut results = Vec::new();
+	for handle in handles {
+		results.push(handle.await.unwrap());
+	}
+
+	// Assert
+	let created = results.iter().filter(|r| r.is_ok()).count();
+	let rejected = results
+		.iter()
+		.filter(|r| matches!(r, Err(TenantRepositoryError::QuotaExceeded(_))))
+		.count();
+	assert_eq!(created, 5);
+	assert_eq!(rejected, 5);
+
+	let stored: i64 = sqlx::query_scalar(
+		"SELECT COUNT(*) FROM tenant_monitors WHERE tenant_id = $1 AND deleted_at IS NULL",
+	)
+	.bind(tenant.id)
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	assert_eq!(stored, 5);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
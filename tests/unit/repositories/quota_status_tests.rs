@@ -0,0 +1,198 @@
+use std::future::Future;
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc, Once,
+};
+use std::time::Duration;
+use stellar_monitor_tenant_isolation::{
+	models::BlockchainQuota,
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, network::TenantNetworkRepositoryTrait,
+		tenant::TenantRepositoryTrait, trigger::TenantTriggerRepositoryTrait,
+		TenantMonitorRepository, TenantNetworkRepository, TenantRepository,
+		TenantTriggerRepository,
+	},
+	utils::{with_tenant_context, QuotaStatusCache},
+};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+use crate::utils::{
+	builders::{
+		CreateMonitorRequestBuilder, CreateNetworkRequestBuilder, CreateTenantRequestBuilder,
+		CreateTriggerRequestBuilder,
+	},
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+tokio::task_local! {
+	static QUERY_COUNT: Arc<AtomicUsize>;
+}
+
+/// Counts the statements sqlx logs into the counter of the task logging them
+struct QueryCounter;
+
+impl<S: Subscriber> Layer<S> for QueryCounter {
+	fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+		if event.metadata().target() == "sqlx::query" {
+			let _ = QUERY_COUNT.try_with(|count| count.fetch_add(1, Ordering::SeqCst));
+		}
+	}
+}
+
+/// Runs `future`, counting the statements it sends. The counter is installed
+/// globally once: a per-future subscriber misses events whenever tracing's
+/// process-wide interest cache was built while tests in parallel had none.
+async fn count_queries<F: Future>(future: F) -> (F::Output, usize) {
+	static INSTALL: Once = Once::new();
+	INSTALL.call_once(|| {
+		tracing_subscriber::registry()
+			.with(QueryCounter)
+			.try_init()
+			.expect("no other global subscriber in the test binary");
+	});
+
+	let count = Arc::new(AtomicUsize::new(0));
+	let output = QUERY_COUNT.scope(count.clone(), future).await;
+	(output, count.load(Ordering::SeqCst))
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_quota_status_loads_in_one_query() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("quota-single-query")
+				.build(),
+		)
+		.await
+		.unwrap();
+	tenant_repo
+		.set_blockchain_quota(
+			tenant.id,
+			BlockchainQuota {
+				blockchain: "stellar".to_string(),
+				max_monitors: Some(3),
+				max_networks: None,
+			},
+		)
+		.await
+		.unwrap();
+	let (busy, idle) = with_tenant_context(owner_context(tenant.id), async {
+		let network = TenantNetworkRepository::new(db.pool.clone())
+			.create(CreateNetworkRequestBuilder::new().build())
+			.await
+			.unwrap();
+		let monitors = TenantMonitorRepository::new(db.pool.clone());
+		let busy = monitors
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		let idle = monitors
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			)
+			.await
+			.unwrap();
+		for _ in 0..2 {
+			TenantTriggerRepository::new(db.pool.clone())
+				.create(
+					CreateTriggerRequestBuilder::new()
+						.with_monitor_id(busy.id)
+						.build(),
+				)
+				.await
+				.unwrap();
+		}
+		(busy, idle)
+	})
+	.await;
+
+	// Act
+	let (status, queries) = count_queries(tenant_repo.get_quota_status(tenant.id)).await;
+
+	// Assert
+	let status = status.unwrap();
+	assert_eq!(queries, 1);
+	assert_eq!(status.usage.monitors_count, 2);
+	assert_eq!(status.usage.networks_count, 1);
+	assert_eq!(status.usage.triggers_count, 2);
+	assert_eq!(status.blockchains.len(), 1);
+	assert_eq!(status.blockchains[0].max_monitors, Some(3));
+	assert_eq!(status.blockchains[0].monitors_count, 2);
+	assert_eq!(status.blockchains[0].networks_count, 1);
+	assert_eq!(status.monitor_triggers[&busy.monitor_id].triggers_count, 2);
+	assert_eq!(status.monitor_triggers[&idle.monitor_id].triggers_count, 0);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_cached_quota_status_is_invalidated_by_creates() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let cache = QuotaStatusCache::new(Duration::from_secs(60));
+	let tenant_repo = TenantRepository::new(db.pool.clone()).with_quota_cache(cache.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("quota-cache")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let network = with_tenant_context(
+		context.clone(),
+		TenantNetworkRepository::new(db.pool.clone())
+			.with_quota_cache(cache.clone())
+			.create(CreateNetworkRequestBuilder::new().build()),
+	)
+	.await
+	.unwrap();
+
+	// Act
+	let first = tenant_repo.get_quota_status(tenant.id).await.unwrap();
+	// Written behind the repositories' back, so nothing invalidates the cache
+	sqlx::query(
+		"INSERT INTO tenant_monitors (tenant_id, monitor_id, name, network_id, configuration) VALUES ($1, 'unseen', 'Unseen', $2, '{}')",
+	)
+	.bind(tenant.id)
+	.bind(network.id)
+	.execute(&db.pool)
+	.await
+	.unwrap();
+	let cached = tenant_repo.get_quota_status(tenant.id).await.unwrap();
+	with_tenant_context(
+		context,
+		TenantMonitorRepository::new(db.pool.clone())
+			.with_quota_cache(cache)
+			.create(
+				CreateMonitorRequestBuilder::new()
+					.with_network_id(network.id)
+					.build(),
+			),
+	)
+	.await
+	.unwrap();
+	let after_create = tenant_repo.get_quota_status(tenant.id).await.unwrap();
+
+	// Assert
+	assert_eq!(first.usage.networks_count, 1);
+	assert_eq!(cached.usage.monitors_count, 0);
+	assert_eq!(after_create.usage.monitors_count, 2);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
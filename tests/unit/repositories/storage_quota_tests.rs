@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use stellar_monitor_tenant_isolation::{
+	models::{RequestMetadata, StorageEnforcement, TenantQuotas, TenantRole},
+	repositories::{
+		tenant::TenantRepositoryTrait, TenantNetworkRepository, TenantRepository, UsageRepository,
+	},
+	services::{
+		AuditService, NetworkService, NetworkServiceTrait, ServiceError, StorageAccounting,
+		StorageRecorder, UsageService,
+	},
+	utils::{with_tenant_context, AuthenticatedUser, TenantContext},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::{CreateNetworkRequestBuilder, CreateTenantRequestBuilder},
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_create_rejected_once_recorded_storage_fills_quota() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant_repo = TenantRepository::new(db.pool.clone());
+	let tenant = tenant_repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("storage-full")
+				.with_max_storage_mb(2)
+				.build(),
+		)
+		.await
+		.unwrap();
+	// Audit entries reference the acting user, so the context needs a real one
+	let user_id: Uuid = sqlx::query_scalar(
+		"INSERT INTO users (email, password_hash) VALUES ('owner@storage-full.test', '') RETURNING id",
+	)
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	let context = TenantContext::with_user(
+		tenant.id,
+		AuthenticatedUser {
+			id: user_id,
+			email: "owner@storage-full.test".to_string(),
+			role: TenantRole::Owner,
+		},
+		TenantQuotas::default(),
+	);
+	let service = NetworkService::new(
+		TenantNetworkRepository::new(db.pool.clone()),
+		tenant_repo,
+		AuditService::new(db.pool.clone()),
+	)
+	.with_storage_accounting(StorageAccounting::new(
+		Some(
+			Arc::new(UsageService::new(UsageRepository::new(db.pool.clone())))
+				as Arc<dyn StorageRecorder>,
+		),
+		StorageEnforcement::Hard,
+	));
+	let request = |slug: &str| {
+		CreateNetworkRequestBuilder::new()
+			.with_network_id(slug)
+			.with_name(slug)
+			.build()
+	};
+
+	// Act
+	let results = with_tenant_context(context, async {
+		let mut results = Vec::new();
+		for slug in ["net-1", "net-2", "net-3"] {
+			results.push(
+				service
+					.create_network(request(slug), RequestMetadata::default())
+					.await,
+			);
+		}
+		results
+	})
+	.await;
+
+	// Assert
+	assert!(results[0].is_ok());
+	assert!(results[1].is_ok());
+	assert!(matches!(results[2], Err(ServiceError::QuotaExceeded(_))));
+
+	let stored: i64 = sqlx::query_scalar(
+		"SELECT usage_value FROM resource_usage WHERE tenant_id = $1 AND resource_type = 'storage' AND usage_date = CURRENT_DATE",
+	)
+	.bind(tenant.id)
+	.fetch_one(&db.pool)
+	.await
+	.unwrap();
+	assert_eq!(stored, 2);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
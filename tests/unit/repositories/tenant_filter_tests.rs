@@ -0,0 +1,129 @@
+use stellar_monitor_tenant_isolation::{
+	models::{Tenant, TenantFilter, UpdateTenantRequest},
+	repositories::{tenant::TenantRepositoryTrait, TenantRepository},
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+async fn seed_tenants(repo: &TenantRepository) {
+	for (name, slug) in [
+		("Acme Corp", "acme-corp"),
+		("ACME Labs", "acme-labs"),
+		("Globex", "globex"),
+		("100% Uptime", "uptime"),
+	] {
+		repo.create(
+			CreateTenantRequestBuilder::new()
+				.with_name(name)
+				.with_slug(slug)
+				.build(),
+		)
+		.await
+		.unwrap();
+	}
+}
+
+fn slugs(tenants: Vec<Tenant>) -> Vec<String> {
+	let mut slugs: Vec<_> = tenants.into_iter().map(|tenant| tenant.slug).collect();
+	slugs.sort();
+	slugs
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_name_search_is_case_insensitive() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	seed_tenants(&repo).await;
+	let filter = TenantFilter {
+		name_contains: Some("aCmE".to_string()),
+		..Default::default()
+	};
+
+	// Act
+	let tenants = repo.list_filtered(filter.clone(), 10, 0).await.unwrap();
+	let total = repo.count_filtered(filter).await.unwrap();
+
+	// Assert
+	assert_eq!(slugs(tenants), ["acme-corp", "acme-labs"]);
+	assert_eq!(total, 2);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_active_and_slug_prefix_filters_combine() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	seed_tenants(&repo).await;
+	let labs = repo.get_by_slug("acme-labs").await.unwrap();
+	repo.update(
+		labs.id,
+		UpdateTenantRequest {
+			is_active: Some(false),
+			..Default::default()
+		},
+	)
+	.await
+	.unwrap();
+
+	// Act
+	let active = repo
+		.list_filtered(
+			TenantFilter {
+				is_active: Some(true),
+				slug_prefix: Some("acme".to_string()),
+				..Default::default()
+			},
+			10,
+			0,
+		)
+		.await
+		.unwrap();
+	let inactive = repo
+		.count_filtered(TenantFilter {
+			is_active: Some(false),
+			..Default::default()
+		})
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(slugs(active), ["acme-corp"]);
+	assert_eq!(inactive, 1);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_search_wildcards_match_literally() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	seed_tenants(&repo).await;
+
+	// Act
+	let tenants = repo
+		.list_filtered(
+			TenantFilter {
+				name_contains: Some("%".to_string()),
+				..Default::default()
+			},
+			10,
+			0,
+		)
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(slugs(tenants), ["uptime"]);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -1,7 +1,8 @@
 use mockall::predicate::*;
+use std::sync::{Arc, Mutex};
 use stellar_monitor_tenant_isolation::{
 	models::*,
-	repositories::{error::TenantRepositoryError, tenant::TenantRepositoryTrait},
+	repositories::{error::TenantRepositoryError, tenant::TenantRepositoryTrait, TenantRepository},
 };
 use uuid::Uuid;
 
@@ -215,12 +216,12 @@ async fn test_delete_tenant_success() {
 
 	mock_repo
 		.expect_delete()
-		.with(eq(test_ids.tenant_1))
+		.with(eq(test_ids.tenant_1), eq(false))
 		.times(1)
-		.returning(|_| Ok(()));
+		.returning(|_, _| Ok(()));
 
 	// Act
-	let result = mock_repo.delete(test_ids.tenant_1).await;
+	let result = mock_repo.delete(test_ids.tenant_1, false).await;
 
 	// Assert
 	assert!(result.is_ok());
@@ -234,12 +235,12 @@ async fn test_delete_tenant_not_found() {
 
 	mock_repo
 		.expect_delete()
-		.with(eq(unknown_id))
+		.with(eq(unknown_id), eq(false))
 		.times(1)
-		.returning(|id| Err(TenantRepositoryError::TenantNotFound(id)));
+		.returning(|id, _| Err(TenantRepositoryError::TenantNotFound(id)));
 
 	// Act
-	let result = mock_repo.delete(unknown_id).await;
+	let result = mock_repo.delete(unknown_id, false).await;
 
 	// Assert
 	assert!(result.is_err());
@@ -251,6 +252,123 @@ async fn test_delete_tenant_not_found() {
 	}
 }
 
+/// Mirrors TenantRepository::delete: non-empty tenants need `force`
+fn delete_guarded_by(counts: ResourceCounts) -> MockTenantRepository {
+	let mut mock_repo = MockTenantRepository::new();
+	mock_repo.expect_delete().returning(move |_, force| {
+		if !force && !counts.is_empty() {
+			return Err(TenantRepositoryError::ValidationError(format!(
+				"Tenant still has {} monitors, {} networks and {} triggers",
+				counts.monitors, counts.networks, counts.triggers
+			)));
+		}
+		Ok(())
+	});
+	mock_repo
+}
+
+#[tokio::test]
+async fn test_delete_tenant_with_monitors_requires_force() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mock_repo = delete_guarded_by(ResourceCounts {
+		monitors: 2,
+		networks: 1,
+		triggers: 0,
+	});
+
+	// Act
+	let without_force = mock_repo.delete(test_ids.tenant_1, false).await;
+	let with_force = mock_repo.delete(test_ids.tenant_1, true).await;
+
+	// Assert
+	match without_force {
+		Err(TenantRepositoryError::ValidationError(message)) => {
+			assert!(message.contains("2 monitors"));
+		}
+		other => panic!("Expected ValidationError, got {:?}", other),
+	}
+	assert!(with_force.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_empty_tenant_without_force() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mock_repo = delete_guarded_by(ResourceCounts::default());
+
+	// Act
+	let result = mock_repo.delete(test_ids.tenant_1, false).await;
+
+	// Assert
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_restore_tenant_success() {
+	// Arrange
+	let mut mock_repo = MockTenantRepository::new();
+	let test_ids = TestIds::default();
+
+	let restored = TenantBuilder::new()
+		.with_id(test_ids.tenant_1)
+		.with_active(true)
+		.build();
+
+	let restored_clone = restored.clone();
+	mock_repo
+		.expect_delete()
+		.with(eq(test_ids.tenant_1), eq(false))
+		.times(1)
+		.returning(|_, _| Ok(()));
+	mock_repo
+		.expect_get()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(|id| Err(TenantRepositoryError::TenantNotFound(id)));
+	mock_repo
+		.expect_restore()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(move |_| Ok(restored_clone.clone()));
+
+	// Act
+	mock_repo.delete(test_ids.tenant_1, false).await.unwrap();
+	let hidden = mock_repo.get(test_ids.tenant_1).await;
+	let result = mock_repo.restore(test_ids.tenant_1).await;
+
+	// Assert
+	assert!(matches!(
+		hidden,
+		Err(TenantRepositoryError::TenantNotFound(_))
+	));
+	let tenant = result.unwrap();
+	assert_eq!(tenant.id, test_ids.tenant_1);
+	assert!(tenant.is_active);
+}
+
+#[tokio::test]
+async fn test_purge_tenant_not_found() {
+	// Arrange
+	let mut mock_repo = MockTenantRepository::new();
+	let unknown_id = Uuid::new_v4();
+
+	mock_repo
+		.expect_purge()
+		.with(eq(unknown_id))
+		.times(1)
+		.returning(|id| Err(TenantRepositoryError::TenantNotFound(id)));
+
+	// Act
+	let result = mock_repo.purge(unknown_id).await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(TenantRepositoryError::TenantNotFound(id)) if id == unknown_id
+	));
+}
+
 #[tokio::test]
 async fn test_list_tenants_success() {
 	// Arrange
@@ -338,6 +456,86 @@ async fn test_add_member_success() {
 	assert_eq!(returned_membership.role, TenantRole::Member);
 }
 
+#[tokio::test]
+async fn test_add_member_twice_returns_already_exists() {
+	// Arrange
+	let mut mock_repo = MockTenantRepository::new();
+	let test_ids = TestIds::default();
+	let members: Arc<Mutex<Vec<TenantMembership>>> = Arc::new(Mutex::new(Vec::new()));
+
+	let stored = members.clone();
+	mock_repo
+		.expect_add_member()
+		.times(2)
+		.returning(move |tenant_id, user_id, role| {
+			let mut members = stored.lock().unwrap();
+			if members
+				.iter()
+				.any(|m| m.tenant_id == tenant_id && m.user_id == user_id)
+			{
+				return Err(TenantRepositoryError::AlreadyExists {
+					resource_type: "membership".to_string(),
+					resource_id: format!("{}/{}", tenant_id, user_id),
+				});
+			}
+			let membership = TenantMembership {
+				id: Uuid::new_v4(),
+				tenant_id,
+				user_id,
+				role,
+				created_at: Some(chrono::Utc::now()),
+				updated_at: Some(chrono::Utc::now()),
+			};
+			members.push(membership.clone());
+			Ok(membership)
+		});
+
+	// Act
+	let first = mock_repo
+		.add_member(test_ids.tenant_1, test_ids.user_1, TenantRole::Member)
+		.await;
+	let second = mock_repo
+		.add_member(test_ids.tenant_1, test_ids.user_1, TenantRole::Admin)
+		.await;
+
+	// Assert
+	assert!(first.is_ok());
+	assert!(matches!(
+		second,
+		Err(TenantRepositoryError::AlreadyExists { ref resource_type, .. }) if resource_type == "membership"
+	));
+	assert_eq!(members.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_find_duplicate_memberships_reports_existing_duplicates() {
+	// Arrange
+	let mut mock_repo = MockTenantRepository::new();
+	let test_ids = TestIds::default();
+	let duplicate_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+
+	let expected_ids = duplicate_ids.clone();
+	mock_repo
+		.expect_find_duplicate_memberships()
+		.times(1)
+		.returning(move || {
+			Ok(vec![DuplicateMembership {
+				tenant_id: test_ids.tenant_1,
+				user_id: test_ids.user_1,
+				membership_ids: expected_ids.clone(),
+			}])
+		});
+
+	// Act
+	let duplicates = mock_repo.find_duplicate_memberships().await.unwrap();
+
+	// Assert
+	assert_eq!(duplicates.len(), 1);
+	assert_eq!(duplicates[0].tenant_id, test_ids.tenant_1);
+	assert_eq!(duplicates[0].user_id, test_ids.user_1);
+	assert_eq!(duplicates[0].membership_ids, duplicate_ids);
+}
+
 #[tokio::test]
 async fn test_remove_member_success() {
 	// Arrange
@@ -512,6 +710,9 @@ async fn test_get_quota_status_success() {
 			rpc_requests_per_minute: 5500,
 			storage_mb: 900,
 		},
+		blockchains: vec![],
+		warnings: vec![],
+		monitor_triggers: Default::default(),
 	};
 
 	let quota_status_clone = quota_status.clone();
@@ -531,3 +732,102 @@ async fn test_get_quota_status_success() {
 	assert_eq!(status.quotas.max_monitors, 10);
 	assert_eq!(status.available.monitors, 5);
 }
+
+#[tokio::test]
+async fn test_get_quota_statuses_batch() {
+	// Arrange
+	let mut mock_repo = MockTenantRepository::new();
+	let test_ids = TestIds::default();
+	let tenant_3 = Uuid::new_v4();
+
+	let usage_for = |monitors_count: i32, networks_count: i32| CurrentUsage {
+		monitors_count,
+		networks_count,
+		triggers_count: monitors_count * 2,
+		rpc_requests_last_minute: 0,
+		storage_mb_used: 0,
+	};
+	let usages = [
+		(test_ids.tenant_1, usage_for(1, 1)),
+		(test_ids.tenant_2, usage_for(4, 2)),
+		(tenant_3, usage_for(10, 5)),
+	];
+
+	let statuses: Vec<ResourceQuotaStatus> = usages
+		.iter()
+		.map(|(tenant_id, usage)| {
+			let quotas = TenantQuotas {
+				max_monitors: 10,
+				max_networks: 5,
+				max_triggers_per_monitor: 10,
+				max_rpc_requests_per_minute: 1000,
+				max_storage_mb: 1000,
+				api_rate_limits: Default::default(),
+			};
+			ResourceQuotaStatus::from_usage(*tenant_id, quotas, usage.clone(), vec![])
+		})
+		.collect();
+
+	let tenant_ids = vec![test_ids.tenant_1, test_ids.tenant_2, tenant_3];
+	let expected_ids = tenant_ids.clone();
+	mock_repo
+		.expect_get_quota_statuses()
+		.withf(move |ids| ids.to_vec() == expected_ids)
+		.times(1)
+		.returning(move |_| Ok(statuses.clone()));
+
+	// Act
+	let result = mock_repo.get_quota_statuses(&tenant_ids).await.unwrap();
+
+	// Assert
+	assert_eq!(result.len(), 3);
+	for ((tenant_id, usage), status) in usages.iter().zip(&result) {
+		assert_eq!(status.tenant_id, *tenant_id);
+		assert_eq!(status.usage.monitors_count, usage.monitors_count);
+		assert_eq!(status.usage.networks_count, usage.networks_count);
+		assert_eq!(status.available.monitors, 10 - usage.monitors_count);
+		assert_eq!(status.available.networks, 5 - usage.networks_count);
+	}
+	assert!(!result[2].can_create_monitor());
+}
+
+#[tokio::test]
+async fn test_create_tenant_rejects_invalid_slugs() {
+	// Arrange: the slug is checked before the lazy pool is ever connected
+	let repo = TenantRepository::new(
+		sqlx::postgres::PgPoolOptions::new()
+			.connect_lazy("postgres://localhost/unused")
+			.unwrap(),
+	);
+	let over_length = "a".repeat(SLUG_MAX_LENGTH + 1);
+
+	for slug in [
+		"ab",
+		over_length.as_str(),
+		"Acme-Corp",
+		"acme corp",
+		"acme/corp",
+		"-acme",
+		"acme-",
+		"api",
+		"health",
+		"admin",
+	] {
+		// Act
+		let request = CreateTenantRequestBuilder::new().with_slug(slug).build();
+		let result = repo.create(request.clone()).await;
+		let with_owner = repo.create_with_owner(request, Uuid::new_v4()).await;
+
+		// Assert
+		assert!(
+			matches!(result, Err(TenantRepositoryError::ValidationError(_))),
+			"{} should be rejected",
+			slug
+		);
+		assert!(
+			matches!(with_owner, Err(TenantRepositoryError::ValidationError(_))),
+			"{} should be rejected",
+			slug
+		);
+	}
+}
@@ -0,0 +1,51 @@
+use stellar_monitor_tenant_isolation::{
+	models::UpdateTenantRequest,
+	repositories::{tenant::TenantRepositoryTrait, TenantRepository},
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_update_only_changes_provided_columns() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let repo = TenantRepository::new(db.pool.clone());
+	let tenant = repo
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_name("Acme Corp")
+				.with_slug("acme-update")
+				.build(),
+		)
+		.await
+		.unwrap();
+
+	// Act
+	let updated = repo
+		.update(
+			tenant.id,
+			UpdateTenantRequest {
+				max_monitors: Some(42),
+				..Default::default()
+			},
+		)
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(updated.max_monitors, 42);
+	assert_eq!(updated.name, "Acme Corp");
+	assert_eq!(updated.slug, tenant.slug);
+	assert_eq!(updated.is_active, tenant.is_active);
+	assert_eq!(updated.max_networks, tenant.max_networks);
+	assert_eq!(
+		repo.get(tenant.id).await.unwrap().name,
+		"Acme Corp".to_string()
+	);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
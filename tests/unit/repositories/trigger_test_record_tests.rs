@@ -0,0 +1,121 @@
+use serde_json::json;
+use stellar_monitor_tenant_isolation::{
+	models::{
+		ImportMode, MonitorExportEntry, NetworkExportEntry, TenantExportDocument,
+		TriggerExportEntry, UpdateTriggerRequest, EXPORT_FORMAT_VERSION,
+	},
+	repositories::{
+		monitor::TenantMonitorRepositoryTrait, tenant::TenantRepositoryTrait,
+		trigger::TenantTriggerRepositoryTrait, TenantImportRepository, TenantMonitorRepository,
+		TenantRepository, TenantTriggerRepository,
+	},
+	utils::with_tenant_context,
+};
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	context::owner_context,
+	database::{cleanup_database, TestDatabase},
+};
+
+fn webhook_trigger(trigger_id: &str) -> TriggerExportEntry {
+	TriggerExportEntry {
+		trigger_id: trigger_id.to_string(),
+		name: trigger_id.to_string(),
+		monitor_id: "payments".to_string(),
+		trigger_type: "webhook".to_string(),
+		configuration: json!({ "url": "https://example.com/hook", "method": "POST" }),
+		is_active: true,
+	}
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_successful_test_is_recorded_until_configuration_changes() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenant = TenantRepository::new(db.pool.clone())
+		.create(
+			CreateTenantRequestBuilder::new()
+				.with_slug("trigger-tests")
+				.build(),
+		)
+		.await
+		.unwrap();
+	let context = owner_context(tenant.id);
+	let document = TenantExportDocument {
+		version: EXPORT_FORMAT_VERSION,
+		networks: vec![NetworkExportEntry {
+			network_id: "stellar-mainnet".to_string(),
+			name: "Stellar Mainnet".to_string(),
+			blockchain: "stellar".to_string(),
+			configuration: json!({ "rpc_url": "https://example.com/rpc" }),
+			is_active: true,
+		}],
+		monitors: vec![MonitorExportEntry {
+			monitor_id: "payments".to_string(),
+			name: "Payments".to_string(),
+			network_id: "stellar-mainnet".to_string(),
+			configuration: json!({}),
+			is_active: false,
+		}],
+		triggers: vec![
+			webhook_trigger("payments-hook"),
+			webhook_trigger("ops-hook"),
+		],
+	};
+	with_tenant_context(
+		context.clone(),
+		TenantImportRepository::new(db.pool.clone()).import(&document, ImportMode::Fail),
+	)
+	.await
+	.unwrap();
+	let monitors = TenantMonitorRepository::new(db.pool.clone());
+	let triggers = TenantTriggerRepository::new(db.pool.clone());
+	let untested_ids = |monitor_id| {
+		let monitors = monitors.clone();
+		with_tenant_context(context.clone(), async move {
+			monitors
+				.list_untested_triggers(monitor_id)
+				.await
+				.unwrap()
+				.into_iter()
+				.map(|trigger| trigger.trigger_id)
+				.collect::<Vec<_>>()
+		})
+	};
+	let monitor = with_tenant_context(context.clone(), monitors.get("payments"))
+		.await
+		.unwrap();
+
+	// Act
+	let before = untested_ids(monitor.id).await;
+	with_tenant_context(
+		context.clone(),
+		triggers.record_successful_test("payments-hook"),
+	)
+	.await
+	.unwrap();
+	let after_test = untested_ids(monitor.id).await;
+	with_tenant_context(
+		context.clone(),
+		triggers.update(
+			"payments-hook",
+			UpdateTriggerRequest {
+				name: None,
+				configuration: Some(json!({ "url": "https://example.com/new", "method": "POST" })),
+				is_active: None,
+			},
+		),
+	)
+	.await
+	.unwrap();
+	let after_change = untested_ids(monitor.id).await;
+
+	// Assert
+	assert_eq!(before.len(), 2);
+	assert_eq!(after_test, ["ops-hook"]);
+	assert_eq!(after_change.len(), 2);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
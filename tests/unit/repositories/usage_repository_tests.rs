@@ -0,0 +1,91 @@
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use stellar_monitor_tenant_isolation::{models::*, repositories::usage::UsageRepositoryTrait};
+use uuid::Uuid;
+
+use crate::mocks::MockUsageRepository;
+
+fn day(d: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(2024, 3, d).unwrap()
+}
+
+#[tokio::test]
+async fn test_api_request_series_sums_per_day() {
+	// Arrange
+	let counts: Arc<Mutex<BTreeMap<(Uuid, NaiveDate), i64>>> = Arc::default();
+	let tenant_id = Uuid::new_v4();
+	let other_tenant = Uuid::new_v4();
+
+	let mut mock_repo = MockUsageRepository::new();
+	let recorded = counts.clone();
+	mock_repo
+		.expect_record_api_request()
+		.returning(move |tenant_id, date| {
+			*recorded
+				.lock()
+				.unwrap()
+				.entry((tenant_id, date))
+				.or_default() += 1;
+			Ok(())
+		});
+	let stored = counts.clone();
+	mock_repo
+		.expect_api_request_counts()
+		.returning(move |tenant_id, from, to| {
+			Ok(stored
+				.lock()
+				.unwrap()
+				.iter()
+				.filter(|((t, date), _)| *t == tenant_id && *date >= from && *date <= to)
+				.map(|((_, date), value)| UsagePoint {
+					date: *date,
+					value: *value,
+				})
+				.collect())
+		});
+
+	for (tenant, date, requests) in [
+		(tenant_id, day(1), 3),
+		(tenant_id, day(2), 1),
+		(tenant_id, day(4), 5),
+		(other_tenant, day(2), 7),
+	] {
+		for _ in 0..requests {
+			mock_repo.record_api_request(tenant, date).await.unwrap();
+		}
+	}
+
+	// Act
+	let points = mock_repo
+		.api_request_counts(tenant_id, day(1), day(5))
+		.await
+		.unwrap();
+	let series = daily_series(day(1), day(5), &points);
+
+	// Assert
+	let values: Vec<i64> = series.iter().map(|p| p.value).collect();
+	assert_eq!(values, vec![3, 1, 0, 5, 0]);
+	assert_eq!(series.first().unwrap().date, day(1));
+	assert_eq!(series.last().unwrap().date, day(5));
+}
+
+#[test]
+fn test_daily_series_merges_duplicate_days() {
+	let points = vec![
+		UsagePoint {
+			date: day(2),
+			value: 2,
+		},
+		UsagePoint {
+			date: day(2),
+			value: 4,
+		},
+	];
+
+	let series = daily_series(day(1), day(2), &points);
+
+	assert_eq!(series.len(), 2);
+	assert_eq!(series[0].value, 0);
+	assert_eq!(series[1].value, 6);
+}
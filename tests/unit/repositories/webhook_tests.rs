@@ -0,0 +1,175 @@
+use chrono::Utc;
+use std::time::Duration;
+use stellar_monitor_tenant_isolation::{
+	models::{AuditLog, CreateWebhookRequest, Tenant, UpdateWebhookRequest, WebhookDeliveryStatus},
+	repositories::{
+		error::TenantRepositoryError, tenant::TenantRepositoryTrait, TenantRepository,
+		WebhookRepository,
+	},
+	services::{WebhookDispatcher, WebhookRetryPolicy},
+};
+use uuid::Uuid;
+
+use crate::utils::{
+	builders::CreateTenantRequestBuilder,
+	database::{cleanup_database, TestDatabase},
+};
+
+async fn create_tenant(repo: &TenantRepository, slug: &str) -> Tenant {
+	repo.create(CreateTenantRequestBuilder::new().with_slug(slug).build())
+		.await
+		.unwrap()
+}
+
+fn webhook_request(url: String, events: &[&str]) -> CreateWebhookRequest {
+	CreateWebhookRequest {
+		url,
+		secret: None,
+		events: events.iter().map(|event| event.to_string()).collect(),
+		is_active: None,
+	}
+}
+
+fn audit_log(tenant_id: Uuid, action: &str) -> AuditLog {
+	AuditLog {
+		id: Uuid::new_v4(),
+		tenant_id,
+		user_id: None,
+		api_key_id: None,
+		action: action.to_string(),
+		resource_type: Some("monitor".to_string()),
+		resource_id: Some(Uuid::new_v4()),
+		changes: None,
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+		created_at: Some(Utc::now()),
+	}
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_webhooks_are_scoped_to_their_tenant() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let webhooks = WebhookRepository::new(db.pool.clone());
+	let owner = create_tenant(&tenants, "webhook-owner").await;
+	let other = create_tenant(&tenants, "webhook-other").await;
+	let webhook = webhooks
+		.create(
+			owner.id,
+			&webhook_request("https://example.com/hook".to_string(), &[]),
+			"secret",
+			None,
+		)
+		.await
+		.unwrap();
+
+	// Act
+	let foreign_get = webhooks.get(other.id, webhook.id).await;
+	let foreign_update = webhooks
+		.update(
+			other.id,
+			webhook.id,
+			&UpdateWebhookRequest {
+				is_active: Some(false),
+				..Default::default()
+			},
+		)
+		.await;
+	let foreign_deliveries = webhooks.list_deliveries(other.id, webhook.id, 20).await;
+
+	// Assert
+	assert!(matches!(
+		foreign_get,
+		Err(TenantRepositoryError::ResourceNotFound { .. })
+	));
+	assert!(matches!(
+		foreign_update,
+		Err(TenantRepositoryError::ResourceNotFound { .. })
+	));
+	assert!(matches!(
+		foreign_deliveries,
+		Err(TenantRepositoryError::ResourceNotFound { .. })
+	));
+	assert!(webhooks.list(other.id).await.unwrap().is_empty());
+	assert!(webhooks.get(owner.id, webhook.id).await.unwrap().is_active);
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL via SMT__DATABASE__URL"]
+async fn test_dispatcher_retries_and_records_deliveries() {
+	// Arrange
+	let db = TestDatabase::new().await.unwrap();
+	let tenants = TenantRepository::new(db.pool.clone());
+	let webhooks = WebhookRepository::new(db.pool.clone());
+	let tenant = create_tenant(&tenants, "webhook-dispatch").await;
+	let mut server = mockito::Server::new_async().await;
+	let _failing = server
+		.mock("POST", "/failing")
+		.with_status(500)
+		.expect(3)
+		.create_async()
+		.await;
+	let _ok = server
+		.mock("POST", "/ok")
+		.with_status(200)
+		.create_async()
+		.await;
+	let failing = webhooks
+		.create(
+			tenant.id,
+			&webhook_request(format!("{}/failing", server.url()), &["monitor_created"]),
+			"secret",
+			None,
+		)
+		.await
+		.unwrap();
+	let filtered = webhooks
+		.create(
+			tenant.id,
+			&webhook_request(format!("{}/ok", server.url()), &["monitor_deleted"]),
+			"secret",
+			None,
+		)
+		.await
+		.unwrap();
+	let dispatcher = WebhookDispatcher::new(
+		webhooks.clone(),
+		WebhookRetryPolicy {
+			max_attempts: 3,
+			initial_backoff: Duration::from_millis(1),
+			timeout: Duration::from_secs(5),
+		},
+	);
+
+	// Act
+	let tasks = dispatcher
+		.dispatch(&audit_log(tenant.id, "monitor_created"))
+		.await
+		.unwrap();
+	for task in tasks {
+		task.await.unwrap();
+	}
+
+	// Assert
+	let deliveries = webhooks
+		.list_deliveries(tenant.id, failing.id, 20)
+		.await
+		.unwrap();
+	assert_eq!(deliveries.len(), 1);
+	assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Failed);
+	assert_eq!(deliveries[0].attempts, 3);
+	assert_eq!(deliveries[0].response_status, Some(500));
+	assert_eq!(deliveries[0].event, "monitor_created");
+	assert!(webhooks
+		.list_deliveries(tenant.id, filtered.id, 20)
+		.await
+		.unwrap()
+		.is_empty());
+
+	cleanup_database(db.pool.clone()).await.unwrap();
+}
@@ -0,0 +1,90 @@
+use chrono::{Duration, Utc};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use stellar_monitor_tenant_isolation::{
+	models::*, repositories::TenantRepositoryError, services::ApiKeyCleanupService,
+};
+use uuid::Uuid;
+
+use crate::{
+	mocks::{MockApiKeyRepository, MockAuditService},
+	utils::fixtures::TestIds,
+};
+
+fn api_key(tenant_id: Uuid, name: &str, last_used_days_ago: i64) -> ApiKey {
+	let now = Utc::now();
+	ApiKey {
+		id: Uuid::new_v4(),
+		tenant_id,
+		name: name.to_string(),
+		key_hash: "hash".to_string(),
+		permissions: json!([]),
+		last_used_at: Some(now - Duration::days(last_used_days_ago)),
+		expires_at: None,
+		is_active: true,
+		created_at: now - Duration::days(365),
+		updated_at: now,
+	}
+}
+
+#[tokio::test]
+async fn test_unused_api_keys_are_disabled() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let stale = api_key(test_ids.tenant_1, "stale", 120);
+	let recent = api_key(test_ids.tenant_1, "recent", 2);
+	let keys = Arc::new(Mutex::new(vec![stale.clone(), recent.clone()]));
+
+	let mut api_key_repo = MockApiKeyRepository::new();
+	let list_keys = keys.clone();
+	api_key_repo
+		.expect_list_unused_since()
+		.times(1)
+		.returning(move |cutoff| {
+			Ok(list_keys
+				.lock()
+				.unwrap()
+				.iter()
+				.filter(|k| k.is_unused_since(cutoff))
+				.cloned()
+				.collect())
+		});
+	let deactivate_keys = keys.clone();
+	api_key_repo
+		.expect_deactivate()
+		.times(1)
+		.returning(move |key_id| {
+			let mut keys = deactivate_keys.lock().unwrap();
+			let key = keys
+				.iter_mut()
+				.find(|k| k.id == key_id)
+				.ok_or_else(|| TenantRepositoryError::Internal("missing key".to_string()))?;
+			key.is_active = false;
+			Ok(key.clone())
+		});
+
+	let mut audit_service = MockAuditService::new();
+	let stale_id = stale.id;
+	audit_service
+		.expect_log()
+		.withf(move |r| {
+			matches!(r.action, AuditAction::ApiKeyDisabled) && r.resource_id == Some(stale_id)
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+
+	let service = ApiKeyCleanupService::new(api_key_repo, audit_service, 90);
+
+	// Act
+	let disabled = service.run_once(Utc::now()).await.unwrap();
+
+	// Assert
+	assert_eq!(disabled.len(), 1);
+	assert_eq!(disabled[0].id, stale.id);
+
+	let keys = keys.lock().unwrap();
+	let stale = keys.iter().find(|k| k.id == stale.id).unwrap();
+	let recent = keys.iter().find(|k| k.id == recent.id).unwrap();
+	assert!(!stale.is_active);
+	assert!(recent.is_active);
+}
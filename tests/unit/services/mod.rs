@@ -1,4 +1,8 @@
+pub mod api_key_cleanup;
 pub mod audit_service;
+pub mod monitor_purge;
 pub mod monitor_service;
 pub mod network_service;
 pub mod trigger_service;
+pub mod usage_service;
+pub mod webhook_dispatcher;
@@ -0,0 +1,75 @@
+use chrono::{Duration, Utc};
+use std::sync::{Arc, Mutex};
+use stellar_monitor_tenant_isolation::{models::*, services::MonitorPurgeService};
+
+use crate::{
+	mocks::{MockAuditService, MockTenantMonitorRepository},
+	utils::{builders::MonitorBuilder, fixtures::TestIds},
+};
+
+#[tokio::test]
+async fn test_monitors_past_retention_are_purged() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let now = Utc::now();
+	let expired = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("expired")
+		.with_deleted_at(now - Duration::days(45))
+		.build();
+	let recently_deleted = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("recently-deleted")
+		.with_deleted_at(now - Duration::days(3))
+		.build();
+	let live = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_2)
+		.with_monitor_id("live")
+		.build();
+	let monitors = Arc::new(Mutex::new(vec![
+		expired.clone(),
+		recently_deleted.clone(),
+		live.clone(),
+	]));
+
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let stored = monitors.clone();
+	monitor_repo
+		.expect_purge_deleted_before()
+		.times(1)
+		.returning(move |cutoff| {
+			let mut monitors = stored.lock().unwrap();
+			let (purged, kept): (Vec<_>, Vec<_>) = monitors
+				.drain(..)
+				.partition(|m| m.deleted_at.is_some_and(|at| at < cutoff));
+			*monitors = kept;
+			Ok(purged)
+		});
+
+	let mut audit_service = MockAuditService::new();
+	let expired_id = expired.id;
+	audit_service
+		.expect_log()
+		.withf(move |r| {
+			matches!(r.action, AuditAction::MonitorPurged) && r.resource_id == Some(expired_id)
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+
+	let service = MonitorPurgeService::new(monitor_repo, audit_service, 30);
+
+	// Act
+	let purged = service.run_once(now).await.unwrap();
+
+	// Assert
+	assert_eq!(purged.len(), 1);
+	assert_eq!(purged[0].id, expired.id);
+
+	let remaining: Vec<String> = monitors
+		.lock()
+		.unwrap()
+		.iter()
+		.map(|m| m.monitor_id.clone())
+		.collect();
+	assert_eq!(remaining, vec!["recently-deleted", "live"]);
+}
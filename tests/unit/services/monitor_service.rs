@@ -1,15 +1,21 @@
 use mockall::predicate::*;
+use std::sync::{Arc, Mutex};
 use stellar_monitor_tenant_isolation::{
 	models::*,
 	repositories::error::TenantRepositoryError,
-	services::{MonitorServiceTrait, ServiceError},
+	services::{MonitorService, MonitorServiceTrait, ServiceError},
+	utils::with_tenant_context,
 };
 
 use crate::{
-	mocks::MockMonitorService,
+	mocks::{
+		MockAuditService, MockMonitorService, MockTenantMonitorRepository,
+		MockTenantNetworkRepository, MockTenantRepository,
+	},
 	utils::{
-		builders::{CreateMonitorRequestBuilder, MonitorBuilder},
-		fixtures::{stellar_monitor_config, TestIds},
+		builders::{CreateMonitorRequestBuilder, MonitorBuilder, NetworkBuilder, TriggerBuilder},
+		context::{owner_context, user_context},
+		fixtures::{evm_monitor_config, stellar_monitor_config, TestIds},
 	},
 };
 
@@ -35,6 +41,7 @@ async fn test_create_monitor_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -60,6 +67,7 @@ async fn test_create_monitor_quota_exceeded() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -164,6 +172,7 @@ async fn test_update_monitor_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -192,6 +201,7 @@ async fn test_delete_monitor_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -215,6 +225,7 @@ async fn test_delete_monitor_with_triggers() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -292,3 +303,1452 @@ async fn test_unauthorized_access() {
 		_ => panic!("Expected AccessDenied error"),
 	}
 }
+
+#[tokio::test]
+async fn test_deactivate_with_triggers_deactivates_monitor_and_triggers() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let monitor = MonitorBuilder::new()
+		.with_id(test_ids.monitor_1)
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.with_active(false)
+		.build();
+	let triggers = vec![
+		TriggerBuilder::new()
+			.with_trigger_id("trigger-1")
+			.with_monitor_id(test_ids.monitor_1)
+			.with_active(false)
+			.build(),
+		TriggerBuilder::new()
+			.with_trigger_id("trigger-2")
+			.with_monitor_id(test_ids.monitor_1)
+			.with_active(false)
+			.build(),
+	];
+
+	let result_clone = (monitor.clone(), triggers.clone());
+	monitor_repo
+		.expect_deactivate_with_triggers()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(move |_| Ok(result_clone.clone()));
+
+	// One entry for the monitor and one per trigger
+	audit_service
+		.expect_log()
+		.withf(|request| {
+			matches!(
+				request.action,
+				AuditAction::MonitorDisabled | AuditAction::TriggerDisabled
+			)
+		})
+		.times(3)
+		.returning(|_| Ok(()));
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.deactivate_with_triggers("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	let deactivation = result.unwrap();
+	assert_eq!(deactivation.monitor.is_active, Some(false));
+	assert_eq!(deactivation.triggers.len(), 2);
+	assert!(deactivation
+		.triggers
+		.iter()
+		.all(|t| t.is_active == Some(false)));
+}
+
+#[tokio::test]
+async fn test_deactivate_with_triggers_surfaces_repository_error() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	// The repository rolls the transaction back and surfaces the failure
+	monitor_repo
+		.expect_deactivate_with_triggers()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(|_| {
+			Err(TenantRepositoryError::Internal(
+				"trigger update failed".to_string(),
+			))
+		});
+	monitor_repo.expect_update().times(0);
+	audit_service.expect_log().times(0);
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.deactivate_with_triggers("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(ServiceError::Repository(TenantRepositoryError::Internal(_)))
+	));
+}
+
+#[tokio::test]
+async fn test_deactivate_with_triggers_succeeds_when_audit_fails() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let monitor = MonitorBuilder::new()
+		.with_id(test_ids.monitor_1)
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.with_active(false)
+		.build();
+	let trigger = TriggerBuilder::new()
+		.with_trigger_id("trigger-1")
+		.with_monitor_id(test_ids.monitor_1)
+		.with_active(false)
+		.build();
+	monitor_repo
+		.expect_deactivate_with_triggers()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(move |_| Ok((monitor.clone(), vec![trigger.clone()])));
+
+	// The change is committed before auditing, so every entry is still attempted
+	audit_service
+		.expect_log()
+		.times(2)
+		.returning(|_| Err(ServiceError::Internal("audit write failed".to_string())));
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.deactivate_with_triggers("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	let deactivation = result.unwrap();
+	assert_eq!(deactivation.monitor.is_active, Some(false));
+	assert_eq!(deactivation.triggers.len(), 1);
+}
+
+#[tokio::test]
+async fn test_deactivate_with_triggers_requires_write_access() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_deactivate_with_triggers().times(0);
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Viewer),
+		service.deactivate_with_triggers("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::AccessDenied(_))));
+}
+
+#[tokio::test]
+async fn test_member_cannot_delete_monitor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_delete().times(0);
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Member),
+		service.delete_monitor("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::AccessDenied(_))));
+}
+
+#[tokio::test]
+async fn test_delete_monitor_with_triggers_audits_each_trigger() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let monitor = MonitorBuilder::new()
+		.with_id(test_ids.monitor_1)
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.build();
+	let triggers = vec![
+		TriggerBuilder::new()
+			.with_trigger_id("trigger-1")
+			.with_monitor_id(test_ids.monitor_1)
+			.build(),
+		TriggerBuilder::new()
+			.with_trigger_id("trigger-2")
+			.with_monitor_id(test_ids.monitor_1)
+			.build(),
+	];
+
+	let result_clone = (monitor.clone(), triggers.clone());
+	monitor_repo
+		.expect_delete_with_triggers()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(move |_| Ok(result_clone.clone()));
+	monitor_repo.expect_delete().times(0);
+
+	let actions = Arc::new(Mutex::new(Vec::new()));
+	let recorded = actions.clone();
+	audit_service
+		.expect_log()
+		.times(3)
+		.returning(move |request| {
+			recorded.lock().unwrap().push(request.action);
+			Ok(())
+		});
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.delete_monitor_with_triggers("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(result.unwrap().len(), 2);
+	let actions = actions.lock().unwrap();
+	assert!(matches!(
+		actions.as_slice(),
+		[
+			AuditAction::TriggerDeleted,
+			AuditAction::TriggerDeleted,
+			AuditAction::MonitorDeleted
+		]
+	));
+}
+
+#[tokio::test]
+async fn test_imported_monitors_are_listed_by_source() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let store: Arc<Mutex<Vec<TenantMonitor>>> = Arc::new(Mutex::new(Vec::new()));
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let stored = store.clone();
+	let tenant_id = test_ids.tenant_1;
+	monitor_repo
+		.expect_create()
+		.times(1)
+		.returning(move |request| {
+			let monitor = MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_monitor_id(request.monitor_id)
+				.with_network_id(request.network_id)
+				.build();
+			stored.lock().unwrap().push(monitor.clone());
+			Ok(monitor)
+		});
+
+	let stored = store.clone();
+	monitor_repo
+		.expect_create_with_source()
+		.with(always(), eq(CreationSource::Import))
+		.times(2)
+		.returning(move |request, source| {
+			let monitor = MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_monitor_id(request.monitor_id)
+				.with_network_id(request.network_id)
+				.with_creation_source(source.as_str())
+				.build();
+			stored.lock().unwrap().push(monitor.clone());
+			Ok(monitor)
+		});
+
+	let stored = store.clone();
+	monitor_repo
+		.expect_list_by_source()
+		.returning(move |source, _, _| {
+			Ok(stored
+				.lock()
+				.unwrap()
+				.iter()
+				.filter(|m| m.creation_source == source.as_str())
+				.cloned()
+				.collect())
+		});
+
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		Ok(NetworkBuilder::new()
+			.with_id(id)
+			.with_tenant_id(tenant_id)
+			.with_blockchain("stellar")
+			.build())
+	});
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 10,
+					max_triggers_per_monitor: 10,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: 0,
+					networks_count: 1,
+					triggers_count: 0,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+
+	audit_service.expect_log().times(3).returning(|_| Ok(()));
+
+	let service = MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service);
+	let request = |monitor_id: &str| {
+		CreateMonitorRequestBuilder::new()
+			.with_monitor_id(monitor_id)
+			.with_network_id(test_ids.network_1)
+			.with_configuration(stellar_monitor_config())
+			.build()
+	};
+
+	// Act
+	let (imported, from_import, from_api) = with_tenant_context(owner_context(tenant_id), async {
+		service
+			.create_monitor(request("manual"), RequestMetadata::default())
+			.await
+			.unwrap();
+		let imported = service
+			.import_monitors(
+				vec![request("imported-1"), request("imported-2")],
+				RequestMetadata::default(),
+			)
+			.await
+			.unwrap();
+		let from_import = service
+			.list_monitors_by_source(CreationSource::Import, 50, 0)
+			.await
+			.unwrap();
+		let from_api = service
+			.list_monitors_by_source(CreationSource::Api, 50, 0)
+			.await
+			.unwrap();
+		(imported, from_import, from_api)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(imported.len(), 2);
+	assert!(imported.iter().all(|m| m.creation_source == "import"));
+	assert_eq!(from_import.len(), 2);
+	assert_eq!(from_api.len(), 1);
+	assert_eq!(from_api[0].monitor_id, "manual");
+}
+
+fn quota_status_with_monitors(tenant_id: uuid::Uuid, monitors_count: i32) -> ResourceQuotaStatus {
+	ResourceQuotaStatus::from_usage(
+		tenant_id,
+		TenantQuotas {
+			max_monitors: 2,
+			max_networks: 10,
+			max_triggers_per_monitor: 10,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		CurrentUsage {
+			monitors_count,
+			networks_count: 1,
+			triggers_count: 0,
+			rpc_requests_last_minute: 0,
+			storage_mb_used: 0,
+		},
+		vec![],
+	)
+}
+
+#[tokio::test]
+async fn test_restore_monitor_success() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let restored = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("monitor-123")
+		.build();
+	let restored_clone = restored.clone();
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_with_monitors(tenant_id, 1)));
+	monitor_repo
+		.expect_restore()
+		.with(eq("monitor-123"))
+		.times(1)
+		.returning(move |_| Ok(restored_clone.clone()));
+	audit_service
+		.expect_log()
+		.withf(|r| matches!(r.action, AuditAction::MonitorRestored))
+		.times(1)
+		.returning(|_| Ok(()));
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		tenant_repo,
+		audit_service,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.restore_monitor("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	let monitor = result.unwrap();
+	assert_eq!(monitor.id, restored.id);
+	assert!(monitor.deleted_at.is_none());
+}
+
+#[tokio::test]
+async fn test_restore_monitor_respects_quota() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_with_monitors(tenant_id, 2)));
+	monitor_repo.expect_restore().never();
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.restore_monitor("monitor-123", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::QuotaExceeded(_))));
+}
+
+#[tokio::test]
+async fn test_list_including_deleted_requires_admin() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+
+	let deleted = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_deleted_at(chrono::Utc::now())
+		.build();
+	monitor_repo
+		.expect_list_including_deleted()
+		.times(1)
+		.returning(move |_, _| Ok(vec![deleted.clone()]));
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+
+	// Act
+	let as_member = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Member),
+		service.list_monitors_including_deleted(20, 0),
+	)
+	.await;
+	let as_admin = with_tenant_context(
+		user_context(test_ids.tenant_1, TenantRole::Admin),
+		service.list_monitors_including_deleted(20, 0),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(as_member, Err(ServiceError::AccessDenied(_))));
+	assert_eq!(as_admin.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_duplicate_contract_address_rejected_per_network() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let store: Arc<Mutex<Vec<TenantMonitor>>> = Arc::new(Mutex::new(Vec::new()));
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+	let tenant_id = test_ids.tenant_1;
+
+	let stored = store.clone();
+	monitor_repo
+		.expect_contract_address_in_use()
+		.times(3)
+		.returning(move |network_id, address| {
+			Ok(stored.lock().unwrap().iter().any(|m| {
+				m.network_id == network_id
+					&& m.configuration["contract_address"]
+						.as_str()
+						.is_some_and(|a| a.eq_ignore_ascii_case(address))
+			}))
+		});
+
+	let stored = store.clone();
+	monitor_repo
+		.expect_create()
+		.times(2)
+		.returning(move |request| {
+			let monitor = MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_monitor_id(request.monitor_id)
+				.with_network_id(request.network_id)
+				.with_configuration(request.configuration)
+				.build();
+			stored.lock().unwrap().push(monitor.clone());
+			Ok(monitor)
+		});
+
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		Ok(NetworkBuilder::new()
+			.with_id(id)
+			.with_tenant_id(tenant_id)
+			.build())
+	});
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_with_monitors(tenant_id, 0)));
+
+	audit_service.expect_log().times(2).returning(|_| Ok(()));
+
+	let service = MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+		.with_unique_contract_addresses(true);
+	let request = |monitor_id: &str, network_id| {
+		CreateMonitorRequestBuilder::new()
+			.with_monitor_id(monitor_id)
+			.with_network_id(network_id)
+			.with_configuration(evm_monitor_config())
+			.build()
+	};
+
+	// Act
+	let (first, duplicate, other_network) = with_tenant_context(owner_context(tenant_id), async {
+		let first = service
+			.create_monitor(
+				request("contract-x", test_ids.network_1),
+				RequestMetadata::default(),
+			)
+			.await;
+		let duplicate = service
+			.create_monitor(
+				request("contract-x-again", test_ids.network_1),
+				RequestMetadata::default(),
+			)
+			.await;
+		let other_network = service
+			.create_monitor(
+				request("contract-x-elsewhere", test_ids.network_2),
+				RequestMetadata::default(),
+			)
+			.await;
+		(first, duplicate, other_network)
+	})
+	.await;
+
+	// Assert
+	assert!(first.is_ok());
+	assert!(matches!(
+		duplicate,
+		Err(ServiceError::Repository(
+			TenantRepositoryError::AlreadyExists { .. }
+		))
+	));
+	assert!(other_network.is_ok());
+}
+
+fn quota_status_over_storage(tenant_id: uuid::Uuid) -> ResourceQuotaStatus {
+	ResourceQuotaStatus::from_usage(
+		tenant_id,
+		TenantQuotas {
+			max_monitors: 10,
+			max_networks: 10,
+			max_triggers_per_monitor: 10,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		CurrentUsage {
+			monitors_count: 1,
+			networks_count: 1,
+			triggers_count: 0,
+			rpc_requests_last_minute: 0,
+			storage_mb_used: 1200,
+		},
+		vec![],
+	)
+}
+
+#[tokio::test]
+async fn test_create_monitor_over_storage_quota_soft_enforcement_warns() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let tenant_id = test_ids.tenant_1;
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_over_storage(tenant_id)));
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		Ok(NetworkBuilder::new()
+			.with_id(id)
+			.with_tenant_id(tenant_id)
+			.build())
+	});
+	monitor_repo
+		.expect_create()
+		.times(1)
+		.returning(move |request| {
+			Ok(MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_monitor_id(request.monitor_id)
+				.build())
+		});
+	audit_service.expect_log().times(1).returning(|_| Ok(()));
+
+	let service = MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+		.with_storage_enforcement(StorageEnforcement::Soft);
+	let request = CreateMonitorRequestBuilder::new()
+		.with_network_id(test_ids.network_1)
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(tenant_id),
+		service.create_monitor(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(result.is_ok());
+	assert_eq!(
+		quota_status_over_storage(tenant_id).warnings,
+		vec![QuotaWarning {
+			resource: "storage_mb".to_string(),
+			usage: 1200,
+			limit: 1000,
+			percent_used: 120,
+		}]
+	);
+}
+
+#[tokio::test]
+async fn test_create_monitor_over_storage_quota_hard_enforcement_rejects() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_over_storage(tenant_id)));
+	monitor_repo.expect_create().times(0);
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	);
+	let request = CreateMonitorRequestBuilder::new()
+		.with_network_id(test_ids.network_1)
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_monitor(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::QuotaExceeded(msg)) => {
+			assert_eq!(msg, "Storage quota exceeded: 1200/1000 MB")
+		}
+		other => panic!(
+			"Expected QuotaExceeded error, got {:?}",
+			other.map(|m| m.id)
+		),
+	}
+}
+
+/// In-memory stand-in for a monitor row and its `monitor_config_history`
+struct MonitorWithHistory {
+	monitor: TenantMonitor,
+	history: Vec<serde_json::Value>,
+}
+
+#[tokio::test]
+async fn test_rollback_monitor_restores_earlier_version() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let config = |threshold: i64| {
+		serde_json::json!({
+			"name": "Large Transfers",
+			"paused": false,
+			"threshold": threshold
+		})
+	};
+	let state = Arc::new(Mutex::new(MonitorWithHistory {
+		monitor: MonitorBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_monitor_id("monitor-123")
+			.with_configuration(config(100))
+			.build(),
+		history: vec![],
+	}));
+
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let current = state.clone();
+	monitor_repo
+		.expect_get()
+		.with(eq("monitor-123"))
+		.returning(move |_| Ok(current.lock().unwrap().monitor.clone()));
+	let updated = state.clone();
+	monitor_repo
+		.expect_update()
+		.with(eq("monitor-123"), always())
+		.times(3)
+		.returning(move |_, request| {
+			let mut state = updated.lock().unwrap();
+			let configuration = request.configuration.unwrap();
+			if configuration != state.monitor.configuration {
+				let previous = state.monitor.configuration.clone();
+				state.history.push(previous);
+				state.monitor.configuration = configuration;
+			}
+			Ok(state.monitor.clone())
+		});
+	let rolled_back = state.clone();
+	monitor_repo
+		.expect_rollback()
+		.with(eq("monitor-123"), eq(2))
+		.times(1)
+		.returning(move |_, version| {
+			let mut state = rolled_back.lock().unwrap();
+			let configuration = state.history[version as usize - 1].clone();
+			let previous = state.monitor.configuration.clone();
+			state.history.push(previous);
+			state.monitor.configuration = configuration;
+			Ok(MonitorRollback {
+				monitor: state.monitor.clone(),
+				restored_version: version,
+				version: state.history.len() as i32 + 1,
+			})
+		});
+
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().times(4).returning(|_| Ok(()));
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	);
+
+	// Act: versions 1 (100) -> 2 (200) -> 3 (300) -> 4 (400), then restore 2
+	let result = with_tenant_context(owner_context(test_ids.tenant_1), async {
+		for threshold in [200, 300, 400] {
+			service
+				.update_monitor(
+					"monitor-123",
+					UpdateMonitorRequest {
+						name: None,
+						configuration: Some(config(threshold)),
+						is_active: None,
+					},
+					RequestMetadata::default(),
+				)
+				.await?;
+		}
+		service
+			.rollback_monitor("monitor-123", 2, RequestMetadata::default())
+			.await
+	})
+	.await;
+
+	// Assert
+	let rollback = result.unwrap();
+	assert_eq!(rollback.restored_version, 2);
+	assert_eq!(rollback.version, 5);
+	assert_eq!(rollback.monitor.configuration, config(200));
+	assert_eq!(state.lock().unwrap().history.last(), Some(&config(400)));
+}
+
+#[tokio::test]
+async fn test_rollback_monitor_rejects_version_zero() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_rollback().never();
+
+	let service = MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.rollback_monitor("monitor-123", 0, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_monitor_enforces_name_pattern() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let tenant_id = test_ids.tenant_1;
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	tenant_repo
+		.expect_get_quota_status()
+		.times(1)
+		.returning(|tenant_id| Ok(quota_status_with_monitors(tenant_id, 0)));
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		Ok(NetworkBuilder::new()
+			.with_id(id)
+			.with_tenant_id(tenant_id)
+			.build())
+	});
+	monitor_repo
+		.expect_create()
+		.times(1)
+		.returning(move |request| {
+			Ok(MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_name(&request.name)
+				.build())
+		});
+	audit_service.expect_log().times(1).returning(|_| Ok(()));
+
+	let service = MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+		.with_name_pattern(Some(regex::Regex::new("^prod-[a-z0-9-]+$").unwrap()));
+	let request = |name: &str| {
+		CreateMonitorRequestBuilder::new()
+			.with_name(name)
+			.with_network_id(test_ids.network_1)
+			.build()
+	};
+
+	// Act
+	let (conforming, non_conforming) = with_tenant_context(owner_context(tenant_id), async {
+		let conforming = service
+			.create_monitor(request("prod-usdc-transfers"), RequestMetadata::default())
+			.await;
+		let non_conforming = service
+			.create_monitor(request("My Test Monitor"), RequestMetadata::default())
+			.await;
+		(conforming, non_conforming)
+	})
+	.await;
+
+	// Assert
+	assert_eq!(conforming.unwrap().name, "prod-usdc-transfers");
+	match non_conforming {
+		Err(ServiceError::ValidationError(msg)) => assert!(msg.contains("^prod-[a-z0-9-]+$")),
+		other => panic!("Expected ValidationError, got {:?}", other.map(|m| m.id)),
+	}
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_serialized_creates_hold_quota_per_tenant() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let counts: Arc<Mutex<std::collections::HashMap<uuid::Uuid, i32>>> = Arc::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let quota_counts = counts.clone();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(move |tenant_id| {
+			let count = *quota_counts.lock().unwrap().get(&tenant_id).unwrap_or(&0);
+			// Widen the gap between the quota check and the insert
+			std::thread::sleep(std::time::Duration::from_millis(10));
+			Ok(quota_status_with_monitors(tenant_id, count))
+		});
+	network_repo
+		.expect_get_by_uuid()
+		.returning(|id| Ok(NetworkBuilder::new().with_id(id).build()));
+	let created_counts = counts.clone();
+	monitor_repo.expect_create().returning(move |request| {
+		let tenant_id = stellar_monitor_tenant_isolation::utils::current_tenant_id();
+		*created_counts.lock().unwrap().entry(tenant_id).or_default() += 1;
+		Ok(MonitorBuilder::new()
+			.with_tenant_id(tenant_id)
+			.with_name(&request.name)
+			.build())
+	});
+	audit_service.expect_log().returning(|_| Ok(()));
+
+	let service = Arc::new(
+		MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+			.with_serialized_creates(true),
+	);
+
+	// Act
+	let handles: Vec<_> = std::iter::repeat_n(test_ids.tenant_1, 6)
+		.chain(std::iter::repeat_n(test_ids.tenant_2, 2))
+		.map(|tenant_id| {
+			let service = service.clone();
+			let request = CreateMonitorRequestBuilder::new()
+				.with_network_id(test_ids.network_1)
+				.build();
+			tokio::spawn(with_tenant_context(owner_context(tenant_id), async move {
+				(
+					tenant_id,
+					service
+						.create_monitor(request, RequestMetadata::default())
+						.await,
+				)
+			}))
+		})
+		.collect();
+	let mut results = Vec::new();
+	for handle in handles {
+		results.push(handle.await.unwrap());
+	}
+
+	// Assert
+	let outcomes = |tenant_id| {
+		let of_tenant = results.iter().filter(|(t, _)| *t == tenant_id);
+		let created = of_tenant.clone().filter(|(_, r)| r.is_ok()).count();
+		let rejected = of_tenant
+			.filter(|(_, r)| matches!(r, Err(ServiceError::QuotaExceeded(_))))
+			.count();
+		(created, rejected)
+	};
+	assert_eq!(outcomes(test_ids.tenant_1), (2, 4));
+	assert_eq!(outcomes(test_ids.tenant_2), (2, 0));
+}
+
+#[tokio::test]
+async fn test_create_rate_limit_throttles_bursts_under_quota() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let created = Arc::new(Mutex::new(0));
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let quota_created = created.clone();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(move |tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 10,
+					max_triggers_per_monitor: 10,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: *quota_created.lock().unwrap(),
+					networks_count: 1,
+					triggers_count: 0,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+	network_repo
+		.expect_get_by_uuid()
+		.returning(|id| Ok(NetworkBuilder::new().with_id(id).build()));
+	let repo_created = created.clone();
+	monitor_repo.expect_create().returning(move |request| {
+		*repo_created.lock().unwrap() += 1;
+		Ok(MonitorBuilder::new().with_name(&request.name).build())
+	});
+	audit_service.expect_log().returning(|_| Ok(()));
+
+	let service = MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+		.with_create_rate_limit(Some(3));
+
+	// Act
+	let (first_tenant, other_tenant) =
+		with_tenant_context(owner_context(test_ids.tenant_1), async {
+			let mut results = Vec::new();
+			for _ in 0..6 {
+				let request = CreateMonitorRequestBuilder::new()
+					.with_network_id(test_ids.network_1)
+					.build();
+				results.push(
+					service
+						.create_monitor(request, RequestMetadata::default())
+						.await,
+				);
+			}
+			let other = with_tenant_context(
+				owner_context(test_ids.tenant_2),
+				service.create_monitor(
+					CreateMonitorRequestBuilder::new()
+						.with_network_id(test_ids.network_1)
+						.build(),
+					RequestMetadata::default(),
+				),
+			)
+			.await;
+			(results, other)
+		})
+		.await;
+
+	// Assert
+	assert_eq!(first_tenant.iter().filter(|r| r.is_ok()).count(), 3);
+	assert_eq!(
+		first_tenant
+			.iter()
+			.filter(|r| matches!(
+				r,
+				Err(ServiceError::Repository(
+					TenantRepositoryError::RateLimited(_)
+				))
+			))
+			.count(),
+		3
+	);
+	assert!(other_tenant.is_ok());
+	assert_eq!(*created.lock().unwrap(), 4);
+}
+
+/// A service whose tenant holds `source` and `monitors_count` monitors, with `network_1`
+/// and `network_2` as its networks
+fn clone_service(
+	source: TenantMonitor,
+	monitors_count: i32,
+	monitor_repo: MockTenantMonitorRepository,
+	audit_service: MockAuditService,
+) -> MonitorService<
+	MockTenantMonitorRepository,
+	MockTenantNetworkRepository,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let test_ids = TestIds::default();
+	let tenant_id = source.tenant_id;
+	let mut monitor_repo = monitor_repo;
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	monitor_repo
+		.expect_get()
+		.returning(move |_| Ok(source.clone()));
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(move |tenant_id| Ok(quota_status_with_monitors(tenant_id, monitors_count)));
+	network_repo.expect_get_by_uuid().returning(move |id| {
+		if id == test_ids.network_1 || id == test_ids.network_2 {
+			Ok(NetworkBuilder::new()
+				.with_id(id)
+				.with_tenant_id(tenant_id)
+				.build())
+		} else {
+			Err(TenantRepositoryError::ResourceNotFound {
+				resource_type: "network".to_string(),
+				resource_id: id.to_string(),
+			})
+		}
+	});
+
+	MonitorService::new(monitor_repo, network_repo, tenant_repo, audit_service)
+}
+
+fn clone_source(test_ids: &TestIds) -> TenantMonitor {
+	MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("usdc-transfers")
+		.with_name("USDC Transfers")
+		.with_network_id(test_ids.network_1)
+		.with_configuration(stellar_monitor_config())
+		.build()
+}
+
+#[tokio::test]
+async fn test_clone_monitor_copies_onto_target_network() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let source = clone_source(&test_ids);
+	let source_id = source.id;
+	let tenant_id = test_ids.tenant_1;
+	let target_network = test_ids.network_2;
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	monitor_repo
+		.expect_create()
+		.withf(move |request| {
+			request.monitor_id == "usdc-transfers-testnet"
+				&& request.name == "USDC Transfers (copy)"
+				&& request.network_id == target_network
+				&& request.configuration == stellar_monitor_config()
+		})
+		.times(1)
+		.returning(move |request| {
+			Ok(MonitorBuilder::new()
+				.with_tenant_id(tenant_id)
+				.with_monitor_id(&request.monitor_id)
+				.with_name(&request.name)
+				.with_network_id(request.network_id)
+				.with_configuration(request.configuration)
+				.build())
+		});
+	audit_service
+		.expect_log()
+		.withf(move |r| {
+			let changes = r.changes.as_ref().unwrap();
+			matches!(r.action, AuditAction::MonitorCreated)
+				&& changes["cloned_from"]["monitor_id"] == "usdc-transfers"
+				&& changes["cloned_from"]["id"] == source_id.to_string()
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+	let service = clone_service(source, 1, monitor_repo, audit_service);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.clone_monitor(
+			"usdc-transfers",
+			"usdc-transfers-testnet".to_string(),
+			Some(target_network),
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	let monitor = result.unwrap();
+	assert_eq!(monitor.name, "USDC Transfers (copy)");
+	assert_eq!(monitor.network_id, target_network);
+}
+
+#[tokio::test]
+async fn test_clone_monitor_rejects_network_outside_tenant() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_create().never();
+	let service = clone_service(
+		clone_source(&test_ids),
+		1,
+		monitor_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.clone_monitor(
+			"usdc-transfers",
+			"usdc-transfers-copy".to_string(),
+			Some(uuid::Uuid::new_v4()),
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(ServiceError::Repository(
+			TenantRepositoryError::ResourceNotFound { .. }
+		))
+	));
+}
+
+#[tokio::test]
+async fn test_clone_monitor_respects_quota() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_create().never();
+	let service = clone_service(
+		clone_source(&test_ids),
+		2,
+		monitor_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.clone_monitor(
+			"usdc-transfers",
+			"usdc-transfers-copy".to_string(),
+			None,
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::QuotaExceeded(_))));
+}
+
+#[tokio::test]
+async fn test_clone_monitor_rejects_taken_monitor_id() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	monitor_repo.expect_create().times(1).returning(|request| {
+		Err(TenantRepositoryError::AlreadyExists {
+			resource_type: "monitor".to_string(),
+			resource_id: request.monitor_id,
+		})
+	});
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().never();
+	let service = clone_service(clone_source(&test_ids), 1, monitor_repo, audit_service);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.clone_monitor(
+			"usdc-transfers",
+			"usdc-transfers".to_string(),
+			None,
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::Repository(TenantRepositoryError::AlreadyExists {
+			resource_id, ..
+		})) => {
+			assert_eq!(resource_id, "usdc-transfers")
+		}
+		other => panic!("Expected AlreadyExists, got {:?}", other.map(|m| m.id)),
+	}
+}
+
+/// Monitor service over an inactive monitor whose untested triggers are read from `untested`
+fn service_with_untested_triggers(
+	untested: Arc<Mutex<Vec<TenantTrigger>>>,
+	require_tested_triggers: bool,
+) -> MonitorService<
+	MockTenantMonitorRepository,
+	MockTenantNetworkRepository,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let test_ids = TestIds::default();
+	let monitor = MonitorBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_monitor_id("payments")
+		.with_active(false)
+		.build();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let existing = monitor.clone();
+	monitor_repo
+		.expect_get()
+		.returning(move |_| Ok(existing.clone()));
+	monitor_repo
+		.expect_list_untested_triggers()
+		.returning(move |_| Ok(untested.lock().unwrap().clone()));
+	monitor_repo.expect_update().returning(move |_, request| {
+		let mut updated = monitor.clone();
+		updated.is_active = request.is_active.or(updated.is_active);
+		Ok(updated)
+	});
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().returning(|_| Ok(()));
+
+	MonitorService::new(
+		monitor_repo,
+		MockTenantNetworkRepository::new(),
+		MockTenantRepository::new(),
+		audit_service,
+	)
+	.with_required_trigger_tests(require_tested_triggers)
+}
+
+fn activate() -> UpdateMonitorRequest {
+	UpdateMonitorRequest {
+		name: None,
+		configuration: None,
+		is_active: Some(true),
+	}
+}
+
+#[tokio::test]
+async fn test_activation_waits_for_successful_trigger_tests() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let untested = Arc::new(Mutex::new(vec![
+		TriggerBuilder::new()
+			.with_trigger_id("payments-hook")
+			.with_trigger_type("webhook")
+			.build(),
+		// Email triggers can't be test-delivered, so they never block activation
+		TriggerBuilder::new()
+			.with_trigger_id("payments-email")
+			.with_trigger_type("email")
+			.build(),
+	]));
+	let service = service_with_untested_triggers(untested.clone(), true);
+
+	// Act
+	let (refused, activated) = with_tenant_context(owner_context(test_ids.tenant_1), async {
+		let refused = service
+			.update_monitor("payments", activate(), RequestMetadata::default())
+			.await;
+		// A successful test of the webhook trigger is recorded
+		untested
+			.lock()
+			.unwrap()
+			.retain(|trigger| trigger.trigger_id != "payments-hook");
+		let activated = service
+			.update_monitor("payments", activate(), RequestMetadata::default())
+			.await;
+		(refused, activated)
+	})
+	.await;
+
+	// Assert
+	match refused {
+		Err(ServiceError::ValidationError(message)) => {
+			assert!(message.contains("payments-hook"), "{}", message);
+			assert!(!message.contains("payments-email"), "{}", message);
+		}
+		other => panic!(
+			"Expected ValidationError, got {:?}",
+			other.map(|m| m.is_active)
+		),
+	}
+	assert_eq!(activated.unwrap().is_active, Some(true));
+}
+
+#[tokio::test]
+async fn test_activation_ignores_trigger_tests_when_not_required() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let untested = Arc::new(Mutex::new(vec![TriggerBuilder::new()
+		.with_trigger_id("payments-hook")
+		.with_trigger_type("webhook")
+		.build()]));
+	let service = service_with_untested_triggers(untested, false);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.update_monitor("payments", activate(), RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(result.unwrap().is_active, Some(true));
+}
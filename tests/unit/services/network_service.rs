@@ -1,14 +1,26 @@
+use std::sync::{Arc, Mutex};
+
 use mockall::predicate::*;
 use stellar_monitor_tenant_isolation::{
 	models::*,
-	services::{NetworkServiceTrait, ServiceError},
+	repositories::TenantRepositoryError,
+	services::{
+		probe_rpc_url, NetworkProbe, NetworkService, NetworkServiceTrait, ServiceError,
+		StorageAccounting, StorageRecorder, RPC_PROBE_TIMEOUT,
+	},
+	utils::with_tenant_context,
 };
+use uuid::Uuid;
 
 use crate::{
-	mocks::MockNetworkService,
+	mocks::{
+		MockAuditService, MockNetworkProbe, MockNetworkService, MockStorageRecorder,
+		MockTenantNetworkRepository, MockTenantRepository,
+	},
 	utils::{
-		builders::{CreateNetworkRequestBuilder, NetworkBuilder},
-		fixtures::{stellar_network_config, TestIds},
+		builders::{CreateNetworkRequestBuilder, MonitorBuilder, NetworkBuilder, TriggerBuilder},
+		context::owner_context,
+		fixtures::{evm_network_config, stellar_network_config, TestIds},
 	},
 };
 
@@ -33,6 +45,7 @@ async fn test_create_network_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -58,6 +71,7 @@ async fn test_create_network_quota_exceeded() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -127,6 +141,7 @@ async fn test_update_network_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -155,6 +170,7 @@ async fn test_delete_network_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -178,15 +194,24 @@ async fn test_delete_network_with_monitors() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
 		.expect_delete_network()
 		.with(eq(network_id), always())
 		.times(1)
-		.returning(|_, _| {
-			Err(ServiceError::ValidationError(
-				"Cannot delete network with active monitors".to_string(),
+		.returning(|network_id, _| {
+			Err(ServiceError::Repository(
+				TenantRepositoryError::NetworkHasMonitors {
+					network_id: network_id.to_string(),
+					monitors: vec![DependentMonitor {
+						id: Uuid::nil(),
+						monitor_id: "payments".to_string(),
+						name: "Payments".to_string(),
+						deleted: false,
+					}],
+				},
 			))
 		});
 
@@ -194,12 +219,14 @@ async fn test_delete_network_with_monitors() {
 	let result = mock_service.delete_network(network_id, metadata).await;
 
 	// Assert
-	assert!(result.is_err());
 	match result.unwrap_err() {
-		ServiceError::ValidationError(msg) => {
-			assert_eq!(msg, "Cannot delete network with active monitors")
+		ServiceError::Repository(TenantRepositoryError::NetworkHasMonitors {
+			monitors, ..
+		}) => {
+			assert_eq!(monitors.len(), 1);
+			assert_eq!(monitors[0].monitor_id, "payments");
 		}
-		_ => panic!("Expected ValidationError"),
+		_ => panic!("Expected NetworkHasMonitors"),
 	}
 }
 
@@ -240,6 +267,7 @@ async fn test_network_validation_error() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -289,3 +317,579 @@ async fn test_unauthorized_network_access() {
 		_ => panic!("Expected AccessDenied error"),
 	}
 }
+
+fn quota_status_with_evm_limit(
+	tenant_id: Uuid,
+	evm_networks: i32,
+	stellar_networks: i32,
+) -> ResourceQuotaStatus {
+	let networks_count = evm_networks + stellar_networks;
+	ResourceQuotaStatus {
+		tenant_id,
+		quotas: TenantQuotas {
+			max_monitors: 10,
+			max_networks: 10,
+			max_triggers_per_monitor: 10,
+			max_rpc_requests_per_minute: 1000,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		usage: CurrentUsage {
+			monitors_count: 0,
+			networks_count,
+			triggers_count: 0,
+			rpc_requests_last_minute: 0,
+			storage_mb_used: 0,
+		},
+		available: AvailableResources {
+			monitors: 10,
+			networks: 10 - networks_count,
+			triggers: 0,
+			rpc_requests_per_minute: 1000,
+			storage_mb: 1000,
+		},
+		blockchains: vec![BlockchainQuotaStatus {
+			blockchain: "evm".to_string(),
+			max_monitors: None,
+			max_networks: Some(2),
+			monitors_count: 0,
+			networks_count: evm_networks,
+		}],
+		warnings: vec![],
+		monitor_triggers: Default::default(),
+	}
+}
+
+#[tokio::test]
+async fn test_create_network_blockchain_quota_exceeded() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let counts = Arc::new(Mutex::new((0, 0)));
+
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let create_counts = counts.clone();
+	network_repo
+		.expect_create()
+		.times(3)
+		.returning(move |request| {
+			let mut counts = create_counts.lock().unwrap();
+			if request.blockchain == "evm" {
+				counts.0 += 1;
+			} else {
+				counts.1 += 1;
+			}
+			Ok(NetworkBuilder::new()
+				.with_tenant_id(test_ids.tenant_1)
+				.with_name(request.name)
+				.with_blockchain(request.blockchain)
+				.build())
+		});
+
+	let mut tenant_repo = MockTenantRepository::new();
+	let quota_counts = counts.clone();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(move |tenant_id| {
+			let (evm, stellar) = *quota_counts.lock().unwrap();
+			Ok(quota_status_with_evm_limit(tenant_id, evm, stellar))
+		});
+
+	let mut audit_service = MockAuditService::new();
+	audit_service.expect_log().times(3).returning(|_| Ok(()));
+
+	let service = NetworkService::new(network_repo, tenant_repo, audit_service);
+	let request = |name: &str, blockchain: &str| {
+		CreateNetworkRequestBuilder::new()
+			.with_network_id(name)
+			.with_name(name)
+			.with_blockchain(blockchain)
+			.build()
+	};
+	let metadata = || RequestMetadata {
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+	};
+
+	// Act
+	let (first, second, third, stellar) =
+		with_tenant_context(owner_context(test_ids.tenant_1), async {
+			(
+				service
+					.create_network(request("evm-1", "evm"), metadata())
+					.await,
+				service
+					.create_network(request("evm-2", "evm"), metadata())
+					.await,
+				service
+					.create_network(request("evm-3", "evm"), metadata())
+					.await,
+				service
+					.create_network(request("stellar-1", "stellar"), metadata())
+					.await,
+			)
+		})
+		.await;
+
+	// Assert
+	assert!(first.is_ok());
+	assert!(second.is_ok());
+	match third {
+		Err(ServiceError::QuotaExceeded(msg)) => {
+			assert!(msg.contains("evm network quota exceeded"))
+		}
+		other => panic!("Expected QuotaExceeded, got {:?}", other.map(|n| n.name)),
+	}
+	assert!(stellar.is_ok());
+}
+
+fn network_service_for_create(
+	network_repo: MockTenantNetworkRepository,
+	audit_calls: usize,
+) -> NetworkService<MockTenantNetworkRepository, MockTenantRepository, MockAuditService> {
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| Ok(quota_status_with_evm_limit(tenant_id, 0, 0)));
+
+	let mut audit_service = MockAuditService::new();
+	audit_service
+		.expect_log()
+		.times(audit_calls)
+		.returning(|_| Ok(()));
+
+	NetworkService::new(network_repo, tenant_repo, audit_service)
+}
+
+#[tokio::test]
+async fn test_create_network_normalizes_rpc_url() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut config = stellar_network_config();
+	config["rpc_url"] = serde_json::json!("  HTTPS://horizon-testnet.stellar.org/  ");
+	config["chain_id"] = serde_json::json!(" testnet ");
+
+	let mut network_repo = MockTenantNetworkRepository::new();
+	network_repo
+		.expect_create()
+		.withf(|request| {
+			request.configuration["rpc_url"] == "https://horizon-testnet.stellar.org"
+				&& request.configuration["chain_id"] == "testnet"
+		})
+		.times(1)
+		.returning(move |request| {
+			Ok(NetworkBuilder::new()
+				.with_tenant_id(test_ids.tenant_1)
+				.with_configuration(request.configuration)
+				.build())
+		});
+
+	let service = network_service_for_create(network_repo, 1);
+	let request = CreateNetworkRequestBuilder::new()
+		.with_blockchain("stellar")
+		.with_configuration(config)
+		.build();
+	let metadata = RequestMetadata {
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+	};
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_network(request, metadata),
+	)
+	.await;
+
+	// Assert
+	let network = result.unwrap();
+	assert_eq!(
+		network.configuration["rpc_url"],
+		"https://horizon-testnet.stellar.org"
+	);
+}
+
+#[tokio::test]
+async fn test_create_network_requires_rpc_url() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut config = stellar_network_config();
+	config.as_object_mut().unwrap().remove("rpc_url");
+
+	let mut network_repo = MockTenantNetworkRepository::new();
+	network_repo.expect_create().never();
+
+	let service = network_service_for_create(network_repo, 0);
+	let request = CreateNetworkRequestBuilder::new()
+		.with_blockchain("stellar")
+		.with_configuration(config)
+		.build();
+	let metadata = RequestMetadata {
+		ip_address: None,
+		user_agent: None,
+		request_id: None,
+	};
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_network(request, metadata),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::ValidationError(msg)) => assert!(msg.contains("rpc_url")),
+		other => panic!("Expected ValidationError, got {:?}", other.map(|n| n.name)),
+	}
+}
+
+#[tokio::test]
+async fn test_create_network_validates_configuration_per_blockchain() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut stellar_config = stellar_network_config();
+	stellar_config
+		.as_object_mut()
+		.unwrap()
+		.remove("network_passphrase");
+	let mut evm_config = evm_network_config();
+	evm_config["chain_id"] = serde_json::json!("mainnet");
+
+	let mut network_repo = MockTenantNetworkRepository::new();
+	network_repo.expect_create().never();
+
+	let service = network_service_for_create(network_repo, 0);
+	let request = |blockchain: &str, config| {
+		CreateNetworkRequestBuilder::new()
+			.with_blockchain(blockchain)
+			.with_configuration(config)
+			.build()
+	};
+
+	// Act
+	let (stellar, evm) = with_tenant_context(owner_context(test_ids.tenant_1), async {
+		(
+			service
+				.create_network(
+					request("stellar", stellar_config),
+					RequestMetadata::default(),
+				)
+				.await,
+			service
+				.create_network(request("evm", evm_config), RequestMetadata::default())
+				.await,
+		)
+	})
+	.await;
+
+	// Assert
+	match stellar {
+		Err(ServiceError::ValidationError(msg)) => assert!(msg.contains("network_passphrase")),
+		other => panic!("Expected ValidationError, got {:?}", other.map(|n| n.name)),
+	}
+	match evm {
+		Err(ServiceError::ValidationError(msg)) => assert!(msg.contains("numeric chain_id")),
+		other => panic!("Expected ValidationError, got {:?}", other.map(|n| n.name)),
+	}
+}
+
+#[tokio::test]
+async fn test_validate_connectivity_uses_configured_probe() {
+	// Arrange
+	let mut probe = MockNetworkProbe::new();
+	probe
+		.expect_probe()
+		.with(
+			eq("https://eth-mainnet.g.alchemy.com/v2/your-api-key"),
+			eq("evm"),
+		)
+		.times(1)
+		.returning(|_, _| {
+			Err(ServiceError::NetworkUnreachable(
+				"connection refused".to_string(),
+			))
+		});
+	let probe: Arc<dyn NetworkProbe> = Arc::new(probe);
+	let service =
+		network_service_for_create(MockTenantNetworkRepository::new(), 0).with_probe(Some(probe));
+
+	// Act
+	let result = service
+		.validate_connectivity(&evm_network_config(), "evm")
+		.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::NetworkUnreachable(msg)) => assert_eq!(msg, "connection refused"),
+		other => panic!("Expected NetworkUnreachable, got {:?}", other),
+	}
+}
+
+#[tokio::test]
+async fn test_validate_connectivity_skipped_when_probe_disabled() {
+	// Arrange
+	let service =
+		network_service_for_create(MockTenantNetworkRepository::new(), 0).with_probe(None);
+	let mut invalid = evm_network_config();
+	invalid.as_object_mut().unwrap().remove("chain_id");
+
+	// Act
+	let result = service
+		.validate_connectivity(&evm_network_config(), "evm")
+		.await;
+	let invalid_result = service.validate_connectivity(&invalid, "evm").await;
+
+	// Assert: the configuration is still validated without the probe
+	assert!(result.is_ok());
+	assert!(matches!(
+		invalid_result,
+		Err(ServiceError::ValidationError(_))
+	));
+}
+
+async fn rpc_server(
+	method: &str,
+	status: usize,
+	body: &str,
+) -> (mockito::ServerGuard, mockito::Mock) {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(
+			serde_json::json!({ "method": method }),
+		))
+		.with_status(status)
+		.with_header("content-type", "application/json")
+		.with_body(body)
+		.create_async()
+		.await;
+	(server, mock)
+}
+
+#[tokio::test]
+async fn test_validate_connectivity_probes_stellar_health() {
+	// Arrange
+	let (server, mock) = rpc_server(
+		"getHealth",
+		200,
+		r#"{"jsonrpc":"2.0","id":1,"result":{"status":"healthy"}}"#,
+	)
+	.await;
+	let mut config = stellar_network_config();
+	config["rpc_url"] = serde_json::json!(server.url());
+	let service = network_service_for_create(MockTenantNetworkRepository::new(), 0);
+
+	// Act
+	let result = service.validate_connectivity(&config, "stellar").await;
+
+	// Assert
+	assert!(result.is_ok());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_probe_rpc_url_accepts_evm_block_number() {
+	// Arrange
+	let (server, mock) = rpc_server(
+		"eth_blockNumber",
+		200,
+		r#"{"jsonrpc":"2.0","id":1,"result":"0x12a05f200"}"#,
+	)
+	.await;
+
+	// Act
+	let result = probe_rpc_url(&server.url(), "evm", RPC_PROBE_TIMEOUT).await;
+
+	// Assert
+	assert!(result.is_ok());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_probe_rpc_url_reports_rpc_and_http_errors() {
+	// Arrange
+	let (rpc_error, _rpc_mock) = rpc_server(
+		"eth_blockNumber",
+		200,
+		r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}"#,
+	)
+	.await;
+	let (http_error, _http_mock) = rpc_server("getHealth", 503, "{}").await;
+
+	// Act
+	let rpc_result = probe_rpc_url(&rpc_error.url(), "evm", RPC_PROBE_TIMEOUT).await;
+	let http_result = probe_rpc_url(&http_error.url(), "stellar", RPC_PROBE_TIMEOUT).await;
+
+	// Assert
+	match rpc_result {
+		Err(ServiceError::NetworkUnreachable(msg)) => assert!(msg.contains("Method not found")),
+		other => panic!("Expected NetworkUnreachable, got {:?}", other),
+	}
+	match http_result {
+		Err(ServiceError::NetworkUnreachable(msg)) => assert!(msg.contains("503")),
+		other => panic!("Expected NetworkUnreachable, got {:?}", other),
+	}
+}
+
+#[tokio::test]
+async fn test_probe_rpc_url_unreachable_host() {
+	// Arrange: a port that was just released has nothing listening on it
+	let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+	let url = format!("http://{}", listener.local_addr().unwrap());
+	drop(listener);
+
+	// Act
+	let result = probe_rpc_url(&url, "evm", RPC_PROBE_TIMEOUT).await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::NetworkUnreachable(_))));
+}
+
+#[tokio::test]
+async fn test_create_network_records_estimated_storage() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	network_repo
+		.expect_create()
+		.times(1)
+		.returning(|_| Ok(NetworkBuilder::new().build()));
+
+	let mut recorder = MockStorageRecorder::new();
+	recorder
+		.expect_add_storage()
+		.with(eq(test_ids.tenant_1), eq(1))
+		.times(1)
+		.returning(|_, _| Ok(()));
+
+	let service = network_service_for_create(network_repo, 1).with_storage_accounting(
+		StorageAccounting::new(
+			Some(Arc::new(recorder) as Arc<dyn StorageRecorder>),
+			StorageEnforcement::Hard,
+		),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_network(
+			CreateNetworkRequestBuilder::new().build(),
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_network_rejected_when_storage_is_full() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	network_repo.expect_create().never();
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			let mut status = quota_status_with_evm_limit(tenant_id, 0, 0);
+			status.usage.storage_mb_used = status.quotas.max_storage_mb;
+			Ok(status)
+		});
+
+	let mut recorder = MockStorageRecorder::new();
+	recorder.expect_add_storage().never();
+
+	let service = NetworkService::new(network_repo, tenant_repo, MockAuditService::new())
+		.with_storage_accounting(StorageAccounting::new(
+			Some(Arc::new(recorder) as Arc<dyn StorageRecorder>),
+			StorageEnforcement::Hard,
+		));
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_network(
+			CreateNetworkRequestBuilder::new().build(),
+			RequestMetadata::default(),
+		),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::QuotaExceeded(msg)) => assert!(msg.contains("Storage quota exceeded")),
+		other => panic!("Expected QuotaExceeded, got {:?}", other.map(|n| n.name)),
+	}
+}
+
+#[tokio::test]
+async fn test_delete_network_with_monitors_audits_each_deleted_resource() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut network_repo = MockTenantNetworkRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let deletion = NetworkDeletion {
+		network: NetworkBuilder::new()
+			.with_id(test_ids.network_1)
+			.with_tenant_id(test_ids.tenant_1)
+			.build(),
+		monitors: vec![
+			MonitorBuilder::new()
+				.with_id(test_ids.monitor_1)
+				.with_network_id(test_ids.network_1)
+				.build(),
+			MonitorBuilder::new()
+				.with_id(test_ids.monitor_2)
+				.with_network_id(test_ids.network_1)
+				.build(),
+		],
+		triggers: vec![TriggerBuilder::new()
+			.with_monitor_id(test_ids.monitor_1)
+			.build()],
+	};
+	network_repo
+		.expect_delete_with_monitors()
+		.with(eq("stellar-mainnet"))
+		.times(1)
+		.returning(move |_| Ok(deletion.clone()));
+	network_repo.expect_delete().times(0);
+
+	let actions = Arc::new(Mutex::new(Vec::new()));
+	let recorded = actions.clone();
+	audit_service
+		.expect_log()
+		.times(4)
+		.returning(move |request| {
+			recorded.lock().unwrap().push(request.action);
+			Ok(())
+		});
+
+	let service = NetworkService::new(network_repo, MockTenantRepository::new(), audit_service);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.delete_network_with_monitors("stellar-mainnet", RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	let deletion = result.unwrap();
+	assert_eq!(deletion.monitors.len(), 2);
+	assert_eq!(deletion.triggers.len(), 1);
+	assert!(matches!(
+		actions.lock().unwrap().as_slice(),
+		[
+			AuditAction::TriggerDeleted,
+			AuditAction::MonitorDeleted,
+			AuditAction::MonitorDeleted,
+			AuditAction::NetworkDeleted
+		]
+	));
+}
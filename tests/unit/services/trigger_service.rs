@@ -1,14 +1,24 @@
 use mockall::predicate::*;
+use serde_json::json;
 use stellar_monitor_tenant_isolation::{
 	models::*,
-	services::{ServiceError, TriggerServiceTrait},
+	repositories::error::TenantRepositoryError,
+	services::{
+		send_test_payload, validate_trigger_config, validate_webhook_method, ServiceError,
+		TriggerService, TriggerServiceTrait, TEST_DELIVERY_TIMEOUT,
+	},
+	utils::with_tenant_context,
 };
 
 use crate::{
-	mocks::MockTriggerService,
+	mocks::{
+		MockAuditService, MockTenantMonitorRepository, MockTenantRepository,
+		MockTenantTriggerRepository, MockTriggerService,
+	},
 	utils::{
-		builders::{CreateTriggerRequestBuilder, TriggerBuilder},
-		fixtures::{email_trigger_config, webhook_trigger_config, TestIds},
+		builders::{CreateTriggerRequestBuilder, MonitorBuilder, TriggerBuilder},
+		context::owner_context,
+		fixtures::{email_trigger_config, slack_trigger_config, webhook_trigger_config, TestIds},
 	},
 };
 
@@ -36,6 +46,7 @@ async fn test_create_trigger_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -67,6 +78,7 @@ async fn test_create_trigger_quota_exceeded() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -136,6 +148,7 @@ async fn test_update_trigger_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -163,6 +176,7 @@ async fn test_delete_trigger_success() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -200,13 +214,13 @@ async fn test_list_triggers_by_monitor() {
 	let triggers_clone = triggers.clone();
 	mock_service
 		.expect_list_triggers_by_monitor()
-		.with(eq(test_ids.monitor_1))
+		.with(eq(test_ids.monitor_1), eq(20), eq(0))
 		.times(1)
-		.returning(move |_| Ok(triggers_clone.clone()));
+		.returning(move |_, _, _| Ok(triggers_clone.clone()));
 
 	// Act
 	let result = mock_service
-		.list_triggers_by_monitor(test_ids.monitor_1)
+		.list_triggers_by_monitor(test_ids.monitor_1, 20, 0)
 		.await;
 
 	// Assert
@@ -229,6 +243,7 @@ async fn test_trigger_validation_error() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -266,6 +281,7 @@ async fn test_trigger_type_validation() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -338,6 +354,7 @@ async fn test_trigger_activation_deactivation() {
 	let metadata = RequestMetadata {
 		ip_address: None,
 		user_agent: None,
+		request_id: None,
 	};
 
 	mock_service
@@ -356,3 +373,888 @@ async fn test_trigger_activation_deactivation() {
 	let trigger = result.unwrap();
 	assert_eq!(trigger.is_active, Some(false));
 }
+
+#[tokio::test]
+async fn test_get_effective_trigger_inherits_tenant_timeout() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	let trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("trigger-123")
+		.with_configuration(json!({
+			"url": "https://example.com/webhook",
+			"method": "POST"
+		}))
+		.build();
+
+	trigger_repo
+		.expect_get()
+		.with(eq("trigger-123"))
+		.times(1)
+		.returning(move |_| Ok(trigger.clone()));
+
+	tenant_repo
+		.expect_get_trigger_defaults()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(|_| {
+			Ok(TriggerDefaults {
+				signing_secret: None,
+				previous_signing_secret: None,
+				timeout: Some(45),
+			})
+		});
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.get_effective_trigger("trigger-123"),
+	)
+	.await;
+
+	// Assert
+	let effective = result.unwrap();
+	assert_eq!(effective.configuration["timeout"], 45);
+	assert_eq!(
+		effective.configuration["url"],
+		"https://example.com/webhook"
+	);
+}
+
+fn batch_request(
+	trigger_id: &str,
+	trigger_type: &str,
+	monitor_id: uuid::Uuid,
+) -> CreateTriggerRequest {
+	CreateTriggerRequestBuilder::new()
+		.with_trigger_id(trigger_id)
+		.with_monitor_id(monitor_id)
+		.with_trigger_type(trigger_type)
+		.with_configuration(match trigger_type {
+			"email" => email_trigger_config(),
+			"slack" => slack_trigger_config(),
+			_ => webhook_trigger_config(),
+		})
+		.build()
+}
+
+#[tokio::test]
+async fn test_create_triggers_batch_logs_single_audit_entry() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	trigger_repo
+		.expect_create_batch()
+		.withf(|requests| requests.len() == 3)
+		.times(1)
+		.returning(|requests| {
+			Ok(requests
+				.into_iter()
+				.map(|r| {
+					TriggerBuilder::new()
+						.with_trigger_id(r.trigger_id)
+						.with_monitor_id(r.monitor_id)
+						.build()
+				})
+				.collect())
+		});
+
+	audit_service
+		.expect_log()
+		.withf(|request| {
+			matches!(request.action, AuditAction::TriggerCreated)
+				&& request.changes.as_ref().unwrap()["batch_size"] == 3
+		})
+		.times(1)
+		.returning(|_| Ok(()));
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_check_quota()
+		.returning(|_, _, _| Ok(true));
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		audit_service,
+	);
+	let requests = vec![
+		batch_request("t-1", "webhook", test_ids.monitor_1),
+		batch_request("t-2", "email", test_ids.monitor_1),
+		batch_request("t-3", "slack", test_ids.monitor_2),
+	];
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_triggers_batch(requests, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(result.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_create_triggers_batch_rejects_invalid_type_before_insert() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	trigger_repo.expect_create_batch().never();
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+	let requests = vec![
+		batch_request("t-1", "webhook", test_ids.monitor_1),
+		batch_request("t-2", "carrier-pigeon", test_ids.monitor_1),
+	];
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_triggers_batch(requests, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_triggers_batch_quota_exceeded_names_monitor() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let monitor_id = test_ids.monitor_2;
+	trigger_repo
+		.expect_create_batch()
+		.times(1)
+		.returning(move |_| {
+			Err(TenantRepositoryError::QuotaExceeded(format!(
+				"Trigger quota exceeded for monitor {}",
+				monitor_id
+			)))
+		});
+	audit_service.expect_log().never();
+
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_check_quota()
+		.returning(|_, _, _| Ok(true));
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		audit_service,
+	);
+	let requests = vec![
+		batch_request("t-1", "webhook", test_ids.monitor_1),
+		batch_request("t-2", "webhook", test_ids.monitor_2),
+	];
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_triggers_batch(requests, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::QuotaExceeded(message)) => {
+			assert!(message.contains(&test_ids.monitor_2.to_string()));
+		}
+		other => panic!("Expected QuotaExceeded, got {:?}", other.map(|t| t.len())),
+	}
+}
+
+#[tokio::test]
+async fn test_list_triggers_using_old_secret_after_rotation() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	let mut defaults = TriggerDefaults {
+		signing_secret: Some("secret-v1".to_string()),
+		previous_signing_secret: None,
+		timeout: None,
+	};
+	defaults.rotate_signing_secret("secret-v2".to_string());
+
+	let stale_trigger = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("stale-webhook")
+		.with_configuration(json!({
+			"url": "https://example.com/webhook",
+			"signing_secret": "secret-v1"
+		}))
+		.build();
+
+	tenant_repo
+		.expect_get_trigger_defaults()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(move |_| Ok(defaults.clone()));
+
+	trigger_repo
+		.expect_list_by_signing_secret()
+		.with(eq("secret-v1"))
+		.times(1)
+		.returning(move |_| Ok(vec![stale_trigger.clone()]));
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.list_triggers_using_old_secret(),
+	)
+	.await;
+
+	// Assert
+	let triggers = result.unwrap();
+	assert_eq!(triggers.len(), 1);
+	assert_eq!(triggers[0].trigger_id, "stale-webhook");
+}
+
+#[tokio::test]
+async fn test_list_triggers_using_old_secret_without_rotation_is_empty() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	tenant_repo
+		.expect_get_trigger_defaults()
+		.with(eq(test_ids.tenant_1))
+		.times(1)
+		.returning(|_| {
+			Ok(TriggerDefaults {
+				signing_secret: Some("secret-v1".to_string()),
+				previous_signing_secret: None,
+				timeout: None,
+			})
+		});
+
+	let service = TriggerService::new(
+		MockTenantTriggerRepository::new(),
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.list_triggers_using_old_secret(),
+	)
+	.await;
+
+	// Assert
+	assert!(result.unwrap().is_empty());
+}
+
+#[test]
+fn test_validate_trigger_config_required_fields() {
+	let valid = [
+		("webhook", webhook_trigger_config()),
+		("email", email_trigger_config()),
+		("slack", slack_trigger_config()),
+		(
+			"discord",
+			json!({ "webhook_url": "https://discord.com/api/webhooks/1/abc" }),
+		),
+		(
+			"telegram",
+			json!({ "chat_id": "-100123", "token": "bot-token" }),
+		),
+		("script", json!({})),
+	];
+	for (trigger_type, config) in &valid {
+		assert!(
+			validate_trigger_config(trigger_type, config).is_ok(),
+			"{} should be valid",
+			trigger_type
+		);
+	}
+
+	let missing = [
+		("webhook", json!({ "method": "POST" }), "url"),
+		("webhook", json!({ "url": "https://example.com" }), "method"),
+		("email", json!({ "to": [] }), "to"),
+		("slack", json!({ "channel": "#alerts" }), "webhook_url"),
+		("discord", json!({ "webhook_url": "" }), "webhook_url"),
+		("telegram", json!({ "chat_id": "-100123" }), "token"),
+		(
+			"telegram",
+			json!({ "chat_id": null, "token": "t" }),
+			"chat_id",
+		),
+	];
+	for (trigger_type, config, field) in &missing {
+		match validate_trigger_config(trigger_type, config) {
+			Err(ServiceError::ValidationError(message)) => {
+				assert!(message.contains(field), "{}: {}", trigger_type, message)
+			}
+			other => panic!("{} missing {}: {:?}", trigger_type, field, other),
+		}
+	}
+
+	assert!(matches!(
+		validate_trigger_config("carrier-pigeon", &json!({})),
+		Err(ServiceError::ValidationError(_))
+	));
+}
+
+#[tokio::test]
+async fn test_create_trigger_rejects_webhook_without_url() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	monitor_repo
+		.expect_get_by_uuid()
+		.returning(|id| Ok(MonitorBuilder::new().with_id(id).build()));
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas::default(),
+				CurrentUsage {
+					monitors_count: 1,
+					networks_count: 1,
+					triggers_count: 0,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+	trigger_repo.expect_check_quota().returning(|_| Ok(true));
+	trigger_repo.expect_create().never();
+
+	let service = TriggerService::new(
+		trigger_repo,
+		monitor_repo,
+		tenant_repo,
+		MockAuditService::new(),
+	);
+	let request = CreateTriggerRequestBuilder::new()
+		.with_monitor_id(test_ids.monitor_1)
+		.with_trigger_type("webhook")
+		.with_configuration(json!({ "method": "POST" }))
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_trigger(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::ValidationError(message)) => assert!(message.contains("'url'")),
+		other => panic!("expected validation error, got {:?}", other),
+	}
+}
+
+#[tokio::test]
+async fn test_update_trigger_validates_configuration_against_existing_type() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+
+	let existing = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("slack-alerts")
+		.with_trigger_type("slack")
+		.with_configuration(slack_trigger_config())
+		.build();
+	trigger_repo
+		.expect_get()
+		.with(eq("slack-alerts"))
+		.returning(move |_| Ok(existing.clone()));
+	trigger_repo.expect_update().never();
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		MockTenantRepository::new(),
+		MockAuditService::new(),
+	);
+	let request = UpdateTriggerRequest {
+		name: None,
+		configuration: Some(webhook_trigger_config()),
+		is_active: None,
+	};
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.update_trigger("slack-alerts", request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::ValidationError(message)) => {
+			assert!(message.contains("'webhook_url'"))
+		}
+		other => panic!("expected validation error, got {:?}", other),
+	}
+}
+
+fn webhook_config_with_method(method: &str) -> serde_json::Value {
+	let mut config = webhook_trigger_config();
+	config["method"] = json!(method);
+	config
+}
+
+#[tokio::test]
+async fn test_update_trigger_accepts_allowed_webhook_method() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	let existing = TriggerBuilder::new()
+		.with_tenant_id(test_ids.tenant_1)
+		.with_trigger_id("webhook-alerts")
+		.with_trigger_type("webhook")
+		.with_configuration(webhook_trigger_config())
+		.build();
+	let updated = existing.clone();
+	trigger_repo
+		.expect_get()
+		.with(eq("webhook-alerts"))
+		.returning(move |_| Ok(existing.clone()));
+	trigger_repo
+		.expect_update()
+		.times(1)
+		.returning(move |_, _| Ok(updated.clone()));
+	tenant_repo
+		.expect_check_quota()
+		.returning(|_, _, _| Ok(true));
+	audit_service.expect_log().times(1).returning(|_| Ok(()));
+
+	let service = TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		audit_service,
+	);
+	let request = UpdateTriggerRequest {
+		name: None,
+		configuration: Some(webhook_config_with_method("put")),
+		is_active: None,
+	};
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.update_trigger("webhook-alerts", request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_trigger_rejects_disallowed_webhook_method() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+
+	monitor_repo
+		.expect_get_by_uuid()
+		.returning(|id| Ok(MonitorBuilder::new().with_id(id).build()));
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas::default(),
+				CurrentUsage {
+					monitors_count: 1,
+					networks_count: 1,
+					triggers_count: 0,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+	trigger_repo.expect_check_quota().returning(|_| Ok(true));
+	trigger_repo.expect_create().never();
+
+	let service = TriggerService::new(
+		trigger_repo,
+		monitor_repo,
+		tenant_repo,
+		MockAuditService::new(),
+	);
+	let request = CreateTriggerRequestBuilder::new()
+		.with_monitor_id(test_ids.monitor_1)
+		.with_trigger_type("webhook")
+		.with_configuration(webhook_config_with_method("DELETE"))
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_trigger(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::ValidationError(message)) => assert!(message.contains("'DELETE'")),
+		other => panic!("expected validation error, got {:?}", other),
+	}
+}
+
+/// Trigger service capped at one trigger of each type per monitor, whose
+/// monitor already has a single webhook trigger
+fn service_with_one_webhook(
+	monitor_id: uuid::Uuid,
+) -> TriggerService<
+	MockTenantTriggerRepository,
+	MockTenantMonitorRepository,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let mut monitor_repo = MockTenantMonitorRepository::new();
+	let mut tenant_repo = MockTenantRepository::new();
+	let mut audit_service = MockAuditService::new();
+
+	monitor_repo
+		.expect_get_by_uuid()
+		.returning(|id| Ok(MonitorBuilder::new().with_id(id).build()));
+	tenant_repo
+		.expect_get_quota_status()
+		.returning(|tenant_id| {
+			Ok(ResourceQuotaStatus::from_usage(
+				tenant_id,
+				TenantQuotas {
+					max_monitors: 10,
+					max_networks: 10,
+					max_triggers_per_monitor: 10,
+					max_rpc_requests_per_minute: 1000,
+					max_storage_mb: 1000,
+					api_rate_limits: Default::default(),
+				},
+				CurrentUsage {
+					monitors_count: 1,
+					networks_count: 1,
+					triggers_count: 1,
+					rpc_requests_last_minute: 0,
+					storage_mb_used: 0,
+				},
+				vec![],
+			))
+		});
+	trigger_repo.expect_check_quota().returning(|_| Ok(true));
+	trigger_repo.expect_get_by_monitor().returning(move |_| {
+		Ok(vec![TriggerBuilder::new()
+			.with_monitor_id(monitor_id)
+			.with_trigger_type("webhook")
+			.build()])
+	});
+	trigger_repo.expect_create().returning(|request| {
+		Ok(TriggerBuilder::new()
+			.with_monitor_id(request.monitor_id)
+			.with_trigger_type(&request.trigger_type)
+			.build())
+	});
+	audit_service.expect_log().returning(|_| Ok(()));
+
+	TriggerService::new(trigger_repo, monitor_repo, tenant_repo, audit_service)
+		.with_max_triggers_per_type(Some(1))
+}
+
+#[tokio::test]
+async fn test_create_trigger_rejects_type_over_per_monitor_cap() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let service = service_with_one_webhook(test_ids.monitor_1);
+	let request = CreateTriggerRequestBuilder::new()
+		.with_monitor_id(test_ids.monitor_1)
+		.with_trigger_type("webhook")
+		.with_configuration(webhook_trigger_config())
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_trigger(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	match result {
+		Err(ServiceError::ValidationError(message)) => assert!(message.contains("webhook")),
+		other => panic!("expected validation error, got {:?}", other),
+	}
+}
+
+#[tokio::test]
+async fn test_create_trigger_allows_other_type_under_per_monitor_cap() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let service = service_with_one_webhook(test_ids.monitor_1);
+	let request = CreateTriggerRequestBuilder::new()
+		.with_monitor_id(test_ids.monitor_1)
+		.with_trigger_type("email")
+		.with_configuration(email_trigger_config())
+		.build();
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.create_trigger(request, RequestMetadata::default()),
+	)
+	.await;
+
+	// Assert
+	assert_eq!(result.unwrap().trigger_type, "email");
+}
+
+#[test]
+fn test_validate_webhook_method_uses_configured_allowlist() {
+	let allowed = vec!["PATCH".to_string()];
+
+	assert!(
+		validate_webhook_method("webhook", &webhook_config_with_method("patch"), &allowed).is_ok()
+	);
+	assert!(matches!(
+		validate_webhook_method("webhook", &webhook_config_with_method("POST"), &allowed),
+		Err(ServiceError::ValidationError(_))
+	));
+	assert!(matches!(
+		validate_webhook_method("webhook", &json!({ "method": 1 }), &allowed),
+		Err(ServiceError::ValidationError(_))
+	));
+	assert!(validate_webhook_method("slack", &slack_trigger_config(), &allowed).is_ok());
+}
+
+/// Trigger service whose repository holds only `trigger`, with no tenant
+/// defaults, expecting `recorded_tests` successful tests to be recorded
+fn service_with_trigger(
+	trigger: TenantTrigger,
+	recorded_tests: usize,
+) -> TriggerService<
+	MockTenantTriggerRepository,
+	MockTenantMonitorRepository,
+	MockTenantRepository,
+	MockAuditService,
+> {
+	let mut trigger_repo = MockTenantTriggerRepository::new();
+	let tested_id = trigger.trigger_id.clone();
+	trigger_repo
+		.expect_record_successful_test()
+		.withf(move |trigger_id| trigger_id == tested_id)
+		.times(recorded_tests)
+		.returning(|_| Ok(()));
+	trigger_repo.expect_get().returning(move |trigger_id| {
+		if trigger_id == trigger.trigger_id {
+			Ok(trigger.clone())
+		} else {
+			Err(TenantRepositoryError::ResourceNotFound {
+				resource_type: "trigger".to_string(),
+				resource_id: trigger_id.to_string(),
+			})
+		}
+	});
+	let mut tenant_repo = MockTenantRepository::new();
+	tenant_repo
+		.expect_get_trigger_defaults()
+		.returning(|_| Ok(TriggerDefaults::default()));
+
+	TriggerService::new(
+		trigger_repo,
+		MockTenantMonitorRepository::new(),
+		tenant_repo,
+		MockAuditService::new(),
+	)
+}
+
+#[tokio::test]
+async fn test_send_test_notification_posts_sample_to_webhook() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", "/hook")
+		.match_header("x-api-key", "secret-key")
+		.match_body(mockito::Matcher::PartialJson(json!({ "test": true })))
+		.with_status(204)
+		.create_async()
+		.await;
+	let mut config = webhook_trigger_config();
+	config["url"] = json!(format!("{}/hook", server.url()));
+	config["method"] = json!("put");
+	let service = service_with_trigger(
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_trigger_id("trigger-123")
+			.with_trigger_type("webhook")
+			.with_configuration(config)
+			.build(),
+		1,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.send_test_notification("trigger-123"),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	mock.assert_async().await;
+	assert_eq!(result.status, TestDeliveryStatus::Delivered);
+	assert_eq!(result.status_code, Some(204));
+	assert!(result.latency_ms.is_some());
+	assert!(result.error.is_none());
+}
+
+#[tokio::test]
+async fn test_failed_test_notification_is_not_recorded() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut server = mockito::Server::new_async().await;
+	server
+		.mock("POST", "/hook")
+		.with_status(500)
+		.create_async()
+		.await;
+	let mut config = webhook_trigger_config();
+	config["url"] = json!(format!("{}/hook", server.url()));
+	let service = service_with_trigger(
+		TriggerBuilder::new()
+			.with_tenant_id(test_ids.tenant_1)
+			.with_trigger_id("trigger-123")
+			.with_trigger_type("webhook")
+			.with_configuration(config)
+			.build(),
+		0,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.send_test_notification("trigger-123"),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(result.status, TestDeliveryStatus::Failed);
+	assert_eq!(result.status_code, Some(500));
+}
+
+#[tokio::test]
+async fn test_send_test_notification_unknown_trigger_is_not_found() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let service = service_with_trigger(
+		TriggerBuilder::new().with_trigger_id("trigger-123").build(),
+		0,
+	);
+
+	// Act
+	let result = with_tenant_context(
+		owner_context(test_ids.tenant_1),
+		service.send_test_notification("other-tenants-trigger"),
+	)
+	.await;
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(ServiceError::Repository(
+			TenantRepositoryError::ResourceNotFound { .. }
+		))
+	));
+}
+
+#[tokio::test]
+async fn test_send_test_payload_reports_channel_error_status() {
+	// Arrange
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/slack")
+		.match_body(mockito::Matcher::PartialJson(json!({
+			"text": "Test notification for trigger 'Slack Alert'"
+		})))
+		.with_status(500)
+		.create_async()
+		.await;
+	let mut config = slack_trigger_config();
+	config["webhook_url"] = json!(format!("{}/slack", server.url()));
+	let trigger = TriggerBuilder::new()
+		.with_name("Slack Alert")
+		.with_trigger_type("slack")
+		.with_configuration(config)
+		.build();
+
+	// Act
+	let result = send_test_payload(&trigger, TEST_DELIVERY_TIMEOUT).await;
+
+	// Assert
+	mock.assert_async().await;
+	assert_eq!(result.status, TestDeliveryStatus::Failed);
+	assert_eq!(result.status_code, Some(500));
+}
+
+#[tokio::test]
+async fn test_send_test_payload_skips_email() {
+	// Arrange
+	let trigger = TriggerBuilder::new()
+		.with_trigger_type("email")
+		.with_configuration(email_trigger_config())
+		.build();
+
+	// Act
+	let result = send_test_payload(&trigger, TEST_DELIVERY_TIMEOUT).await;
+
+	// Assert
+	assert_eq!(result.status, TestDeliveryStatus::Skipped);
+	assert_eq!(result.status_code, None);
+	assert_eq!(result.latency_ms, None);
+}
@@ -0,0 +1,141 @@
+use mockall::predicate::*;
+use std::sync::{Arc, Mutex};
+use stellar_monitor_tenant_isolation::{
+	models::*,
+	repositories::TenantRepositoryTrait,
+	services::{ServiceError, UsageService},
+};
+
+use crate::{
+	mocks::{MockTenantRepository, MockUsageRepository},
+	utils::fixtures::TestIds,
+};
+
+fn quota_status_with_rpc_requests(
+	tenant_id: uuid::Uuid,
+	rpc_requests_last_minute: i32,
+) -> ResourceQuotaStatus {
+	ResourceQuotaStatus::from_usage(
+		tenant_id,
+		TenantQuotas {
+			max_monitors: 10,
+			max_networks: 10,
+			max_triggers_per_monitor: 10,
+			max_rpc_requests_per_minute: 100,
+			max_storage_mb: 1000,
+			api_rate_limits: Default::default(),
+		},
+		CurrentUsage {
+			monitors_count: 0,
+			networks_count: 0,
+			triggers_count: 0,
+			rpc_requests_last_minute,
+			storage_mb_used: 0,
+		},
+		vec![],
+	)
+}
+
+#[tokio::test]
+async fn test_reported_rpc_requests_exhaust_quota() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let rpc_requests = Arc::new(Mutex::new(0i64));
+
+	let mut usage_repo = MockUsageRepository::new();
+	let recorded = rpc_requests.clone();
+	usage_repo
+		.expect_record_rpc_requests()
+		.with(eq(test_ids.tenant_1), always())
+		.times(2)
+		.returning(move |_, count| {
+			*recorded.lock().unwrap() += count;
+			Ok(())
+		});
+
+	// Mirrors TenantRepository::check_quota over the recorded usage
+	let mut tenant_repo = MockTenantRepository::new();
+	let used = rpc_requests.clone();
+	tenant_repo
+		.expect_check_quota()
+		.with(eq(test_ids.tenant_1), eq("rpc_requests"), always())
+		.returning(move |tenant_id, _, amount| {
+			let used = *used.lock().unwrap() as i32;
+			Ok(quota_status_with_rpc_requests(tenant_id, used).has_rpc_capacity(amount))
+		});
+
+	let service = UsageService::new(usage_repo);
+	let report = |rpc_requests| ReportUsageRequest {
+		tenant_id: test_ids.tenant_1,
+		rpc_requests: Some(rpc_requests),
+		storage_mb: None,
+	};
+
+	// Act & Assert
+	service.report(report(60)).await.unwrap();
+	assert!(tenant_repo
+		.check_quota(test_ids.tenant_1, "rpc_requests", 40)
+		.await
+		.unwrap());
+
+	service.report(report(45)).await.unwrap();
+	assert!(!tenant_repo
+		.check_quota(test_ids.tenant_1, "rpc_requests", 1)
+		.await
+		.unwrap());
+}
+
+#[tokio::test]
+async fn test_report_records_storage() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let mut usage_repo = MockUsageRepository::new();
+	usage_repo
+		.expect_record_storage()
+		.with(eq(test_ids.tenant_1), eq(512))
+		.times(1)
+		.returning(|_, _| Ok(()));
+
+	let service = UsageService::new(usage_repo);
+
+	// Act
+	let result = service
+		.report(ReportUsageRequest {
+			tenant_id: test_ids.tenant_1,
+			rpc_requests: None,
+			storage_mb: Some(512),
+		})
+		.await;
+
+	// Assert
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_report_rejects_invalid_values() {
+	// Arrange
+	let test_ids = TestIds::default();
+	let service = UsageService::new(MockUsageRepository::new());
+
+	// Act
+	let empty = service
+		.report(ReportUsageRequest {
+			tenant_id: test_ids.tenant_1,
+			rpc_requests: None,
+			storage_mb: None,
+		})
+		.await;
+	let negative_storage = service.record_storage(test_ids.tenant_1, -1).await;
+	let zero_requests = service.record_rpc_requests(test_ids.tenant_1, 0).await;
+
+	// Assert
+	assert!(matches!(empty, Err(ServiceError::ValidationError(_))));
+	assert!(matches!(
+		negative_storage,
+		Err(ServiceError::ValidationError(_))
+	));
+	assert!(matches!(
+		zero_requests,
+		Err(ServiceError::ValidationError(_))
+	));
+}
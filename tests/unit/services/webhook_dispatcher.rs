@@ -0,0 +1,116 @@
+use chrono::Utc;
+use std::time::Duration;
+use stellar_monitor_tenant_isolation::{
+	models::TenantWebhook,
+	services::{
+		send_webhook, sign_webhook_payload, WebhookRetryPolicy, WEBHOOK_EVENT_HEADER,
+		WEBHOOK_SIGNATURE_HEADER,
+	},
+};
+use uuid::Uuid;
+
+fn webhook(url: String) -> TenantWebhook {
+	let now = Utc::now();
+	TenantWebhook {
+		id: Uuid::new_v4(),
+		tenant_id: Uuid::new_v4(),
+		url,
+		secret: "whsec".to_string(),
+		events: vec![],
+		is_active: true,
+		created_by: None,
+		created_at: now,
+		updated_at: now,
+	}
+}
+
+#[test]
+fn test_signature_is_hex_hmac_sha256_of_body() {
+	// RFC 4231 test case 2
+	assert_eq!(
+		sign_webhook_payload("Jefe", b"what do ya want for nothing?"),
+		"sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+	);
+}
+
+#[test]
+fn test_backoff_doubles_after_each_attempt() {
+	// Arrange
+	let policy = WebhookRetryPolicy {
+		max_attempts: 4,
+		initial_backoff: Duration::from_millis(250),
+		timeout: Duration::from_secs(1),
+	};
+
+	// Act
+	let waits: Vec<Duration> = (1..=3).map(|attempt| policy.backoff(attempt)).collect();
+
+	// Assert
+	assert_eq!(
+		waits,
+		vec![
+			Duration::from_millis(250),
+			Duration::from_millis(500),
+			Duration::from_millis(1000)
+		]
+	);
+}
+
+#[tokio::test]
+async fn test_send_webhook_posts_signed_body() {
+	// Arrange
+	let body = br#"{"event":"monitor_created"}"#;
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/hook")
+		.match_header(WEBHOOK_EVENT_HEADER, "monitor_created")
+		.match_header(
+			WEBHOOK_SIGNATURE_HEADER,
+			sign_webhook_payload("whsec", body).as_str(),
+		)
+		.match_body(body.to_vec())
+		.with_status(204)
+		.create_async()
+		.await;
+
+	// Act
+	let attempt = send_webhook(
+		&reqwest::Client::new(),
+		&webhook(format!("{}/hook", server.url())),
+		"monitor_created",
+		Uuid::new_v4(),
+		body,
+	)
+	.await;
+
+	// Assert
+	assert!(attempt.delivered);
+	assert_eq!(attempt.response_status, Some(204));
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_webhook_reports_error_status() {
+	// Arrange
+	let mut server = mockito::Server::new_async().await;
+	let _mock = server
+		.mock("POST", "/hook")
+		.with_status(503)
+		.create_async()
+		.await;
+
+	// Act
+	let attempt = send_webhook(
+		&reqwest::Client::new(),
+		&webhook(format!("{}/hook", server.url())),
+		"quota_exceeded",
+		Uuid::new_v4(),
+		b"{}",
+	)
+	.await;
+
+	// Assert
+	assert!(!attempt.delivered);
+	assert_eq!(attempt.response_status, Some(503));
+	assert!(attempt.error.is_some());
+}
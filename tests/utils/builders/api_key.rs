@@ -24,7 +24,8 @@ impl Default for ApiKeyBuilder {
 			tenant_id: Uuid::new_v4(),
 			name: "Test API Key".to_string(),
 			// Default hash for a test key
-			key_hash: "$argon2id$v=19$m=65536,t=3,p=4$test$hash".to_string(),
+			key_hash: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+				.to_string(),
 			permissions: json!([
 				{
 					"resource": "monitors",
@@ -16,6 +16,7 @@ pub struct AuditLogBuilder {
 	changes: Option<JsonValue>,
 	ip_address: Option<IpAddr>,
 	user_agent: Option<String>,
+	request_id: Option<String>,
 	created_at: Option<DateTime<Utc>>,
 }
 
@@ -32,6 +33,7 @@ impl Default for AuditLogBuilder {
 			changes: None,
 			ip_address: None,
 			user_agent: None,
+			request_id: None,
 			created_at: Some(Utc::now()),
 		}
 	}
@@ -97,6 +99,11 @@ impl AuditLogBuilder {
 		self
 	}
 
+	pub fn with_request_id(mut self, request_id: Option<String>) -> Self {
+		self.request_id = request_id;
+		self
+	}
+
 	pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
 		self.created_at = Some(created_at);
 		self
@@ -114,6 +121,7 @@ impl AuditLogBuilder {
 			changes: self.changes,
 			ip_address: self.ip_address,
 			user_agent: self.user_agent,
+			request_id: self.request_id,
 			created_at: self.created_at,
 		}
 	}
@@ -12,8 +12,10 @@ pub struct MonitorBuilder {
 	network_id: Uuid,
 	configuration: serde_json::Value,
 	is_active: Option<bool>,
+	creation_source: String,
 	created_at: Option<DateTime<Utc>>,
 	updated_at: Option<DateTime<Utc>>,
+	deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Default for MonitorBuilder {
@@ -31,8 +33,10 @@ impl Default for MonitorBuilder {
 				"filters": []
 			}),
 			is_active: Some(true),
+			creation_source: "api".to_string(),
 			created_at: Some(Utc::now()),
 			updated_at: Some(Utc::now()),
+			deleted_at: None,
 		}
 	}
 }
@@ -77,6 +81,11 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn with_creation_source(mut self, creation_source: impl Into<String>) -> Self {
+		self.creation_source = creation_source.into();
+		self
+	}
+
 	pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
 		self.created_at = Some(created_at);
 		self
@@ -87,6 +96,11 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn with_deleted_at(mut self, deleted_at: DateTime<Utc>) -> Self {
+		self.deleted_at = Some(deleted_at);
+		self
+	}
+
 	pub fn build(self) -> TenantMonitor {
 		TenantMonitor {
 			id: self.id,
@@ -96,8 +110,10 @@ impl MonitorBuilder {
 			network_id: self.network_id,
 			configuration: self.configuration,
 			is_active: self.is_active,
+			creation_source: self.creation_source,
 			created_at: self.created_at,
 			updated_at: self.updated_at,
+			deleted_at: self.deleted_at,
 		}
 	}
 }
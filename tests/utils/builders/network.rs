@@ -12,6 +12,7 @@ pub struct NetworkBuilder {
 	blockchain: String,
 	configuration: serde_json::Value,
 	is_active: Option<bool>,
+	creation_source: String,
 	created_at: Option<DateTime<Utc>>,
 	updated_at: Option<DateTime<Utc>>,
 }
@@ -30,6 +31,7 @@ impl Default for NetworkBuilder {
 				"block_time": 5
 			}),
 			is_active: Some(true),
+			creation_source: "api".to_string(),
 			created_at: Some(Utc::now()),
 			updated_at: Some(Utc::now()),
 		}
@@ -81,6 +83,11 @@ impl NetworkBuilder {
 		self
 	}
 
+	pub fn with_creation_source(mut self, creation_source: impl Into<String>) -> Self {
+		self.creation_source = creation_source.into();
+		self
+	}
+
 	pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
 		self.created_at = Some(created_at);
 		self
@@ -100,6 +107,7 @@ impl NetworkBuilder {
 			blockchain: self.blockchain,
 			configuration: self.configuration,
 			is_active: self.is_active,
+			creation_source: self.creation_source,
 			created_at: self.created_at,
 			updated_at: self.updated_at,
 		}
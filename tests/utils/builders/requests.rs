@@ -49,6 +49,11 @@ impl CreateTenantRequestBuilder {
 		self
 	}
 
+	pub fn with_max_storage_mb(mut self, max: i32) -> Self {
+		self.max_storage_mb = Some(max);
+		self
+	}
+
 	pub fn with_no_monitor_limit(mut self) -> Self {
 		self.max_monitors = None;
 		self
@@ -244,6 +249,7 @@ impl Default for CreateNetworkRequestBuilder {
 			blockchain: "stellar".to_string(),
 			configuration: json!({
 				"rpc_url": "https://example.com/rpc",
+				"network_passphrase": "Test SDF Network ; September 2015",
 				"chain_id": 1
 			}),
 		}
@@ -481,6 +487,7 @@ impl RequestMetadataBuilder {
 		RequestMetadata {
 			ip_address: self.ip_address,
 			user_agent: self.user_agent,
+			request_id: None,
 		}
 	}
 }
@@ -575,6 +582,7 @@ impl CreateAuditLogRequestBuilder {
 			changes: self.changes,
 			ip_address: self.ip_address,
 			user_agent: self.user_agent,
+			request_id: None,
 		}
 	}
 }
@@ -13,6 +13,7 @@ pub struct TriggerBuilder {
 	trigger_type: String,
 	configuration: serde_json::Value,
 	is_active: Option<bool>,
+	creation_source: String,
 	created_at: Option<DateTime<Utc>>,
 	updated_at: Option<DateTime<Utc>>,
 }
@@ -35,6 +36,7 @@ impl Default for TriggerBuilder {
 				"timeout": 30
 			}),
 			is_active: Some(true),
+			creation_source: "api".to_string(),
 			created_at: Some(Utc::now()),
 			updated_at: Some(Utc::now()),
 		}
@@ -86,6 +88,11 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn with_creation_source(mut self, creation_source: impl Into<String>) -> Self {
+		self.creation_source = creation_source.into();
+		self
+	}
+
 	pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
 		self.created_at = Some(created_at);
 		self
@@ -106,6 +113,7 @@ impl TriggerBuilder {
 			trigger_type: self.trigger_type,
 			configuration: self.configuration,
 			is_active: self.is_active,
+			creation_source: self.creation_source,
 			created_at: self.created_at,
 			updated_at: self.updated_at,
 		}
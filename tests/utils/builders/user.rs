@@ -8,6 +8,7 @@ pub struct UserBuilder {
 	email: String,
 	password_hash: String,
 	is_active: bool,
+	email_verified: bool,
 	created_at: Option<DateTime<Utc>>,
 	updated_at: Option<DateTime<Utc>>,
 }
@@ -20,6 +21,7 @@ impl Default for UserBuilder {
 			// Default hash for password "password123"
 			password_hash: "$argon2id$v=19$m=65536,t=3,p=4$abcd1234$hash".to_string(),
 			is_active: true,
+			email_verified: true,
 			created_at: Some(Utc::now()),
 			updated_at: Some(Utc::now()),
 		}
@@ -51,6 +53,11 @@ impl UserBuilder {
 		self
 	}
 
+	pub fn with_email_verified(mut self, email_verified: bool) -> Self {
+		self.email_verified = email_verified;
+		self
+	}
+
 	pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
 		self.created_at = Some(created_at);
 		self
@@ -67,6 +74,7 @@ impl UserBuilder {
 			email: self.email,
 			password_hash: self.password_hash,
 			is_active: self.is_active,
+			email_verified: self.email_verified,
 			created_at: self.created_at,
 			updated_at: self.updated_at,
 		}
@@ -0,0 +1,28 @@
+use stellar_monitor_tenant_isolation::{
+	models::{TenantQuotas, TenantRole},
+	utils::{AuthenticatedUser, TenantContext},
+};
+use uuid::Uuid;
+
+/// Build a tenant context for a user with the given role
+pub fn user_context(tenant_id: Uuid, role: TenantRole) -> TenantContext {
+	TenantContext::with_user(
+		tenant_id,
+		AuthenticatedUser {
+			id: Uuid::new_v4(),
+			email: "user@example.com".to_string(),
+			role,
+		},
+		TenantQuotas::default(),
+	)
+}
+
+/// Build a tenant context for an owner of the tenant
+pub fn owner_context(tenant_id: Uuid) -> TenantContext {
+	user_context(tenant_id, TenantRole::Owner)
+}
+
+/// Build a tenant context authenticated through an API key
+pub fn api_key_context(tenant_id: Uuid, api_key_id: Uuid) -> TenantContext {
+	TenantContext::with_api_key(tenant_id, api_key_id, TenantQuotas::default())
+}
@@ -65,6 +65,7 @@ pub fn evm_monitor_config() -> serde_json::Value {
 pub fn stellar_network_config() -> serde_json::Value {
 	json!({
 		"rpc_url": "https://horizon-testnet.stellar.org",
+		"network_passphrase": "Test SDF Network ; September 2015",
 		"chain_id": "testnet",
 		"block_time": 5,
 		"max_retries": 3,
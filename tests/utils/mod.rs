@@ -1,9 +1,11 @@
 pub mod builders;
+pub mod context;
 pub mod database;
 pub mod fixtures;
 pub mod helpers;
 
 pub use builders::*;
+pub use context::*;
 pub use database::*;
 pub use fixtures::*;
 pub use helpers::*;